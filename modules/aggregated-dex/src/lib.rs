@@ -24,11 +24,14 @@
 
 use frame_support::{pallet_prelude::*, transactional};
 use frame_system::pallet_prelude::*;
-use module_support::{AggregatedSwapPath, DEXManager, RebasedStableAssetError, Swap, SwapLimit};
-use nutsfinance_stable_asset::traits::StableAsset as StableAssetT;
-use primitives::{Balance, CurrencyId};
-use sp_runtime::traits::{Convert, Zero};
-use sp_std::{marker::PhantomData, vec::Vec};
+use module_support::{AggregatedSwapPath, DEXManager, RebasedStableAssetError, StableAssetQuote, Swap, SwapLimit};
+use nutsfinance_stable_asset::{traits::StableAsset as StableAssetT, StableAssetPoolId};
+use primitives::{Balance, CurrencyId, TradingPair};
+use sp_runtime::{
+	traits::{Convert, Zero},
+	PerThing, Permill, RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
 
 mod mock;
 mod tests;
@@ -39,6 +42,33 @@ pub use weights::WeightInfo;
 
 pub type SwapPath = AggregatedSwapPath<CurrencyId>;
 
+/// The proportions of the supply amount tried on the best path when splitting an order across
+/// two paths. The rest goes to the second path.
+const SPLIT_PROPORTIONS: [Permill; 3] = [
+	Permill::from_percent(25),
+	Permill::from_percent(50),
+	Permill::from_percent(75),
+];
+
+/// A route found by the router. The supply amount is executed on at most two paths which do not
+/// share any liquidity pool.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SwapRoute {
+	/// The aggregated swap paths and the supply amount of each path.
+	pub paths: Vec<(Vec<SwapPath>, Balance)>,
+	/// The total supply amount.
+	pub supply_amount: Balance,
+	/// The total target amount.
+	pub target_amount: Balance,
+}
+
+/// The liquidity pool used by a segment of aggregated swap path.
+#[derive(Eq, PartialEq)]
+enum RoutePool {
+	Dex(TradingPair),
+	Taiga(StableAssetPoolId),
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -57,6 +87,9 @@ pub mod module {
 			BlockNumber = BlockNumberFor<Self>,
 		>;
 
+		/// Quotes of Taiga pools
+		type StableAssetQuote: StableAssetQuote<CurrencyId, Balance, Self::AccountId, BlockNumberFor<Self>>;
+
 		/// Origin represented Governance
 		type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
@@ -68,6 +101,14 @@ pub mod module {
 		#[pallet::constant]
 		type SwapPathLimit: Get<u32>;
 
+		/// The maximum number of candidate paths evaluated by the router
+		#[pallet::constant]
+		type MaxRouteCandidates: Get<u32>;
+
+		/// The maximum number of liquidity pools looked up by the router to find and quote a route
+		#[pallet::constant]
+		type MaxRouteSearchNodes: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -176,6 +217,30 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Swap with aggregated DEX at exact supply amount, along the best route found by the
+		/// router across DEX and Taiga pools.
+		///
+		/// - `supply_currency_id`: the currency to supply.
+		/// - `target_currency_id`: the currency to receive.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::route_swap(T::MaxRouteSearchNodes::get()))]
+		pub fn swap_with_best_route(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let limit = SwapLimit::ExactSupply(supply_amount, min_target_amount);
+			let route =
+				Self::get_best_route(supply_currency_id, target_currency_id, limit).ok_or(Error::<T>::CannotSwap)?;
+			let _ = Self::do_route_swap(&who, &route, limit)?;
+			Ok(())
+		}
 	}
 }
 
@@ -258,15 +323,12 @@ impl<T: Config> Pallet<T> {
 						}
 						SwapPath::Taiga(pool_id, supply_asset_index, target_asset_index) => {
 							// use the output of the previous swap as input.
-							let (_, actual_output_amount) = T::StableAsset::get_swap_output_amount(
+							output_amount = T::StableAssetQuote::get_swap_output_amount(
 								*pool_id,
 								*supply_asset_index,
 								*target_asset_index,
 								output_amount,
-							)
-							.map(|result| (result.dx, result.dy))?;
-
-							output_amount = actual_output_amount;
+							)?;
 						}
 					}
 				}
@@ -291,15 +353,12 @@ impl<T: Config> Pallet<T> {
 						}
 						SwapPath::Taiga(pool_id, supply_asset_index, target_asset_index) => {
 							// calculate the input amount
-							let (actual_input_amount, _) = T::StableAsset::get_swap_input_amount(
+							input_amount = T::StableAssetQuote::get_swap_input_amount(
 								*pool_id,
 								*supply_asset_index,
 								*target_asset_index,
 								input_amount,
-							)
-							.map(|result| (result.dx, result.dy))?;
-
-							input_amount = actual_input_amount;
+							)?;
 						}
 					}
 				}
@@ -378,6 +437,357 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	/// Enumerate the candidate aggregated swap paths from `supply_currency_id` to
+	/// `target_currency_id`. Paths mix DEX and Taiga segments, have at most `SwapPathLimit`
+	/// segments and never visit a currency twice. At most `MaxRouteCandidates` paths are returned,
+	/// after looking up at most `MaxRouteSearchNodes` liquidity pools.
+	pub fn get_route_candidates(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Vec<Vec<SwapPath>> {
+		Self::search_routes(
+			supply_currency_id,
+			target_currency_id,
+			&mut T::MaxRouteSearchNodes::get(),
+		)
+	}
+
+	/// Spend `lookups` of the `remaining` liquidity pool lookups of the router. Returns false if
+	/// not enough lookups remain.
+	fn spend_route_lookups(remaining: &mut u32, lookups: u32) -> bool {
+		if *remaining < lookups {
+			return false;
+		}
+		*remaining -= lookups;
+		true
+	}
+
+	fn search_routes(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		remaining_lookups: &mut u32,
+	) -> Vec<Vec<SwapPath>> {
+		let mut candidates: Vec<Vec<SwapPath>> = vec![];
+		if supply_currency_id == target_currency_id {
+			return candidates;
+		}
+
+		let mut pools: Vec<(StableAssetPoolId, Vec<CurrencyId>)> = vec![];
+		for pool_id in 0..T::StableAsset::pool_count() {
+			if !Self::spend_route_lookups(remaining_lookups, 1) {
+				break;
+			}
+			if let Some(pool_info) = T::StableAsset::pool(pool_id) {
+				pools.push((pool_id, pool_info.assets));
+			}
+		}
+
+		Self::search_route_candidates(
+			supply_currency_id,
+			target_currency_id,
+			&pools,
+			&mut vec![supply_currency_id],
+			&mut vec![],
+			&mut candidates,
+			remaining_lookups,
+		);
+
+		candidates
+	}
+
+	/// Depth-first search for the candidate paths from `currency_id`, which is the output of
+	/// `current` path.
+	fn search_route_candidates(
+		currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		pools: &[(StableAssetPoolId, Vec<CurrencyId>)],
+		visited: &mut Vec<CurrencyId>,
+		current: &mut Vec<SwapPath>,
+		candidates: &mut Vec<Vec<SwapPath>>,
+		remaining_lookups: &mut u32,
+	) {
+		if current.len() >= T::SwapPathLimit::get() as usize || remaining_lookups.is_zero() {
+			return;
+		}
+
+		let mut next_segments: Vec<(SwapPath, CurrencyId)> = vec![];
+
+		// consecutive DEX segments are already covered by the DEX path with joints.
+		if !matches!(current.last(), Some(SwapPath::Dex(_))) {
+			// a DEX segment must end at the target or at an asset of Taiga pools to continue.
+			let mut outputs: Vec<CurrencyId> = vec![target_currency_id];
+			for (_, assets) in pools {
+				for asset in assets {
+					if !outputs.contains(asset) {
+						outputs.push(*asset);
+					}
+				}
+			}
+
+			for output in outputs {
+				if !visited.contains(&output) {
+					for dex_path in Self::get_dex_segments(currency_id, output, visited, remaining_lookups) {
+						next_segments.push((SwapPath::Dex(dex_path), output));
+					}
+				}
+			}
+		}
+
+		let previous_pool_id = match current.last() {
+			Some(SwapPath::Taiga(pool_id, _, _)) => Some(*pool_id),
+			_ => None,
+		};
+		for (pool_id, assets) in pools {
+			if previous_pool_id == Some(*pool_id) {
+				continue;
+			}
+			if let Some(input_index) = assets.iter().position(|asset| *asset == currency_id) {
+				for (output_index, output) in assets.iter().enumerate() {
+					if output_index != input_index && !visited.contains(output) {
+						next_segments.push((
+							SwapPath::Taiga(*pool_id, input_index as u32, output_index as u32),
+							*output,
+						));
+					}
+				}
+			}
+		}
+
+		for (segment, output) in next_segments {
+			if candidates.len() >= T::MaxRouteCandidates::get() as usize {
+				return;
+			}
+
+			current.push(segment);
+			if output == target_currency_id {
+				candidates.push(current.clone());
+			} else {
+				visited.push(output);
+				Self::search_route_candidates(
+					output,
+					target_currency_id,
+					pools,
+					visited,
+					current,
+					candidates,
+					remaining_lookups,
+				);
+				visited.pop();
+			}
+			current.pop();
+		}
+	}
+
+	/// The DEX paths from `supply_currency_id` to `target_currency_id`, directly or through the
+	/// joints of `DexSwapJointList`, whose liquidity pools all have liquidity.
+	fn get_dex_segments(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		visited: &[CurrencyId],
+		remaining_lookups: &mut u32,
+	) -> Vec<Vec<CurrencyId>> {
+		let mut has_liquidity = |currency_id_a: CurrencyId, currency_id_b: CurrencyId| -> bool {
+			if !Self::spend_route_lookups(remaining_lookups, 1) {
+				return false;
+			}
+			let (pool_a, pool_b) = T::DEX::get_liquidity_pool(currency_id_a, currency_id_b);
+			!pool_a.is_zero() && !pool_b.is_zero()
+		};
+
+		let mut segments: Vec<Vec<CurrencyId>> = vec![];
+		if has_liquidity(supply_currency_id, target_currency_id) {
+			segments.push(vec![supply_currency_id, target_currency_id]);
+		}
+
+		for joint in T::DexSwapJointList::get() {
+			if joint.is_empty()
+				|| joint
+					.iter()
+					.any(|currency_id| *currency_id == target_currency_id || visited.contains(currency_id))
+			{
+				continue;
+			}
+
+			let mut dex_path = vec![supply_currency_id];
+			dex_path.extend(joint);
+			dex_path.push(target_currency_id);
+
+			if dex_path.windows(2).all(|pair| has_liquidity(pair[0], pair[1])) {
+				segments.push(dex_path);
+			}
+		}
+
+		segments
+	}
+
+	fn get_route_pools(paths: &[SwapPath]) -> Vec<RoutePool> {
+		let mut route_pools: Vec<RoutePool> = vec![];
+		for path in paths {
+			match path {
+				SwapPath::Dex(dex_path) => {
+					for pair in dex_path.windows(2) {
+						if let Some(trading_pair) = TradingPair::from_currency_ids(pair[0], pair[1]) {
+							route_pools.push(RoutePool::Dex(trading_pair));
+						}
+					}
+				}
+				SwapPath::Taiga(pool_id, _, _) => route_pools.push(RoutePool::Taiga(*pool_id)),
+			}
+		}
+		route_pools
+	}
+
+	/// The liquidity pool lookups to quote `paths`.
+	fn get_route_lookups(paths: &[SwapPath]) -> u32 {
+		Self::get_route_pools(paths).len() as u32
+	}
+
+	/// Find the best route from `supply_currency_id` to `target_currency_id` among the candidate
+	/// paths. For `ExactSupply`, also try to split the supply amount across the best path and the
+	/// best other path which shares no liquidity pool with it.
+	///
+	/// Finding the candidates and quoting them share the budget of `MaxRouteSearchNodes` liquidity
+	/// pool lookups. Quoting a path looks up each of its liquidity pools.
+	pub fn get_best_route(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Option<SwapRoute> {
+		let mut remaining_lookups = T::MaxRouteSearchNodes::get();
+		let candidates = Self::search_routes(supply_currency_id, target_currency_id, &mut remaining_lookups);
+
+		match limit {
+			SwapLimit::ExactSupply(supply_amount, min_target_amount) => {
+				let mut quotes: Vec<(Vec<SwapPath>, Balance)> = vec![];
+				for paths in candidates {
+					if !Self::spend_route_lookups(&mut remaining_lookups, Self::get_route_lookups(&paths)) {
+						break;
+					}
+					if let Some((_, target_amount)) =
+						Self::get_aggregated_swap_amount(&paths, SwapLimit::ExactSupply(supply_amount, Zero::zero()))
+					{
+						quotes.push((paths, target_amount));
+					}
+				}
+				// the best path first, stable for the paths with the same target amount.
+				quotes.sort_by(|a, b| b.1.cmp(&a.1));
+
+				let (best_paths, best_target_amount) = quotes.first()?.clone();
+				let mut route = SwapRoute {
+					paths: vec![(best_paths.clone(), supply_amount)],
+					supply_amount,
+					target_amount: best_target_amount,
+				};
+
+				let best_pools = Self::get_route_pools(&best_paths);
+				if let Some((other_paths, _)) = quotes.iter().skip(1).find(|(paths, _)| {
+					Self::get_route_pools(paths)
+						.iter()
+						.all(|route_pool| !best_pools.contains(route_pool))
+				}) {
+					for proportion in SPLIT_PROPORTIONS {
+						if !Self::spend_route_lookups(
+							&mut remaining_lookups,
+							Self::get_route_lookups(&best_paths).saturating_add(Self::get_route_lookups(other_paths)),
+						) {
+							break;
+						}
+
+						let best_supply_amount = proportion.mul_floor(supply_amount);
+						let other_supply_amount = supply_amount.saturating_sub(best_supply_amount);
+
+						if let (Some((_, best_target_amount)), Some((_, other_target_amount))) = (
+							Self::get_aggregated_swap_amount(
+								&best_paths,
+								SwapLimit::ExactSupply(best_supply_amount, Zero::zero()),
+							),
+							Self::get_aggregated_swap_amount(
+								other_paths,
+								SwapLimit::ExactSupply(other_supply_amount, Zero::zero()),
+							),
+						) {
+							let target_amount = best_target_amount.saturating_add(other_target_amount);
+							if target_amount > route.target_amount {
+								route = SwapRoute {
+									paths: vec![
+										(best_paths.clone(), best_supply_amount),
+										(other_paths.clone(), other_supply_amount),
+									],
+									supply_amount,
+									target_amount,
+								};
+							}
+						}
+					}
+				}
+
+				if route.target_amount >= min_target_amount {
+					Some(route)
+				} else {
+					None
+				}
+			}
+			SwapLimit::ExactTarget(_, _) => {
+				let mut best_route: Option<SwapRoute> = None;
+				for paths in candidates {
+					// the quote of exact target goes through the paths twice.
+					if !Self::spend_route_lookups(
+						&mut remaining_lookups,
+						Self::get_route_lookups(&paths).saturating_mul(2),
+					) {
+						break;
+					}
+					if let Some((supply_amount, target_amount)) = Self::get_aggregated_swap_amount(&paths, limit) {
+						if best_route
+							.as_ref()
+							.map_or(true, |route| supply_amount < route.supply_amount)
+						{
+							best_route = Some(SwapRoute {
+								paths: vec![(paths, supply_amount)],
+								supply_amount,
+								target_amount,
+							});
+						}
+					}
+				}
+				best_route
+			}
+		}
+	}
+
+	/// Swap along the `route` found by the router.
+	#[transactional]
+	fn do_route_swap(
+		who: &T::AccountId,
+		route: &SwapRoute,
+		limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		match limit {
+			SwapLimit::ExactSupply(_, min_target_amount) => {
+				let mut supply_amount: Balance = Zero::zero();
+				let mut target_amount: Balance = Zero::zero();
+
+				for (paths, path_supply_amount) in route.paths.iter() {
+					let (actual_supply, actual_target) = Self::do_aggregated_swap(
+						who,
+						paths,
+						SwapLimit::ExactSupply(*path_supply_amount, Zero::zero()),
+					)?;
+
+					supply_amount = supply_amount.saturating_add(actual_supply);
+					target_amount = target_amount.saturating_add(actual_target);
+				}
+
+				// the result must meet the swap_limit.
+				ensure!(target_amount >= min_target_amount, Error::<T>::CannotSwap);
+
+				Ok((supply_amount, target_amount))
+			}
+			// the route of `ExactTarget` is never split.
+			SwapLimit::ExactTarget(_, _) => {
+				let (paths, _) = route.paths.first().ok_or(Error::<T>::CannotSwap)?;
+				Self::do_aggregated_swap(who, paths, limit)
+			}
+		}
+	}
 }
 
 /// Swap by Acala DEX which has specific joints.
@@ -450,12 +860,11 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for TaigaSwap<T> {
 				let (pool_id, input_index, output_index, _) =
 					T::StableAsset::get_best_route(supply_currency_id, target_currency_id, supply_amount)?;
 
-				if let Some((input_amount, output_amount)) =
-					T::StableAsset::get_swap_output_amount(pool_id, input_index, output_index, supply_amount)
-						.map(|result| (result.dx, result.dy))
+				if let Some(output_amount) =
+					T::StableAssetQuote::get_swap_output_amount(pool_id, input_index, output_index, supply_amount)
 				{
 					if output_amount >= min_target_amount {
-						return Some((input_amount, output_amount));
+						return Some((supply_amount, output_amount));
 					}
 				}
 			}
@@ -463,9 +872,8 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for TaigaSwap<T> {
 				let (pool_id, input_index, output_index, _) =
 					T::StableAsset::get_best_route(supply_currency_id, target_currency_id, max_supply_amount)?;
 
-				if let Some((input_amount, _)) =
-					T::StableAsset::get_swap_input_amount(pool_id, input_index, output_index, target_amount)
-						.map(|result| (result.dx, result.dy))
+				if let Some(input_amount) =
+					T::StableAssetQuote::get_swap_input_amount(pool_id, input_index, output_index, target_amount)
 				{
 					if !input_amount.is_zero() && input_amount <= max_supply_amount {
 						// actually swap by `ExactSupply` limit
@@ -738,6 +1146,72 @@ impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for AggregatedSwap<T> {
 	}
 }
 
+/// Choose the better price between `AggregatedSwap` and the best route found by the router,
+/// which searches mixed DEX and Taiga paths and may split the order across two paths.
+pub struct RoutedSwap<T>(PhantomData<T>);
+
+impl<T: Config> RoutedSwap<T> {
+	fn get_swap_params(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> (Option<(Balance, Balance)>, Option<SwapRoute>) {
+		let aggregated_swap_result =
+			AggregatedSwap::<T>::get_swap_amount(supply_currency_id, target_currency_id, limit);
+		let route = Pallet::<T>::get_best_route(supply_currency_id, target_currency_id, limit);
+
+		let route_is_better = match (aggregated_swap_result, &route) {
+			(Some((supply_amount, target_amount)), Some(route)) => match limit {
+				SwapLimit::ExactSupply(_, _) => route.target_amount > target_amount,
+				SwapLimit::ExactTarget(_, _) => route.supply_amount < supply_amount,
+			},
+			(None, Some(_)) => true,
+			_ => false,
+		};
+
+		if route_is_better {
+			(None, route)
+		} else {
+			(aggregated_swap_result, None)
+		}
+	}
+}
+
+impl<T: Config> Swap<T::AccountId, Balance, CurrencyId> for RoutedSwap<T> {
+	fn get_swap_amount(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Option<(Balance, Balance)> {
+		match Self::get_swap_params(supply_currency_id, target_currency_id, limit) {
+			(_, Some(route)) => Some((route.supply_amount, route.target_amount)),
+			(aggregated_swap_result, None) => aggregated_swap_result,
+		}
+	}
+
+	fn swap(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> sp_std::result::Result<(Balance, Balance), DispatchError> {
+		match Self::get_swap_params(supply_currency_id, target_currency_id, limit) {
+			(_, Some(route)) => Pallet::<T>::do_route_swap(who, &route, limit),
+			(Some(_), None) => AggregatedSwap::<T>::swap(who, supply_currency_id, target_currency_id, limit),
+			(None, None) => Err(Error::<T>::CannotSwap.into()),
+		}
+	}
+
+	// RoutedSwap support swap by aggregated path.
+	fn swap_by_aggregated_path(
+		who: &T::AccountId,
+		swap_path: &[SwapPath],
+		limit: SwapLimit<Balance>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		AggregatedSwap::<T>::swap_by_aggregated_path(who, swap_path, limit)
+	}
+}
+
 pub struct RebasedStableAssetErrorConvertor<T>(PhantomData<T>);
 impl<T: Config> Convert<RebasedStableAssetError, DispatchError> for RebasedStableAssetErrorConvertor<T> {
 	fn convert(e: RebasedStableAssetError) -> DispatchError {
@@ -27,11 +27,15 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
-pub use module_support::{ExchangeRate, RebasedStableAsset};
+pub use module_support::{ExchangeRate, RebasedStableAsset, StableAssetQuoter};
 use orml_tokens::ConvertBalance;
 pub use orml_traits::{parameter_type_with_key, MultiCurrency};
 use primitives::{Amount, TokenSymbol, TradingPair};
-use sp_runtime::{testing::H256, traits::IdentityLookup, AccountId32, ArithmeticError, BuildStorage, FixedPointNumber};
+use sp_runtime::{
+	testing::H256,
+	traits::{AccountIdConversion, IdentityLookup},
+	AccountId32, ArithmeticError, BuildStorage, FixedPointNumber,
+};
 
 pub type AccountId = AccountId32;
 
@@ -185,15 +189,21 @@ impl nutsfinance_stable_asset::Config for Runtime {
 
 parameter_types! {
 	pub static DexSwapJointList: Vec<Vec<CurrencyId>> = vec![];
+	pub static MaxRouteSearchNodes: u32 = 64;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
+	pub StableAssetQuoteAccount: AccountId = StableAssetPalletId::get().into_sub_account_truncating(b"quote");
 }
 
 impl Config for Runtime {
 	type DEX = Dex;
 	type StableAsset = StableAssetWrapper;
+	type StableAssetQuote =
+		StableAssetQuoter<StableAssetWrapper, RebaseTokens, ConvertBalanceHoma, StableAssetQuoteAccount>;
 	type GovernanceOrigin = EnsureSignedBy<Admin, AccountId>;
 	type DexSwapJointList = DexSwapJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxRouteCandidates = ConstU32<16>;
+	type MaxRouteSearchNodes = MaxRouteSearchNodes;
 	type WeightInfo = ();
 }
 
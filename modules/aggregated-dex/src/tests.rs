@@ -1301,3 +1301,264 @@ fn aggregated_swap_swap_work() {
 		);
 	});
 }
+
+#[test]
+fn get_route_candidates_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, LDOT),
+			Vec::<Vec<SwapPath>>::new()
+		);
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, DOT),
+			Vec::<Vec<SwapPath>>::new()
+		);
+
+		assert_ok!(inject_liquidity(DOT, LDOT, 1_000_000u128, 10_000_000u128));
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 100_000_000u128));
+		assert_ok!(inject_liquidity(AUSD, LDOT, 100_000_000u128, 10_000_000u128));
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, LDOT),
+			vec![vec![SwapPath::Dex(vec![DOT, LDOT])]]
+		);
+
+		set_dex_swap_joint_list(vec![vec![AUSD]]);
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, LDOT),
+			vec![
+				vec![SwapPath::Dex(vec![DOT, LDOT])],
+				vec![SwapPath::Dex(vec![DOT, AUSD, LDOT])]
+			]
+		);
+		// the joint can not be the target
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, AUSD),
+			vec![vec![SwapPath::Dex(vec![DOT, AUSD])]]
+		);
+	});
+}
+
+#[test]
+fn get_route_candidates_mix_dex_and_taiga() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(initial_taiga_dot_ldot_pool());
+		assert_ok!(inject_liquidity(LDOT, AUSD, 30_000_000_000u128, 60_000_000_000u128));
+
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, LDOT),
+			vec![vec![SwapPath::Taiga(0, 0, 1)]]
+		);
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, AUSD),
+			vec![vec![SwapPath::Taiga(0, 0, 1), SwapPath::Dex(vec![LDOT, AUSD])]]
+		);
+		assert_eq!(
+			AggregatedDex::get_route_candidates(AUSD, DOT),
+			vec![vec![SwapPath::Dex(vec![AUSD, LDOT]), SwapPath::Taiga(0, 1, 0)]]
+		);
+
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, AUSD, SwapLimit::ExactSupply(3_000_000_000u128, 0)),
+			Some(SwapRoute {
+				paths: vec![(
+					vec![SwapPath::Taiga(0, 0, 1), SwapPath::Dex(vec![LDOT, AUSD])],
+					3_000_000_000u128
+				)],
+				supply_amount: 3_000_000_000u128,
+				target_amount: 29_992_618_334u128,
+			})
+		);
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, AUSD, SwapLimit::ExactSupply(3_000_000_000u128, 29_992_618_335u128)),
+			None
+		);
+	});
+}
+
+#[test]
+fn get_best_route_split_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			None
+		);
+
+		assert_ok!(inject_liquidity(DOT, LDOT, 1_000_000u128, 10_000_000u128));
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 100_000_000u128));
+		assert_ok!(inject_liquidity(AUSD, LDOT, 100_000_000u128, 10_000_000u128));
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Some(SwapRoute {
+				paths: vec![(vec![SwapPath::Dex(vec![DOT, LDOT])], 200_000u128)],
+				supply_amount: 200_000u128,
+				target_amount: 1_666_666u128,
+			})
+		);
+
+		set_dex_swap_joint_list(vec![vec![AUSD]]);
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Some(SwapRoute {
+				paths: vec![
+					(vec![SwapPath::Dex(vec![DOT, LDOT])], 150_000u128),
+					(vec![SwapPath::Dex(vec![DOT, AUSD, LDOT])], 50_000u128)
+				],
+				supply_amount: 200_000u128,
+				target_amount: 1_758_892u128,
+			})
+		);
+
+		// the route of exact target is never split
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactTarget(u128::MAX, 1_000_000u128)),
+			Some(SwapRoute {
+				paths: vec![(vec![SwapPath::Dex(vec![DOT, LDOT])], 111_112u128)],
+				supply_amount: 111_112u128,
+				target_amount: 1_000_000u128,
+			})
+		);
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactTarget(111_111u128, 1_000_000u128)),
+			None
+		);
+	});
+}
+
+#[test]
+fn swap_with_best_route_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AggregatedDex::swap_with_best_route(RuntimeOrigin::none(), DOT, LDOT, 200_000u128, 0),
+			BadOrigin
+		);
+		assert_noop!(
+			AggregatedDex::swap_with_best_route(RuntimeOrigin::signed(ALICE), DOT, LDOT, 200_000u128, 0),
+			Error::<Runtime>::CannotSwap
+		);
+
+		assert_ok!(inject_liquidity(DOT, LDOT, 1_000_000u128, 10_000_000u128));
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 100_000_000u128));
+		assert_ok!(inject_liquidity(AUSD, LDOT, 100_000_000u128, 10_000_000u128));
+		set_dex_swap_joint_list(vec![vec![AUSD]]);
+
+		assert_noop!(
+			AggregatedDex::swap_with_best_route(RuntimeOrigin::signed(ALICE), DOT, LDOT, 200_000u128, 1_758_893u128),
+			Error::<Runtime>::CannotSwap
+		);
+
+		assert_ok!(AggregatedDex::swap_with_best_route(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			LDOT,
+			200_000u128,
+			1_758_892u128
+		));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 99_999_800_000u128);
+		assert_eq!(Tokens::free_balance(LDOT, &ALICE), 1_758_892u128);
+		assert_eq!(Dex::get_liquidity_pool(DOT, LDOT), (1_150_000u128, 8_695_653u128));
+	});
+}
+
+#[test]
+fn swap_with_exact_supply_does_not_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(inject_liquidity(DOT, LDOT, 1_000_000u128, 10_000_000u128));
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 100_000_000u128));
+		assert_ok!(inject_liquidity(AUSD, LDOT, 100_000_000u128, 10_000_000u128));
+		set_dex_swap_joint_list(vec![vec![AUSD]]);
+
+		// the better split route is not used for the supplied path
+		assert_noop!(
+			AggregatedDex::swap_with_exact_supply(
+				RuntimeOrigin::signed(ALICE),
+				vec![SwapPath::Dex(vec![DOT, LDOT])],
+				200_000u128,
+				1_758_892u128
+			),
+			Error::<Runtime>::CannotSwap
+		);
+
+		assert_ok!(AggregatedDex::swap_with_exact_supply(
+			RuntimeOrigin::signed(ALICE),
+			vec![SwapPath::Dex(vec![DOT, LDOT])],
+			200_000u128,
+			1_666_666u128
+		));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 99_999_800_000u128);
+		assert_eq!(Tokens::free_balance(LDOT, &ALICE), 1_666_666u128);
+		assert_eq!(Dex::get_liquidity_pool(DOT, LDOT), (1_200_000u128, 8_333_334u128));
+		assert_eq!(Dex::get_liquidity_pool(DOT, AUSD), (1_000_000u128, 100_000_000u128));
+	});
+}
+
+#[test]
+fn route_search_is_bounded_by_max_route_search_nodes() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(inject_liquidity(DOT, LDOT, 1_000_000u128, 10_000_000u128));
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 100_000_000u128));
+		assert_ok!(inject_liquidity(AUSD, LDOT, 100_000_000u128, 10_000_000u128));
+		set_dex_swap_joint_list(vec![vec![AUSD]]);
+
+		// the path through the joint needs two more lookups
+		MaxRouteSearchNodes::set(1);
+		assert_eq!(
+			AggregatedDex::get_route_candidates(DOT, LDOT),
+			vec![vec![SwapPath::Dex(vec![DOT, LDOT])]]
+		);
+		// no lookup left to quote the candidate
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			None
+		);
+
+		// enough to quote the direct path, but not to try splitting
+		MaxRouteSearchNodes::set(6);
+		assert_eq!(
+			AggregatedDex::get_best_route(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Some(SwapRoute {
+				paths: vec![(vec![SwapPath::Dex(vec![DOT, LDOT])], 200_000u128)],
+				supply_amount: 200_000u128,
+				target_amount: 1_666_666u128,
+			})
+		);
+	});
+}
+
+#[test]
+fn routed_swap_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			RoutedSwap::<Runtime>::get_swap_amount(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			None
+		);
+		assert_noop!(
+			RoutedSwap::<Runtime>::swap(&ALICE, DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Error::<Runtime>::CannotSwap
+		);
+
+		assert_ok!(inject_liquidity(DOT, LDOT, 1_000_000u128, 10_000_000u128));
+		assert_ok!(inject_liquidity(DOT, AUSD, 1_000_000u128, 100_000_000u128));
+		assert_ok!(inject_liquidity(AUSD, LDOT, 100_000_000u128, 10_000_000u128));
+		set_dex_swap_joint_list(vec![vec![AUSD]]);
+
+		assert_eq!(
+			AggregatedSwap::<Runtime>::get_swap_amount(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Some((200_000u128, 1_666_666u128))
+		);
+		assert_eq!(
+			RoutedSwap::<Runtime>::get_swap_amount(DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Some((200_000u128, 1_758_892u128))
+		);
+		assert_eq!(
+			RoutedSwap::<Runtime>::get_swap_amount(DOT, LDOT, SwapLimit::ExactTarget(u128::MAX, 1_000_000u128)),
+			AggregatedSwap::<Runtime>::get_swap_amount(DOT, LDOT, SwapLimit::ExactTarget(u128::MAX, 1_000_000u128))
+		);
+
+		assert_eq!(
+			RoutedSwap::<Runtime>::swap(&ALICE, DOT, LDOT, SwapLimit::ExactSupply(200_000u128, 0)),
+			Ok((200_000u128, 1_758_892u128))
+		);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 99_999_800_000u128);
+		assert_eq!(Tokens::free_balance(LDOT, &ALICE), 1_758_892u128);
+	});
+}
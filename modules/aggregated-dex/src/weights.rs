@@ -49,6 +49,7 @@ pub trait WeightInfo {
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
 	fn update_aggregated_swap_paths(u: u32, ) -> Weight;
+	fn route_swap(n: u32, ) -> Weight;
 }
 
 /// Weights for module_aggregated_dex using the Acala node and recommended hardware.
@@ -79,6 +80,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	fn route_swap(n: u32, ) -> Weight {
+		Weight::from_parts(112_630_000, 0)
+			// Standard Error: 96_000
+			.saturating_add(Weight::from_parts(9_874_000, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -108,4 +117,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
+	fn route_swap(n: u32, ) -> Weight {
+		Weight::from_parts(112_630_000, 0)
+			// Standard Error: 96_000
+			.saturating_add(Weight::from_parts(9_874_000, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
 }
@@ -27,10 +27,12 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, EnsureOrigin},
+	traits::{BalanceStatus, Contains, Currency, EnsureOrigin, ReservableCurrency},
 };
 use frame_system::pallet_prelude::*;
-use module_support::{AssetIdMapping, BuyWeightRate, EVMBridge, Erc20InfoMapping, InvokeContext, Ratio};
+use module_support::{
+	AssetIdMapping, BuyWeightRate, DEXPriceProvider, EVMBridge, Erc20InfoMapping, InvokeContext, Ratio,
+};
 use primitives::{
 	currency::{
 		AssetIds, AssetMetadata, CurrencyIdType, DexShare, DexShareType, Erc20Id, ForeignAssetId, Lease,
@@ -44,7 +46,10 @@ use primitives::{
 	CurrencyId,
 };
 use scale_info::prelude::format;
-use sp_runtime::{traits::One, ArithmeticError, FixedPointNumber, FixedU128};
+use sp_runtime::{
+	traits::{Convert, One, Saturating, Zero},
+	ArithmeticError, FixedPointNumber, FixedU128,
+};
 use sp_std::{boxed::Box, vec::Vec};
 
 use xcm::{v3::prelude::*, VersionedMultiLocation};
@@ -59,6 +64,16 @@ pub use weights::WeightInfo;
 /// Type alias for currency balance.
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// An asset registered permissionlessly, which stays unverified until governance verifies or
+/// rejects it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UnverifiedAsset<AccountId, Balance> {
+	/// The account that registered the asset.
+	pub depositor: AccountId,
+	/// The deposit reserved from the depositor.
+	pub deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -69,7 +84,7 @@ pub mod module {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Currency type for withdraw and balance storage.
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// The Currency ID for the staking currency
 		#[pallet::constant]
@@ -81,6 +96,19 @@ pub mod module {
 		/// Required origin for registering asset.
 		type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The deposit reserved for registering an asset permissionlessly.
+		#[pallet::constant]
+		type RegistrationDeposit: Get<BalanceOf<Self>>;
+
+		/// The account which receives the deposit of rejected assets.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Whether an asset is still in use, e.g. it has issuance or a DEX trading pair. Assets in
+		/// use can't be deregistered. It is checked by the signed `deregister_asset` at a fixed
+		/// weight, so it must read a bounded number of storage items.
+		type AssetInUse: Contains<CurrencyId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -96,6 +124,14 @@ pub mod module {
 		AssetIdNotExists,
 		/// AssetId exists
 		AssetIdExisted,
+		/// The asset is not an unverified asset
+		AssetNotUnverified,
+		/// The caller is not the depositor of the asset
+		NotDepositor,
+		/// Unverified assets can't be used for this
+		AssetUnverified,
+		/// The asset is still in use and can't be deregistered
+		AssetInUse,
 	}
 
 	#[pallet::event]
@@ -123,6 +159,32 @@ pub mod module {
 			asset_id: AssetIds,
 			metadata: AssetMetadata<BalanceOf<T>>,
 		},
+		/// Paying XCM fee with DEX price enabled or disabled for the asset.
+		DexPricedFeeAssetUpdated { currency_id: CurrencyId, enabled: bool },
+		/// The asset registered permissionlessly and is unverified.
+		UnverifiedAssetRegistered {
+			asset_id: AssetIds,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		/// The unverified asset verified and the deposit unreserved.
+		AssetVerified {
+			asset_id: AssetIds,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		/// The unverified asset rejected and the deposit slashed.
+		AssetRejected {
+			asset_id: AssetIds,
+			depositor: T::AccountId,
+			slashed: BalanceOf<T>,
+		},
+		/// The unverified asset deregistered and the deposit refunded.
+		AssetDeregistered {
+			asset_id: AssetIds,
+			depositor: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
 	}
 
 	/// Next available Foreign AssetId ID.
@@ -168,6 +230,21 @@ pub mod module {
 	pub type AssetMetadatas<T: Config> =
 		StorageMap<_, Twox64Concat, AssetIds, AssetMetadata<BalanceOf<T>>, OptionQuery>;
 
+	/// The assets allowed to pay XCM fee priced by the DEX.
+	///
+	/// DexPricedFeeAssets: map CurrencyId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn dex_priced_fee_assets)]
+	pub type DexPricedFeeAssets<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, bool, ValueQuery>;
+
+	/// The assets registered permissionlessly and not verified yet.
+	///
+	/// UnverifiedAssets: map AssetIds => Option<UnverifiedAsset>
+	#[pallet::storage]
+	#[pallet::getter(fn unverified_assets)]
+	pub type UnverifiedAssets<T: Config> =
+		StorageMap<_, Twox64Concat, AssetIds, UnverifiedAsset<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
@@ -344,6 +421,132 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_dex_priced_fee_asset())]
+		pub fn set_dex_priced_fee_asset(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			enabled: bool,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			if enabled {
+				ensure!(!Self::is_unverified(currency_id), Error::<T>::AssetUnverified);
+				DexPricedFeeAssets::<T>::insert(currency_id, true);
+			} else {
+				DexPricedFeeAssets::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::<T>::DexPricedFeeAssetUpdated { currency_id, enabled });
+			Ok(())
+		}
+
+		/// Register a foreign asset by reserving `RegistrationDeposit`. The asset is unverified
+		/// until `RegisterOrigin` verifies it.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::register_foreign_asset_with_deposit())]
+		pub fn register_foreign_asset_with_deposit(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			metadata: Box<AssetMetadata<BalanceOf<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			let foreign_asset_id = Self::do_register_foreign_asset(&location, &metadata)?;
+			Self::do_reserve_registration_deposit(who, AssetIds::ForeignAssetId(foreign_asset_id))?;
+
+			Self::deposit_event(Event::<T>::ForeignAssetRegistered {
+				asset_id: foreign_asset_id,
+				asset_address: location,
+				metadata: *metadata,
+			});
+			Ok(())
+		}
+
+		/// Register an ERC20 asset by reserving `RegistrationDeposit`. The asset is unverified
+		/// until `RegisterOrigin` verifies it.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::register_erc20_asset_with_deposit())]
+		pub fn register_erc20_asset_with_deposit(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			minimal_balance: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let metadata = Self::do_register_erc20_asset(contract, minimal_balance)?;
+			Self::do_reserve_registration_deposit(who, AssetIds::Erc20(contract))?;
+
+			Self::deposit_event(Event::<T>::AssetRegistered {
+				asset_id: AssetIds::Erc20(contract),
+				metadata,
+			});
+			Ok(())
+		}
+
+		/// Verify an unverified asset and unreserve the deposit of the depositor.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::verify_asset())]
+		pub fn verify_asset(origin: OriginFor<T>, asset_id: AssetIds) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let UnverifiedAsset { depositor, deposit } =
+				UnverifiedAssets::<T>::take(&asset_id).ok_or(Error::<T>::AssetNotUnverified)?;
+			T::Currency::unreserve(&depositor, deposit);
+
+			Self::deposit_event(Event::<T>::AssetVerified {
+				asset_id,
+				depositor,
+				deposit,
+			});
+			Ok(())
+		}
+
+		/// Reject an unverified asset, deregister it and slash the deposit to `TreasuryAccount`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::reject_asset())]
+		pub fn reject_asset(origin: OriginFor<T>, asset_id: AssetIds) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+
+			let UnverifiedAsset { depositor, deposit } =
+				UnverifiedAssets::<T>::take(&asset_id).ok_or(Error::<T>::AssetNotUnverified)?;
+			Self::do_deregister_asset(&asset_id)?;
+
+			let unslashed =
+				T::Currency::repatriate_reserved(&depositor, &T::TreasuryAccount::get(), deposit, BalanceStatus::Free)?;
+			let slashed = deposit.saturating_sub(unslashed);
+
+			Self::deposit_event(Event::<T>::AssetRejected {
+				asset_id,
+				depositor,
+				slashed,
+			});
+			Ok(())
+		}
+
+		/// Deregister an unverified asset by its depositor and refund the deposit.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::deregister_asset())]
+		pub fn deregister_asset(origin: OriginFor<T>, asset_id: AssetIds) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let UnverifiedAsset { depositor, deposit } =
+				UnverifiedAssets::<T>::get(&asset_id).ok_or(Error::<T>::AssetNotUnverified)?;
+			ensure!(who == depositor, Error::<T>::NotDepositor);
+
+			UnverifiedAssets::<T>::remove(&asset_id);
+			Self::do_deregister_asset(&asset_id)?;
+			T::Currency::unreserve(&depositor, deposit);
+
+			Self::deposit_event(Event::<T>::AssetDeregistered {
+				asset_id,
+				depositor,
+				deposit,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -522,6 +725,67 @@ impl<T: Config> Pallet<T> {
 			},
 		)
 	}
+
+	fn do_reserve_registration_deposit(depositor: T::AccountId, asset_id: AssetIds) -> DispatchResult {
+		let deposit = T::RegistrationDeposit::get();
+		T::Currency::reserve(&depositor, deposit)?;
+		UnverifiedAssets::<T>::insert(
+			&asset_id,
+			UnverifiedAsset {
+				depositor: depositor.clone(),
+				deposit,
+			},
+		);
+
+		Self::deposit_event(Event::<T>::UnverifiedAssetRegistered {
+			asset_id,
+			depositor,
+			deposit,
+		});
+		Ok(())
+	}
+
+	/// Remove a foreign or ERC20 asset from the registry. Only permissionlessly registered assets
+	/// can be deregistered, and only while they are not in use.
+	fn do_deregister_asset(asset_id: &AssetIds) -> DispatchResult {
+		let currency_id = match *asset_id {
+			AssetIds::ForeignAssetId(foreign_asset_id) => CurrencyId::ForeignAsset(foreign_asset_id),
+			AssetIds::Erc20(contract) => CurrencyId::Erc20(contract),
+			AssetIds::StableAssetId(_) | AssetIds::NativeAssetId(_) => {
+				return Err(Error::<T>::AssetNotUnverified.into());
+			}
+		};
+		ensure!(
+			!T::AssetInUse::contains(&currency_id) && !DexPricedFeeAssets::<T>::contains_key(currency_id),
+			Error::<T>::AssetInUse
+		);
+
+		AssetMetadatas::<T>::take(asset_id).ok_or(Error::<T>::AssetIdNotExists)?;
+		match *asset_id {
+			AssetIds::ForeignAssetId(foreign_asset_id) => {
+				if let Some(location) = ForeignAssetLocations::<T>::take(foreign_asset_id) {
+					LocationToCurrencyIds::<T>::remove(location);
+				}
+			}
+			AssetIds::Erc20(contract) => {
+				Erc20IdToAddress::<T>::remove(Into::<Erc20Id>::into(DexShare::Erc20(contract)));
+			}
+			AssetIds::StableAssetId(_) | AssetIds::NativeAssetId(_) => {}
+		}
+
+		Ok(())
+	}
+
+	/// Whether the currency is registered permissionlessly and not verified yet.
+	pub fn is_unverified(currency_id: CurrencyId) -> bool {
+		match currency_id {
+			CurrencyId::ForeignAsset(foreign_asset_id) => {
+				UnverifiedAssets::<T>::contains_key(AssetIds::ForeignAssetId(foreign_asset_id))
+			}
+			CurrencyId::Erc20(address) => UnverifiedAssets::<T>::contains_key(AssetIds::Erc20(address)),
+			_ => false,
+		}
+	}
 }
 
 pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
@@ -561,6 +825,9 @@ where
 {
 	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
 		if let Some(CurrencyId::ForeignAsset(foreign_asset_id)) = Pallet::<T>::location_to_currency_ids(location) {
+			if Pallet::<T>::is_unverified(CurrencyId::ForeignAsset(foreign_asset_id)) {
+				return None;
+			}
 			if let Some(asset_metadata) = Pallet::<T>::asset_metadatas(AssetIds::ForeignAssetId(foreign_asset_id)) {
 				let minimum_balance = asset_metadata.minimal_balance.into();
 				let rate = FixedU128::saturating_from_rational(minimum_balance, T::Currency::minimum_balance().into());
@@ -633,7 +900,9 @@ where
 	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
 		let currency = key_to_currency(location);
 		match currency {
-			Some(CurrencyId::Erc20(address)) if !is_system_contract(&address) => {
+			Some(CurrencyId::Erc20(address))
+				if !is_system_contract(&address) && !Pallet::<T>::is_unverified(CurrencyId::Erc20(address)) =>
+			{
 				if let Some(asset_metadata) = Pallet::<T>::asset_metadatas(AssetIds::Erc20(address)) {
 					let minimum_balance = asset_metadata.minimal_balance.into();
 					let rate =
@@ -649,6 +918,39 @@ where
 	}
 }
 
+/// Prices weight in any asset enabled in `DexPricedFeeAssets` by the DEX price against the
+/// native currency, plus a safety margin to cover price movements.
+pub struct BuyWeightRateOfDexPrice<T, C, P, NativeCurrencyId, SafetyMargin>(
+	sp_std::marker::PhantomData<(T, C, P, NativeCurrencyId, SafetyMargin)>,
+);
+
+impl<T, C, P, NativeCurrencyId, SafetyMargin> BuyWeightRate
+	for BuyWeightRateOfDexPrice<T, C, P, NativeCurrencyId, SafetyMargin>
+where
+	T: Config,
+	C: Convert<MultiLocation, Option<CurrencyId>>,
+	P: DEXPriceProvider<CurrencyId>,
+	NativeCurrencyId: Get<CurrencyId>,
+	SafetyMargin: Get<Ratio>,
+{
+	fn calculate_rate(location: MultiLocation) -> Option<Ratio> {
+		let currency_id = C::convert(location)?;
+		let native_currency_id = NativeCurrencyId::get();
+		if currency_id == native_currency_id
+			|| !Pallet::<T>::dex_priced_fee_assets(currency_id)
+			|| Pallet::<T>::is_unverified(currency_id)
+		{
+			return None;
+		}
+
+		// the amount of `currency_id` equivalent to a unit of native currency
+		let price = P::get_relative_price(native_currency_id, currency_id).filter(|p| !p.is_zero())?;
+		let rate = price.saturating_mul(Ratio::one().saturating_add(SafetyMargin::get()));
+		log::debug!(target: "asset-registry::weight", "DexPrice: {:?}, price: {:?}, rate:{:?}", currency_id, price, rate);
+		Some(rate)
+	}
+}
+
 pub struct EvmErc20InfoMapping<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> EvmErc20InfoMapping<T> {
@@ -23,16 +23,20 @@
 use crate as asset_registry;
 use frame_support::{
 	assert_ok, construct_runtime, ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU32, ConstU64, Everything},
+	traits::{ConstU128, ConstU32, ConstU64, Contains, Everything},
 };
 use frame_system::EnsureSignedBy;
-use module_support::{mocks::MockAddressMapping, AddressMapping};
+use module_support::{mocks::MockAddressMapping, AddressMapping, DEXPriceProvider, ExchangeRate, Ratio};
 use primitives::{
 	evm::convert_decimals_to_evm, evm::EvmAddress, AccountId, Balance, CurrencyId, ReserveIdentifier, TokenSymbol,
 };
 use sp_core::{H160, H256, U256};
-use sp_runtime::BuildStorage;
+use sp_runtime::{
+	traits::{Convert, Zero},
+	BuildStorage, FixedPointNumber,
+};
 use std::str::FromStr;
+use xcm::v3::prelude::*;
 
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = Everything;
@@ -119,6 +123,7 @@ impl module_evm::Config for Runtime {
 	type FindAuthor = ();
 	type Task = ();
 	type IdleScheduler = ();
+	type ContractPauseFilter = ();
 	type WeightInfo = ();
 }
 
@@ -128,16 +133,53 @@ impl module_evm_bridge::Config for Runtime {
 
 parameter_types! {
 	pub const KSMCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
+	pub static InUseCurrencies: Vec<CurrencyId> = vec![];
+}
+
+pub struct MockAssetInUse;
+impl Contains<CurrencyId> for MockAssetInUse {
+	fn contains(currency_id: &CurrencyId) -> bool {
+		InUseCurrencies::get().contains(currency_id)
+	}
 }
+
 impl asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type StakingCurrencyId = KSMCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type RegisterOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type RegistrationDeposit = ConstU128<1_000>;
+	type TreasuryAccount = TreasuryAccount;
+	type AssetInUse = MockAssetInUse;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const NativeCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+	pub XcmFeeSafetyMargin: Ratio = Ratio::saturating_from_rational(10, 100);
+}
+
+pub struct MockDEXPriceProvider;
+impl DEXPriceProvider<CurrencyId> for MockDEXPriceProvider {
+	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<ExchangeRate> {
+		match (base, quote) {
+			(CurrencyId::Token(TokenSymbol::ACA), CurrencyId::Token(TokenSymbol::AUSD)) => {
+				Some(ExchangeRate::saturating_from_rational(2, 1))
+			}
+			(CurrencyId::Token(TokenSymbol::ACA), CurrencyId::Token(TokenSymbol::LDOT)) => Some(ExchangeRate::zero()),
+			_ => None,
+		}
+	}
+}
+
+pub struct MockCurrencyIdConvert;
+impl Convert<MultiLocation, Option<CurrencyId>> for MockCurrencyIdConvert {
+	fn convert(location: MultiLocation) -> Option<CurrencyId> {
+		crate::key_to_currency(location)
+	}
+}
+
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 construct_runtime!(
@@ -24,10 +24,13 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	alice, deploy_contracts, deploy_contracts_same_prefix, erc20_address, erc20_address_not_exists,
-	erc20_address_same_prefix, AssetRegistry, CouncilAccount, ExtBuilder, Runtime, RuntimeEvent, RuntimeOrigin, System,
+	erc20_address_same_prefix, AssetRegistry, Balances, CouncilAccount, ExtBuilder, InUseCurrencies,
+	MockCurrencyIdConvert, MockDEXPriceProvider, NativeCurrencyId, Runtime, RuntimeEvent, RuntimeOrigin, System,
+	TreasuryAccount, XcmFeeSafetyMargin,
 };
 use primitives::TokenSymbol;
 use sp_core::H160;
+use sp_runtime::traits::BadOrigin;
 use std::str::{from_utf8, FromStr};
 
 #[test]
@@ -1275,3 +1278,340 @@ fn decode_evm_address_works() {
 			);
 		});
 }
+
+#[test]
+fn set_dex_priced_fee_asset_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ausd = CurrencyId::Token(TokenSymbol::AUSD);
+		assert_noop!(
+			AssetRegistry::set_dex_priced_fee_asset(RuntimeOrigin::signed(alice()), ausd, true),
+			BadOrigin
+		);
+
+		assert!(!AssetRegistry::dex_priced_fee_assets(ausd));
+		assert_ok!(AssetRegistry::set_dex_priced_fee_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			ausd,
+			true
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::DexPricedFeeAssetUpdated {
+			currency_id: ausd,
+			enabled: true,
+		}));
+		assert!(AssetRegistry::dex_priced_fee_assets(ausd));
+
+		assert_ok!(AssetRegistry::set_dex_priced_fee_asset(
+			RuntimeOrigin::signed(CouncilAccount::get()),
+			ausd,
+			false
+		));
+		System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::DexPricedFeeAssetUpdated {
+			currency_id: ausd,
+			enabled: false,
+		}));
+		assert!(!DexPricedFeeAssets::<Runtime>::contains_key(ausd));
+	});
+}
+
+#[test]
+fn buy_weight_rate_of_dex_price_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		type DexPriceRate = BuyWeightRateOfDexPrice<
+			Runtime,
+			MockCurrencyIdConvert,
+			MockDEXPriceProvider,
+			NativeCurrencyId,
+			XcmFeeSafetyMargin,
+		>;
+		let location = |currency_id: CurrencyId| {
+			MultiLocation::new(
+				0,
+				Junctions::X1(Junction::from(BoundedVec::try_from(currency_id.encode()).unwrap())),
+			)
+		};
+		let ausd = CurrencyId::Token(TokenSymbol::AUSD);
+		let ldot = CurrencyId::Token(TokenSymbol::LDOT);
+		let dot = CurrencyId::Token(TokenSymbol::DOT);
+
+		// not enabled
+		assert_eq!(DexPriceRate::calculate_rate(location(ausd)), None);
+
+		for currency_id in [ausd, ldot, dot, NativeCurrencyId::get()] {
+			assert_ok!(AssetRegistry::set_dex_priced_fee_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				currency_id,
+				true
+			));
+		}
+
+		// price with safety margin
+		assert_eq!(
+			DexPriceRate::calculate_rate(location(ausd)),
+			Some(Ratio::saturating_from_rational(22, 10))
+		);
+		// zero price
+		assert_eq!(DexPriceRate::calculate_rate(location(ldot)), None);
+		// no price
+		assert_eq!(DexPriceRate::calculate_rate(location(dot)), None);
+		// native currency is not priced by dex
+		assert_eq!(DexPriceRate::calculate_rate(location(NativeCurrencyId::get())), None);
+		// unknown location
+		assert_eq!(DexPriceRate::calculate_rate(MultiLocation::parent()), None);
+	});
+}
+
+#[test]
+fn register_foreign_asset_with_deposit_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 10_000)])
+		.build()
+		.execute_with(|| {
+			let location = MultiLocation::new(1, X1(Parachain(2000)));
+			let metadata = AssetMetadata {
+				name: b"Token Name".to_vec(),
+				symbol: b"TN".to_vec(),
+				decimals: 12,
+				minimal_balance: 1,
+			};
+
+			assert_noop!(
+				AssetRegistry::register_foreign_asset_with_deposit(
+					RuntimeOrigin::signed(TreasuryAccount::get()),
+					Box::new(location.into()),
+					Box::new(metadata.clone())
+				),
+				pallet_balances::Error::<Runtime>::InsufficientBalance
+			);
+
+			assert_ok!(AssetRegistry::register_foreign_asset_with_deposit(
+				RuntimeOrigin::signed(alice()),
+				Box::new(location.into()),
+				Box::new(metadata.clone())
+			));
+			System::assert_has_event(RuntimeEvent::AssetRegistry(crate::Event::UnverifiedAssetRegistered {
+				asset_id: AssetIds::ForeignAssetId(0),
+				depositor: alice(),
+				deposit: 1_000,
+			}));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::ForeignAssetRegistered {
+				asset_id: 0,
+				asset_address: location,
+				metadata: metadata.clone(),
+			}));
+			assert_eq!(Balances::reserved_balance(alice()), 1_000);
+			assert_eq!(
+				AssetRegistry::unverified_assets(AssetIds::ForeignAssetId(0)),
+				Some(UnverifiedAsset {
+					depositor: alice(),
+					deposit: 1_000,
+				})
+			);
+			assert_eq!(
+				AssetRegistry::location_to_currency_ids(location),
+				Some(CurrencyId::ForeignAsset(0))
+			);
+
+			// unverified asset can't pay for XCM fee
+			assert!(AssetRegistry::is_unverified(CurrencyId::ForeignAsset(0)));
+			assert_eq!(BuyWeightRateOfForeignAsset::<Runtime>::calculate_rate(location), None);
+			assert_noop!(
+				AssetRegistry::set_dex_priced_fee_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					CurrencyId::ForeignAsset(0),
+					true
+				),
+				Error::<Runtime>::AssetUnverified
+			);
+
+			assert_noop!(
+				AssetRegistry::verify_asset(RuntimeOrigin::signed(alice()), AssetIds::ForeignAssetId(0)),
+				BadOrigin
+			);
+			assert_ok!(AssetRegistry::verify_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				AssetIds::ForeignAssetId(0)
+			));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetVerified {
+				asset_id: AssetIds::ForeignAssetId(0),
+				depositor: alice(),
+				deposit: 1_000,
+			}));
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+			assert_eq!(AssetRegistry::unverified_assets(AssetIds::ForeignAssetId(0)), None);
+			assert_eq!(
+				BuyWeightRateOfForeignAsset::<Runtime>::calculate_rate(location),
+				Some(Ratio::one())
+			);
+
+			// verified asset can't be verified or deregistered again
+			assert_noop!(
+				AssetRegistry::verify_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					AssetIds::ForeignAssetId(0)
+				),
+				Error::<Runtime>::AssetNotUnverified
+			);
+			assert_noop!(
+				AssetRegistry::deregister_asset(RuntimeOrigin::signed(alice()), AssetIds::ForeignAssetId(0)),
+				Error::<Runtime>::AssetNotUnverified
+			);
+		});
+}
+
+#[test]
+fn register_erc20_asset_with_deposit_and_deregister_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let free_balance = Balances::free_balance(alice());
+
+			assert_ok!(AssetRegistry::register_erc20_asset_with_deposit(
+				RuntimeOrigin::signed(alice()),
+				erc20_address(),
+				1
+			));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetRegistered {
+				asset_id: AssetIds::Erc20(erc20_address()),
+				metadata: AssetMetadata {
+					name: b"long string name, long string name, long string name, long string name, long string name"
+						.to_vec(),
+					symbol: b"TestToken".to_vec(),
+					decimals: 17,
+					minimal_balance: 1,
+				},
+			}));
+			assert_eq!(Balances::free_balance(alice()), free_balance - 1_000);
+			assert_eq!(Balances::reserved_balance(alice()), 1_000);
+			assert_eq!(Erc20IdToAddress::<Runtime>::get(0x5dddfce5), Some(erc20_address()));
+			assert!(AssetRegistry::is_unverified(CurrencyId::Erc20(erc20_address())));
+
+			let location = MultiLocation::new(
+				0,
+				X1(Junction::from(
+					BoundedVec::try_from(CurrencyId::Erc20(erc20_address()).encode()).unwrap(),
+				)),
+			);
+			assert_eq!(BuyWeightRateOfErc20::<Runtime>::calculate_rate(location), None);
+
+			assert_noop!(
+				AssetRegistry::deregister_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					AssetIds::Erc20(erc20_address())
+				),
+				Error::<Runtime>::NotDepositor
+			);
+			assert_ok!(AssetRegistry::deregister_asset(
+				RuntimeOrigin::signed(alice()),
+				AssetIds::Erc20(erc20_address())
+			));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetDeregistered {
+				asset_id: AssetIds::Erc20(erc20_address()),
+				depositor: alice(),
+				deposit: 1_000,
+			}));
+			assert_eq!(Balances::free_balance(alice()), free_balance);
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+			assert_eq!(Erc20IdToAddress::<Runtime>::get(0x5dddfce5), None);
+			assert_eq!(AssetMetadatas::<Runtime>::get(AssetIds::Erc20(erc20_address())), None);
+			assert_eq!(AssetRegistry::unverified_assets(AssetIds::Erc20(erc20_address())), None);
+
+			// can be registered again
+			assert_ok!(AssetRegistry::register_erc20_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				erc20_address(),
+				1
+			));
+		});
+}
+
+#[test]
+fn reject_asset_works() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 10_000)])
+		.build()
+		.execute_with(|| {
+			let location = MultiLocation::new(1, X1(Parachain(2000)));
+			assert_ok!(AssetRegistry::register_foreign_asset_with_deposit(
+				RuntimeOrigin::signed(alice()),
+				Box::new(location.into()),
+				Box::new(AssetMetadata {
+					name: b"Token Name".to_vec(),
+					symbol: b"TN".to_vec(),
+					decimals: 12,
+					minimal_balance: 1,
+				})
+			));
+
+			assert_noop!(
+				AssetRegistry::reject_asset(RuntimeOrigin::signed(alice()), AssetIds::ForeignAssetId(0)),
+				BadOrigin
+			);
+			assert_noop!(
+				AssetRegistry::reject_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					AssetIds::ForeignAssetId(1)
+				),
+				Error::<Runtime>::AssetNotUnverified
+			);
+
+			assert_ok!(AssetRegistry::reject_asset(
+				RuntimeOrigin::signed(CouncilAccount::get()),
+				AssetIds::ForeignAssetId(0)
+			));
+			System::assert_last_event(RuntimeEvent::AssetRegistry(crate::Event::AssetRejected {
+				asset_id: AssetIds::ForeignAssetId(0),
+				depositor: alice(),
+				slashed: 1_000,
+			}));
+			assert_eq!(Balances::free_balance(alice()), 9_000);
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+			assert_eq!(Balances::free_balance(TreasuryAccount::get()), 1_000);
+			assert_eq!(AssetRegistry::unverified_assets(AssetIds::ForeignAssetId(0)), None);
+			assert_eq!(ForeignAssetLocations::<Runtime>::get(0), None);
+			assert_eq!(AssetRegistry::location_to_currency_ids(location), None);
+			assert_eq!(AssetMetadatas::<Runtime>::get(AssetIds::ForeignAssetId(0)), None);
+		});
+}
+
+#[test]
+fn deregister_asset_in_use_fails() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 10_000)])
+		.build()
+		.execute_with(|| {
+			let location = MultiLocation::new(1, X1(Parachain(2000)));
+			assert_ok!(AssetRegistry::register_foreign_asset_with_deposit(
+				RuntimeOrigin::signed(alice()),
+				Box::new(location.into()),
+				Box::new(AssetMetadata {
+					name: b"Token Name".to_vec(),
+					symbol: b"TN".to_vec(),
+					decimals: 12,
+					minimal_balance: 1,
+				})
+			));
+
+			// e.g. someone holds the asset
+			InUseCurrencies::set(vec![CurrencyId::ForeignAsset(0)]);
+			assert_noop!(
+				AssetRegistry::deregister_asset(RuntimeOrigin::signed(alice()), AssetIds::ForeignAssetId(0)),
+				Error::<Runtime>::AssetInUse
+			);
+			assert_noop!(
+				AssetRegistry::reject_asset(
+					RuntimeOrigin::signed(CouncilAccount::get()),
+					AssetIds::ForeignAssetId(0)
+				),
+				Error::<Runtime>::AssetInUse
+			);
+
+			InUseCurrencies::set(vec![]);
+			assert_ok!(AssetRegistry::deregister_asset(
+				RuntimeOrigin::signed(alice()),
+				AssetIds::ForeignAssetId(0)
+			));
+			assert_eq!(AssetRegistry::location_to_currency_ids(location), None);
+		});
+}
@@ -24,6 +24,9 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!   - `debit auction`: mint native currency for getting a fixed amount of stable currency to
+//!     recapitalise the system's bad debit that can not be offset by surplus, the amount of native
+//!     currency for sale decreases as the bid price increases
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -36,7 +39,8 @@ use frame_system::{
 	pallet_prelude::*,
 };
 use module_support::{
-	AuctionManager, CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, PriceProvider, Rate, SwapLimit,
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, LiquidationKeeperReward, PriceProvider, Rate,
+	SwapLimit,
 };
 use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult};
 use orml_utilities::OffchainErr;
@@ -128,6 +132,37 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// Information of a debit auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DebitAuctionItem<BlockNumber> {
+	/// Initial amount of native currency for sale
+	#[codec(compact)]
+	initial_amount: Balance,
+	/// Current amount of native currency for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Fixed amount of stable currency to be paid by the winner
+	#[codec(compact)]
+	fix: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
+impl<BlockNumber> DebitAuctionItem<BlockNumber> {
+	/// Return new native currency amount for sale at specific last bid
+	/// price and new bid price
+	fn amount_for_sale(&self, last_bid_price: Balance, new_bid_price: Balance) -> Balance {
+		if new_bid_price > last_bid_price && new_bid_price > self.fix {
+			Rate::checked_from_rational(sp_std::cmp::max(last_bid_price, self.fix), new_bid_price)
+				.and_then(|n| n.checked_mul_int(self.amount))
+				.unwrap_or(self.amount)
+		} else {
+			self.amount
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -153,6 +188,10 @@ pub mod module {
 		#[pallet::constant]
 		type GetStableCurrencyId: Get<CurrencyId>;
 
+		/// The native currency id, minted by debit auctions
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
 		/// Currency to transfer assets
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
@@ -175,6 +214,9 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// Rewards the keepers who cancel collateral auctions.
+		type KeeperReward: LiquidationKeeperReward<Self::AccountId, CurrencyId, Balance>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -193,6 +235,8 @@ pub mod module {
 		InvalidBidPrice,
 		/// Invalid input amount
 		InvalidAmount,
+		/// The system has been shutdown
+		AlreadyShutdown,
 	}
 
 	#[pallet::event]
@@ -231,6 +275,24 @@ pub mod module {
 			target_stable_amount: Balance,
 			refund_recipient: T::AccountId,
 		},
+		/// Debit auction created.
+		NewDebitAuction {
+			auction_id: AuctionId,
+			initial_supply_amount: Balance,
+			fix_payment_amount: Balance,
+		},
+		/// Debit auction dealt.
+		DebitAuctionDealt {
+			auction_id: AuctionId,
+			amount: Balance,
+			winner: T::AccountId,
+			payment_amount: Balance,
+		},
+		/// Debit auction aborted without any bid.
+		DebitAuctionAborted {
+			auction_id: AuctionId,
+			fix_payment_amount: Balance,
+		},
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -241,6 +303,15 @@ pub mod module {
 	pub type CollateralAuctions<T: Config> =
 		StorageMap<_, Twox64Concat, AuctionId, CollateralAuctionItem<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
 
+	/// Mapping from auction id to the collateral type of the liquidated CDPs and the liquidation
+	/// penalty included in the target, for the collateral auctions liquidating CDPs
+	///
+	/// LiquidationAuctions: map AuctionId => Option<(CurrencyId, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_auctions)]
+	pub type LiquidationAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, (CurrencyId, Balance), OptionQuery>;
+
 	/// Record of the total collateral amount of all active collateral auctions
 	/// under specific collateral type CollateralType -> TotalAmount
 	///
@@ -256,15 +327,32 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Mapping from auction id to debit auction info
+	///
+	/// DebitAuctions: map AuctionId => Option<DebitAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auctions)]
+	pub type DebitAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DebitAuctionItem<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Record of the total fixed amount of all active debit auctions
+	///
+	/// TotalDebitInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_debit_in_auction)]
+	pub type TotalDebitInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// Start offchain worker in order to submit unsigned tx to cancel
-		/// active auction after system shutdown.
+		/// active auction after system shutdown or collateral shutdown.
 		fn offchain_worker(now: BlockNumberFor<T>) {
-			if T::EmergencyShutdown::is_shutdown() && sp_io::offchain::is_validator() {
+			if (T::EmergencyShutdown::is_shutdown() || T::EmergencyShutdown::has_collateral_shutdown())
+				&& sp_io::offchain::is_validator()
+			{
 				if let Err(e) = Self::_offchain_worker() {
 					log::info!(
 						target: "auction-manager",
@@ -284,18 +372,39 @@ pub mod module {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Cancel active auction after system shutdown
+		/// Cancel active auction after system shutdown or the shutdown of its collateral, debit
+		/// auctions can only be cancelled after system shutdown
 		///
 		/// The dispatch origin of this call must be _None_.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::cancel_collateral_auction())]
 		pub fn cancel(origin: OriginFor<T>, id: AuctionId) -> DispatchResult {
 			ensure_none(origin)?;
-			ensure!(T::EmergencyShutdown::is_shutdown(), Error::<T>::MustAfterShutdown);
+			ensure!(Self::is_cancellable(id), Error::<T>::MustAfterShutdown);
 			<Self as AuctionManager<T::AccountId>>::cancel_auction(id)?;
 			Self::deposit_event(Event::CancelAuction { auction_id: id });
 			Ok(())
 		}
+
+		/// Cancel active auction after system shutdown or the shutdown of its collateral, and
+		/// reward the caller with the flat cancellation reward if it's a collateral auction.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		#[pallet::call_index(1)]
+		#[pallet::weight((T::WeightInfo::cancel_by_keeper(), DispatchClass::Operational))]
+		pub fn cancel_by_keeper(origin: OriginFor<T>, id: AuctionId) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			ensure!(Self::is_cancellable(id), Error::<T>::MustAfterShutdown);
+			let maybe_collateral_auction = Self::collateral_auctions(id);
+			<Self as AuctionManager<T::AccountId>>::cancel_auction(id)?;
+			Self::deposit_event(Event::CancelAuction { auction_id: id });
+
+			// no penalty is collected by the cancelled auction, the keeper only takes the flat reward
+			if let Some(collateral_auction) = maybe_collateral_auction {
+				T::KeeperReward::reward_cancellation(&keeper, collateral_auction.currency_id);
+			}
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -303,7 +412,7 @@ pub mod module {
 		type Call = Call<T>;
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
 			if let Call::cancel { id: auction_id } = call {
-				if !T::EmergencyShutdown::is_shutdown() {
+				if !Self::is_cancellable(*auction_id) {
 					return InvalidTransaction::Call.into();
 				}
 
@@ -314,7 +423,7 @@ pub mod module {
 							return InvalidTransaction::Stale.into();
 						}
 					}
-				} else {
+				} else if !DebitAuctions::<T>::contains_key(auction_id) {
 					return InvalidTransaction::Stale.into();
 				}
 
@@ -332,6 +441,15 @@ pub mod module {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whether the auction can be cancelled, that is after emergency shutdown or the shutdown of
+	/// the collateral of the collateral auction.
+	fn is_cancellable(auction_id: AuctionId) -> bool {
+		T::EmergencyShutdown::is_shutdown()
+			|| Self::collateral_auctions(auction_id).map_or(false, |collateral_auction| {
+				T::EmergencyShutdown::is_collateral_shutdown(collateral_auction.currency_id)
+			})
+	}
+
 	fn get_last_bid(auction_id: AuctionId) -> Option<(T::AccountId, Balance)> {
 		T::Auction::auction_info(auction_id).and_then(|auction_info| auction_info.bid)
 	}
@@ -371,6 +489,14 @@ impl<T: Config> Pallet<T> {
 			max_iterations
 		);
 
+		// cancel debit auctions after emergency shutdown
+		if T::EmergencyShutdown::is_shutdown() {
+			for (debit_auction_id, _) in <DebitAuctions<T>>::iter().take(max_iterations as usize) {
+				Self::submit_cancel_auction_tx(debit_auction_id);
+				guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+			}
+		}
+
 		// start iterations to cancel collateral auctions
 		let mut iterator = match start_key {
 			Some(key) => <CollateralAuctions<T>>::iter_from(key),
@@ -384,6 +510,15 @@ impl<T: Config> Pallet<T> {
 		while let Some((collateral_auction_id, _)) = iterator.next() {
 			iteration_count += 1;
 
+			// skip the auctions whose collateral is still running
+			if !Self::is_cancellable(collateral_auction_id) {
+				if iteration_count == max_iterations {
+					finished = false;
+					break;
+				}
+				continue;
+			}
+
 			if let (Some(collateral_auction), Some((_, last_bid_price))) = (
 				Self::collateral_auctions(collateral_auction_id),
 				Self::get_last_bid(collateral_auction_id),
@@ -475,6 +610,23 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn cancel_debit_auction(id: AuctionId, debit_auction: DebitAuctionItem<BlockNumberFor<T>>) -> DispatchResult {
+		// if there's bid
+		if let Some((bidder, _)) = Self::get_last_bid(id) {
+			// refund stable token to the bidder, the payment may have been used to offset the
+			// debit pool already
+			T::CDPTreasury::issue_debit(&bidder, debit_auction.fix, false)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		} else {
+			// decrease total debit in auction, which is already decreased at the first bid
+			TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+		}
+
+		Ok(())
+	}
+
 	/// Return `true` if price increment rate is greater than or equal to
 	/// minimum.
 	///
@@ -594,6 +746,190 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Handles debit auction new bid. The bid price is the value of the
+	/// native currency for sale in stable currency, the winner always pays
+	/// the fixed amount and the native currency for sale decreases as the
+	/// bid price increases. Returns `Ok(new_auction_end_time)` if bid
+	/// accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn debit_auction_bid_handler(
+		now: BlockNumberFor<T>,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<BlockNumberFor<T>, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+
+		<DebitAuctions<T>>::try_mutate_exists(
+			id,
+			|debit_auction| -> sp_std::result::Result<BlockNumberFor<T>, DispatchError> {
+				let debit_auction = debit_auction.as_mut().ok_or(Error::<T>::AuctionNotExists)?;
+				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+				// ensure new bid price is valid
+				ensure!(
+					new_bid_price >= debit_auction.fix
+						&& Self::check_minimum_increment(
+							new_bid_price,
+							last_bid_price,
+							debit_auction.fix,
+							Self::get_minimum_increment_size(now, debit_auction.start_time),
+						),
+					Error::<T>::InvalidBidPrice
+				);
+
+				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+				if let Some(last_bidder) = last_bidder {
+					// if there's bid before, return the fixed payment from new bidder to last bidder
+					T::Currency::transfer(
+						T::GetStableCurrencyId::get(),
+						&new_bidder,
+						last_bidder,
+						debit_auction.fix,
+					)?;
+				} else {
+					// otherwise transfer the fixed payment from new bidder to CDP treasury to offset
+					// the debit pool, the debit is no longer in auction
+					T::CDPTreasury::deposit_surplus(&new_bidder, debit_auction.fix)?;
+					TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+				}
+
+				debit_auction.amount = debit_auction.amount_for_sale(last_bid_price, new_bid_price);
+
+				Self::swap_bidders(&new_bidder, last_bidder);
+
+				Ok(now + Self::get_auction_time_to_close(now, debit_auction.start_time))
+			},
+		)
+	}
+
+	fn debit_auction_end_handler(
+		auction_id: AuctionId,
+		debit_auction: DebitAuctionItem<BlockNumberFor<T>>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, _)) = winner {
+			// mint native currency to the winner. If failed, just the winner did not get the
+			// amount. It can be fixed by treasury council.
+			let res = T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, debit_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"deposit: failed to mint native {:?} to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					debit_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::DebitAuctionDealt {
+				auction_id,
+				amount: debit_auction.amount,
+				winner: bidder,
+				payment_amount: debit_auction.fix,
+			});
+		} else {
+			// nobody bids, the debit can be auctioned again by cdp treasury.
+			TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+
+			Self::deposit_event(Event::DebitAuctionAborted {
+				auction_id,
+				fix_payment_amount: debit_auction.fix,
+			});
+		}
+	}
+
+	fn do_new_collateral_auction(
+		refund_recipient: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+	) -> Result<AuctionId, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		TotalCollateralInAuction::<T>::try_mutate(currency_id, |total| -> DispatchResult {
+			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		if !target.is_zero() {
+			// no-op if target is zero
+			TotalTargetInAuction::<T>::try_mutate(|total| -> DispatchResult {
+				*total = total.checked_add(target).ok_or(Error::<T>::InvalidAmount)?;
+				Ok(())
+			})?;
+		}
+
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<CollateralAuctions<T>>::insert(
+			auction_id,
+			CollateralAuctionItem {
+				refund_recipient: refund_recipient.clone(),
+				currency_id,
+				initial_amount: amount,
+				amount,
+				target,
+				start_time,
+			},
+		);
+
+		// increment recipient account reference
+		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
+			// No providers for the locks. This is impossible under normal circumstances
+			// since the funds that are under the lock will themselves be stored in the
+			// account and therefore will need a reference.
+			log::warn!(
+				target: "auction-manager",
+				"Attempt to `inc_consumers` for {:?} failed. \
+				This is unexpected but should be safe.",
+				refund_recipient.clone()
+			);
+		}
+
+		Self::deposit_event(Event::NewCollateralAuction {
+			auction_id,
+			collateral_type: currency_id,
+			collateral_amount: amount,
+			target_bid_price: target,
+		});
+		Ok(auction_id)
+	}
+
+	/// Account the liquidation of CDPs of `collateral_type` settled by a collateral auction
+	/// of `target`, which includes `penalty`. The stable currency `received` pays the debit first,
+	/// the rest is the liquidation penalty received and the debit not paid is the loss.
+	fn settle_liquidation(collateral_type: CurrencyId, target: Balance, penalty: Balance, received: Balance) {
+		let debit = target.saturating_sub(penalty);
+		let received_penalty = received.saturating_sub(debit);
+		if !received_penalty.is_zero() {
+			if let Err(e) = T::CDPTreasury::on_liquidation_penalty(collateral_type, received_penalty) {
+				log::warn!(
+					target: "auction-manager",
+					"on_liquidation_penalty: failed to record penalty {:?} of {:?}: {:?}. \
+					This is unexpected but should be safe",
+					received_penalty, collateral_type, e
+				);
+			}
+		}
+		let loss = debit.saturating_sub(received);
+		if !loss.is_zero() {
+			if let Err(e) = T::CDPTreasury::on_liquidation_loss(collateral_type, loss) {
+				log::warn!(
+					target: "auction-manager",
+					"on_liquidation_loss: failed to cover loss {:?} of {:?}: {:?}. \
+					This is unexpected but should be safe",
+					loss, collateral_type, e
+				);
+			}
+		}
+	}
+
 	fn collateral_auction_end_handler(
 		auction_id: AuctionId,
 		collateral_auction: CollateralAuctionItem<T::AccountId, BlockNumberFor<T>>,
@@ -612,7 +948,7 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// if DEX give a price no less than the last_bidder for swap target
-		if let Ok((actual_supply_amount, actual_target_amount)) =
+		let received = if let Ok((actual_supply_amount, actual_target_amount)) =
 			T::CDPTreasury::swap_collateral_to_stable(collateral_auction.currency_id, swap_limit, true)
 		{
 			Self::try_refund_collateral(
@@ -641,6 +977,7 @@ impl<T: Config> Pallet<T> {
 				supply_collateral_amount: actual_supply_amount,
 				target_stable_amount: actual_target_amount,
 			});
+			collateral_auction.payment_amount(actual_target_amount)
 		} else if last_bidder.is_some() && bid_price >= collateral_auction.target {
 			// if these's bid which is gte target, auction should dealt by the last bidder.
 			let winner = last_bidder.expect("ensured last bidder not empty; qed");
@@ -655,6 +992,7 @@ impl<T: Config> Pallet<T> {
 				winner,
 				payment_amount,
 			});
+			payment_amount
 		} else {
 			// abort this collateral auction, these collateral can be reprocessed by cdp treausry.
 			Self::try_refund_bid(&collateral_auction, last_bid);
@@ -666,6 +1004,21 @@ impl<T: Config> Pallet<T> {
 				target_stable_amount: collateral_auction.target,
 				refund_recipient: collateral_auction.refund_recipient.clone(),
 			});
+			// nothing is sold yet, the liquidation is settled by the re-auction of cdp treasury
+			if let Some((collateral_type, penalty)) = LiquidationAuctions::<T>::take(auction_id) {
+				T::CDPTreasury::on_liquidation_auction_aborted(
+					collateral_type,
+					collateral_auction.currency_id,
+					collateral_auction.amount,
+					collateral_auction.target,
+					penalty,
+				);
+			}
+			Zero::zero()
+		};
+
+		if let Some((collateral_type, penalty)) = LiquidationAuctions::<T>::take(auction_id) {
+			Self::settle_liquidation(collateral_type, collateral_auction.target, penalty, received);
 		}
 
 		// decrement recipient account reference
@@ -744,7 +1097,11 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, BlockNumberFor<T>, Auction
 		new_bid: (T::AccountId, Balance),
 		last_bid: Option<(T::AccountId, Balance)>,
 	) -> OnNewBidResult<BlockNumberFor<T>> {
-		let bid_result = Self::collateral_auction_bid_handler(now, id, new_bid, last_bid);
+		let bid_result = if <DebitAuctions<T>>::contains_key(id) {
+			Self::debit_auction_bid_handler(now, id, new_bid, last_bid)
+		} else {
+			Self::collateral_auction_bid_handler(now, id, new_bid, last_bid)
+		};
 
 		match bid_result {
 			Ok(new_auction_end_time) => OnNewBidResult {
@@ -761,6 +1118,8 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, BlockNumberFor<T>, Auction
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
 			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::debit_auction_end_handler(id, debit_auction, winner.clone());
 		}
 
 		if let Some((bidder, _)) = &winner {
@@ -781,62 +1140,65 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 		target: Self::Balance,
 	) -> DispatchResult {
-		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
-		TotalCollateralInAuction::<T>::try_mutate(currency_id, |total| -> DispatchResult {
-			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
+		Self::do_new_collateral_auction(refund_recipient, currency_id, amount, target)?;
+		Ok(())
+	}
+
+	fn new_liquidation_auction(
+		refund_recipient: &T::AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		collateral_type: Self::CurrencyId,
+		penalty: Self::Balance,
+	) -> DispatchResult {
+		let auction_id = Self::do_new_collateral_auction(refund_recipient, currency_id, amount, target)?;
+		LiquidationAuctions::<T>::insert(auction_id, (collateral_type, penalty));
+		Ok(())
+	}
+
+	fn new_debit_auction(initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult {
+		ensure!(
+			!initial_amount.is_zero() && !fix_debit.is_zero(),
+			Error::<T>::InvalidAmount,
+		);
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		TotalDebitInAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(fix_debit).ok_or(Error::<T>::InvalidAmount)?;
 			Ok(())
 		})?;
 
-		if !target.is_zero() {
-			// no-op if target is zero
-			TotalTargetInAuction::<T>::try_mutate(|total| -> DispatchResult {
-				*total = total.checked_add(target).ok_or(Error::<T>::InvalidAmount)?;
-				Ok(())
-			})?;
-		}
-
 		let start_time = <frame_system::Pallet<T>>::block_number();
-		// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
+		// use start_time + AuctionDurationSoftCap as the initial end-time of debit auction.
 		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
 		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
 
-		<CollateralAuctions<T>>::insert(
+		<DebitAuctions<T>>::insert(
 			auction_id,
-			CollateralAuctionItem {
-				refund_recipient: refund_recipient.clone(),
-				currency_id,
-				initial_amount: amount,
-				amount,
-				target,
+			DebitAuctionItem {
+				initial_amount,
+				amount: initial_amount,
+				fix: fix_debit,
 				start_time,
 			},
 		);
 
-		// increment recipient account reference
-		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
-			// No providers for the locks. This is impossible under normal circumstances
-			// since the funds that are under the lock will themselves be stored in the
-			// account and therefore will need a reference.
-			log::warn!(
-				target: "auction-manager",
-				"Attempt to `inc_consumers` for {:?} failed. \
-				This is unexpected but should be safe.",
-				refund_recipient.clone()
-			);
-		}
-
-		Self::deposit_event(Event::NewCollateralAuction {
+		Self::deposit_event(Event::NewDebitAuction {
 			auction_id,
-			collateral_type: currency_id,
-			collateral_amount: amount,
-			target_bid_price: target,
+			initial_supply_amount: initial_amount,
+			fix_payment_amount: fix_debit,
 		});
 		Ok(())
 	}
 
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
-		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
-		Self::cancel_collateral_auction(id, collateral_auction)?;
+		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
+			Self::cancel_collateral_auction(id, collateral_auction)?;
+			LiquidationAuctions::<T>::remove(id);
+		} else {
+			let debit_auction = <DebitAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
+			Self::cancel_debit_auction(id, debit_auction)?;
+		}
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
@@ -848,4 +1210,8 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 	fn get_total_target_in_auction() -> Self::Balance {
 		Self::total_target_in_auction()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Self::total_debit_in_auction()
+	}
 }
@@ -47,6 +47,7 @@ pub type Amount = i64;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CAROL: AccountId = 3;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::ForeignAsset(255);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -115,6 +116,7 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub const MaxAuctionsCount: u32 = 10_000;
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub TreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account_truncating();
@@ -132,6 +134,7 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = DEXModule;
 	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
 	type MaxAuctionsCount = MaxAuctionsCount;
+	type DebitAuctionMintPeriod = ConstU64<10>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
@@ -184,17 +187,50 @@ impl module_dex::Config for Runtime {
 
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static SHUTDOWN_COLLATERALS: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 }
 
 pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_collateral_shutdown(currency_id: CurrencyId) {
+	SHUTDOWN_COLLATERALS.with(|v| v.borrow_mut().push(currency_id))
+}
+
 pub struct MockEmergencyShutdown;
 impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
 		IS_SHUTDOWN.with(|v| *v.borrow_mut())
 	}
+
+	fn is_collateral_shutdown(currency_id: CurrencyId) -> bool {
+		SHUTDOWN_COLLATERALS.with(|v| v.borrow().contains(&currency_id))
+	}
+
+	fn has_collateral_shutdown() -> bool {
+		SHUTDOWN_COLLATERALS.with(|v| !v.borrow().is_empty())
+	}
+}
+
+thread_local! {
+	static KEEPER_REWARDS: RefCell<Vec<(AccountId, CurrencyId)>> = RefCell::new(vec![]);
+}
+
+pub fn keeper_rewards() -> Vec<(AccountId, CurrencyId)> {
+	KEEPER_REWARDS.with(|v| v.borrow().clone())
+}
+
+pub struct MockKeeperReward;
+impl LiquidationKeeperReward<AccountId, CurrencyId, Balance> for MockKeeperReward {
+	fn reward_keeper(_keeper: &AccountId, _currency_id: CurrencyId, _penalty: Balance) -> Balance {
+		Zero::zero()
+	}
+
+	fn reward_cancellation(keeper: &AccountId, currency_id: CurrencyId) -> Balance {
+		KEEPER_REWARDS.with(|v| v.borrow_mut().push((*keeper, currency_id)));
+		Zero::zero()
+	}
 }
 
 parameter_types! {
@@ -209,10 +245,12 @@ impl Config for Runtime {
 	type AuctionTimeToClose = ConstU64<100>;
 	type AuctionDurationSoftCap = ConstU64<2000>;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type PriceSource = MockPriceSource;
 	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type KeeperReward = MockKeeperReward;
 	type WeightInfo = ();
 }
 
@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeCall as MockCall, RuntimeEvent, *};
-use module_support::DEXManager;
+use module_support::{DEXManager, Ratio};
 use sp_core::offchain::{testing, DbExternalities, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt};
 use sp_io::offchain;
 use sp_runtime::traits::One;
@@ -529,6 +529,99 @@ fn collateral_auction_with_bid_aborted() {
 	});
 }
 
+#[test]
+fn liquidation_auction_dealt_credits_insurance_fund() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::set_insurance_fund_share(
+			RuntimeOrigin::signed(1),
+			BTC,
+			Ratio::saturating_from_rational(1, 2)
+		));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_liquidation_auction(
+			&ALICE, BTC, 100, 200, BTC, 40
+		));
+		assert_eq!(AuctionManagerModule::liquidation_auctions(0), Some((BTC, 40)));
+
+		// the penalty is not credited before the auction ends
+		assert_ok!(AuctionManagerModule::collateral_auction_bid_handler(
+			1,
+			0,
+			(BOB, 250),
+			None
+		));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 200);
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 0);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 250)));
+		assert_eq!(AuctionManagerModule::liquidation_auctions(0), None);
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 20);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 180);
+		assert_eq!(CDPTreasuryModule::loss_records(BTC), Default::default());
+	});
+}
+
+#[test]
+fn liquidation_auction_aborted_is_settled_by_re_auction() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(100));
+		assert_ok!(CDPTreasuryModule::deposit_insurance_fund(
+			RuntimeOrigin::signed(1),
+			BTC,
+			100
+		));
+		assert_ok!(CDPTreasuryModule::on_system_debit(160));
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_liquidation_auction(
+			&ALICE, BTC, 100, 200, BTC, 40
+		));
+
+		// the auction without bid is aborted, the collateral is not sold and no loss is recorded
+		AuctionManagerModule::on_auction_ended(0, None);
+		assert_eq!(AuctionManagerModule::liquidation_auctions(0), None);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 100);
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 160);
+		assert_eq!(CDPTreasuryModule::loss_records(BTC), Default::default());
+		assert_eq!(CDPTreasuryModule::aborted_liquidations(BTC, BTC), Some((100, 160)));
+
+		// the re-auction carries the liquidation, the debit beyond its target is the loss
+		assert_ok!(CDPTreasuryModule::auction_collateral(
+			RuntimeOrigin::signed(1),
+			BTC,
+			100,
+			150,
+			false
+		));
+		assert_eq!(CDPTreasuryModule::aborted_liquidations(BTC, BTC), None);
+		assert_eq!(AuctionManagerModule::liquidation_auctions(1), Some((BTC, 0)));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 90);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 150);
+
+		// the rest of the debit is recovered when the collateral is sold
+		assert_ok!(AuctionManagerModule::collateral_auction_bid_handler(
+			1,
+			1,
+			(BOB, 150),
+			None
+		));
+		AuctionManagerModule::on_auction_ended(1, Some((BOB, 150)));
+		assert_eq!(AuctionManagerModule::liquidation_auctions(1), None);
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 90);
+		assert_eq!(
+			CDPTreasuryModule::loss_records(BTC),
+			module_cdp_treasury::LossRecord {
+				covered_by_insurance_fund: 10,
+				covered_by_surplus: 0,
+				left_to_debit_auctions: 0,
+				socialised: 0,
+			}
+		);
+	});
+}
+
 #[test]
 fn swap_bidders_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -623,6 +716,34 @@ fn cancel_collateral_auction_work() {
 	});
 }
 
+#[test]
+fn cancel_by_keeper_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 10));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+
+		assert_noop!(
+			AuctionManagerModule::cancel_by_keeper(RuntimeOrigin::signed(CAROL), 0),
+			Error::<Runtime>::MustAfterShutdown,
+		);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel_by_keeper(RuntimeOrigin::signed(CAROL), 0));
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(crate::Event::CancelAuction {
+			auction_id: 0,
+		}));
+		assert!(AuctionManagerModule::collateral_auctions(0).is_none());
+		assert_eq!(keeper_rewards(), vec![(CAROL, BTC)]);
+
+		// debit auctions are cancelled without reward
+		assert_ok!(AuctionManagerModule::cancel_by_keeper(RuntimeOrigin::signed(CAROL), 1));
+		assert!(AuctionManagerModule::debit_auctions(1).is_none());
+		assert_eq!(keeper_rewards(), vec![(CAROL, BTC)]);
+	});
+}
+
 #[test]
 fn offchain_worker_cancels_auction_in_shutdown() {
 	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
@@ -657,6 +778,244 @@ fn offchain_worker_cancels_auction_in_shutdown() {
 	});
 }
 
+#[test]
+fn offchain_worker_cancels_auction_in_collateral_shutdown() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&BOB, DOT, 10, 100));
+		assert!(AuctionManagerModule::collateral_auctions(0).is_some());
+		assert!(AuctionManagerModule::collateral_auctions(1).is_some());
+
+		mock_collateral_shutdown(DOT);
+		assert!(!MockEmergencyShutdown::is_shutdown());
+		assert_noop!(
+			AuctionManagerModule::cancel(RuntimeOrigin::none(), 0),
+			Error::<Runtime>::MustAfterShutdown,
+		);
+
+		// offchain worker only cancels the auction of the collateral which has been shutdown
+		run_to_block_offchain(2);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.call, MockCall::AuctionManagerModule(crate::Call::cancel { id: 1 }));
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		assert_ok!(AuctionManagerModule::cancel(RuntimeOrigin::none(), 1));
+		assert!(AuctionManagerModule::collateral_auctions(1).is_none());
+		assert!(AuctionManagerModule::collateral_auctions(0).is_some());
+	});
+}
+
+#[test]
+fn debit_auction_methods() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+		let debit_auction = AuctionManagerModule::debit_auctions(0).unwrap();
+		assert_eq!(debit_auction.amount_for_sale(0, 100), 1000);
+		assert_eq!(debit_auction.amount_for_sale(100, 200), 500);
+		assert_eq!(debit_auction.amount_for_sale(0, 400), 250);
+		assert_eq!(debit_auction.amount_for_sale(200, 100), 1000);
+	});
+}
+
+#[test]
+fn new_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(0, 100),
+			Error::<Runtime>::InvalidAmount,
+		);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(1000, 0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(crate::Event::NewDebitAuction {
+			auction_id: 0,
+			initial_supply_amount: 1000,
+			fix_payment_amount: 100,
+		}));
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_eq!(AuctionModule::auctions_index(), 1);
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: Some(2001)
+			})
+		);
+
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(1000, 100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn debit_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		// the bid price can not be lower than the fixed payment
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 99), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_ok!(AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		// the fixed payment offsets the debit pool, it's no longer in auction
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 1000);
+		let bob_ref_count_1 = System::consumers(&BOB);
+		assert_eq!(bob_ref_count_1, bob_ref_count_0 + 1);
+		let carol_ref_count_0 = System::consumers(&CAROL);
+
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(2, 0, (CAROL, 104), Some((BOB, 100))),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert_eq!(
+			AuctionManagerModule::debit_auction_bid_handler(2, 0, (CAROL, 200), Some((BOB, 100))).unwrap(),
+			102
+		);
+
+		// the fixed payment is returned to the last bidder, the native currency for sale
+		// decreases
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 900);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 500);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_1 - 1);
+		assert_eq!(System::consumers(&CAROL), carol_ref_count_0 + 1);
+	});
+}
+
+#[test]
+fn debit_auction_dealt() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 200));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 0);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 200)));
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(crate::Event::DebitAuctionDealt {
+			auction_id: 0,
+			amount: 500,
+			winner: BOB,
+			payment_amount: 100,
+		}));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 500);
+		assert_eq!(Tokens::total_issuance(ACA), 500);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn debit_auction_without_bid_aborted() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+
+		AuctionManagerModule::on_auction_ended(0, None);
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(crate::Event::DebitAuctionAborted {
+			auction_id: 0,
+			fix_payment_amount: 100,
+		}));
+		assert_eq!(Tokens::total_issuance(ACA), 0);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+	});
+}
+
+#[test]
+fn cancel_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 100));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		assert_noop!(
+			AuctionManagerModule::cancel(RuntimeOrigin::none(), 0),
+			Error::<Runtime>::MustAfterShutdown,
+		);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(RuntimeOrigin::none(), 0));
+		System::assert_last_event(RuntimeEvent::AuctionManagerModule(crate::Event::CancelAuction {
+			auction_id: 0,
+		}));
+
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn offchain_worker_cancels_debit_auction_in_shutdown() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(1000, 100));
+
+		// debit auctions are not cancelled in collateral shutdown
+		mock_collateral_shutdown(BTC);
+		run_to_block_offchain(2);
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		mock_shutdown();
+		run_to_block_offchain(3);
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.call, MockCall::AuctionManagerModule(crate::Call::cancel { id: 0 }));
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		assert_ok!(AuctionManagerModule::cancel(RuntimeOrigin::none(), 0));
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+	});
+}
+
 #[test]
 fn offchain_worker_max_iterations_check() {
 	let (mut offchain, _offchain_state) = testing::TestOffchainExt::new();
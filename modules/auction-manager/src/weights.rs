@@ -48,6 +48,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_auction_manager.
 pub trait WeightInfo {
 	fn cancel_collateral_auction() -> Weight;
+	fn cancel_by_keeper() -> Weight;
 }
 
 /// Weights for module_auction_manager using the Acala node and recommended hardware.
@@ -56,7 +57,12 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn cancel_collateral_auction() -> Weight {
 		Weight::from_parts(78_000_000, 0)
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	fn cancel_by_keeper() -> Weight {
+		Weight::from_parts(101_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
 	}
 }
 
@@ -65,6 +71,11 @@ impl WeightInfo for () {
 	fn cancel_collateral_auction() -> Weight {
 		Weight::from_parts(78_000_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(9 as u64))
-			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	fn cancel_by_keeper() -> Weight {
+		Weight::from_parts(101_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
 	}
 }
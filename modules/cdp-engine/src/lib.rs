@@ -34,9 +34,9 @@ use frame_system::{
 	pallet_prelude::*,
 };
 use module_support::{
-	AddressMapping, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, ExchangeRate, FractionalRate,
-	InvokeContext, LiquidateCollateral, LiquidationEvmBridge, Price, PriceProvider, Rate, Ratio, RiskManager, Swap,
-	SwapLimit,
+	AddressMapping, CDPTreasury, CDPTreasuryExtended, DEXManager, DEXPriceProvider, EmergencyShutdown, ExchangeRate,
+	FractionalRate, InvokeContext, LiquidateCollateral, LiquidationEvmBridge, LiquidationKeeperReward, Price,
+	PriceProvider, Rate, Ratio, RiskManager, Swap, SwapLimit,
 };
 use orml_traits::{Change, GetByKey, MultiCurrency};
 use orml_utilities::OffchainErr;
@@ -48,6 +48,7 @@ use rand_chacha::{
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	offchain::{
 		storage::StorageValueRef,
 		storage_lock::{StorageLock, Time},
@@ -59,7 +60,7 @@ use sp_runtime::{
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
-	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Rounding, RuntimeDebug,
 };
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -106,6 +107,79 @@ pub struct RiskManagementParams {
 	pub required_collateral_ratio: Option<Ratio>,
 }
 
+/// Params of the stability fee controller for a collateral type
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct StabilityFeeControllerParams {
+	/// The minimum interest rate per sec the controller can set.
+	pub min_interest_rate_per_sec: Rate,
+
+	/// The maximum interest rate per sec the controller can set.
+	pub max_interest_rate_per_sec: Rate,
+
+	/// The maximum change of interest rate per sec in one adjustment.
+	pub max_rate_change: Rate,
+
+	/// The interest rate per sec change for a unit of deviation of the
+	/// stablecoin market price from the peg. The rate rises when the market
+	/// price is below the peg and falls when it is above.
+	pub peg_sensitivity: Rate,
+
+	/// The target utilisation of `maximum_total_debit_value`.
+	pub target_utilization: Ratio,
+
+	/// The interest rate per sec rise for a unit of utilisation above the
+	/// target utilisation.
+	pub utilization_sensitivity: Rate,
+}
+
+/// The peg reference of the stablecoin market price
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PegReference {
+	/// The bridged stablecoin which the stablecoin is pegged to.
+	pub currency_id: CurrencyId,
+
+	/// The relative price of the stablecoin to `currency_id` at the peg,
+	/// accounting for the different decimals of the two currencies.
+	pub target_price: ExchangeRate,
+}
+
+/// Params of the reward for keepers who liquidate unsafe CDPs or settle
+/// collateral auctions of a collateral type
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct KeeperRewardParams {
+	/// The share of the liquidation penalty paid to the keeper.
+	pub penalty_share: Ratio,
+
+	/// The maximum reward in stablecoin for a single liquidation or
+	/// settlement.
+	pub max_reward: Balance,
+
+	/// The flat reward in stablecoin for cancelling a collateral auction
+	/// after shutdown, as no penalty is collected by cancelled auctions.
+	pub cancellation_reward: Balance,
+}
+
+/// Liquidation statistics of a liquidation contract
+#[derive(Encode, Decode, Clone, Default, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ContractLiquidationStats {
+	/// The number of liquidations settled by the contract.
+	pub succeeded: u32,
+
+	/// The number of liquidations the contract failed to settle.
+	pub failed: u32,
+
+	/// The number of failed liquidations since the last successful one.
+	pub consecutive_failures: u32,
+
+	/// The total stablecoin repaid by the contract.
+	pub total_repayment: Balance,
+
+	/// The total target stablecoin amount of the liquidations settled by the
+	/// contract since its exposure cap was last set, which is checked against
+	/// the exposure cap.
+	pub exposure: Balance,
+}
+
 // typedef to help polkadot.js disambiguate Change with different generic
 // parameters
 type ChangeOptionRate = Change<Option<Rate>>;
@@ -190,6 +264,14 @@ pub mod module {
 		/// Swap
 		type Swap: Swap<Self::AccountId, Balance, CurrencyId>;
 
+		/// The DEX TWAP price source of the stablecoin market price.
+		type DEXPriceSource: DEXPriceProvider<CurrencyId>;
+
+		/// The stability fee controller adjusts the interest rates every
+		/// `StabilityFeeAdjustmentPeriod` blocks. Zero disables the controller.
+		#[pallet::constant]
+		type StabilityFeeAdjustmentPeriod: Get<BlockNumberFor<Self>>;
+
 		/// The origin for liquidation contracts registering and deregistering.
 		type LiquidationContractsUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -201,6 +283,11 @@ pub mod module {
 		#[pallet::constant]
 		type MaxLiquidationContracts: Get<u32>;
 
+		/// A liquidation contract is deregistered after failing this many
+		/// liquidations in a row. Zero disables the deregistration.
+		#[pallet::constant]
+		type MaxLiquidationContractFailures: Get<u32>;
+
 		type LiquidationEvmBridge: LiquidationEvmBridge;
 
 		#[pallet::constant]
@@ -254,6 +341,10 @@ pub mod module {
 		CollateralContractNotFound,
 		/// Invalid rate
 		InvalidRate,
+		/// Invalid stability fee controller params
+		InvalidStabilityFeeControllerParams,
+		/// Invalid keeper reward params
+		InvalidKeeperRewardParams,
 	}
 
 	#[pallet::event]
@@ -309,6 +400,40 @@ pub mod module {
 		LiquidationContractRegistered { address: EvmAddress },
 		/// A new liquidation contract is deregistered.
 		LiquidationContractDeregistered { address: EvmAddress },
+		/// The exposure cap of a liquidation contract updated.
+		LiquidationContractExposureCapUpdated { address: EvmAddress, cap: Option<Balance> },
+		/// A liquidation contract failed to settle the liquidation.
+		LiquidationContractFailed {
+			address: EvmAddress,
+			consecutive_failures: u32,
+		},
+		/// The stability fee controller for specific collateral type updated.
+		StabilityFeeControllerUpdated {
+			collateral_type: CurrencyId,
+			params: Option<StabilityFeeControllerParams>,
+		},
+		/// The peg reference of the stablecoin market price updated.
+		StablecoinPegReferenceUpdated { peg_reference: Option<PegReference> },
+		/// The stability fee controller adjusted the interest rate per sec.
+		StabilityFeeAdjusted {
+			collateral_type: CurrencyId,
+			old_interest_rate_per_sec: Rate,
+			new_interest_rate_per_sec: Rate,
+			market_price: Option<Price>,
+			utilization: Ratio,
+		},
+		/// The keeper reward for specific collateral type updated.
+		LiquidationKeeperRewardUpdated {
+			collateral_type: CurrencyId,
+			params: Option<KeeperRewardParams>,
+		},
+		/// A keeper is rewarded for liquidating an unsafe CDP or settling a
+		/// collateral auction.
+		KeeperRewarded {
+			keeper: T::AccountId,
+			collateral_type: CurrencyId,
+			reward: Balance,
+		},
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -338,6 +463,45 @@ pub mod module {
 	pub type LiquidationContracts<T: Config> =
 		StorageValue<_, BoundedVec<EvmAddress, T::MaxLiquidationContracts>, ValueQuery>;
 
+	/// Mapping from liquidation contract to its liquidation statistics
+	///
+	/// LiquidationContractStats: EvmAddress => ContractLiquidationStats
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_contract_stats)]
+	pub type LiquidationContractStats<T: Config> =
+		StorageMap<_, Twox64Concat, EvmAddress, ContractLiquidationStats, ValueQuery>;
+
+	/// Mapping from liquidation contract to the maximum total target
+	/// stablecoin amount of the liquidations it can settle
+	///
+	/// LiquidationContractExposureCaps: EvmAddress => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_contract_exposure_caps)]
+	pub type LiquidationContractExposureCaps<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, Balance, OptionQuery>;
+
+	/// Mapping from collateral type to its stability fee controller params
+	///
+	/// StabilityFeeControllers: CurrencyId => Option<StabilityFeeControllerParams>
+	#[pallet::storage]
+	#[pallet::getter(fn stability_fee_controllers)]
+	pub type StabilityFeeControllers<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, StabilityFeeControllerParams, OptionQuery>;
+
+	/// The peg reference of the stablecoin market price
+	///
+	/// StablecoinPegReference: Option<PegReference>
+	#[pallet::storage]
+	#[pallet::getter(fn stablecoin_peg_reference)]
+	pub type StablecoinPegReference<T: Config> = StorageValue<_, PegReference, OptionQuery>;
+
+	/// Mapping from collateral type to its keeper reward params
+	///
+	/// LiquidationKeeperRewards: CurrencyId => Option<KeeperRewardParams>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_keeper_rewards)]
+	pub type LiquidationKeeperRewards<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, KeeperRewardParams, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T> {
@@ -398,10 +562,21 @@ pub mod module {
 			} else {
 				Default::default()
 			};
-			<T as Config>::WeightInfo::on_initialize(Self::accumulate_interest(
+			let mut weight = <T as Config>::WeightInfo::on_initialize(Self::accumulate_interest(
 				now_as_secs,
 				Self::last_accumulation_secs(),
-			))
+			));
+
+			// adjust the interest rates after the interest has been accumulated at the old rates,
+			// so the new rates only apply from now on.
+			let period = T::StabilityFeeAdjustmentPeriod::get();
+			if !period.is_zero() && (now % period).is_zero() {
+				weight = weight.saturating_add(<T as Config>::WeightInfo::adjust_stability_fees(
+					Self::adjust_stability_fees(),
+				));
+			}
+
+			weight
 		}
 
 		/// Runs after every block. Start offchain worker to check CDP and
@@ -441,12 +616,12 @@ pub mod module {
 		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
 			let who = T::Lookup::lookup(who)?;
-			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(!Self::is_settling(currency_id), Error::<T>::AlreadyShutdown);
 			let consumed_weight: Weight = Self::liquidate_unsafe_cdp(who, currency_id)?;
 			Ok(Some(consumed_weight).into())
 		}
 
-		/// Settle CDP has debit after system shutdown
+		/// Settle CDP has debit after system shutdown or the collateral shutdown
 		///
 		/// The dispatch origin of this call must be _None_.
 		///
@@ -461,7 +636,7 @@ pub mod module {
 		) -> DispatchResult {
 			ensure_none(origin)?;
 			let who = T::Lookup::lookup(who)?;
-			ensure!(T::EmergencyShutdown::is_shutdown(), Error::<T>::MustAfterShutdown);
+			ensure!(Self::is_settling(currency_id), Error::<T>::MustAfterShutdown);
 			Self::settle_cdp_has_debit(who, currency_id)?;
 			Ok(())
 		}
@@ -552,6 +727,12 @@ pub mod module {
 		pub fn register_liquidation_contract(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
 			T::LiquidationContractsUpdateOrigin::ensure_origin(origin)?;
 			LiquidationContracts::<T>::try_append(address).map_err(|()| Error::<T>::TooManyLiquidationContracts)?;
+			// a re-registered contract starts with a clean failure streak
+			LiquidationContractStats::<T>::mutate_exists(address, |maybe_stats| {
+				if let Some(stats) = maybe_stats {
+					stats.consecutive_failures = 0;
+				}
+			});
 			Self::deposit_event(Event::LiquidationContractRegistered { address });
 			Ok(())
 		}
@@ -566,6 +747,161 @@ pub mod module {
 			Self::deposit_event(Event::LiquidationContractDeregistered { address });
 			Ok(())
 		}
+
+		/// Update the stability fee controller of specific collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `params`: the controller params, `None` means remove the controller.
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_stability_fee_controller(), DispatchClass::Operational))]
+		pub fn set_stability_fee_controller(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			params: Option<StabilityFeeControllerParams>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(ref params) = params {
+				ensure!(
+					Self::collateral_params(currency_id).is_some(),
+					Error::<T>::InvalidCollateralType
+				);
+				ensure!(
+					params.min_interest_rate_per_sec <= params.max_interest_rate_per_sec
+						&& FractionalRate::try_from(params.max_interest_rate_per_sec).is_ok()
+						&& FractionalRate::default().try_set(params.max_rate_change).is_ok(),
+					Error::<T>::InvalidStabilityFeeControllerParams
+				);
+				StabilityFeeControllers::<T>::insert(currency_id, params);
+			} else {
+				StabilityFeeControllers::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::StabilityFeeControllerUpdated {
+				collateral_type: currency_id,
+				params,
+			});
+			Ok(())
+		}
+
+		/// Update the peg reference of the stablecoin market price used by the
+		/// stability fee controller
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `peg_reference`: the peg reference, `None` means the controller
+		///   ignores the market price.
+		#[pallet::call_index(6)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_stablecoin_peg_reference(), DispatchClass::Operational))]
+		pub fn set_stablecoin_peg_reference(
+			origin: OriginFor<T>,
+			peg_reference: Option<PegReference>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(ref peg_reference) = peg_reference {
+				ensure!(
+					peg_reference.currency_id != T::GetStableCurrencyId::get() && !peg_reference.target_price.is_zero(),
+					Error::<T>::InvalidStabilityFeeControllerParams
+				);
+			}
+			StablecoinPegReference::<T>::set(peg_reference.clone());
+
+			Self::deposit_event(Event::StablecoinPegReferenceUpdated { peg_reference });
+			Ok(())
+		}
+
+		/// Update the keeper reward of specific collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `params`: the keeper reward params, `None` means no reward.
+		#[pallet::call_index(7)]
+		#[pallet::weight((<T as Config>::WeightInfo::set_liquidation_keeper_reward(), DispatchClass::Operational))]
+		pub fn set_liquidation_keeper_reward(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			params: Option<KeeperRewardParams>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(ref params) = params {
+				ensure!(
+					Self::collateral_params(currency_id).is_some(),
+					Error::<T>::InvalidCollateralType
+				);
+				ensure!(
+					params.penalty_share <= Ratio::one() && params.cancellation_reward <= params.max_reward,
+					Error::<T>::InvalidKeeperRewardParams
+				);
+				LiquidationKeeperRewards::<T>::insert(currency_id, params);
+			} else {
+				LiquidationKeeperRewards::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::LiquidationKeeperRewardUpdated {
+				collateral_type: currency_id,
+				params,
+			});
+			Ok(())
+		}
+
+		/// Liquidate unsafe CDP and reward the caller with a share of the
+		/// liquidation penalty collected by the liquidation. The penalty of
+		/// collateral sent to auctions is not collected yet and not rewarded.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `currency_id`: CDP's collateral type.
+		/// - `who`: CDP's owner.
+		#[pallet::call_index(8)]
+		#[pallet::weight((
+			<T as Config>::WeightInfo::liquidate_by_keeper(<T as Config>::CDPTreasury::max_auction()),
+			DispatchClass::Operational,
+		))]
+		pub fn liquidate_by_keeper(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!Self::is_settling(currency_id), Error::<T>::AlreadyShutdown);
+
+			let collected_penalty = Self::do_liquidate_unsafe_cdp(who, currency_id)?;
+			Self::reward_keeper(&keeper, currency_id, collected_penalty);
+			Ok(())
+		}
+
+		/// Update the exposure cap of a liquidation contract
+		///
+		/// The dispatch origin of this call must be
+		/// `LiquidationContractsUpdateOrigin`.
+		///
+		/// - `address`: the liquidation contract.
+		/// - `cap`: the maximum total target stablecoin amount of the
+		///   liquidations routed to the contract, `None` means no cap. The
+		///   exposure of the contract is reset.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_liquidation_contract_exposure_cap())]
+		pub fn set_liquidation_contract_exposure_cap(
+			origin: OriginFor<T>,
+			address: EvmAddress,
+			cap: Option<Balance>,
+		) -> DispatchResult {
+			T::LiquidationContractsUpdateOrigin::ensure_origin(origin)?;
+			LiquidationContractExposureCaps::<T>::set(address, cap);
+			LiquidationContractStats::<T>::mutate_exists(address, |maybe_stats| {
+				if let Some(stats) = maybe_stats {
+					stats.exposure = Zero::zero();
+				}
+			});
+			Self::deposit_event(Event::LiquidationContractExposureCapUpdated { address, cap });
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -580,7 +916,7 @@ pub mod module {
 					if !matches!(
 						Self::check_cdp_status(*currency_id, collateral, debit),
 						CDPStatus::Unsafe
-					) || T::EmergencyShutdown::is_shutdown()
+					) || Self::is_settling(*currency_id)
 					{
 						return InvalidTransaction::Stale.into();
 					}
@@ -595,7 +931,7 @@ pub mod module {
 				Call::settle { currency_id, who } => {
 					let account = T::Lookup::lookup(who.clone())?;
 					let Position { debit, .. } = <LoansOf<T>>::positions(currency_id, account);
-					if debit.is_zero() || !T::EmergencyShutdown::is_shutdown() {
+					if debit.is_zero() || !Self::is_settling(*currency_id) {
 						return InvalidTransaction::Stale.into();
 					}
 
@@ -620,6 +956,11 @@ impl<T: Config> Pallet<T> {
 			let interval_secs = now_secs.saturating_sub(last_accumulation_secs);
 
 			for currency_id in Self::get_collateral_currency_ids() {
+				// the debit of the collateral which has been shutdown no longer accrues interest
+				if T::EmergencyShutdown::is_collateral_shutdown(currency_id) {
+					continue;
+				}
+
 				if let Ok(interest_rate) = Self::get_interest_rate_per_sec(currency_id) {
 					let rate_to_accumulate = Self::compound_interest_rate(interest_rate, interval_secs);
 					let total_debits = <LoansOf<T>>::total_positions(currency_id).debit;
@@ -658,6 +999,117 @@ impl<T: Config> Pallet<T> {
 		count
 	}
 
+	/// The stablecoin market price normalized by the peg, from the DEX TWAP
+	/// against the bridged stablecoin. One means at the peg.
+	pub fn get_stablecoin_market_price() -> Option<Price> {
+		let peg_reference = Self::stablecoin_peg_reference()?;
+		T::DEXPriceSource::get_relative_price(T::GetStableCurrencyId::get(), peg_reference.currency_id)
+			.and_then(|price| price.checked_div(&peg_reference.target_price))
+	}
+
+	/// Adjust the interest rate per sec of the collateral types which have a
+	/// stability fee controller, by the stablecoin market price and the
+	/// utilisation of `maximum_total_debit_value`. Returns the count of
+	/// controllers.
+	pub fn adjust_stability_fees() -> u32 {
+		let mut count: u32 = 0;
+
+		if T::EmergencyShutdown::is_shutdown() {
+			return count;
+		}
+
+		let market_price = Self::get_stablecoin_market_price();
+
+		for (currency_id, controller) in StabilityFeeControllers::<T>::iter() {
+			count += 1;
+
+			if T::EmergencyShutdown::is_collateral_shutdown(currency_id) {
+				continue;
+			}
+			let mut collateral_params = match Self::collateral_params(currency_id) {
+				Some(collateral_params) => collateral_params,
+				None => continue,
+			};
+
+			let old_rate = collateral_params
+				.interest_rate_per_sec
+				.map(|v| v.into_inner())
+				.unwrap_or_default();
+			let mut increment = Rate::zero();
+			let mut decrement = Rate::zero();
+
+			// below the peg makes debit more expensive to shrink the supply, and vice versa.
+			if let Some(market_price) = market_price {
+				if market_price < Price::one() {
+					increment = increment.saturating_add(
+						controller
+							.peg_sensitivity
+							.saturating_mul(Price::one().saturating_sub(market_price)),
+					);
+				} else {
+					decrement = decrement.saturating_add(
+						controller
+							.peg_sensitivity
+							.saturating_mul(market_price.saturating_sub(Price::one())),
+					);
+				}
+			}
+
+			let total_debit_value =
+				Self::get_debit_value(currency_id, <LoansOf<T>>::total_positions(currency_id).debit);
+			let utilization =
+				Ratio::checked_from_rational(total_debit_value, collateral_params.maximum_total_debit_value)
+					.unwrap_or_else(|| {
+						if total_debit_value.is_zero() {
+							Ratio::zero()
+						} else {
+							Ratio::one()
+						}
+					});
+			if utilization > controller.target_utilization {
+				increment = increment.saturating_add(
+					controller
+						.utilization_sensitivity
+						.saturating_mul(utilization.saturating_sub(controller.target_utilization)),
+				);
+			}
+
+			let new_rate = if increment >= decrement {
+				old_rate.saturating_add(increment.saturating_sub(decrement).min(controller.max_rate_change))
+			} else {
+				old_rate.saturating_sub(decrement.saturating_sub(increment).min(controller.max_rate_change))
+			}
+			.max(controller.min_interest_rate_per_sec)
+			.min(controller.max_interest_rate_per_sec);
+
+			if new_rate != old_rate {
+				let updated = match collateral_params.interest_rate_per_sec.as_mut() {
+					Some(existing) => existing.try_set(new_rate).is_ok(),
+					None => match FractionalRate::try_from(new_rate) {
+						Ok(rate) => {
+							collateral_params.interest_rate_per_sec = Some(rate);
+							true
+						}
+						Err(_) => false,
+					},
+				};
+
+				if updated {
+					CollateralParams::<T>::insert(currency_id, collateral_params);
+					Self::deposit_event(Event::StabilityFeeAdjusted {
+						collateral_type: currency_id,
+						old_interest_rate_per_sec: old_rate,
+						new_interest_rate_per_sec: new_rate,
+						market_price,
+						utilization,
+					});
+				}
+			}
+		}
+
+		count
+	}
+
 	fn submit_unsigned_liquidation_tx(currency_id: CurrencyId, who: T::AccountId) {
 		let who = T::Lookup::unlookup(who);
 		let call = Call::<T>::liquidate {
@@ -736,7 +1188,7 @@ impl<T: Config> Pallet<T> {
 			}
 		};
 
-		let is_shutdown = T::EmergencyShutdown::is_shutdown();
+		let is_shutdown = Self::is_settling(currency_id);
 
 		// If start key is Some(value) continue iterating from that point in storage otherwise start
 		// iterating from the beginning of <module_loans::Positions<T>>
@@ -759,7 +1211,7 @@ impl<T: Config> Pallet<T> {
 				// liquidate unsafe CDPs before emergency shutdown occurs
 				Self::submit_unsigned_liquidation_tx(currency_id, who);
 			} else if is_shutdown && !debit.is_zero() {
-				// settle CDPs with debit after emergency shutdown occurs.
+				// settle CDPs with debit after emergency shutdown or the collateral shutdown occurs.
 				Self::submit_unsigned_settlement_tx(currency_id, who);
 			}
 
@@ -1140,6 +1592,12 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Whether the CDPs of `currency_id` should be settled rather than liquidated, that is after
+	/// emergency shutdown or the shutdown of this collateral.
+	fn is_settling(currency_id: CurrencyId) -> bool {
+		T::EmergencyShutdown::is_shutdown() || T::EmergencyShutdown::is_collateral_shutdown(currency_id)
+	}
+
 	// settle cdp has debit when emergency shutdown
 	pub fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
@@ -1208,6 +1666,13 @@ impl<T: Config> Pallet<T> {
 
 	// liquidate unsafe cdp
 	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> Result<Weight, DispatchError> {
+		Self::do_liquidate_unsafe_cdp(who, currency_id)?;
+		Ok(T::WeightInfo::liquidate_by_dex())
+	}
+
+	/// Liquidate unsafe CDP, returns the liquidation penalty collected by
+	/// the liquidation immediately, excluding the part left to auctions.
+	fn do_liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> Result<Balance, DispatchError> {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
 
 		// ensure the cdp is unsafe
@@ -1225,8 +1690,20 @@ impl<T: Config> Pallet<T> {
 		let bad_debt_value = Self::get_debit_value(currency_id, debit);
 		let liquidation_penalty = Self::get_liquidation_penalty(currency_id)?;
 		let target_stable_amount = liquidation_penalty.saturating_mul_acc_int(bad_debt_value);
+		// the penalty takes its proportion of any part of the target stable amount
+		let penalty_of = |amount: Balance| {
+			multiply_by_rational_with_rounding(
+				amount,
+				target_stable_amount.saturating_sub(bad_debt_value),
+				target_stable_amount,
+				Rounding::Down,
+			)
+			.unwrap_or_default()
+		};
 
-		match currency_id {
+		// the stable amount collected immediately, by the stable refunded from liquidity or by
+		// DEX and liquidation contracts
+		let collected_stable_amount = match currency_id {
 			CurrencyId::DexShare(dex_share_0, dex_share_1) => {
 				let token_0: CurrencyId = dex_share_0.into();
 				let token_1: CurrencyId = dex_share_1.into();
@@ -1256,17 +1733,73 @@ impl<T: Config> Pallet<T> {
 					}
 
 					let remain_target = target_stable_amount.saturating_sub(existing_stable);
-					Self::handle_liquidated_collateral(&who, need_handle_currency, handle_amount, remain_target)?;
+					existing_stable
+						.min(target_stable_amount)
+						.saturating_add(Self::handle_liquidated_collateral(
+							&who,
+							currency_id,
+							need_handle_currency,
+							handle_amount,
+							remain_target,
+							penalty_of(remain_target),
+						)?)
 				} else {
-					// token_0 and token_1 each take half target_stable
-					let target_0 = target_stable_amount / 2;
+					// token_0 and token_1 take the target_stable in proportion to their value by the
+					// oracle price, so that each one is swapped by DEX within the slippage limit of
+					// its own price, or auctioned otherwise. Without prices, each takes half.
+					let value_of = |currency_id: CurrencyId, amount: Balance| {
+						T::PriceSource::get_relative_price(currency_id, stable_currency_id)
+							.map(|price| price.saturating_mul_int(amount))
+					};
+					let target_0 = match (value_of(token_0, amount_0), value_of(token_1, amount_1)) {
+						(Some(value_0), Some(value_1)) => multiply_by_rational_with_rounding(
+							target_stable_amount,
+							value_0,
+							value_0.saturating_add(value_1),
+							Rounding::Down,
+						),
+						_ => None,
+					}
+					.unwrap_or(target_stable_amount / 2);
 					let target_1 = target_stable_amount.saturating_sub(target_0);
-					Self::handle_liquidated_collateral(&who, token_0, amount_0, target_0)?;
-					Self::handle_liquidated_collateral(&who, token_1, amount_1, target_1)?;
+					Self::handle_liquidated_collateral(
+						&who,
+						currency_id,
+						token_0,
+						amount_0,
+						target_0,
+						penalty_of(target_0),
+					)?
+					.saturating_add(Self::handle_liquidated_collateral(
+						&who,
+						currency_id,
+						token_1,
+						amount_1,
+						target_1,
+						penalty_of(target_1),
+					)?)
 				}
 			}
-			_ => {
-				Self::handle_liquidated_collateral(&who, currency_id, collateral, target_stable_amount)?;
+			_ => Self::handle_liquidated_collateral(
+				&who,
+				currency_id,
+				currency_id,
+				collateral,
+				target_stable_amount,
+				penalty_of(target_stable_amount),
+			)?,
+		};
+		// The DEX and liquidation contracts always collect the whole target. The penalty and the
+		// loss of the collateral sent to auctions are accounted when the auctions end.
+		let collected_penalty = penalty_of(collected_stable_amount);
+		if !collected_penalty.is_zero() {
+			if let Err(e) = <T as Config>::CDPTreasury::on_liquidation_penalty(currency_id, collected_penalty) {
+				log::warn!(
+					target: "cdp-engine",
+					"on_liquidation_penalty: failed to record penalty {:?} of {:?}: {:?}. \
+					This is unexpected but should be safe",
+					collected_penalty, currency_id, e,
+				);
 			}
 		}
 
@@ -1277,23 +1810,40 @@ impl<T: Config> Pallet<T> {
 			bad_debt_value,
 			target_amount: target_stable_amount,
 		});
-		Ok(T::WeightInfo::liquidate_by_dex())
+		Ok(collected_penalty)
 	}
 
+	/// Liquidate the collateral of CDPs of `collateral_type` by DEX and liquidation contracts
+	/// first, and by auctions otherwise. `penalty` is the liquidation penalty included in
+	/// `target_stable_amount`. Returns the stable amount collected immediately, which is zero if
+	/// the collateral is sent to auctions.
 	pub fn handle_liquidated_collateral(
 		who: &T::AccountId,
+		collateral_type: CurrencyId,
 		currency_id: CurrencyId,
 		amount: Balance,
 		target_stable_amount: Balance,
-	) -> DispatchResult {
+		penalty: Balance,
+	) -> Result<Balance, DispatchError> {
 		if target_stable_amount.is_zero() {
 			// refund collateral to CDP owner
 			if !amount.is_zero() {
 				<T as Config>::CDPTreasury::withdraw_collateral(who, currency_id, amount)?;
 			}
-			return Ok(());
+			return Ok(Zero::zero());
+		}
+		if LiquidateImmediately::<T>::liquidate(who, currency_id, amount, target_stable_amount).is_ok() {
+			return Ok(target_stable_amount);
 		}
-		LiquidateByPriority::<T>::liquidate(who, currency_id, amount, target_stable_amount)
+		<T as Config>::CDPTreasury::create_liquidation_auctions(
+			collateral_type,
+			currency_id,
+			amount,
+			target_stable_amount,
+			penalty,
+			who.clone(),
+		)?;
+		Ok(Zero::zero())
 	}
 
 	pub fn get_collateral_currency_ids() -> Vec<CurrencyId> {
@@ -1308,9 +1858,56 @@ impl<T: Config> Pallet<T> {
 	fn evm_address() -> EvmAddress {
 		T::EvmAddressMapping::get_or_create_evm_address(&Self::account_id())
 	}
+
+	/// Record a liquidation settled by the liquidation contract.
+	fn record_liquidation_contract_success(contract: EvmAddress, repayment: Balance, target_stable_amount: Balance) {
+		LiquidationContractStats::<T>::mutate(contract, |stats| {
+			stats.succeeded = stats.succeeded.saturating_add(1);
+			stats.consecutive_failures = 0;
+			stats.total_repayment = stats.total_repayment.saturating_add(repayment);
+			stats.exposure = stats.exposure.saturating_add(target_stable_amount);
+		});
+	}
+
+	/// Record a liquidation the liquidation contract failed to settle, the
+	/// contract is deregistered if it fails too many times in a row.
+	fn record_liquidation_contract_failure(contract: EvmAddress) {
+		let consecutive_failures = LiquidationContractStats::<T>::mutate(contract, |stats| {
+			stats.failed = stats.failed.saturating_add(1);
+			stats.consecutive_failures = stats.consecutive_failures.saturating_add(1);
+			stats.consecutive_failures
+		});
+		Self::deposit_event(Event::LiquidationContractFailed {
+			address: contract,
+			consecutive_failures,
+		});
+
+		let max_failures = T::MaxLiquidationContractFailures::get();
+		if !max_failures.is_zero() && consecutive_failures >= max_failures {
+			LiquidationContracts::<T>::mutate(|contracts| {
+				contracts.retain(|c| c != &contract);
+			});
+			Self::deposit_event(Event::LiquidationContractDeregistered { address: contract });
+		}
+	}
+
+	/// Pay the keeper reward out of the surplus pool, returns the reward paid.
+	fn pay_keeper_reward(keeper: &T::AccountId, currency_id: CurrencyId, reward: Balance) -> Balance {
+		let reward = reward.min(<T as Config>::CDPTreasury::get_surplus_pool());
+		if reward.is_zero() || <T as Config>::CDPTreasury::withdraw_surplus(keeper, reward).is_err() {
+			return Zero::zero();
+		}
+
+		Self::deposit_event(Event::KeeperRewarded {
+			keeper: keeper.clone(),
+			collateral_type: currency_id,
+			reward,
+		});
+		reward
+	}
 }
 
-type LiquidateByPriority<T> = (LiquidateViaDex<T>, LiquidateViaContracts<T>, LiquidateViaAuction<T>);
+type LiquidateImmediately<T> = (LiquidateViaDex<T>, LiquidateViaContracts<T>);
 
 pub struct LiquidateViaDex<T>(PhantomData<T>);
 impl<T: Config> LiquidateCollateral<T::AccountId> for LiquidateViaDex<T> {
@@ -1405,8 +2002,44 @@ impl<T: Config> LiquidateCollateral<T::AccountId> for LiquidateViaContracts<T> {
 			right
 		};
 
-		// try liquidation on each contract
+		// ask the contracts within exposure cap for quotes, the ones requiring less collateral
+		// are tried first, the ones not quoting are tried afterwards with the max collateral supply.
+		let mut quoted: Vec<(EvmAddress, Balance)> = Vec::new();
+		let mut unquoted: Vec<(EvmAddress, Balance)> = Vec::new();
 		for contract in contracts_by_priority.into_iter() {
+			if Pallet::<T>::liquidation_contract_exposure_caps(contract).map_or(false, |cap| {
+				Pallet::<T>::liquidation_contract_stats(contract)
+					.exposure
+					.saturating_add(target_stable_amount)
+					> cap
+			}) {
+				continue;
+			}
+			match T::LiquidationEvmBridge::quote(
+				InvokeContext {
+					contract,
+					sender: repay_dest,
+					origin: contract,
+				},
+				collateral,
+				collateral_supply,
+				target_stable_amount,
+			) {
+				Ok(quote) => {
+					// quotes above the slippage limit are rejected
+					if !quote.is_zero() && quote <= collateral_supply {
+						quoted.push((contract, quote));
+					}
+				}
+				Err(_) => unquoted.push((contract, collateral_supply)),
+			}
+		}
+		// stable sort, the round-robin priority is kept for the same quotes
+		quoted.sort_by_key(|(_, quote)| *quote);
+		quoted.append(&mut unquoted);
+
+		// try liquidation on each contract
+		for (contract, collateral_supply) in quoted.into_iter() {
 			let repay_dest_balance = CurrencyOf::<T>::free_balance(stable_coin, &repay_dest_account_id);
 			if T::LiquidationEvmBridge::liquidate(
 				InvokeContext {
@@ -1439,6 +2072,7 @@ impl<T: Config> LiquidateCollateral<T::AccountId> for LiquidateViaContracts<T> {
 							currency_id, collateral_supply, contract, e,
 						);
 					} else {
+						Pallet::<T>::record_liquidation_contract_success(contract, repayment, target_stable_amount);
 						// notify liquidation success
 						T::LiquidationEvmBridge::on_collateral_transfer(
 							InvokeContext {
@@ -1483,6 +2117,7 @@ impl<T: Config> LiquidateCollateral<T::AccountId> for LiquidateViaContracts<T> {
 					);
 				}
 			}
+			Pallet::<T>::record_liquidation_contract_failure(contract);
 		}
 
 		Err(Error::<T>::LiquidationFailed.into())
@@ -1557,6 +2192,12 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 	}
 
 	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: Balance) -> DispatchResult {
+		// new debit is frozen after the collateral shutdown
+		ensure!(
+			!T::EmergencyShutdown::is_collateral_shutdown(currency_id),
+			Error::<T>::AlreadyShutdown
+		);
+
 		let hard_cap = Self::maximum_total_debit_value(currency_id)?;
 		let total_debit_value = Self::get_debit_value(currency_id, total_debit_balance);
 
@@ -1566,6 +2207,23 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 	}
 }
 
+impl<T: Config> LiquidationKeeperReward<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn reward_keeper(keeper: &T::AccountId, currency_id: CurrencyId, penalty: Balance) -> Balance {
+		let reward = match Self::liquidation_keeper_rewards(currency_id) {
+			Some(params) => params.penalty_share.saturating_mul_int(penalty).min(params.max_reward),
+			None => Zero::zero(),
+		};
+		Self::pay_keeper_reward(keeper, currency_id, reward)
+	}
+
+	fn reward_cancellation(keeper: &T::AccountId, currency_id: CurrencyId) -> Balance {
+		let reward = Self::liquidation_keeper_rewards(currency_id)
+			.map(|params| params.cancellation_reward)
+			.unwrap_or_default();
+		Self::pay_keeper_reward(keeper, currency_id, reward)
+	}
+}
+
 pub struct CollateralCurrencyIds<T>(PhantomData<T>);
 // Returns a list of currently supported/configured collateral currency
 impl<T: Config> Get<Vec<CurrencyId>> for CollateralCurrencyIds<T> {
@@ -179,6 +179,7 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 
 thread_local! {
 	pub static AUCTION: RefCell<Option<(AccountId, CurrencyId, Balance, Balance)>> = RefCell::new(None);
+	pub static LIQUIDATION_AUCTION: RefCell<Option<(CurrencyId, Balance)>> = RefCell::new(None);
 }
 
 pub struct MockAuctionManager;
@@ -189,6 +190,9 @@ impl MockAuctionManager {
 			cloned
 		})
 	}
+	pub fn liquidation_auction() -> Option<(CurrencyId, Balance)> {
+		LIQUIDATION_AUCTION.with(|v| *v.borrow())
+	}
 }
 impl AuctionManager<AccountId> for MockAuctionManager {
 	type Balance = Balance;
@@ -205,6 +209,22 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_liquidation_auction(
+		refund_recipient: &AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		collateral_type: Self::CurrencyId,
+		penalty: Self::Balance,
+	) -> DispatchResult {
+		LIQUIDATION_AUCTION.with(|v| *v.borrow_mut() = Some((collateral_type, penalty)));
+		Self::new_collateral_auction(refund_recipient, currency_id, amount, target)
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		AUCTION.with(|v| *v.borrow_mut() = None);
 		Ok(())
@@ -214,8 +234,15 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Self::auction().map(|auction| auction.3).unwrap_or_default()
 	}
 
-	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
-		Self::auction().map(|auction| auction.2).unwrap_or_default()
+	fn get_total_collateral_in_auction(id: Self::CurrencyId) -> Self::Balance {
+		Self::auction()
+			.filter(|auction| auction.1 == id)
+			.map(|auction| auction.2)
+			.unwrap_or_default()
+	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
 	}
 }
 
@@ -237,6 +264,7 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = DEXModule;
 	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
 	type MaxAuctionsCount = ConstU32<10_000>;
+	type DebitAuctionMintPeriod = ConstU64<10>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
@@ -249,6 +277,7 @@ parameter_types! {
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
+		TradingPair::from_currency_ids(DOT, BTC).unwrap(),
 		TradingPair::from_currency_ids(ACA, BTC).unwrap(),
 		TradingPair::from_currency_ids(ACA, DOT).unwrap(),
 		TradingPair::from_currency_ids(ACA, AUSD).unwrap(),
@@ -285,14 +314,38 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = ();
 }
 
+thread_local! {
+	static AUSD_DEX_PRICE: RefCell<Option<ExchangeRate>> = RefCell::new(None);
+}
+
+pub struct MockDEXPriceSource;
+impl MockDEXPriceSource {
+	pub fn set_stable_price(price: Option<ExchangeRate>) {
+		AUSD_DEX_PRICE.with(|v| *v.borrow_mut() = price)
+	}
+}
+impl DEXPriceProvider<CurrencyId> for MockDEXPriceSource {
+	fn get_relative_price(a: CurrencyId, _b: CurrencyId) -> Option<ExchangeRate> {
+		match a {
+			AUSD => AUSD_DEX_PRICE.with(|v| *v.borrow()),
+			_ => None,
+		}
+	}
+}
+
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
+	static SHUTDOWN_COLLATERALS: RefCell<Vec<CurrencyId>> = RefCell::new(vec![]);
 }
 
 pub fn mock_shutdown() {
 	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_collateral_shutdown(currency_id: CurrencyId) {
+	SHUTDOWN_COLLATERALS.with(|v| v.borrow_mut().push(currency_id))
+}
+
 pub fn liquidation_contract_addr() -> EvmAddress {
 	EvmAddress::from_str(&"0x1000000000000000000000000000000000000000").unwrap()
 }
@@ -302,6 +355,14 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	fn is_shutdown() -> bool {
 		IS_SHUTDOWN.with(|v| *v.borrow_mut())
 	}
+
+	fn is_collateral_shutdown(currency_id: CurrencyId) -> bool {
+		SHUTDOWN_COLLATERALS.with(|v| v.borrow().contains(&currency_id))
+	}
+
+	fn has_collateral_shutdown() -> bool {
+		SHUTDOWN_COLLATERALS.with(|v| !v.borrow().is_empty())
+	}
 }
 
 thread_local! {
@@ -310,6 +371,8 @@ thread_local! {
 	static REFUNDED: RefCell<(EvmAddress, Balance)> = RefCell::new((EvmAddress::default(), 0));
 	static LIQUIDATION_RESULT: RefCell<DispatchResult> = RefCell::new(Err(Error::<Runtime>::LiquidationFailed.into()));
 	static REPAYMENT: RefCell<Option<Balance>> = RefCell::new(None);
+	static QUOTES: RefCell<Vec<(EvmAddress, Balance)>> = RefCell::new(Vec::new());
+	static LIQUIDATION_CONTRACT: RefCell<EvmAddress> = RefCell::new(EvmAddress::default());
 }
 
 pub struct MockLiquidationEvmBridge;
@@ -323,9 +386,16 @@ impl MockLiquidationEvmBridge {
 	pub fn refunded() -> (EvmAddress, Balance) {
 		REFUNDED.with(|v| v.borrow().clone())
 	}
+	pub fn liquidation_contract() -> EvmAddress {
+		LIQUIDATION_CONTRACT.with(|v| *v.borrow())
+	}
 	pub fn reset() {
 		LIQUIDATION_RESULT.with(|v| *v.borrow_mut() = Err(Error::<Runtime>::LiquidationFailed.into()));
 		REPAYMENT.with(|v| *v.borrow_mut() = None);
+		QUOTES.with(|v| v.borrow_mut().clear());
+	}
+	pub fn set_quote(contract: EvmAddress, quote: Balance) {
+		QUOTES.with(|v| v.borrow_mut().push((contract, quote)));
 	}
 	pub fn set_liquidation_result(r: DispatchResult) {
 		LIQUIDATION_RESULT.with(|v| *v.borrow_mut() = r);
@@ -335,8 +405,18 @@ impl MockLiquidationEvmBridge {
 	}
 }
 impl LiquidationEvmBridge for MockLiquidationEvmBridge {
+	fn quote(
+		context: InvokeContext,
+		_collateral: EvmAddress,
+		_amount: Balance,
+		_min_repayment: Balance,
+	) -> Result<Balance, DispatchError> {
+		QUOTES
+			.with(|v| v.borrow().iter().find(|(c, _)| *c == context.contract).map(|(_, q)| *q))
+			.ok_or_else(|| Error::<Runtime>::LiquidationFailed.into())
+	}
 	fn liquidate(
-		_context: InvokeContext,
+		context: InvokeContext,
 		collateral: EvmAddress,
 		repay_dest: EvmAddress,
 		amount: Balance,
@@ -352,6 +432,7 @@ impl LiquidationEvmBridge for MockLiquidationEvmBridge {
 			let _ = Currencies::deposit(GetStableCurrencyId::get(), &CDPEngineModule::account_id(), repayment);
 		}
 		LIQUIDATED.with(|v| *v.borrow_mut() = (collateral, repay_dest, amount, min_repayment));
+		LIQUIDATION_CONTRACT.with(|v| *v.borrow_mut() = context.contract);
 		result
 	}
 	fn on_collateral_transfer(_context: InvokeContext, collateral: EvmAddress, amount: Balance) {
@@ -398,9 +479,12 @@ impl Config for Runtime {
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = DEXModule;
+	type DEXPriceSource = MockDEXPriceSource;
+	type StabilityFeeAdjustmentPeriod = ConstU64<10>;
 	type LiquidationContractsUpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxLiquidationContractFailures = ConstU32<3>;
 	type LiquidationEvmBridge = MockLiquidationEvmBridge;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
@@ -873,6 +873,264 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 	});
 }
 
+#[test]
+fn liquidate_unsafe_cdp_credits_insurance_fund_by_collected_penalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(100));
+		assert_ok!(CDPTreasuryModule::set_insurance_fund_share(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_rational(1, 2)
+		));
+		let set_liquidation_ratio = |ratio: Ratio| {
+			assert_ok!(CDPEngineModule::set_collateral_params(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Change::NoChange,
+				Change::NewValue(Some(ratio)),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+			));
+		};
+
+		// sent to auctions, the penalty is credited when the auctions end
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		set_liquidation_ratio(Ratio::saturating_from_rational(3, 1));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		assert_eq!(MockAuctionManager::auction(), Some((ALICE, BTC, 100, 60)));
+		assert_eq!(MockAuctionManager::liquidation_auction(), Some((BTC, 10)));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+
+		// liquidated by DEX, half of the collected penalty goes to insurance fund
+		set_liquidation_ratio(Ratio::saturating_from_rational(3, 2));
+		assert_ok!(DEXModule::add_liquidity(
+			RuntimeOrigin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+		set_liquidation_ratio(Ratio::saturating_from_rational(3, 1));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(BOB, BTC));
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			module_cdp_treasury::Event::InsuranceFundDeposited {
+				collateral_type: BTC,
+				amount: 5,
+			},
+		));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 5);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 155);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::loss_records(BTC), Default::default());
+	});
+}
+
+#[test]
+fn set_liquidation_keeper_reward_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = KeeperRewardParams {
+			penalty_share: Ratio::saturating_from_rational(1, 2),
+			max_reward: 100,
+			cancellation_reward: 10,
+		};
+
+		assert_noop!(
+			CDPEngineModule::set_liquidation_keeper_reward(RuntimeOrigin::signed(BOB), BTC, Some(params.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_keeper_reward(RuntimeOrigin::signed(ALICE), BTC, Some(params.clone())),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_default_collateral(BTC);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_keeper_reward(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some(KeeperRewardParams {
+					penalty_share: Ratio::saturating_from_rational(3, 2),
+					max_reward: 100,
+					cancellation_reward: 10,
+				})
+			),
+			Error::<Runtime>::InvalidKeeperRewardParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_keeper_reward(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some(KeeperRewardParams {
+					penalty_share: Ratio::saturating_from_rational(1, 2),
+					max_reward: 100,
+					cancellation_reward: 101,
+				})
+			),
+			Error::<Runtime>::InvalidKeeperRewardParams
+		);
+
+		assert_ok!(CDPEngineModule::set_liquidation_keeper_reward(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(params.clone())
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidationKeeperRewardUpdated {
+				collateral_type: BTC,
+				params: Some(params.clone()),
+			},
+		));
+		assert_eq!(CDPEngineModule::liquidation_keeper_rewards(BTC), Some(params));
+
+		assert_ok!(CDPEngineModule::set_liquidation_keeper_reward(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidationKeeperRewardUpdated {
+				collateral_type: BTC,
+				params: None,
+			},
+		));
+		assert_eq!(CDPEngineModule::liquidation_keeper_rewards(BTC), None);
+	});
+}
+
+#[test]
+fn liquidate_by_keeper_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(AUSD);
+		assert_ok!(CDPEngineModule::set_liquidation_keeper_reward(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(KeeperRewardParams {
+				penalty_share: Ratio::saturating_from_rational(1, 2),
+				max_reward: 100,
+				cancellation_reward: 10,
+			})
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(20));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(RuntimeOrigin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+
+		let set_liquidation_ratio = |ratio: Ratio| {
+			assert_ok!(CDPEngineModule::set_collateral_params(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Change::NoChange,
+				Change::NewValue(Some(ratio)),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+			));
+		};
+
+		// no DEX liquidity, the collateral is sent to auctions and no penalty is collected yet
+		set_liquidation_ratio(Ratio::saturating_from_rational(3, 1));
+		let bob_ausd = Currencies::free_balance(AUSD, &BOB);
+		assert_ok!(CDPEngineModule::liquidate_by_keeper(
+			RuntimeOrigin::signed(BOB),
+			BTC,
+			ALICE
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: BTC,
+			owner: ALICE,
+			collateral_amount: 100,
+			bad_debt_value: 50,
+			target_amount: 60,
+		}));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), bob_ausd);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+
+		// liquidated by DEX, the penalty 60 - 50 is collected
+		set_liquidation_ratio(Ratio::saturating_from_rational(3, 2));
+		assert_ok!(DEXModule::add_liquidity(
+			RuntimeOrigin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		set_liquidation_ratio(Ratio::saturating_from_rational(3, 1));
+		assert_ok!(CDPEngineModule::liquidate_by_keeper(
+			RuntimeOrigin::signed(BOB),
+			BTC,
+			ALICE
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
+
+		// half of the collected penalty
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::KeeperRewarded {
+			keeper: BOB,
+			collateral_type: BTC,
+			reward: 5,
+		}));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), bob_ausd + 5);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+
+		// the reward is capped by the max reward
+		assert_ok!(CDPEngineModule::set_liquidation_keeper_reward(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(KeeperRewardParams {
+				penalty_share: Ratio::one(),
+				max_reward: 8,
+				cancellation_reward: 3,
+			})
+		));
+		assert_eq!(CDPEngineModule::reward_keeper(&BOB, BTC, 10), 8);
+		assert_eq!(CDPEngineModule::reward_keeper(&BOB, BTC, 5), 5);
+		assert_eq!(CDPEngineModule::reward_keeper(&BOB, DOT, 10), 0);
+
+		// the cancellation reward is flat
+		assert_eq!(CDPEngineModule::reward_cancellation(&BOB, BTC), 3);
+		assert_eq!(CDPEngineModule::reward_cancellation(&BOB, DOT), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), bob_ausd + 21);
+
+		mock_shutdown();
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(RuntimeOrigin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
+
 #[test]
 fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1296,6 +1554,62 @@ fn liquidate_unsafe_cdp_of_lp_ausd_dot_and_create_dot_auction() {
 	});
 }
 
+#[test]
+fn liquidate_unsafe_cdp_of_lp_dot_btc_splits_target_by_value() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			LP_DOT_BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(None),
+			Change::NewValue(10000),
+		));
+		setup_default_collateral(BTC);
+		setup_default_collateral(DOT);
+
+		assert_ok!(DEXModule::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			DOT,
+			300,
+			100,
+			0,
+			false
+		));
+		assert_eq!(Currencies::free_balance(LP_DOT_BTC, &ALICE), 600);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, LP_DOT_BTC, 600, 3000));
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			LP_DOT_BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, LP_DOT_BTC));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidateUnsafeCDP {
+			collateral_type: LP_DOT_BTC,
+			owner: ALICE,
+			collateral_amount: 600,
+			bad_debt_value: 300,
+			target_amount: 360,
+		}));
+
+		// BTC worth 300 and DOT worth 100 take 270 and 90 of the target, without DEX liquidity
+		// against the stablecoin, both are auctioned.
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, DOT), (0, 0));
+		assert_eq!(Currencies::free_balance(BTC, &CDPTreasuryModule::account_id()), 300);
+		assert_eq!(Currencies::free_balance(DOT, &CDPTreasuryModule::account_id()), 100);
+		assert_eq!(MockAuctionManager::auction(), Some((ALICE, DOT, 100, 90)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 300);
+	});
+}
+
 #[test]
 fn get_interest_rate_per_sec_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -1487,6 +1801,48 @@ fn settle_cdp_has_debit_work() {
 	});
 }
 
+#[test]
+fn collateral_shutdown_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		mock_collateral_shutdown(BTC);
+		assert!(!<Runtime as Config>::EmergencyShutdown::is_shutdown());
+
+		// new debit is frozen, but repayment is still allowed
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -100));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 400);
+
+		// no more interest for the collateral
+		CDPEngineModule::accumulate_interest(2, 1);
+		assert_eq!(CDPEngineModule::debit_exchange_rate(BTC), None);
+
+		// settle instead of liquidate
+		assert_noop!(
+			CDPEngineModule::liquidate(RuntimeOrigin::none(), BTC, ALICE),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_ok!(CDPEngineModule::settle(RuntimeOrigin::none(), BTC, ALICE));
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 40);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 40);
+	});
+}
+
 #[test]
 fn close_cdp_has_debit_by_dex_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2032,3 +2388,540 @@ fn liquidation_fails_if_insufficient_repayment() {
 		);
 	});
 }
+
+#[test]
+fn liquidation_via_contracts_picks_best_quote() {
+	let address = liquidation_contract_addr();
+	let other_address = EvmAddress::repeat_byte(0x20);
+	let greedy_address = EvmAddress::repeat_byte(0x30);
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Currencies::deposit(DOT, &CDPTreasuryModule::account_id(), 1000));
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(address));
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(other_address));
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(greedy_address));
+		MockLiquidationEvmBridge::set_liquidation_result(Ok(()));
+		MockLiquidationEvmBridge::set_quote(address, 80);
+		MockLiquidationEvmBridge::set_quote(other_address, 60);
+		// above the max collateral supply, rejected
+		MockLiquidationEvmBridge::set_quote(greedy_address, 200);
+		let alice_dot = Currencies::free_balance(DOT, &ALICE);
+
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(MockLiquidationEvmBridge::liquidation_contract(), other_address);
+		assert_eq!(MockLiquidationEvmBridge::liquidated().2, 60);
+		let contract_account_id =
+			<module_evm_accounts::EvmAddressMapping<Runtime> as AddressMapping<AccountId>>::get_account_id(
+				&other_address,
+			);
+		assert_eq!(Currencies::free_balance(DOT, &contract_account_id), 60);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), alice_dot + 40);
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(other_address),
+			ContractLiquidationStats {
+				succeeded: 1,
+				failed: 0,
+				consecutive_failures: 0,
+				total_repayment: 1_000,
+				exposure: 1_000,
+			}
+		);
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(address),
+			ContractLiquidationStats::default()
+		);
+	});
+}
+
+#[test]
+fn liquidation_via_contracts_respects_exposure_cap() {
+	let address = liquidation_contract_addr();
+	let other_address = EvmAddress::repeat_byte(0x20);
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Currencies::deposit(DOT, &CDPTreasuryModule::account_id(), 1000));
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(address));
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(other_address));
+		MockLiquidationEvmBridge::set_liquidation_result(Ok(()));
+		MockLiquidationEvmBridge::set_quote(address, 80);
+		MockLiquidationEvmBridge::set_quote(other_address, 60);
+
+		assert_noop!(
+			CDPEngineModule::set_liquidation_contract_exposure_cap(
+				RuntimeOrigin::signed(BOB),
+				other_address,
+				Some(1_500)
+			),
+			BadOrigin
+		);
+		assert_ok!(CDPEngineModule::set_liquidation_contract_exposure_cap(
+			RuntimeOrigin::signed(ALICE),
+			other_address,
+			Some(1_500),
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidationContractExposureCapUpdated {
+				address: other_address,
+				cap: Some(1_500),
+			},
+		));
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_exposure_caps(other_address),
+			Some(1_500)
+		);
+
+		// within the exposure cap
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(MockLiquidationEvmBridge::liquidation_contract(), other_address);
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(other_address).exposure,
+			1_000
+		);
+
+		// the cumulative exposure exceeds the cap
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(MockLiquidationEvmBridge::liquidation_contract(), address);
+		assert_eq!(MockLiquidationEvmBridge::liquidated().2, 80);
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(other_address).exposure,
+			1_000
+		);
+
+		// updating the cap resets the exposure
+		assert_ok!(CDPEngineModule::set_liquidation_contract_exposure_cap(
+			RuntimeOrigin::signed(ALICE),
+			other_address,
+			Some(1_500),
+		));
+		assert_eq!(CDPEngineModule::liquidation_contract_stats(other_address).exposure, 0);
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(MockLiquidationEvmBridge::liquidation_contract(), other_address);
+
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(MockLiquidationEvmBridge::liquidation_contract(), address);
+		assert_ok!(CDPEngineModule::set_liquidation_contract_exposure_cap(
+			RuntimeOrigin::signed(ALICE),
+			other_address,
+			None,
+		));
+		assert_eq!(CDPEngineModule::liquidation_contract_exposure_caps(other_address), None);
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(MockLiquidationEvmBridge::liquidation_contract(), other_address);
+	});
+}
+
+#[test]
+fn liquidation_via_contracts_records_success_after_collateral_transfer() {
+	let address = liquidation_contract_addr();
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(address));
+		MockLiquidationEvmBridge::set_liquidation_result(Ok(()));
+
+		// the collateral is not in the treasury, the transfer to the contract fails
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(address),
+			ContractLiquidationStats::default()
+		);
+
+		assert_ok!(Currencies::deposit(DOT, &CDPTreasuryModule::account_id(), 1000));
+		assert_ok!(LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000));
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(address),
+			ContractLiquidationStats {
+				succeeded: 1,
+				failed: 0,
+				consecutive_failures: 0,
+				total_repayment: 1_000,
+				exposure: 1_000,
+			}
+		);
+	});
+}
+
+#[test]
+fn liquidation_contract_deregistered_after_max_failures() {
+	let address = liquidation_contract_addr();
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Currencies::deposit(DOT, &CDPTreasuryModule::account_id(), 1000));
+		assert_ok!(LiquidationContracts::<Runtime>::try_append(address));
+
+		for _ in 0..2 {
+			assert_err!(
+				LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000),
+				Error::<Runtime>::LiquidationFailed
+			);
+		}
+		assert_eq!(CDPEngineModule::liquidation_contracts(), vec![address]);
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(address).consecutive_failures,
+			2
+		);
+
+		assert_err!(
+			LiquidateViaContracts::<Runtime>::liquidate(&ALICE, DOT, 100, 1_000),
+			Error::<Runtime>::LiquidationFailed
+		);
+		System::assert_has_event(RuntimeEvent::CDPEngineModule(crate::Event::LiquidationContractFailed {
+			address,
+			consecutive_failures: 3,
+		}));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::LiquidationContractDeregistered { address },
+		));
+		assert_eq!(CDPEngineModule::liquidation_contracts(), vec![]);
+
+		// re-registered contract starts with a clean failure streak
+		assert_ok!(CDPEngineModule::register_liquidation_contract(
+			RuntimeOrigin::signed(ALICE),
+			address,
+		));
+		assert_eq!(
+			CDPEngineModule::liquidation_contract_stats(address),
+			ContractLiquidationStats {
+				succeeded: 0,
+				failed: 3,
+				consecutive_failures: 0,
+				total_repayment: 0,
+				exposure: 0,
+			}
+		);
+	});
+}
+
+fn controller_params(
+	min_interest_rate_per_sec: Rate,
+	max_interest_rate_per_sec: Rate,
+	max_rate_change: Rate,
+	peg_sensitivity: Rate,
+	utilization_sensitivity: Rate,
+) -> StabilityFeeControllerParams {
+	StabilityFeeControllerParams {
+		min_interest_rate_per_sec,
+		max_interest_rate_per_sec,
+		max_rate_change,
+		peg_sensitivity,
+		target_utilization: Ratio::saturating_from_rational(1, 2),
+		utilization_sensitivity,
+	}
+}
+
+#[test]
+fn set_stability_fee_controller_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = controller_params(
+			Rate::zero(),
+			Rate::saturating_from_rational(1, 1000),
+			Rate::saturating_from_rational(1, 10000),
+			Rate::saturating_from_rational(1, 100),
+			Rate::zero(),
+		);
+
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(RuntimeOrigin::signed(BOB), BTC, Some(params.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(RuntimeOrigin::signed(ALICE), BTC, Some(params.clone())),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		setup_default_collateral(BTC);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some(StabilityFeeControllerParams {
+					min_interest_rate_per_sec: Rate::saturating_from_rational(2, 1000),
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some(StabilityFeeControllerParams {
+					max_interest_rate_per_sec: Rate::saturating_from_rational(2, 1),
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Some(StabilityFeeControllerParams {
+					max_rate_change: Rate::saturating_from_rational(3, 10),
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(params.clone())
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::StabilityFeeControllerUpdated {
+				collateral_type: BTC,
+				params: Some(params.clone()),
+			},
+		));
+		assert_eq!(CDPEngineModule::stability_fee_controllers(BTC), Some(params));
+
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::StabilityFeeControllerUpdated {
+				collateral_type: BTC,
+				params: None,
+			},
+		));
+		assert_eq!(CDPEngineModule::stability_fee_controllers(BTC), None);
+	});
+}
+
+#[test]
+fn set_stablecoin_peg_reference_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let peg_reference = PegReference {
+			currency_id: DOT,
+			target_price: ExchangeRate::one(),
+		};
+
+		assert_noop!(
+			CDPEngineModule::set_stablecoin_peg_reference(RuntimeOrigin::signed(BOB), Some(peg_reference.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_stablecoin_peg_reference(
+				RuntimeOrigin::signed(ALICE),
+				Some(PegReference {
+					currency_id: AUSD,
+					target_price: ExchangeRate::one(),
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_stablecoin_peg_reference(
+				RuntimeOrigin::signed(ALICE),
+				Some(PegReference {
+					currency_id: DOT,
+					target_price: ExchangeRate::zero(),
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+
+		assert_eq!(CDPEngineModule::get_stablecoin_market_price(), None);
+		assert_ok!(CDPEngineModule::set_stablecoin_peg_reference(
+			RuntimeOrigin::signed(ALICE),
+			Some(peg_reference.clone())
+		));
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(
+			crate::Event::StablecoinPegReferenceUpdated {
+				peg_reference: Some(peg_reference.clone()),
+			},
+		));
+		assert_eq!(CDPEngineModule::stablecoin_peg_reference(), Some(peg_reference));
+
+		MockDEXPriceSource::set_stable_price(Some(ExchangeRate::saturating_from_rational(98, 100)));
+		assert_eq!(
+			CDPEngineModule::get_stablecoin_market_price(),
+			Some(Price::saturating_from_rational(98, 100))
+		);
+
+		assert_ok!(CDPEngineModule::set_stablecoin_peg_reference(
+			RuntimeOrigin::signed(ALICE),
+			None
+		));
+		assert_eq!(CDPEngineModule::stablecoin_peg_reference(), None);
+		assert_eq!(CDPEngineModule::get_stablecoin_market_price(), None);
+	});
+}
+
+#[test]
+fn stability_fee_rises_below_peg() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_default_collateral(BTC);
+		assert_ok!(CDPEngineModule::set_stablecoin_peg_reference(
+			RuntimeOrigin::signed(ALICE),
+			Some(PegReference {
+				currency_id: DOT,
+				target_price: ExchangeRate::one(),
+			})
+		));
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(controller_params(
+				Rate::zero(),
+				Rate::saturating_from_rational(15, 100000),
+				Rate::saturating_from_rational(1, 10000),
+				Rate::saturating_from_rational(1, 100),
+				Rate::zero(),
+			))
+		));
+		MockDEXPriceSource::set_stable_price(Some(ExchangeRate::saturating_from_rational(98, 100)));
+		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(BTC), Ok(Rate::zero()));
+
+		// only adjusts on the adjustment period
+		CDPEngineModule::on_initialize(9);
+		assert_eq!(CDPEngineModule::get_interest_rate_per_sec(BTC), Ok(Rate::zero()));
+
+		// the change is clamped to max_rate_change
+		CDPEngineModule::on_initialize(10);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(1, 10000))
+		);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::StabilityFeeAdjusted {
+			collateral_type: BTC,
+			old_interest_rate_per_sec: Rate::zero(),
+			new_interest_rate_per_sec: Rate::saturating_from_rational(1, 10000),
+			market_price: Some(Price::saturating_from_rational(98, 100)),
+			utilization: Ratio::zero(),
+		}));
+
+		// the rate is clamped to max_interest_rate_per_sec
+		CDPEngineModule::on_initialize(20);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(15, 100000))
+		);
+
+		// collaterals under shutdown are not adjusted
+		MockDEXPriceSource::set_stable_price(Some(ExchangeRate::saturating_from_rational(102, 100)));
+		mock_collateral_shutdown(BTC);
+		CDPEngineModule::on_initialize(30);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(15, 100000))
+		);
+	});
+}
+
+#[test]
+fn stability_fee_falls_above_peg() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(5, 10000))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+		));
+		assert_ok!(CDPEngineModule::set_stablecoin_peg_reference(
+			RuntimeOrigin::signed(ALICE),
+			Some(PegReference {
+				currency_id: DOT,
+				target_price: ExchangeRate::one(),
+			})
+		));
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(controller_params(
+				Rate::saturating_from_rational(4, 10000),
+				Rate::saturating_from_rational(1, 1000),
+				Rate::saturating_from_rational(1, 1000),
+				Rate::saturating_from_rational(1, 100),
+				Rate::zero(),
+			))
+		));
+		MockDEXPriceSource::set_stable_price(Some(ExchangeRate::saturating_from_rational(101, 100)));
+
+		CDPEngineModule::on_initialize(10);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(4, 10000))
+		);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::StabilityFeeAdjusted {
+			collateral_type: BTC,
+			old_interest_rate_per_sec: Rate::saturating_from_rational(5, 10000),
+			new_interest_rate_per_sec: Rate::saturating_from_rational(4, 10000),
+			market_price: Some(Price::saturating_from_rational(101, 100)),
+			utilization: Ratio::zero(),
+		}));
+
+		// the rate is clamped to min_interest_rate_per_sec
+		MockDEXPriceSource::set_stable_price(Some(ExchangeRate::saturating_from_rational(105, 100)));
+		CDPEngineModule::on_initialize(20);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(4, 10000))
+		);
+	});
+}
+
+#[test]
+fn stability_fee_rises_above_target_utilization() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_default_collateral(BTC);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(80),
+		));
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Some(controller_params(
+				Rate::zero(),
+				Rate::saturating_from_rational(2, 1000),
+				Rate::saturating_from_rational(1, 100),
+				Rate::saturating_from_rational(1, 100),
+				Rate::saturating_from_rational(1, 100),
+			))
+		));
+
+		// no peg reference, only the utilisation term applies: (50 / 80 - 1 / 2) * 1%
+		CDPEngineModule::on_initialize(10);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(125, 100000))
+		);
+		System::assert_last_event(RuntimeEvent::CDPEngineModule(crate::Event::StabilityFeeAdjusted {
+			collateral_type: BTC,
+			old_interest_rate_per_sec: Rate::zero(),
+			new_interest_rate_per_sec: Rate::saturating_from_rational(125, 100000),
+			market_price: None,
+			utilization: Ratio::saturating_from_rational(5, 8),
+		}));
+
+		// removed controller stops adjusting
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			None
+		));
+		CDPEngineModule::on_initialize(20);
+		assert_eq!(
+			CDPEngineModule::get_interest_rate_per_sec(BTC),
+			Ok(Rate::saturating_from_rational(125, 100000))
+		);
+	});
+}
@@ -54,6 +54,12 @@ pub trait WeightInfo {
 	fn settle() -> Weight;
 	fn register_liquidation_contract() -> Weight;
 	fn deregister_liquidation_contract() -> Weight;
+	fn adjust_stability_fees(c: u32) -> Weight;
+	fn set_stability_fee_controller() -> Weight;
+	fn set_stablecoin_peg_reference() -> Weight;
+	fn set_liquidation_keeper_reward() -> Weight;
+	fn liquidate_by_keeper(b: u32) -> Weight;
+	fn set_liquidation_contract_exposure_cap() -> Weight;
 }
 
 /// Weights for module_cdp_engine using the Acala node and recommended hardware.
@@ -95,6 +101,37 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
+	fn adjust_stability_fees(c: u32) -> Weight {
+		Weight::from_parts(9_814_000, 0)
+			.saturating_add(Weight::from_parts(21_473_000, 0).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	fn set_stability_fee_controller() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_stablecoin_peg_reference() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn set_liquidation_keeper_reward() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn liquidate_by_keeper(_b: u32) -> Weight {
+		Weight::from_parts(231_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(31 as u64))
+			.saturating_add(T::DbWeight::get().writes(19 as u64))
+	}
+	fn set_liquidation_contract_exposure_cap() -> Weight {
+		Weight::from_parts(24_815_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -136,4 +173,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
+	fn adjust_stability_fees(c: u32) -> Weight {
+		Weight::from_parts(9_814_000, 0)
+			.saturating_add(Weight::from_parts(21_473_000, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	fn set_stability_fee_controller() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_stablecoin_peg_reference() -> Weight {
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn set_liquidation_keeper_reward() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn liquidate_by_keeper(_b: u32) -> Weight {
+		Weight::from_parts(231_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(31 as u64))
+			.saturating_add(RocksDbWeight::get().writes(19 as u64))
+	}
+	fn set_liquidation_contract_exposure_cap() -> Weight {
+		Weight::from_parts(24_815_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
@@ -0,0 +1,42 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use sp_runtime::codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait CdpTreasuryApi<CurrencyId, Balance, LossRecord> where
+		CurrencyId: Codec,
+		Balance: Codec,
+		LossRecord: Codec,
+	{
+		/// The stable currency amount of the surplus pool.
+		fn surplus_pool() -> Balance;
+
+		/// The bad debt of the system that has not been offset yet.
+		fn debit_pool() -> Balance;
+
+		/// The stable currency amount of the insurance fund of `currency_id`.
+		fn insurance_fund(currency_id: CurrencyId) -> Balance;
+
+		/// The cumulative loss of `currency_id` and how it has been covered.
+		fn loss_record(currency_id: CurrencyId) -> LossRecord;
+	}
+}
@@ -24,6 +24,12 @@
 //! CDPs, and handle excessive surplus or debits timely in order to keep the
 //! system healthy with low risk. It's the only entry for issuing/burning stable
 //! coin for whole system.
+//!
+//! The loss of debit that the liquidation of a collateral type can not recover is covered by a
+//! waterfall: the insurance fund of the collateral type first, which is funded by a share of its
+//! liquidation penalties, then the surplus pool, and the remainder is left to debit auctions, or
+//! socialised to the stable currency holders if debit auctions are disabled. When the collateral
+//! auction of a liquidation is aborted, the liquidation is settled by the re-auction instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -37,7 +43,7 @@ use nutsfinance_stable_asset::RedeemProportionResult;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	traits::{AccountIdConversion, One, Saturating, Zero},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
 };
 use sp_std::prelude::*;
@@ -49,6 +55,42 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The reserve of an external asset held by the peg stability module, and the stable currency
+/// issued against it.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PegReserve {
+	/// The amount of the external asset held as reserve.
+	pub reserve: Balance,
+	/// The amount of stable currency issued against the reserve.
+	pub issued: Balance,
+}
+
+/// The cumulative loss of debit of a collateral type and how it has been covered.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LossRecord {
+	/// The loss covered by the insurance fund of the collateral type.
+	pub covered_by_insurance_fund: Balance,
+	/// The loss covered by the surplus pool.
+	pub covered_by_surplus: Balance,
+	/// The loss left in the debit pool to be recapitalised by debit auctions.
+	pub left_to_debit_auctions: Balance,
+	/// The loss left in the debit pool with debit auctions disabled, diluting the stable currency.
+	pub socialised: Balance,
+}
+
+/// Parameters of the debit auctions created to recapitalise the debit pool that the surplus pool
+/// cannot offset.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DebitAuctionParams {
+	/// The fixed amount of stable currency to be paid for per lot debit auction. If zero, no
+	/// debit auction will be created.
+	pub fixed_size: Balance,
+	/// The initial amount of native currency offered for per lot debit auction.
+	pub initial_amount: Balance,
+	/// The cap of native currency offered by all debit auctions created within one period.
+	pub mint_cap_per_period: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -91,6 +133,10 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAuctionsCount: Get<u32>;
 
+		/// The period of the mint cap of debit auctions.
+		#[pallet::constant]
+		type DebitAuctionMintPeriod: Get<BlockNumberFor<Self>>;
+
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
 
@@ -115,6 +161,10 @@ pub mod module {
 		CannotSwap,
 		/// The currency id is not DexShare type
 		NotDexShare,
+		/// The share of liquidation penalty for insurance fund is greater than 100%
+		InvalidInsuranceFundShare,
+		/// The insurance fund of the collateral type is not enough
+		InsuranceFundNotEnough,
 	}
 
 	#[pallet::event]
@@ -128,6 +178,43 @@ pub mod module {
 		},
 		/// The buffer amount of debit pool that will not be offset by suplus pool updated.
 		DebitOffsetBufferUpdated { amount: Balance },
+		/// The parameters of debit auction updated.
+		DebitAuctionParamsUpdated { params: DebitAuctionParams },
+		/// The share of liquidation penalty for the insurance fund of specific collateral type
+		/// updated.
+		InsuranceFundShareUpdated { collateral_type: CurrencyId, share: Ratio },
+		/// Stable currency moved from the surplus pool to the insurance fund of specific collateral
+		/// type.
+		InsuranceFundDeposited {
+			collateral_type: CurrencyId,
+			amount: Balance,
+		},
+		/// Stable currency moved from the insurance fund of specific collateral type to the surplus
+		/// pool.
+		InsuranceFundWithdrawn {
+			collateral_type: CurrencyId,
+			amount: Balance,
+		},
+		/// The loss of specific collateral type covered by its insurance fund.
+		LossCoveredByInsuranceFund {
+			collateral_type: CurrencyId,
+			amount: Balance,
+		},
+		/// The loss of specific collateral type covered by the surplus pool.
+		LossCoveredBySurplus {
+			collateral_type: CurrencyId,
+			amount: Balance,
+		},
+		/// The loss of specific collateral type left to debit auctions.
+		LossLeftToDebitAuctions {
+			collateral_type: CurrencyId,
+			amount: Balance,
+		},
+		/// The loss of specific collateral type socialised as debit auctions are disabled.
+		LossSocialised {
+			collateral_type: CurrencyId,
+			amount: Balance,
+		},
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -153,6 +240,62 @@ pub mod module {
 	#[pallet::getter(fn debit_offset_buffer)]
 	pub type DebitOffsetBuffer<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The reserves held by the peg stability module and the stable currency issued against
+	/// them.
+	///
+	/// PegReserves: map CurrencyId => PegReserve
+	#[pallet::storage]
+	#[pallet::getter(fn peg_reserves)]
+	pub type PegReserves<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, PegReserve, ValueQuery>;
+
+	/// The parameters of debit auction.
+	///
+	/// DebitAuctionParameters: DebitAuctionParams
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auction_params)]
+	pub type DebitAuctionParameters<T: Config> = StorageValue<_, DebitAuctionParams, ValueQuery>;
+
+	/// The start block of the current mint period and the native currency amount offered by the
+	/// debit auctions created within it.
+	///
+	/// DebitAuctionMintInPeriod: (BlockNumber, Balance)
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auction_mint_in_period)]
+	pub type DebitAuctionMintInPeriod<T: Config> = StorageValue<_, (BlockNumberFor<T>, Balance), ValueQuery>;
+
+	/// The share of liquidation penalty of specific collateral type that is set aside for its
+	/// insurance fund.
+	///
+	/// InsuranceFundShares: map CurrencyId => Ratio
+	#[pallet::storage]
+	#[pallet::getter(fn insurance_fund_shares)]
+	pub type InsuranceFundShares<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, ValueQuery>;
+
+	/// The stable currency amount of the insurance fund of specific collateral type, held by the
+	/// insurance fund account.
+	///
+	/// InsuranceFunds: map CurrencyId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn insurance_funds)]
+	pub type InsuranceFunds<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
+
+	/// The cumulative loss of specific collateral type and how it has been covered.
+	///
+	/// LossRecords: map CurrencyId => LossRecord
+	#[pallet::storage]
+	#[pallet::getter(fn loss_records)]
+	pub type LossRecords<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, LossRecord, ValueQuery>;
+
+	/// The collateral amount and the debit not recovered yet of the liquidations whose collateral
+	/// auctions have been aborted, by the collateral currency kept by CDP treasury and the
+	/// collateral type of the liquidated CDPs. They are settled by the re-auction of the collateral.
+	///
+	/// AbortedLiquidations: double_map CurrencyId, CurrencyId => Option<(Balance, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn aborted_liquidations)]
+	pub type AbortedLiquidations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, CurrencyId, (Balance, Balance), OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T> {
@@ -176,6 +319,12 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Create debit auctions for the debit pool that can not be offset by the surplus pool.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let created_auctions = Self::create_debit_auctions(now);
+			T::WeightInfo::on_initialize(created_auctions)
+		}
+
 		/// Handle excessive surplus or debits of system when block end
 		fn on_finalize(_now: BlockNumberFor<T>) {
 			// offset the same amount between debit pool and surplus pool
@@ -222,12 +371,14 @@ pub mod module {
 			splited: bool,
 		) -> DispatchResultWithPostInfo {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			let created_auctions = <Self as CDPTreasuryExtended<T::AccountId>>::create_collateral_auctions(
+			let liquidation = Self::take_aborted_liquidation(currency_id, amount, target)?;
+			let created_auctions = Self::do_create_collateral_auctions(
 				currency_id,
 				amount,
 				target,
 				Self::account_id(),
 				splited,
+				liquidation,
 			)?;
 			Ok(Some(T::WeightInfo::auction_collateral(created_auctions)).into())
 		}
@@ -291,6 +442,90 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Update the parameters of debit auction
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the parameters of debit auction
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::WeightInfo::set_debit_auction_params(), DispatchClass::Operational))]
+		pub fn set_debit_auction_params(origin: OriginFor<T>, params: DebitAuctionParams) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			DebitAuctionParameters::<T>::put(params);
+			Self::deposit_event(Event::DebitAuctionParamsUpdated { params });
+			Ok(())
+		}
+
+		/// Update the share of liquidation penalty for the insurance fund of specific collateral
+		/// type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		/// - `share`: the share of liquidation penalty, at most 100%
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::WeightInfo::set_insurance_fund_share(), DispatchClass::Operational))]
+		pub fn set_insurance_fund_share(origin: OriginFor<T>, currency_id: CurrencyId, share: Ratio) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(share <= Ratio::one(), Error::<T>::InvalidInsuranceFundShare);
+			InsuranceFundShares::<T>::insert(currency_id, share);
+			Self::deposit_event(Event::InsuranceFundShareUpdated {
+				collateral_type: currency_id,
+				share,
+			});
+			Ok(())
+		}
+
+		/// Move stable currency from the surplus pool to the insurance fund of specific
+		/// collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		/// - `amount`: stable currency amount
+		#[pallet::call_index(7)]
+		#[pallet::weight((T::WeightInfo::deposit_insurance_fund(), DispatchClass::Operational))]
+		pub fn deposit_insurance_fund(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(Self::surplus_pool() >= amount, Error::<T>::SurplusPoolNotEnough);
+			Self::do_deposit_insurance_fund(currency_id, amount)
+		}
+
+		/// Move stable currency from the insurance fund of specific collateral type back to the
+		/// surplus pool
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type
+		/// - `amount`: stable currency amount
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::WeightInfo::withdraw_insurance_fund(), DispatchClass::Operational))]
+		pub fn withdraw_insurance_fund(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			InsuranceFunds::<T>::try_mutate(currency_id, |fund| -> DispatchResult {
+				*fund = fund.checked_sub(amount).ok_or(Error::<T>::InsuranceFundNotEnough)?;
+				T::Currency::transfer(
+					T::GetStableCurrencyId::get(),
+					&Self::insurance_fund_account(),
+					&Self::account_id(),
+					amount,
+				)
+			})?;
+			Self::deposit_event(Event::InsuranceFundWithdrawn {
+				collateral_type: currency_id,
+				amount,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -300,6 +535,11 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Get account of the insurance funds of all collateral types.
+	pub fn insurance_fund_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"insurance")
+	}
+
 	/// Get current total surplus of system.
 	pub fn surplus_pool() -> Balance {
 		T::Currency::free_balance(T::GetStableCurrencyId::get(), &Self::account_id())
@@ -344,6 +584,204 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	fn do_deposit_insurance_fund(currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		InsuranceFunds::<T>::try_mutate(currency_id, |fund| -> DispatchResult {
+			*fund = fund.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			T::Currency::transfer(
+				T::GetStableCurrencyId::get(),
+				&Self::account_id(),
+				&Self::insurance_fund_account(),
+				amount,
+			)
+		})?;
+		Self::deposit_event(Event::InsuranceFundDeposited {
+			collateral_type: currency_id,
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Take the share of the aborted liquidation of `currency_id` settled by the re-auction of
+	/// `amount` of the collateral for `target`, and returns the liquidation of the re-auction.
+	/// The debit beyond `target` can not be recovered by the re-auction and is the loss right away.
+	fn take_aborted_liquidation(
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+	) -> Result<Option<(CurrencyId, Balance)>, DispatchError> {
+		let (collateral_type, (aborted_amount, aborted_debit)) =
+			match AbortedLiquidations::<T>::iter_prefix(currency_id).next() {
+				Some(aborted_liquidation) => aborted_liquidation,
+				None => return Ok(None),
+			};
+
+		let debit = if amount >= aborted_amount {
+			AbortedLiquidations::<T>::remove(currency_id, collateral_type);
+			aborted_debit
+		} else {
+			let debit = Ratio::saturating_from_rational(amount, aborted_amount).saturating_mul_int(aborted_debit);
+			AbortedLiquidations::<T>::insert(
+				currency_id,
+				collateral_type,
+				(
+					aborted_amount.saturating_sub(amount),
+					aborted_debit.saturating_sub(debit),
+				),
+			);
+			debit
+		};
+
+		let loss = debit.saturating_sub(target);
+		if !loss.is_zero() {
+			Self::on_liquidation_loss(collateral_type, loss)?;
+		}
+		// the part of target beyond the debit is received as the liquidation penalty
+		Ok(Some((collateral_type, target.saturating_sub(debit))))
+	}
+
+	/// Create collateral auctions, split by the expected collateral auction size if `splited`.
+	/// For the auctions liquidating CDPs, `liquidation` is the collateral type of the CDPs and the
+	/// liquidation penalty included in `target`. Returns the number of created auctions.
+	fn do_create_collateral_auctions(
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+		refund_receiver: T::AccountId,
+		splited: bool,
+		liquidation: Option<(CurrencyId, Balance)>,
+	) -> Result<u32, DispatchError> {
+		ensure!(
+			Self::total_collaterals_not_in_auction(currency_id) >= amount,
+			Error::<T>::CollateralNotEnough,
+		);
+
+		let mut unhandled_collateral_amount = amount;
+		let mut unhandled_target = target;
+		let mut unhandled_penalty = liquidation.map(|(_, penalty)| penalty).unwrap_or_default();
+		let expected_collateral_auction_size = Self::expected_collateral_auction_size(currency_id);
+		let max_auctions_count: Balance = T::MaxAuctionsCount::get().into();
+		let lots_count = if !splited
+			|| max_auctions_count.is_zero()
+			|| expected_collateral_auction_size.is_zero()
+			|| amount <= expected_collateral_auction_size
+		{
+			One::one()
+		} else {
+			let mut count = amount
+				.checked_div(expected_collateral_auction_size)
+				.expect("collateral auction maximum size is not zero; qed");
+
+			let remainder = amount
+				.checked_rem(expected_collateral_auction_size)
+				.expect("collateral auction maximum size is not zero; qed");
+			if !remainder.is_zero() {
+				count = count.saturating_add(One::one());
+			}
+			sp_std::cmp::min(count, max_auctions_count)
+		};
+		let average_amount_per_lot = amount.checked_div(lots_count).expect("lots count is at least 1; qed");
+		let average_target_per_lot = target.checked_div(lots_count).expect("lots count is at least 1; qed");
+		let average_penalty_per_lot = unhandled_penalty
+			.checked_div(lots_count)
+			.expect("lots count is at least 1; qed");
+		let mut created_lots: Balance = Zero::zero();
+
+		while !unhandled_collateral_amount.is_zero() {
+			created_lots = created_lots.saturating_add(One::one());
+			let (lot_collateral_amount, lot_target, lot_penalty) = if created_lots == lots_count {
+				// the last lot may be have some remnant than average
+				(unhandled_collateral_amount, unhandled_target, unhandled_penalty)
+			} else {
+				(average_amount_per_lot, average_target_per_lot, average_penalty_per_lot)
+			};
+
+			if let Some((collateral_type, _)) = liquidation {
+				T::AuctionManagerHandler::new_liquidation_auction(
+					&refund_receiver,
+					currency_id,
+					lot_collateral_amount,
+					lot_target,
+					collateral_type,
+					lot_penalty,
+				)?;
+			} else {
+				T::AuctionManagerHandler::new_collateral_auction(
+					&refund_receiver,
+					currency_id,
+					lot_collateral_amount,
+					lot_target,
+				)?;
+			}
+
+			unhandled_collateral_amount = unhandled_collateral_amount.saturating_sub(lot_collateral_amount);
+			unhandled_target = unhandled_target.saturating_sub(lot_target);
+			unhandled_penalty = unhandled_penalty.saturating_sub(lot_penalty);
+		}
+		let created_auctions: u32 = created_lots.try_into().map_err(|_| ArithmeticError::Overflow)?;
+		Ok(created_auctions)
+	}
+
+	/// Create debit auctions for the part of the debit pool that exceeds the debit offset buffer
+	/// and can not be offset by the surplus pool nor the debit auctions in progress. The native
+	/// currency offered by the created auctions is limited by the mint cap of the current period.
+	/// Returns the number of created auctions.
+	pub fn create_debit_auctions(now: BlockNumberFor<T>) -> u32 {
+		let DebitAuctionParams {
+			fixed_size,
+			initial_amount,
+			mint_cap_per_period,
+		} = Self::debit_auction_params();
+		if fixed_size.is_zero() || initial_amount.is_zero() {
+			return 0;
+		}
+
+		let mut uncovered_debit = Self::debit_pool()
+			.saturating_sub(Self::debit_offset_buffer())
+			.saturating_sub(Self::surplus_pool())
+			.saturating_sub(T::AuctionManagerHandler::get_total_debit_in_auction());
+		if uncovered_debit < fixed_size {
+			return 0;
+		}
+
+		let mint_period = T::DebitAuctionMintPeriod::get();
+		let period_start = if mint_period.is_zero() {
+			Zero::zero()
+		} else {
+			now.saturating_sub(now % mint_period)
+		};
+		let (last_period_start, mut minted) = Self::debit_auction_mint_in_period();
+		if last_period_start != period_start {
+			minted = Zero::zero();
+		}
+
+		let max_auctions_count = T::MaxAuctionsCount::get();
+		let mut created_auctions: u32 = 0;
+		while uncovered_debit >= fixed_size && (max_auctions_count.is_zero() || created_auctions < max_auctions_count) {
+			let new_minted = minted.saturating_add(initial_amount);
+			if new_minted > mint_cap_per_period {
+				break;
+			}
+
+			if let Err(e) = T::AuctionManagerHandler::new_debit_auction(initial_amount, fixed_size) {
+				log::warn!(
+					target: "cdp-treasury",
+					"create_debit_auctions: failed to create debit auction: {:?}",
+					e
+				);
+				break;
+			}
+
+			minted = new_minted;
+			uncovered_debit = uncovered_debit.saturating_sub(fixed_size);
+			created_auctions = created_auctions.saturating_add(1);
+		}
+
+		if !created_auctions.is_zero() {
+			DebitAuctionMintInPeriod::<T>::put((period_start, minted));
+		}
+		created_auctions
+	}
 }
 
 impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
@@ -409,6 +847,119 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 	fn withdraw_collateral(to: &T::AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult {
 		T::Currency::transfer(currency_id, &Self::account_id(), to, amount)
 	}
+
+	fn get_peg_reserve(id: Self::CurrencyId) -> (Self::Balance, Self::Balance) {
+		let PegReserve { reserve, issued } = Self::peg_reserves(id);
+		(reserve, issued)
+	}
+
+	fn on_peg_reserve_deposited(id: Self::CurrencyId, reserve: Self::Balance, issued: Self::Balance) -> DispatchResult {
+		PegReserves::<T>::try_mutate(id, |peg_reserve| -> DispatchResult {
+			peg_reserve.reserve = peg_reserve
+				.reserve
+				.checked_add(reserve)
+				.ok_or(ArithmeticError::Overflow)?;
+			peg_reserve.issued = peg_reserve
+				.issued
+				.checked_add(issued)
+				.ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})
+	}
+
+	fn on_peg_reserve_withdrawn(id: Self::CurrencyId, reserve: Self::Balance, burned: Self::Balance) -> DispatchResult {
+		PegReserves::<T>::try_mutate_exists(id, |maybe_peg_reserve| -> DispatchResult {
+			let mut peg_reserve = maybe_peg_reserve.take().unwrap_or_default();
+			peg_reserve.reserve = peg_reserve
+				.reserve
+				.checked_sub(reserve)
+				.ok_or(ArithmeticError::Underflow)?;
+			peg_reserve.issued = peg_reserve
+				.issued
+				.checked_sub(burned)
+				.ok_or(ArithmeticError::Underflow)?;
+			if peg_reserve != PegReserve::default() {
+				*maybe_peg_reserve = Some(peg_reserve);
+			}
+			Ok(())
+		})
+	}
+
+	#[transactional]
+	fn on_liquidation_penalty(id: Self::CurrencyId, penalty: Self::Balance) -> DispatchResult {
+		// the penalty received may have been used to offset the debit pool
+		let amount = Self::insurance_fund_shares(id)
+			.saturating_mul_int(penalty)
+			.min(Self::surplus_pool());
+		if amount.is_zero() {
+			return Ok(());
+		}
+		Self::do_deposit_insurance_fund(id, amount)
+	}
+
+	#[transactional]
+	fn on_liquidation_loss(id: Self::CurrencyId, loss: Self::Balance) -> DispatchResult {
+		let mut uncovered_loss = loss.min(Self::debit_pool());
+		if uncovered_loss.is_zero() {
+			return Ok(());
+		}
+		let mut loss_record = Self::loss_records(id);
+
+		// cover the loss by the insurance fund of the collateral type first
+		let insurance_amount = uncovered_loss.min(Self::insurance_funds(id));
+		if !insurance_amount.is_zero() {
+			Self::burn_debit(&Self::insurance_fund_account(), insurance_amount)?;
+			InsuranceFunds::<T>::mutate(id, |fund| *fund = fund.saturating_sub(insurance_amount));
+			DebitPool::<T>::mutate(|debit| *debit = debit.saturating_sub(insurance_amount));
+			uncovered_loss = uncovered_loss.saturating_sub(insurance_amount);
+			loss_record.covered_by_insurance_fund =
+				loss_record.covered_by_insurance_fund.saturating_add(insurance_amount);
+			Self::deposit_event(Event::LossCoveredByInsuranceFund {
+				collateral_type: id,
+				amount: insurance_amount,
+			});
+		}
+
+		// then by the surplus pool, except for the debit offset buffer
+		let surplus_amount = uncovered_loss
+			.min(Self::surplus_pool())
+			.min(Self::debit_pool().saturating_sub(Self::debit_offset_buffer()));
+		if !surplus_amount.is_zero() {
+			Self::burn_debit(&Self::account_id(), surplus_amount)?;
+			DebitPool::<T>::mutate(|debit| *debit = debit.saturating_sub(surplus_amount));
+			uncovered_loss = uncovered_loss.saturating_sub(surplus_amount);
+			loss_record.covered_by_surplus = loss_record.covered_by_surplus.saturating_add(surplus_amount);
+			Self::deposit_event(Event::LossCoveredBySurplus {
+				collateral_type: id,
+				amount: surplus_amount,
+			});
+		}
+
+		// the rest stays in the debit pool, to be recapitalised by debit auctions if enabled
+		if !uncovered_loss.is_zero() {
+			let DebitAuctionParams {
+				fixed_size,
+				initial_amount,
+				..
+			} = Self::debit_auction_params();
+			if fixed_size.is_zero() || initial_amount.is_zero() {
+				loss_record.socialised = loss_record.socialised.saturating_add(uncovered_loss);
+				Self::deposit_event(Event::LossSocialised {
+					collateral_type: id,
+					amount: uncovered_loss,
+				});
+			} else {
+				loss_record.left_to_debit_auctions = loss_record.left_to_debit_auctions.saturating_add(uncovered_loss);
+				Self::deposit_event(Event::LossLeftToDebitAuctions {
+					collateral_type: id,
+					amount: uncovered_loss,
+				});
+			}
+		}
+
+		LossRecords::<T>::insert(id, loss_record);
+		Ok(())
+	}
 }
 
 impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
@@ -497,59 +1048,41 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 		refund_receiver: T::AccountId,
 		splited: bool,
 	) -> Result<u32, DispatchError> {
-		ensure!(
-			Self::total_collaterals_not_in_auction(currency_id) >= amount,
-			Error::<T>::CollateralNotEnough,
-		);
-
-		let mut unhandled_collateral_amount = amount;
-		let mut unhandled_target = target;
-		let expected_collateral_auction_size = Self::expected_collateral_auction_size(currency_id);
-		let max_auctions_count: Balance = T::MaxAuctionsCount::get().into();
-		let lots_count = if !splited
-			|| max_auctions_count.is_zero()
-			|| expected_collateral_auction_size.is_zero()
-			|| amount <= expected_collateral_auction_size
-		{
-			One::one()
-		} else {
-			let mut count = amount
-				.checked_div(expected_collateral_auction_size)
-				.expect("collateral auction maximum size is not zero; qed");
-
-			let remainder = amount
-				.checked_rem(expected_collateral_auction_size)
-				.expect("collateral auction maximum size is not zero; qed");
-			if !remainder.is_zero() {
-				count = count.saturating_add(One::one());
-			}
-			sp_std::cmp::min(count, max_auctions_count)
-		};
-		let average_amount_per_lot = amount.checked_div(lots_count).expect("lots count is at least 1; qed");
-		let average_target_per_lot = target.checked_div(lots_count).expect("lots count is at least 1; qed");
-		let mut created_lots: Balance = Zero::zero();
-
-		while !unhandled_collateral_amount.is_zero() {
-			created_lots = created_lots.saturating_add(One::one());
-			let (lot_collateral_amount, lot_target) = if created_lots == lots_count {
-				// the last lot may be have some remnant than average
-				(unhandled_collateral_amount, unhandled_target)
-			} else {
-				(average_amount_per_lot, average_target_per_lot)
-			};
+		Self::do_create_collateral_auctions(currency_id, amount, target, refund_receiver, splited, None)
+	}
 
-			T::AuctionManagerHandler::new_collateral_auction(
-				&refund_receiver,
-				currency_id,
-				lot_collateral_amount,
-				lot_target,
-			)?;
+	fn create_liquidation_auctions(
+		collateral_type: CurrencyId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+		penalty: Balance,
+		refund_receiver: T::AccountId,
+	) -> Result<u32, DispatchError> {
+		Self::do_create_collateral_auctions(
+			currency_id,
+			amount,
+			target,
+			refund_receiver,
+			true,
+			Some((collateral_type, penalty)),
+		)
+	}
 
-			unhandled_collateral_amount = unhandled_collateral_amount.saturating_sub(lot_collateral_amount);
-			unhandled_target = unhandled_target.saturating_sub(lot_target);
-		}
-		let created_auctions: u32 = created_lots.try_into().map_err(|_| ArithmeticError::Overflow)?;
-		Ok(created_auctions)
+	fn on_liquidation_auction_aborted(
+		collateral_type: CurrencyId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target: Balance,
+		penalty: Balance,
+	) {
+		AbortedLiquidations::<T>::mutate(currency_id, collateral_type, |maybe_aborted_liquidation| {
+			let (aborted_amount, aborted_debit) = maybe_aborted_liquidation.unwrap_or_default();
+			*maybe_aborted_liquidation = Some((
+				aborted_amount.saturating_add(amount),
+				aborted_debit.saturating_add(target.saturating_sub(penalty)),
+			));
+		});
 	}
 
 	fn remove_liquidity_for_lp_collateral(
@@ -160,6 +160,9 @@ impl module_dex::Config for Runtime {
 thread_local! {
 	pub static TOTAL_COLLATERAL_AUCTION: RefCell<u32> = RefCell::new(0);
 	pub static TOTAL_COLLATERAL_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
+	pub static TOTAL_DEBIT_AUCTION: RefCell<u32> = RefCell::new(0);
+	pub static TOTAL_DEBIT_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
+	pub static LIQUIDATION_AUCTION_PENALTIES: RefCell<Vec<(CurrencyId, Balance)>> = RefCell::new(Vec::new());
 }
 
 pub struct MockAuctionManager;
@@ -179,6 +182,24 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_liquidation_auction(
+		refund_recipient: &AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		collateral_type: Self::CurrencyId,
+		penalty: Self::Balance,
+	) -> DispatchResult {
+		LIQUIDATION_AUCTION_PENALTIES.with(|v| v.borrow_mut().push((collateral_type, penalty)));
+		Self::new_collateral_auction(refund_recipient, currency_id, amount, target)
+	}
+
+	fn new_debit_auction(_initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult {
+		TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut() += 1);
+		TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut() += fix_debit);
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -190,6 +211,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_target_in_auction() -> Self::Balance {
 		unimplemented!()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut())
+	}
 }
 
 ord_parameter_types! {
@@ -217,6 +242,7 @@ impl Config for Runtime {
 	type DEX = DEXModule;
 	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
 	type MaxAuctionsCount = ConstU32<5>;
+	type DebitAuctionMintPeriod = ConstU64<10>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
@@ -428,6 +428,36 @@ fn create_collateral_auctions_work() {
 	});
 }
 
+#[test]
+fn create_liquidation_auctions_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(BTC, &CDPTreasuryModule::account_id(), 10000));
+		assert_ok!(CDPTreasuryModule::set_expected_collateral_auction_size(
+			RuntimeOrigin::signed(1),
+			BTC,
+			300
+		));
+
+		// the penalty is split to the lots as the target
+		assert_ok!(CDPTreasuryModule::create_liquidation_auctions(
+			DOT, BTC, 1000, 1000, 102, ALICE
+		));
+		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 4);
+		assert_eq!(TOTAL_COLLATERAL_IN_AUCTION.with(|v| *v.borrow_mut()), 1000);
+		assert_eq!(
+			LIQUIDATION_AUCTION_PENALTIES.with(|v| v.borrow().clone()),
+			vec![(DOT, 25), (DOT, 25), (DOT, 25), (DOT, 27)]
+		);
+
+		// collateral auctions carry no liquidation penalty
+		assert_ok!(CDPTreasuryModule::create_collateral_auctions(
+			BTC, 200, 1000, ALICE, true
+		));
+		assert_eq!(TOTAL_COLLATERAL_AUCTION.with(|v| *v.borrow_mut()), 5);
+		assert_eq!(LIQUIDATION_AUCTION_PENALTIES.with(|v| v.borrow().len()), 4);
+	});
+}
+
 #[test]
 fn remove_liquidity_for_lp_collateral_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -550,6 +580,58 @@ fn auction_collateral_work() {
 	});
 }
 
+#[test]
+fn auction_collateral_settles_aborted_liquidation() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Currencies::deposit(BTC, &CDPTreasuryModule::account_id(), 1100));
+		assert_ok!(CDPTreasuryModule::on_system_debit(1000));
+		CDPTreasuryModule::on_liquidation_auction_aborted(DOT, BTC, 1000, 1100, 100);
+		assert_eq!(CDPTreasuryModule::aborted_liquidations(BTC, DOT), Some((1000, 1000)));
+
+		// the re-auction settles the debit of the aborted liquidation pro rata
+		assert_ok!(CDPTreasuryModule::auction_collateral(
+			RuntimeOrigin::signed(1),
+			BTC,
+			400,
+			500,
+			false
+		));
+		assert_eq!(CDPTreasuryModule::aborted_liquidations(BTC, DOT), Some((600, 600)));
+		assert_eq!(
+			LIQUIDATION_AUCTION_PENALTIES.with(|v| v.borrow().clone()),
+			vec![(DOT, 100)]
+		);
+		assert_eq!(CDPTreasuryModule::loss_records(DOT), Default::default());
+
+		// the debit beyond the target can not be recovered
+		assert_ok!(CDPTreasuryModule::auction_collateral(
+			RuntimeOrigin::signed(1),
+			BTC,
+			600,
+			500,
+			false
+		));
+		assert_eq!(CDPTreasuryModule::aborted_liquidations(BTC, DOT), None);
+		assert_eq!(
+			LIQUIDATION_AUCTION_PENALTIES.with(|v| v.borrow().clone()),
+			vec![(DOT, 100), (DOT, 0)]
+		);
+		assert_eq!(CDPTreasuryModule::loss_records(DOT).socialised, 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 1000);
+
+		// the collateral not from aborted liquidations is auctioned as before
+		assert_ok!(CDPTreasuryModule::auction_collateral(
+			RuntimeOrigin::signed(1),
+			BTC,
+			100,
+			100,
+			false
+		));
+		assert_eq!(LIQUIDATION_AUCTION_PENALTIES.with(|v| v.borrow().len()), 2);
+	});
+}
+
 #[test]
 fn exchange_collateral_to_stable_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -681,3 +763,331 @@ fn offset_surplus_and_debit_limited_by_debit_offset_buffer() {
 		assert_eq!(CDPTreasuryModule::debit_offset_buffer(), 200);
 	});
 }
+
+#[test]
+fn peg_reserve_accounting_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(CDPTreasuryModule::get_peg_reserve(DOT), (0, 0));
+
+		assert_ok!(CDPTreasuryModule::on_peg_reserve_deposited(DOT, 100, 1000));
+		assert_ok!(CDPTreasuryModule::on_peg_reserve_deposited(DOT, 50, 500));
+		assert_eq!(
+			CDPTreasuryModule::peg_reserves(DOT),
+			PegReserve {
+				reserve: 150,
+				issued: 1500
+			}
+		);
+		assert_eq!(CDPTreasuryModule::get_peg_reserve(DOT), (150, 1500));
+
+		assert_noop!(
+			CDPTreasuryModule::on_peg_reserve_withdrawn(DOT, 151, 1500),
+			ArithmeticError::Underflow
+		);
+		assert_noop!(
+			CDPTreasuryModule::on_peg_reserve_withdrawn(DOT, 150, 1501),
+			ArithmeticError::Underflow
+		);
+
+		assert_ok!(CDPTreasuryModule::on_peg_reserve_withdrawn(DOT, 100, 1000));
+		assert_eq!(CDPTreasuryModule::get_peg_reserve(DOT), (50, 500));
+
+		assert_ok!(CDPTreasuryModule::on_peg_reserve_withdrawn(DOT, 50, 500));
+		assert!(!PegReserves::<Runtime>::contains_key(DOT));
+	});
+}
+
+#[test]
+fn set_debit_auction_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = DebitAuctionParams {
+			fixed_size: 100,
+			initial_amount: 1000,
+			mint_cap_per_period: 2500,
+		};
+		assert_eq!(CDPTreasuryModule::debit_auction_params(), DebitAuctionParams::default());
+		assert_noop!(
+			CDPTreasuryModule::set_debit_auction_params(RuntimeOrigin::signed(5), params),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_debit_auction_params(
+			RuntimeOrigin::signed(1),
+			params
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::DebitAuctionParamsUpdated { params },
+		));
+		assert_eq!(CDPTreasuryModule::debit_auction_params(), params);
+	});
+}
+
+#[test]
+fn create_debit_auctions_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_debit(650));
+		assert_ok!(CDPTreasuryModule::deposit_surplus(&ALICE, 100));
+		assert_ok!(CDPTreasuryModule::set_debit_offset_buffer(
+			RuntimeOrigin::signed(1),
+			100
+		));
+
+		// no debit auction without params
+		assert_eq!(CDPTreasuryModule::create_debit_auctions(1), 0);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 0);
+
+		assert_ok!(CDPTreasuryModule::set_debit_auction_params(
+			RuntimeOrigin::signed(1),
+			DebitAuctionParams {
+				fixed_size: 100,
+				initial_amount: 1000,
+				mint_cap_per_period: 2500,
+			}
+		));
+
+		// 450 debit is neither covered by the surplus nor the buffer, but the mint cap only
+		// allows two lots within the period
+		assert_eq!(CDPTreasuryModule::create_debit_auctions(1), 2);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 2);
+		assert_eq!(MockAuctionManager::get_total_debit_in_auction(), 200);
+		assert_eq!(CDPTreasuryModule::debit_auction_mint_in_period(), (0, 2000));
+
+		assert_eq!(CDPTreasuryModule::create_debit_auctions(9), 0);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 2);
+
+		// the mint cap resets in the next period
+		assert_eq!(CDPTreasuryModule::create_debit_auctions(10), 2);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 4);
+		assert_eq!(MockAuctionManager::get_total_debit_in_auction(), 400);
+		assert_eq!(CDPTreasuryModule::debit_auction_mint_in_period(), (10, 2000));
+
+		// the remaining 50 debit is less than the fixed size
+		assert_eq!(CDPTreasuryModule::create_debit_auctions(20), 0);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 4);
+		assert_eq!(CDPTreasuryModule::debit_auction_mint_in_period(), (10, 2000));
+	});
+}
+
+#[test]
+fn set_insurance_fund_share_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(CDPTreasuryModule::insurance_fund_shares(BTC), Ratio::zero());
+		assert_noop!(
+			CDPTreasuryModule::set_insurance_fund_share(
+				RuntimeOrigin::signed(5),
+				BTC,
+				Ratio::saturating_from_rational(1, 5)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::set_insurance_fund_share(
+				RuntimeOrigin::signed(1),
+				BTC,
+				Ratio::saturating_from_rational(6, 5)
+			),
+			Error::<Runtime>::InvalidInsuranceFundShare
+		);
+		assert_ok!(CDPTreasuryModule::set_insurance_fund_share(
+			RuntimeOrigin::signed(1),
+			BTC,
+			Ratio::saturating_from_rational(1, 5)
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::InsuranceFundShareUpdated {
+				collateral_type: BTC,
+				share: Ratio::saturating_from_rational(1, 5),
+			},
+		));
+		assert_eq!(
+			CDPTreasuryModule::insurance_fund_shares(BTC),
+			Ratio::saturating_from_rational(1, 5)
+		);
+	});
+}
+
+#[test]
+fn deposit_and_withdraw_insurance_fund_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+
+		assert_noop!(
+			CDPTreasuryModule::deposit_insurance_fund(RuntimeOrigin::signed(5), BTC, 300),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::deposit_insurance_fund(RuntimeOrigin::signed(1), BTC, 501),
+			Error::<Runtime>::SurplusPoolNotEnough
+		);
+		assert_ok!(CDPTreasuryModule::deposit_insurance_fund(
+			RuntimeOrigin::signed(1),
+			BTC,
+			300
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::InsuranceFundDeposited {
+			collateral_type: BTC,
+			amount: 300,
+		}));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 300);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 200);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &CDPTreasuryModule::insurance_fund_account()),
+			300
+		);
+
+		assert_noop!(
+			CDPTreasuryModule::withdraw_insurance_fund(RuntimeOrigin::signed(5), BTC, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPTreasuryModule::withdraw_insurance_fund(RuntimeOrigin::signed(1), DOT, 100),
+			Error::<Runtime>::InsuranceFundNotEnough
+		);
+		assert_ok!(CDPTreasuryModule::withdraw_insurance_fund(
+			RuntimeOrigin::signed(1),
+			BTC,
+			100
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::InsuranceFundWithdrawn {
+			collateral_type: BTC,
+			amount: 100,
+		}));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 200);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+		assert_eq!(
+			Currencies::free_balance(AUSD, &CDPTreasuryModule::insurance_fund_account()),
+			200
+		);
+	});
+}
+
+#[test]
+fn on_liquidation_penalty_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(30));
+
+		// no share of penalty for insurance fund by default
+		assert_ok!(CDPTreasuryModule::on_liquidation_penalty(BTC, 100));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 0);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 30);
+
+		assert_ok!(CDPTreasuryModule::set_insurance_fund_share(
+			RuntimeOrigin::signed(1),
+			BTC,
+			Ratio::saturating_from_rational(1, 5)
+		));
+		assert_ok!(CDPTreasuryModule::on_liquidation_penalty(BTC, 100));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::InsuranceFundDeposited {
+			collateral_type: BTC,
+			amount: 20,
+		}));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 20);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 10);
+
+		// limited by the surplus pool
+		assert_ok!(CDPTreasuryModule::on_liquidation_penalty(BTC, 200));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 30);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+	});
+}
+
+#[test]
+fn on_liquidation_loss_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		assert_ok!(CDPTreasuryModule::deposit_insurance_fund(
+			RuntimeOrigin::signed(1),
+			BTC,
+			300
+		));
+		assert_ok!(CDPTreasuryModule::on_system_debit(1000));
+		assert_ok!(CDPTreasuryModule::set_debit_offset_buffer(
+			RuntimeOrigin::signed(1),
+			100
+		));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 700);
+
+		// covered by the insurance fund
+		assert_ok!(CDPTreasuryModule::on_liquidation_loss(BTC, 200));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::LossCoveredByInsuranceFund {
+				collateral_type: BTC,
+				amount: 200,
+			},
+		));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 800);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 700);
+
+		// covered by the insurance fund, then the surplus pool
+		assert_ok!(CDPTreasuryModule::on_liquidation_loss(BTC, 500));
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(
+			crate::Event::LossCoveredByInsuranceFund {
+				collateral_type: BTC,
+				amount: 100,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::LossCoveredBySurplus {
+			collateral_type: BTC,
+			amount: 400,
+		}));
+		assert_eq!(CDPTreasuryModule::insurance_funds(BTC), 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 300);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+
+		// the surplus pool keeps the debit offset buffer, and the rest is socialised without
+		// debit auctions
+		assert_ok!(CDPTreasuryModule::on_liquidation_loss(BTC, 250));
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(crate::Event::LossCoveredBySurplus {
+			collateral_type: BTC,
+			amount: 200,
+		}));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::LossSocialised {
+			collateral_type: BTC,
+			amount: 50,
+		}));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+
+		// the loss is limited by the debit pool, and the rest is left to debit auctions
+		assert_ok!(CDPTreasuryModule::set_debit_auction_params(
+			RuntimeOrigin::signed(1),
+			DebitAuctionParams {
+				fixed_size: 100,
+				initial_amount: 1000,
+				mint_cap_per_period: 2500,
+			}
+		));
+		assert_ok!(CDPTreasuryModule::on_system_debit(100));
+		assert_ok!(CDPTreasuryModule::on_liquidation_loss(BTC, 300));
+		System::assert_has_event(RuntimeEvent::CDPTreasuryModule(crate::Event::LossCoveredBySurplus {
+			collateral_type: BTC,
+			amount: 100,
+		}));
+		System::assert_last_event(RuntimeEvent::CDPTreasuryModule(crate::Event::LossLeftToDebitAuctions {
+			collateral_type: BTC,
+			amount: 100,
+		}));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+
+		assert_eq!(
+			CDPTreasuryModule::loss_records(BTC),
+			LossRecord {
+				covered_by_insurance_fund: 300,
+				covered_by_surplus: 700,
+				left_to_debit_auctions: 100,
+				socialised: 50,
+			}
+		);
+		assert_eq!(CDPTreasuryModule::loss_records(DOT), LossRecord::default());
+		assert_eq!(
+			Currencies::free_balance(AUSD, &CDPTreasuryModule::insurance_fund_account()),
+			0
+		);
+	});
+}
@@ -35,7 +35,7 @@ use frame_support::{
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use module_support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext};
+use module_support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext, TransferFrom};
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::{OnDust, TransferAll},
@@ -49,7 +49,7 @@ use sp_runtime::{
 	traits::{CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
 	DispatchError, DispatchResult,
 };
-use sp_std::{fmt::Debug, marker, result, vec::Vec};
+use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker, result, vec::Vec};
 
 mod mock;
 mod tests;
@@ -160,6 +160,23 @@ pub mod module {
 		},
 	}
 
+	/// The ERC20 balance locked under a lock id. The locked amount of an account is the maximum of
+	/// its locks and is escrowed to the lock address derived from the account's EVM address.
+	///
+	/// Erc20Locks: map (AccountId, EvmAddress, LockIdentifier) => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_locks)]
+	pub type Erc20Locks<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, EvmAddress>,
+			NMapKey<Twox64Concat, LockIdentifier>,
+		),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -255,7 +272,9 @@ pub mod module {
 		///
 		/// The dispatch origin of this call must be _Root_.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::force_set_lock())]
+		#[pallet::weight(T::WeightInfo::force_set_lock()
+			.saturating_add(if currency_id.is_erc20_currency_id() { T::GasToWeight::convert(erc20::TRANSFER.gas) } else { Weight::zero() })
+		)]
 		pub fn force_set_lock(
 			origin: OriginFor<T>,
 			who: <T::Lookup as StaticLookup>::Source,
@@ -272,7 +291,9 @@ pub mod module {
 		///
 		/// The dispatch origin of this call must be _Root_.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::force_remove_lock())]
+		#[pallet::weight(T::WeightInfo::force_remove_lock()
+			.saturating_add(if currency_id.is_erc20_currency_id() { T::GasToWeight::convert(erc20::TRANSFER.gas) } else { Weight::zero() })
+		)]
 		pub fn force_remove_lock(
 			origin: OriginFor<T>,
 			who: <T::Lookup as StaticLookup>::Source,
@@ -291,6 +312,66 @@ impl<T: Config> Pallet<T> {
 		let origin = T::EVMBridge::get_real_or_xcm_origin().ok_or(Error::<T>::RealOriginNotFound)?;
 		Ok(T::AddressMapping::get_or_create_evm_address(&origin))
 	}
+
+	/// The ERC20 balance of `who` locked by all locks, which is the maximum of them.
+	pub fn erc20_locked_balance(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		Erc20Locks::<T>::iter_prefix_values((who.clone(), contract)).fold(Zero::zero(), |max, locked| max.max(locked))
+	}
+
+	/// Update the ERC20 lock `lock_id` of `who` to `amount`, or remove it if `None`, and move the
+	/// difference of the locked balance between the account and its lock address.
+	fn update_erc20_lock(
+		lock_id: LockIdentifier,
+		contract: EvmAddress,
+		who: &T::AccountId,
+		amount: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let mut locks: BTreeMap<LockIdentifier, BalanceOf<T>> =
+			Erc20Locks::<T>::iter_prefix((who.clone(), contract)).collect();
+		let prev_locked = locks
+			.values()
+			.fold(Zero::zero(), |max: BalanceOf<T>, locked| max.max(*locked));
+		match amount {
+			Some(amount) => locks.insert(lock_id, amount),
+			None => locks.remove(&lock_id),
+		};
+		let new_locked = locks
+			.values()
+			.fold(Zero::zero(), |max: BalanceOf<T>, locked| max.max(*locked));
+
+		if new_locked != prev_locked {
+			let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+			let escrow = lock_address(address);
+			let origin = Self::get_evm_origin().unwrap_or(address);
+			if new_locked > prev_locked {
+				T::EVMBridge::transfer(
+					InvokeContext {
+						contract,
+						sender: address,
+						origin,
+					},
+					escrow,
+					new_locked - prev_locked,
+				)?;
+			} else {
+				T::EVMBridge::transfer(
+					InvokeContext {
+						contract,
+						sender: escrow,
+						origin,
+					},
+					address,
+					prev_locked - new_locked,
+				)?;
+			}
+		}
+
+		match amount {
+			Some(amount) => Erc20Locks::<T>::insert((who, contract, lock_id), amount),
+			None => Erc20Locks::<T>::remove((who, contract, lock_id)),
+		}
+		Ok(())
+	}
 }
 
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
@@ -320,10 +401,13 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 
 	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
-			CurrencyId::Erc20(_) => {
+			CurrencyId::Erc20(contract) => {
 				let free_balance = Self::free_balance(currency_id, who);
 				let reserved_balance = <Self as MultiReservableCurrency<_>>::reserved_balance(currency_id, who);
-				free_balance.saturating_add(reserved_balance)
+				let locked_balance = Self::erc20_locked_balance(contract, who);
+				free_balance
+					.saturating_add(reserved_balance)
+					.saturating_add(locked_balance)
 			}
 			id if id == T::GetNativeCurrencyId::get() => <T::NativeCurrency as BasicCurrency<_>>::total_balance(who),
 			_ => <T::MultiCurrency as MultiCurrency<_>>::total_balance(currency_id, who),
@@ -545,7 +629,12 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Self::update_erc20_lock(lock_id, contract, who, None);
+				}
+				Self::update_erc20_lock(lock_id, contract, who, Some(amount))
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::set_lock(lock_id, who, amount)
 			}
@@ -560,7 +649,13 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				let existing = Erc20Locks::<T>::get((who, contract, lock_id));
+				if amount <= existing {
+					return Ok(());
+				}
+				Self::update_erc20_lock(lock_id, contract, who, Some(amount))
+			}
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::extend_lock(lock_id, who, amount)
 			}
@@ -570,7 +665,7 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 
 	fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => Self::update_erc20_lock(lock_id, contract, who, None),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as BasicLockableCurrency<_>>::remove_lock(lock_id, who)
 			}
@@ -1792,6 +1887,77 @@ fn reserve_address(address: EvmAddress) -> EvmAddress {
 	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
 }
 
+fn lock_address(address: EvmAddress) -> EvmAddress {
+	let payload = (b"erc20:lock:", address);
+	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
+}
+
+impl<T: Config> TransferFrom<T::AccountId> for Pallet<T> {
+	type CurrencyId = CurrencyId;
+	type Balance = BalanceOf<T>;
+
+	fn allowance(currency_id: Self::CurrencyId, owner: &T::AccountId, spender: &T::AccountId) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(contract) => {
+				match (
+					T::AddressMapping::get_evm_address(owner),
+					T::AddressMapping::get_evm_address(spender),
+				) {
+					(Some(owner), Some(spender)) => T::EVMBridge::allowance(
+						InvokeContext {
+							contract,
+							sender: Default::default(),
+							origin: Default::default(),
+						},
+						owner,
+						spender,
+					)
+					.unwrap_or_default(),
+					_ => Zero::zero(),
+				}
+			}
+			_ => <Self as MultiCurrency<_>>::free_balance(currency_id, owner),
+		}
+	}
+
+	fn transfer_from(
+		currency_id: Self::CurrencyId,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		match currency_id {
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() || from == to {
+					return Ok(());
+				}
+				let sender = T::AddressMapping::get_or_create_evm_address(spender);
+				let owner = T::AddressMapping::get_evm_address(from).ok_or(Error::<T>::EvmAccountNotFound)?;
+				let receiver = T::AddressMapping::get_or_create_evm_address(to);
+				T::EVMBridge::transfer_from(
+					InvokeContext {
+						contract,
+						sender,
+						origin: Self::get_evm_origin().unwrap_or(sender),
+					},
+					owner,
+					receiver,
+					amount,
+				)?;
+				Self::deposit_event(Event::Transferred {
+					currency_id,
+					from: from.clone(),
+					to: to.clone(),
+					amount,
+				});
+				Ok(())
+			}
+			_ => <Self as MultiCurrency<_>>::transfer(currency_id, from, to, amount),
+		}
+	}
+}
+
 pub struct TransferDust<T, GetAccountId>(marker::PhantomData<(T, GetAccountId)>);
 impl<T: Config, GetAccountId> OnDust<T::AccountId, CurrencyId, BalanceOf<T>> for TransferDust<T, GetAccountId>
 where
@@ -194,6 +194,7 @@ impl module_evm::Config for Runtime {
 	type FindAuthor = ();
 	type Task = ();
 	type IdleScheduler = ();
+	type ContractPauseFilter = ();
 	type WeightInfo = ();
 }
 
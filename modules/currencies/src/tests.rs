@@ -1021,24 +1021,124 @@ fn erc20_should_not_slash() {
 }
 
 #[test]
-fn erc20_should_not_be_lockable() {
+fn erc20_lockable_should_work() {
 	ExtBuilder::default()
 		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 200000)])
 		.build()
 		.execute_with(|| {
 			deploy_contracts();
+			let erc20 = CurrencyId::Erc20(erc20_address());
+
+			assert_ok!(Currencies::set_lock(ID_1, erc20, &alice(), 100));
+			assert_eq!(Currencies::erc20_locks((alice(), erc20_address(), ID_1)), 100);
+			assert_eq!(Currencies::erc20_locked_balance(erc20_address(), &alice()), 100);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE - 100);
+			assert_eq!(Currencies::total_balance(erc20, &alice()), ALICE_BALANCE);
+
+			// a smaller lock under another id overlaps
+			assert_ok!(Currencies::set_lock(*b"2       ", erc20, &alice(), 60));
+			assert_eq!(Currencies::erc20_locked_balance(erc20_address(), &alice()), 100);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE - 100);
+
+			// extend_lock never shrinks a lock
+			assert_ok!(Currencies::extend_lock(ID_1, erc20, &alice(), 50));
+			assert_eq!(Currencies::erc20_locks((alice(), erc20_address(), ID_1)), 100);
+			assert_ok!(Currencies::extend_lock(ID_1, erc20, &alice(), 150));
+			assert_eq!(Currencies::erc20_locks((alice(), erc20_address(), ID_1)), 150);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE - 150);
+
+			// locked balance can not be transferred
 			assert_noop!(
-				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
-			);
-			assert_noop!(
-				Currencies::extend_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
-			);
-			assert_noop!(
-				Currencies::remove_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice()),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::ensure_can_withdraw(erc20, &alice(), ALICE_BALANCE - 149),
+				Error::<Runtime>::BalanceTooLow
 			);
+
+			assert_ok!(Currencies::remove_lock(ID_1, erc20, &alice()));
+			assert_eq!(Currencies::erc20_locked_balance(erc20_address(), &alice()), 60);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE - 60);
+
+			// set_lock with zero removes the lock
+			assert_ok!(Currencies::set_lock(*b"2       ", erc20, &alice(), 0));
+			assert_eq!(Currencies::erc20_locked_balance(erc20_address(), &alice()), 0);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE);
+			assert_eq!(Currencies::total_balance(erc20, &alice()), ALICE_BALANCE);
+		});
+}
+
+#[test]
+fn erc20_lock_should_fail_with_insufficient_balance() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 200000),
+			(bob(), NATIVE_CURRENCY_ID, 200000),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let erc20 = CurrencyId::Erc20(erc20_address());
+
+			assert!(Currencies::set_lock(ID_1, erc20, &bob(), 1).is_err());
+			assert_eq!(Currencies::erc20_locks((bob(), erc20_address(), ID_1)), 0);
+			assert!(Currencies::set_lock(ID_1, erc20, &alice(), ALICE_BALANCE + 1).is_err());
+			assert_eq!(Currencies::erc20_locks((alice(), erc20_address(), ID_1)), 0);
+		});
+}
+
+#[test]
+fn erc20_transfer_from_should_work() {
+	ExtBuilder::default()
+		.balances(vec![
+			(alice(), NATIVE_CURRENCY_ID, 200000),
+			(bob(), NATIVE_CURRENCY_ID, 200000),
+		])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let erc20 = CurrencyId::Erc20(erc20_address());
+			<EVM as EVMTrait<AccountId>>::set_origin(bob());
+
+			assert_eq!(Currencies::allowance(erc20, &alice(), &bob()), 0);
+			assert!(Currencies::transfer_from(erc20, &bob(), &alice(), &bob(), 10).is_err());
+
+			// ERC20.approve(address,uint256) method hash
+			let mut input = hex::decode("095ea7b3").unwrap();
+			let bob_address = MockAddressMapping::get_evm_address(&bob()).unwrap();
+			input.extend_from_slice(sp_core::H256::from(bob_address).as_bytes());
+			input.extend_from_slice(sp_core::H256::from_low_u64_be(100).as_bytes());
+			assert_ok!(EVM::call(
+				RuntimeOrigin::signed(alice()),
+				erc20_address(),
+				input,
+				0,
+				1_000_000,
+				1_000,
+				vec![]
+			));
+			assert_eq!(Currencies::allowance(erc20, &alice(), &bob()), 100);
+
+			assert_ok!(Currencies::transfer_from(erc20, &bob(), &alice(), &bob(), 60));
+			assert_eq!(Currencies::free_balance(erc20, &bob()), 60);
+			assert_eq!(Currencies::free_balance(erc20, &alice()), ALICE_BALANCE - 60);
+			assert_eq!(Currencies::allowance(erc20, &alice(), &bob()), 40);
+			System::assert_last_event(RuntimeEvent::Currencies(crate::Event::Transferred {
+				currency_id: erc20,
+				from: alice(),
+				to: bob(),
+				amount: 60,
+			}));
+
+			assert!(Currencies::transfer_from(erc20, &bob(), &alice(), &bob(), 41).is_err());
+
+			// other currencies are transferred directly
+			assert_eq!(Currencies::allowance(NATIVE_CURRENCY_ID, &alice(), &bob()), 200000);
+			assert_ok!(Currencies::transfer_from(
+				NATIVE_CURRENCY_ID,
+				&bob(),
+				&alice(),
+				&bob(),
+				100
+			));
+			assert_eq!(Currencies::free_balance(NATIVE_CURRENCY_ID, &bob()), 200100);
 		});
 }
 
@@ -47,6 +47,7 @@ use sp_runtime::{
 };
 use sp_std::{prelude::*, vec};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -264,6 +265,13 @@ pub mod module {
 	pub type TradingPairStatuses<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, TradingPairStatus<Balance, BlockNumberFor<T>>, ValueQuery>;
 
+	/// The number of trading pairs of a currency that are not `Disabled`.
+	///
+	/// ActiveTradingPairCount: map CurrencyId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn active_trading_pair_count)]
+	pub type ActiveTradingPairCount<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, u32, ValueQuery>;
+
 	/// Provision of TradingPair by AccountId.
 	///
 	/// ProvisioningPool: double_map TradingPair, AccountId => (Balance,
@@ -295,8 +303,8 @@ pub mod module {
 		fn build(&self) {
 			self.initial_listing_trading_pairs.iter().for_each(
 				|(trading_pair, min_contribution, target_provision, not_before)| {
-					TradingPairStatuses::<T>::insert(
-						trading_pair,
+					<Pallet<T>>::set_trading_pair_status(
+						*trading_pair,
 						TradingPairStatus::Provisioning(ProvisioningParameters {
 							min_contribution: *min_contribution,
 							target_provision: *target_provision,
@@ -308,7 +316,7 @@ pub mod module {
 			);
 
 			self.initial_enabled_trading_pairs.iter().for_each(|trading_pair| {
-				TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+				<Pallet<T>>::set_trading_pair_status(*trading_pair, TradingPairStatus::<_, _>::Enabled);
 			});
 
 			self.initial_added_liquidity_pools
@@ -336,7 +344,11 @@ pub mod module {
 		}
 	}
 
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -552,7 +564,7 @@ pub mod module {
 				)
 			};
 
-			TradingPairStatuses::<T>::insert(
+			Self::set_trading_pair_status(
 				trading_pair,
 				TradingPairStatus::Provisioning(ProvisioningParameters {
 					min_contribution,
@@ -596,7 +608,7 @@ pub mod module {
 							(target_provision_b, target_provision_a),
 						)
 					};
-					TradingPairStatuses::<T>::insert(
+					Self::set_trading_pair_status(
 						trading_pair,
 						TradingPairStatus::Provisioning(ProvisioningParameters {
 							min_contribution,
@@ -668,7 +680,7 @@ pub mod module {
 					})?;
 
 					// update trading_pair to Enabled status
-					TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+					Self::set_trading_pair_status(trading_pair, TradingPairStatus::<_, _>::Enabled);
 
 					// record initial exchange rate so that founders can use it to calculate their own shares
 					InitialShareExchangeRates::<T>::insert(
@@ -714,7 +726,7 @@ pub mod module {
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
 			}
 
-			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			Self::set_trading_pair_status(trading_pair, TradingPairStatus::Enabled);
 			Self::deposit_event(Event::EnableTradingPair { trading_pair });
 			Ok(())
 		}
@@ -738,7 +750,7 @@ pub mod module {
 				Error::<T>::MustBeEnabled
 			);
 
-			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+			Self::set_trading_pair_status(trading_pair, TradingPairStatus::Disabled);
 			Self::deposit_event(Event::DisableTradingPair { trading_pair });
 			Ok(())
 		}
@@ -820,7 +832,7 @@ pub mod module {
 
 					if !met_target && expired {
 						// update trading_pair to disabled status
-						TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Disabled);
+						Self::set_trading_pair_status(trading_pair, TradingPairStatus::<_, _>::Disabled);
 
 						Self::deposit_event(Event::ProvisioningAborted {
 							trading_pair,
@@ -842,6 +854,28 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Update the status of `trading_pair` and the active trading pair count of its currencies.
+	fn set_trading_pair_status(trading_pair: TradingPair, status: TradingPairStatus<Balance, BlockNumberFor<T>>) {
+		TradingPairStatuses::<T>::mutate(trading_pair, |old_status| {
+			let was_active = !matches!(old_status, TradingPairStatus::Disabled);
+			let is_active = !matches!(status, TradingPairStatus::Disabled);
+			if was_active != is_active {
+				for currency_id in [trading_pair.first(), trading_pair.second()] {
+					ActiveTradingPairCount::<T>::mutate_exists(currency_id, |maybe_count| {
+						let count = maybe_count.unwrap_or_default();
+						let count = if is_active {
+							count.saturating_add(1)
+						} else {
+							count.saturating_sub(1)
+						};
+						*maybe_count = Some(count).filter(|c| !c.is_zero());
+					});
+				}
+			}
+			*old_status = status;
+		});
+	}
+
 	fn try_mutate_liquidity_pool<R, E>(
 		trading_pair: &TradingPair,
 		f: impl FnOnce((&mut Balance, &mut Balance)) -> sp_std::result::Result<R, E>,
@@ -964,7 +998,7 @@ impl<T: Config> Pallet<T> {
 				.checked_add(contribution_1)
 				.ok_or(ArithmeticError::Overflow)?;
 
-			TradingPairStatuses::<T>::insert(
+			Self::set_trading_pair_status(
 				trading_pair,
 				TradingPairStatus::<_, _>::Provisioning(provision_parameters),
 			);
@@ -0,0 +1,50 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{module::STORAGE_VERSION, ActiveTradingPairCount, Config, Pallet, TradingPairStatus, TradingPairStatuses};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+/// Count the trading pairs that are not `Disabled` for each currency into
+/// `ActiveTradingPairCount`.
+pub struct CountActiveTradingPairs<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for CountActiveTradingPairs<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+		for (trading_pair, status) in TradingPairStatuses::<T>::iter() {
+			reads = reads.saturating_add(1);
+			if !matches!(status, TradingPairStatus::Disabled) {
+				ActiveTradingPairCount::<T>::mutate(trading_pair.first(), |count| *count = count.saturating_add(1));
+				ActiveTradingPairCount::<T>::mutate(trading_pair.second(), |count| *count = count.saturating_add(1));
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(2);
+			}
+		}
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
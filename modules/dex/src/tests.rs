@@ -738,6 +738,71 @@ fn disable_trading_pair_work() {
 	});
 }
 
+#[test]
+fn active_trading_pair_count_tracks_status() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_eq!(DexModule::active_trading_pair_count(AUSD), 2);
+			assert_eq!(DexModule::active_trading_pair_count(DOT), 2);
+			assert_eq!(DexModule::active_trading_pair_count(BTC), 2);
+			assert_eq!(DexModule::active_trading_pair_count(ACA), 0);
+
+			assert_ok!(DexModule::disable_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT
+			));
+			assert_eq!(DexModule::active_trading_pair_count(AUSD), 1);
+			assert_eq!(DexModule::active_trading_pair_count(DOT), 1);
+			assert_eq!(DexModule::active_trading_pair_count(BTC), 2);
+
+			assert_ok!(DexModule::list_provisioning(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				ACA,
+				1_000_000_000_000u128,
+				1_000_000_000_000u128,
+				5_000_000_000_000u128,
+				2_000_000_000_000u128,
+				10,
+			));
+			assert_eq!(DexModule::active_trading_pair_count(AUSD), 2);
+			assert_eq!(DexModule::active_trading_pair_count(ACA), 1);
+
+			// updating a provisioning pair doesn't count it twice
+			assert_ok!(DexModule::update_provisioning_parameters(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				ACA,
+				1_000_000_000_000u128,
+				1_000_000_000_000u128,
+				5_000_000_000_000u128,
+				2_000_000_000_000u128,
+				20,
+			));
+			assert_eq!(DexModule::active_trading_pair_count(ACA), 1);
+
+			assert_ok!(DexModule::enable_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				ACA
+			));
+			assert_eq!(DexModule::active_trading_pair_count(AUSD), 2);
+			assert_eq!(DexModule::active_trading_pair_count(ACA), 1);
+
+			assert_ok!(DexModule::disable_trading_pair(
+				RuntimeOrigin::signed(ListingOrigin::get()),
+				AUSD,
+				ACA
+			));
+			assert_eq!(DexModule::active_trading_pair_count(AUSD), 1);
+			assert_eq!(DexModule::active_trading_pair_count(ACA), 0);
+			assert!(!ActiveTradingPairCount::<Runtime>::contains_key(ACA));
+		});
+}
+
 #[test]
 fn on_liquidity_pool_updated_work() {
 	ExtBuilder::default()
@@ -68,18 +68,18 @@ pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn enable_trading_pair() -> Weight {
 		Weight::from_parts(24_728_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn disable_trading_pair() -> Weight {
 		Weight::from_parts(24_891_000, 0)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn list_provisioning() -> Weight {
 		Weight::from_parts(37_619_000, 0)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	fn update_provisioning_parameters() -> Weight {
 		Weight::from_parts(11_808_000, 0)
@@ -146,8 +146,8 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	fn abort_provisioning() -> Weight {
 		Weight::from_parts(78_617_000, 0)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 }
 
@@ -155,18 +155,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 impl WeightInfo for () {
 	fn enable_trading_pair() -> Weight {
 		Weight::from_parts(24_728_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn disable_trading_pair() -> Weight {
 		Weight::from_parts(24_891_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(1 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn list_provisioning() -> Weight {
 		Weight::from_parts(37_619_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(3 as u64))
-			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	fn update_provisioning_parameters() -> Weight {
 		Weight::from_parts(11_808_000, 0)
@@ -233,7 +233,7 @@ impl WeightInfo for () {
 	}
 	fn abort_provisioning() -> Weight {
 		Weight::from_parts(78_617_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 }
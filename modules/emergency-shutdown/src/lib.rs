@@ -28,13 +28,22 @@
 //! CDPs has debit, cancel all active auctions module, when debits and gaps are
 //! settled, the stable currency holder are allowed to refund a basket of
 //! remaining collateral assets.
+//!
+//! When only a single collateral is in trouble, it can be wound down on its
+//! own instead: its price is locked, new debit against it is frozen, its CDPs
+//! are settled and its collateral auctions cancelled by the offchain workers,
+//! and after that the stable currency holders can redeem the stable currency
+//! pro-rata against the collateral pool, at most at the locked price, while
+//! the rest of the system keeps running.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use frame_support::pallet_prelude::*;
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, Ratio};
+use module_support::{
+	AuctionManager, CDPTreasury, EmergencyShutdown, LockablePrice, Price, PriceProvider, Ratio, RiskManager,
+};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{traits::Zero, FixedPointNumber};
 use sp_std::prelude::*;
@@ -46,6 +55,25 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The wind-down stage of a single collateral.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CollateralShutdownStatus {
+	/// The price is locked, CDPs are being settled and auctions cancelled.
+	Settling,
+	/// Settlement is completed, the stable currency can be redeemed against the collateral pool.
+	Refunding,
+}
+
+/// The collateral pool of a collateral which is wound down on its own, shared pro-rata by the
+/// debit value of its CDPs when it was shutdown.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollateralRefundPool {
+	/// The collateral left in the pool.
+	pub collateral_amount: Balance,
+	/// The stable currency amount which can still be refunded against the pool.
+	pub stable_claims: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -60,6 +88,13 @@ pub mod module {
 		/// Price source to freeze currencies' price
 		type PriceSource: LockablePrice<CurrencyId>;
 
+		/// Price source to get the locked price of a collateral which is wound down on its own
+		type LockedPriceSource: PriceProvider<CurrencyId>;
+
+		/// The stable currency id
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
 		/// CDP treasury to escrow collateral assets after settlement
 		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
@@ -87,6 +122,18 @@ pub mod module {
 		ExistPotentialSurplus,
 		/// Exist unhandled debit, means settlement has not been completed
 		ExistUnhandledDebit,
+		/// The currency is not a valid collateral
+		InvalidCollateralType,
+		/// The collateral has already been shutdown
+		CollateralAlreadyShutdown,
+		/// Must after the collateral shutdown
+		MustAfterCollateralShutdown,
+		/// Final redemption of the collateral is still not opened
+		CanNotRefundCollateral,
+		/// The locked price of the collateral is invalid
+		InvalidLockedPrice,
+		/// The stable currency amount exceeds the remaining claims on the collateral pool
+		ExceedCollateralRefundClaims,
 	}
 
 	#[pallet::event]
@@ -102,6 +149,23 @@ pub mod module {
 			stable_coin_amount: Balance,
 			refund_list: Vec<(CurrencyId, Balance)>,
 		},
+		/// A single collateral is shutdown.
+		CollateralShutdown {
+			currency_id: CurrencyId,
+			block_number: BlockNumberFor<T>,
+		},
+		/// The final redemption of a single collateral opened.
+		OpenCollateralPoolRefund {
+			currency_id: CurrencyId,
+			block_number: BlockNumberFor<T>,
+		},
+		/// Refund info of a single collateral.
+		RefundCollateral {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			stable_coin_amount: Balance,
+			refund_amount: Balance,
+		},
 	}
 
 	/// Emergency shutdown flag
@@ -118,6 +182,22 @@ pub mod module {
 	#[pallet::getter(fn can_refund)]
 	pub type CanRefund<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The collaterals which are wound down on their own.
+	///
+	/// CollateralShutdowns: map CurrencyId => Option<CollateralShutdownStatus>
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_shutdowns)]
+	pub type CollateralShutdowns<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CollateralShutdownStatus, OptionQuery>;
+
+	/// The collateral pools of the collaterals which have been shutdown.
+	///
+	/// CollateralRefundPools: map CurrencyId => Option<CollateralRefundPool>
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_refund_pools)]
+	pub type CollateralRefundPools<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CollateralRefundPool, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -138,8 +218,12 @@ pub mod module {
 			// get all collateral types
 			let collateral_currency_ids = T::CollateralCurrencyIds::get();
 
-			// lock price for every collateral
+			// lock price for every collateral, the price of the collateral which has been
+			// shutdown on its own is already locked
 			for currency_id in collateral_currency_ids {
+				if CollateralShutdowns::<T>::contains_key(currency_id) {
+					continue;
+				}
 				// TODO: check the results
 				let _ = <T as Config>::PriceSource::lock_price(currency_id);
 			}
@@ -222,6 +306,140 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Start the wind-down of a single collateral, the rest of the system keeps running.
+		///
+		/// The dispatch origin of this call must be `ShutdownOrigin`.
+		///
+		/// - `currency_id`: the collateral to shutdown.
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::WeightInfo::shutdown_collateral(), DispatchClass::Operational))]
+		pub fn shutdown_collateral(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::ShutdownOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType
+			);
+			ensure!(
+				!CollateralShutdowns::<T>::contains_key(currency_id),
+				Error::<T>::CollateralAlreadyShutdown
+			);
+
+			<T as Config>::PriceSource::lock_price(currency_id)?;
+
+			// the debit of the collateral stops accruing interest from now on, the stable currency
+			// issued against it is the claim on its collateral pool
+			let debit = <module_loans::Pallet<T>>::total_positions(currency_id).debit;
+			let stable_claims = <T as module_loans::Config>::RiskManager::get_debit_value(currency_id, debit);
+
+			CollateralShutdowns::<T>::insert(currency_id, CollateralShutdownStatus::Settling);
+			CollateralRefundPools::<T>::insert(
+				currency_id,
+				CollateralRefundPool {
+					collateral_amount: Zero::zero(),
+					stable_claims,
+				},
+			);
+			Self::deposit_event(Event::CollateralShutdown {
+				currency_id,
+				block_number: <frame_system::Pallet<T>>::block_number(),
+			});
+			Ok(())
+		}
+
+		/// Open final redemption of a single collateral if its settlement is completed.
+		///
+		/// The dispatch origin of this call must be `ShutdownOrigin`.
+		///
+		/// - `currency_id`: the collateral which has been shutdown.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::WeightInfo::open_collateral_pool_refund(), DispatchClass::Operational))]
+		pub fn open_collateral_pool_refund(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::ShutdownOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::collateral_shutdowns(currency_id) == Some(CollateralShutdownStatus::Settling),
+				Error::<T>::MustAfterCollateralShutdown
+			);
+
+			// there's no collateral auction
+			ensure!(
+				<T as Config>::AuctionManagerHandler::get_total_collateral_in_auction(currency_id).is_zero(),
+				Error::<T>::ExistPotentialSurplus,
+			);
+			// there's no debit in CDP
+			ensure!(
+				<module_loans::Pallet<T>>::total_positions(currency_id).debit.is_zero(),
+				Error::<T>::ExistUnhandledDebit,
+			);
+
+			// snapshot the pool, every stable currency unit gets the same share of the pool no matter
+			// when it is refunded
+			CollateralRefundPools::<T>::mutate(currency_id, |maybe_pool| {
+				if let Some(pool) = maybe_pool {
+					pool.collateral_amount = <T as Config>::CDPTreasury::get_total_collaterals(currency_id);
+				}
+			});
+			CollateralShutdowns::<T>::insert(currency_id, CollateralShutdownStatus::Refunding);
+			Self::deposit_event(Event::OpenCollateralPoolRefund {
+				currency_id,
+				block_number: <frame_system::Pallet<T>>::block_number(),
+			});
+			Ok(())
+		}
+
+		/// Refund the collateral which has been shutdown to caller. The caller gets a pro-rata
+		/// share of the collateral pool, at most the locked price.
+		///
+		/// - `currency_id`: the collateral to refund.
+		/// - `amount`: stable currency amount used to refund.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::refund_collateral())]
+		pub fn refund_collateral(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// after emergency shutdown, the stable currency is refunded against the whole basket
+			ensure!(!Self::is_shutdown(), Error::<T>::AlreadyShutdown);
+			ensure!(
+				Self::collateral_shutdowns(currency_id) == Some(CollateralShutdownStatus::Refunding),
+				Error::<T>::CanNotRefundCollateral
+			);
+
+			let settle_price: Price =
+				T::LockedPriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
+					.ok_or(Error::<T>::InvalidLockedPrice)?;
+
+			let refund_amount =
+				CollateralRefundPools::<T>::try_mutate(currency_id, |maybe_pool| -> Result<Balance, DispatchError> {
+					let pool = maybe_pool.as_mut().ok_or(Error::<T>::CanNotRefundCollateral)?;
+					ensure!(amount <= pool.stable_claims, Error::<T>::ExceedCollateralRefundClaims);
+
+					let pro_rata_amount = Ratio::checked_from_rational(amount, pool.stable_claims)
+						.unwrap_or_default()
+						.saturating_mul_int(pool.collateral_amount);
+					let refund_amount = pro_rata_amount.min(settle_price.saturating_mul_int(amount));
+
+					pool.collateral_amount = pool.collateral_amount.saturating_sub(refund_amount);
+					pool.stable_claims = pool.stable_claims.saturating_sub(amount);
+					Ok(refund_amount)
+				})?;
+
+			// burn caller's stable currency by CDP treasury
+			<T as Config>::CDPTreasury::burn_debit(&who, amount)?;
+			// refund collateral to caller by CDP treasury
+			<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_amount)?;
+
+			Self::deposit_event(Event::RefundCollateral {
+				who,
+				currency_id,
+				stable_coin_amount: amount,
+				refund_amount,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -229,4 +447,12 @@ impl<T: Config> EmergencyShutdown for Pallet<T> {
 	fn is_shutdown() -> bool {
 		Self::is_shutdown()
 	}
+
+	fn is_collateral_shutdown(currency_id: CurrencyId) -> bool {
+		CollateralShutdowns::<T>::contains_key(currency_id)
+	}
+
+	fn has_collateral_shutdown() -> bool {
+		CollateralShutdowns::<T>::iter_keys().next().is_some()
+	}
 }
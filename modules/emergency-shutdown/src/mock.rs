@@ -27,7 +27,9 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
-use module_support::{mocks::MockStableAsset, AuctionManager, LockablePrice, RiskManager, SpecificJointsSwap};
+use module_support::{
+	mocks::MockStableAsset, AuctionManager, LockablePrice, Price, PriceProvider, RiskManager, SpecificJointsSwap,
+};
 use orml_traits::parameter_type_with_key;
 use primitives::{Amount, TokenSymbol};
 use sp_core::H256;
@@ -170,6 +172,17 @@ impl LockablePrice<CurrencyId> for MockLockablePrice {
 	}
 }
 
+pub struct MockLockedPriceSource;
+impl PriceProvider<CurrencyId> for MockLockedPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			DOT => Some(Price::saturating_from_integer(10)),
+			_ => None,
+		}
+	}
+}
+
 pub struct MockAuctionManager;
 impl AuctionManager<AccountId> for MockAuctionManager {
 	type Balance = Balance;
@@ -185,6 +198,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		unimplemented!()
 	}
 
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -196,6 +213,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 ord_parameter_types! {
@@ -218,6 +239,7 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = ();
 	type Swap = SpecificJointsSwap<(), AlternativeSwapPathJointList>;
 	type MaxAuctionsCount = ConstU32<10_000>;
+	type DebitAuctionMintPeriod = ConstU64<10>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
@@ -232,6 +254,8 @@ impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = MockCollateralCurrencyIds;
 	type PriceSource = MockLockablePrice;
+	type LockedPriceSource = MockLockedPriceSource;
+	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CDPTreasuryModule;
 	type AuctionManagerHandler = MockAuctionManager;
 	type ShutdownOrigin = EnsureSignedBy<One, AccountId>;
@@ -264,6 +288,7 @@ impl Default for ExtBuilder {
 				(BOB, BTC, 1000),
 				(ALICE, DOT, 1000),
 				(BOB, DOT, 1000),
+				(ALICE, AUSD, 1000),
 			],
 		}
 	}
@@ -23,6 +23,7 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent, *};
+use orml_traits::MultiCurrency;
 use sp_runtime::traits::BadOrigin;
 
 #[test]
@@ -86,3 +87,214 @@ fn refund_collaterals_fail() {
 		);
 	});
 }
+
+#[test]
+fn shutdown_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(RuntimeOrigin::signed(5), DOT),
+			BadOrigin,
+		);
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(RuntimeOrigin::signed(1), ACA),
+			Error::<Runtime>::InvalidCollateralType,
+		);
+		assert!(!<EmergencyShutdownModule as EmergencyShutdown>::has_collateral_shutdown());
+
+		// the debit of DOT is the claim on its pool, not the debit of other collaterals
+		assert_ok!(Loans::adjust_position(&BOB, DOT, 100, 300));
+		assert_ok!(Loans::adjust_position(&BOB, BTC, 100, 500));
+		assert_ok!(EmergencyShutdownModule::shutdown_collateral(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+		System::assert_last_event(RuntimeEvent::EmergencyShutdownModule(
+			crate::Event::CollateralShutdown {
+				currency_id: DOT,
+				block_number: 1,
+			},
+		));
+		assert_eq!(
+			EmergencyShutdownModule::collateral_shutdowns(DOT),
+			Some(CollateralShutdownStatus::Settling)
+		);
+		assert_eq!(
+			EmergencyShutdownModule::collateral_refund_pools(DOT),
+			Some(CollateralRefundPool {
+				collateral_amount: 0,
+				stable_claims: 300,
+			})
+		);
+		assert!(<EmergencyShutdownModule as EmergencyShutdown>::is_collateral_shutdown(
+			DOT
+		));
+		assert!(!<EmergencyShutdownModule as EmergencyShutdown>::is_collateral_shutdown(
+			BTC
+		));
+		assert!(<EmergencyShutdownModule as EmergencyShutdown>::has_collateral_shutdown());
+		assert!(!EmergencyShutdownModule::is_shutdown());
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(RuntimeOrigin::signed(1), DOT),
+			Error::<Runtime>::CollateralAlreadyShutdown,
+		);
+
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			EmergencyShutdownModule::shutdown_collateral(RuntimeOrigin::signed(1), BTC),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn open_collateral_pool_refund_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_pool_refund(RuntimeOrigin::signed(1), DOT),
+			Error::<Runtime>::MustAfterCollateralShutdown,
+		);
+		assert_ok!(EmergencyShutdownModule::shutdown_collateral(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_pool_refund(RuntimeOrigin::signed(5), DOT),
+			BadOrigin,
+		);
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_pool_refund(RuntimeOrigin::signed(1), BTC),
+			Error::<Runtime>::MustAfterCollateralShutdown,
+		);
+
+		assert_ok!(EmergencyShutdownModule::open_collateral_pool_refund(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+		System::assert_last_event(RuntimeEvent::EmergencyShutdownModule(
+			crate::Event::OpenCollateralPoolRefund {
+				currency_id: DOT,
+				block_number: 1,
+			},
+		));
+		assert_eq!(
+			EmergencyShutdownModule::collateral_shutdowns(DOT),
+			Some(CollateralShutdownStatus::Refunding)
+		);
+		assert_noop!(
+			EmergencyShutdownModule::open_collateral_pool_refund(RuntimeOrigin::signed(1), DOT),
+			Error::<Runtime>::MustAfterCollateralShutdown,
+		);
+	});
+}
+
+#[test]
+fn refund_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Loans::adjust_position(&BOB, DOT, 50, 1000));
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(RuntimeOrigin::signed(ALICE), DOT, 100),
+			Error::<Runtime>::CanNotRefundCollateral,
+		);
+
+		assert_ok!(EmergencyShutdownModule::shutdown_collateral(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(RuntimeOrigin::signed(ALICE), DOT, 100),
+			Error::<Runtime>::CanNotRefundCollateral,
+		);
+
+		// the CDP is settled, its collateral goes to the pool
+		assert_ok!(Loans::confiscate_collateral_and_debit(&BOB, DOT, 50, 1000));
+		assert_ok!(EmergencyShutdownModule::open_collateral_pool_refund(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+
+		assert_eq!(
+			EmergencyShutdownModule::collateral_refund_pools(DOT),
+			Some(CollateralRefundPool {
+				collateral_amount: 50,
+				stable_claims: 1000,
+			})
+		);
+
+		// the pool is worth less than the settled debit at the locked price (1 DOT = 10 AUSD),
+		// refund pro-rata
+		assert_ok!(EmergencyShutdownModule::refund_collateral(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			100
+		));
+		System::assert_last_event(RuntimeEvent::EmergencyShutdownModule(crate::Event::RefundCollateral {
+			who: ALICE,
+			currency_id: DOT,
+			stable_coin_amount: 100,
+			refund_amount: 5,
+		}));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1005);
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(DOT), 45);
+		assert_eq!(
+			EmergencyShutdownModule::collateral_refund_pools(DOT),
+			Some(CollateralRefundPool {
+				collateral_amount: 45,
+				stable_claims: 900,
+			})
+		);
+
+		// later refunds get the same share
+		assert_ok!(EmergencyShutdownModule::refund_collateral(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			180
+		));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 720);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1014);
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(DOT), 36);
+
+		// can't claim more than the debit settled for the collateral
+		assert_ok!(CDPTreasuryModule::issue_debit(&BOB, 1000, true));
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(RuntimeOrigin::signed(BOB), DOT, 721),
+			Error::<Runtime>::ExceedCollateralRefundClaims,
+		);
+
+		// after emergency shutdown, refund against the whole basket instead
+		assert_ok!(EmergencyShutdownModule::emergency_shutdown(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			EmergencyShutdownModule::refund_collateral(RuntimeOrigin::signed(ALICE), DOT, 100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
+
+#[test]
+fn refund_collateral_capped_at_locked_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Loans::adjust_position(&BOB, DOT, 500, 1000));
+		assert_ok!(EmergencyShutdownModule::shutdown_collateral(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+		assert_ok!(Loans::confiscate_collateral_and_debit(&BOB, DOT, 500, 1000));
+		assert_ok!(EmergencyShutdownModule::open_collateral_pool_refund(
+			RuntimeOrigin::signed(1),
+			DOT
+		));
+
+		// the pro-rata share is 50 DOT, but 1 DOT = 10 AUSD at the locked price
+		assert_ok!(EmergencyShutdownModule::refund_collateral(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			100
+		));
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 1010);
+		assert_eq!(CDPTreasuryModule::get_total_collaterals(DOT), 490);
+	});
+}
@@ -51,6 +51,9 @@ pub trait WeightInfo {
 	fn emergency_shutdown(c: u32, ) -> Weight;
 	fn open_collateral_refund() -> Weight;
 	fn refund_collaterals(c: u32, ) -> Weight;
+	fn shutdown_collateral() -> Weight;
+	fn open_collateral_pool_refund() -> Weight;
+	fn refund_collateral() -> Weight;
 }
 
 /// Weights for module_emergency_shutdown using the Acala node and recommended hardware.
@@ -78,6 +81,21 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
 	}
+	fn shutdown_collateral() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn open_collateral_pool_refund() -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn refund_collateral() -> Weight {
+		Weight::from_parts(72_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -104,4 +122,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(c as u64)))
 	}
+	fn shutdown_collateral() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn open_collateral_pool_refund() -> Weight {
+		Weight::from_parts(27_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn refund_collateral() -> Weight {
+		Weight::from_parts(72_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
 }
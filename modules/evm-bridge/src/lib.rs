@@ -46,6 +46,9 @@ pub enum Action {
 	TotalSupply = "totalSupply()",
 	BalanceOf = "balanceOf(address)",
 	Transfer = "transfer(address,uint256)",
+	Allowance = "allowance(address,address)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+	Quote = "quote(address,uint256,uint256)",
 	Liquidate = "liquidate(address,address,uint256,uint256)",
 	OnCollateralTransfer = "onCollateralTransfer(address,uint256)",
 	OnRepaymentRefund = "onRepaymentRefund(address,uint256)",
@@ -245,6 +248,76 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for EVMBridge<T> {
 		Ok(())
 	}
 
+	// Calls the allowance method on an ERC20 contract using the given context
+	// and returns the amount `spender` is allowed to spend on behalf of `owner`.
+	fn allowance(context: InvokeContext, owner: H160, spender: H160) -> Result<BalanceOf<T>, DispatchError> {
+		// ERC20.allowance method hash
+		let mut input = Into::<u32>::into(Action::Allowance).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(owner).as_bytes());
+		// append spender address
+		input.extend_from_slice(H256::from(spender).as_bytes());
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			erc20::ALLOWANCE.gas,
+			erc20::ALLOWANCE.storage,
+			ExecutionMode::View,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		ensure!(info.value.len() == 32, Error::<T>::InvalidReturnValue);
+		let value: u128 = U256::from(info.value.as_slice())
+			.try_into()
+			.map_err(|_| ArithmeticError::Overflow)?;
+		let allowance = value.try_into().map_err(|_| ArithmeticError::Overflow)?;
+		Ok(allowance)
+	}
+
+	// Calls the transferFrom method on an ERC20 contract using the given context.
+	// `context.sender` is the spender and must have enough allowance from `from`.
+	fn transfer_from(context: InvokeContext, from: H160, to: H160, value: BalanceOf<T>) -> DispatchResult {
+		// ERC20.transferFrom method hash
+		let mut input = Into::<u32>::into(Action::TransferFrom).to_be_bytes().to_vec();
+		// append owner address
+		input.extend_from_slice(H256::from(from).as_bytes());
+		// append receiver address
+		input.extend_from_slice(H256::from(to).as_bytes());
+		// append amount to be transferred
+		input.extend_from_slice(H256::from_uint(&U256::from(value.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() {
+			0
+		} else {
+			erc20::TRANSFER_FROM.storage
+		};
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			erc20::TRANSFER_FROM.gas,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		// return value is true.
+		let mut bytes = [0u8; 32];
+		U256::from(1).to_big_endian(&mut bytes);
+
+		// Check return value to make sure not calling on empty contracts.
+		ensure!(
+			!info.value.is_empty() && info.value == bytes,
+			Error::<T>::InvalidReturnValue
+		);
+		Ok(())
+	}
+
 	fn get_origin() -> Option<AccountIdOf<T>> {
 		T::EVM::get_origin()
 	}
@@ -277,6 +350,40 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for EVMBridge<T> {
 pub struct LiquidationEvmBridge<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> LiquidationEvmBridgeT for LiquidationEvmBridge<T> {
+	fn quote(
+		context: InvokeContext,
+		collateral: EvmAddress,
+		amount: Balance,
+		min_repayment: Balance,
+	) -> Result<Balance, DispatchError> {
+		// liquidation contract method hash
+		let mut input = Into::<u32>::into(Action::Quote).to_be_bytes().to_vec();
+
+		// append collateral ERC20 address
+		input.extend_from_slice(H256::from(collateral).as_bytes());
+		// append collateral amount
+		input.extend_from_slice(H256::from_uint(&U256::from(amount)).as_bytes());
+		// append minimum repayment amount
+		input.extend_from_slice(H256::from_uint(&U256::from(min_repayment)).as_bytes());
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			liquidation::QUOTE.gas,
+			liquidation::QUOTE.storage,
+			ExecutionMode::View,
+		)?;
+
+		Pallet::<T>::handle_exit_reason(info.exit_reason)?;
+
+		ensure!(info.value.len() == 32, Error::<T>::InvalidReturnValue);
+		let value: u128 = U256::from(info.value.as_slice())
+			.try_into()
+			.map_err(|_| ArithmeticError::Overflow)?;
+		Ok(value)
+	}
+
 	fn liquidate(
 		context: InvokeContext,
 		collateral: EvmAddress,
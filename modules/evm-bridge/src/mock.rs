@@ -124,6 +124,7 @@ impl module_evm::Config for Runtime {
 	type FindAuthor = ();
 	type Task = ();
 	type IdleScheduler = ();
+	type ContractPauseFilter = ();
 	type WeightInfo = ();
 }
 
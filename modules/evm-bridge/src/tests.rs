@@ -201,6 +201,88 @@ fn should_transfer() {
 		});
 }
 
+#[test]
+fn should_read_allowance_and_transfer_from() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000), (bob(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			let context = InvokeContext {
+				contract: erc20_address(),
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(0)
+			);
+
+			assert_err!(
+				EVMBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					alice_evm_addr(),
+					bob_evm_addr(),
+					10
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+
+			// alice approves bob to spend 100
+			// ERC20.approve(address,uint256) method hash
+			let mut input = hex::decode("095ea7b3").unwrap();
+			input.extend_from_slice(H256::from(bob_evm_addr()).as_bytes());
+			input.extend_from_slice(H256::from_uint(&U256::from(100)).as_bytes());
+			assert_ok!(EVM::call(
+				RuntimeOrigin::signed(alice()),
+				erc20_address(),
+				input,
+				0,
+				1_000_000,
+				1_000,
+				vec![]
+			));
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(100)
+			);
+
+			assert_ok!(EVMBridge::<Runtime>::transfer_from(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: bob_evm_addr(),
+					origin: bob_evm_addr(),
+				},
+				alice_evm_addr(),
+				bob_evm_addr(),
+				60
+			));
+			assert_eq!(EVMBridge::<Runtime>::balance_of(context, bob_evm_addr()), Ok(60));
+			assert_eq!(
+				EVMBridge::<Runtime>::allowance(context, alice_evm_addr(), bob_evm_addr()),
+				Ok(40)
+			);
+
+			assert_err!(
+				EVMBridge::<Runtime>::transfer_from(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: bob_evm_addr(),
+						origin: bob_evm_addr(),
+					},
+					alice_evm_addr(),
+					bob_evm_addr(),
+					41
+				),
+				Error::<Runtime>::ExecutionRevert
+			);
+		});
+}
+
 #[test]
 fn liquidation_works() {
 	ExtBuilder::default()
@@ -353,3 +435,28 @@ fn liquidation_err_fails_as_expected() {
 			);
 		});
 }
+
+#[test]
+fn quote_fails_if_not_supported() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			deploy_liquidation_err_contracts();
+			let collateral = EvmAddress::from_str("1000000000000000000000000000000000000111").unwrap();
+
+			assert_noop!(
+				LiquidationEvmBridge::<Runtime>::quote(
+					InvokeContext {
+						contract: erc20_address(),
+						sender: Default::default(),
+						origin: alice_evm_addr(),
+					},
+					collateral,
+					100,
+					100,
+				),
+				Error::<Runtime>::ExecutionRevert,
+			);
+		});
+}
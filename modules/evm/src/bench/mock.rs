@@ -215,6 +215,7 @@ impl Config for Runtime {
 	type FindAuthor = AuthorGiven;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = ();
 	type WeightInfo = ();
 }
 
@@ -50,8 +50,8 @@ pub use module_evm_utility::{
 	Account,
 };
 pub use module_support::{
-	AddressMapping, DispatchableTask, EVMManager, ExecutionMode, IdleScheduler, InvokeContext, TransactionPayment,
-	EVM as EVMTrait,
+	AddressMapping, DispatchableTask, EVMManager, ExecutionMode, IdleScheduler, InvokeContext, PrecompilePauseFilter,
+	TransactionPayment, EVM as EVMTrait,
 };
 pub use orml_traits::{currency::TransferAll, MultiCurrency};
 use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
@@ -243,6 +243,9 @@ pub mod module {
 		/// Idle scheduler for the evm task.
 		type IdleScheduler: IdleScheduler<Self::Task>;
 
+		/// Paused contracts and contract functions, checked on every call frame.
+		type ContractPauseFilter: PrecompilePauseFilter;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
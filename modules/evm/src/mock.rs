@@ -169,6 +169,21 @@ impl Convert<u64, Weight> for GasToWeight {
 	}
 }
 
+parameter_types! {
+	pub static PausedContract: Option<H160> = None;
+}
+
+pub struct MockContractPauseFilter;
+impl PrecompilePauseFilter for MockContractPauseFilter {
+	fn is_paused(_address: H160) -> bool {
+		false
+	}
+
+	fn is_contract_call_paused(address: H160, _input: &[u8]) -> bool {
+		PausedContract::get() == Some(address)
+	}
+}
+
 pub struct AuthorGiven;
 impl FindAuthor<AccountId32> for AuthorGiven {
 	fn find_author<'a, I>(_digests: I) -> Option<AccountId32>
@@ -220,6 +235,7 @@ impl Config for Runtime {
 	type FindAuthor = AuthorGiven;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = MockContractPauseFilter;
 	type WeightInfo = ();
 }
 
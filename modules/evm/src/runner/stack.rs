@@ -36,7 +36,7 @@ use module_evm_utility::{
 	ethereum::Log,
 	evm::{self, backend::Backend as BackendT, ExitError, ExitReason, Transfer},
 };
-use module_support::{AddressMapping, EVM};
+use module_support::{AddressMapping, PrecompilePauseFilter, EVM};
 pub use primitives::{
 	evm::{convert_decimals_from_evm, EvmAddress, Vicinity, MIRRORED_NFT_ADDRESS_START},
 	ReserveIdentifier,
@@ -921,4 +921,8 @@ impl<'vicinity, 'config, T: Config> CustomStackState for SubstrateStackState<'vi
 	fn code_size_at_address(&self, address: H160) -> U256 {
 		Pallet::<T>::code_size_at_address(&address)
 	}
+
+	fn is_contract_call_paused(&self, address: H160, input: &[u8]) -> bool {
+		T::ContractPauseFilter::is_contract_call_paused(address, input)
+	}
 }
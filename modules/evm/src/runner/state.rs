@@ -312,6 +312,7 @@ impl<'config> StackSubstateMetadata<'config> {
 pub trait CustomStackState {
 	fn code_hash_at_address(&self, address: H160) -> H256;
 	fn code_size_at_address(&self, address: H160) -> U256;
+	fn is_contract_call_paused(&self, address: H160, input: &[u8]) -> bool;
 }
 
 pub trait StackState<'config>: Backend + CustomStackState {
@@ -1001,6 +1002,17 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			}
 		}
 
+		// checked for every call frame so that paused contracts and functions can not be reached by
+		// nested calls either
+		if self.state.is_contract_call_paused(code_address, &input) {
+			log::debug!(target: "evm", "Contract call {:?} is paused", code_address);
+			let _ = self.exit_substate(StackExitKind::Reverted);
+			return Capture::Exit((
+				ExitReason::Revert(ExitRevert::Reverted),
+				encode_revert_message(b"contract call is paused"),
+			));
+		}
+
 		if let Some(transfer) = transfer {
 			match self.state.transfer(transfer) {
 				Ok(()) => (),
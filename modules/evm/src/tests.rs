@@ -2967,3 +2967,61 @@ fn should_not_allow_system_contracts_send_tx() {
 		);
 	});
 }
+
+#[test]
+fn paused_contract_call_reverts_in_nested_calls() {
+	// runtime code: CALL(gas, bob, 0, 0, 0, 0, 32), returns the success flag of the call
+	let contract = from_hex(
+		"0x602980600b6000396000f3602060006000600060007310000000000000000000000000000000000000025af160005260206000f3",
+	)
+	.unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		let contract_address = result.value;
+
+		#[cfg(not(feature = "with-ethereum-compatibility"))]
+		publish_free(contract_address);
+
+		let call = |target: H160| {
+			<Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				target,
+				vec![],
+				0,
+				1000000,
+				1000000,
+				vec![],
+				<Runtime as Config>::config(),
+			)
+			.unwrap()
+		};
+
+		let result = call(contract_address);
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from_big_endian(&result.value), U256::one());
+
+		// the nested call to bob is reverted
+		PausedContract::set(Some(bob()));
+		let result = call(contract_address);
+		assert_eq!(result.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+		assert_eq!(U256::from_big_endian(&result.value), U256::zero());
+
+		// the top-level call is reverted
+		PausedContract::set(Some(contract_address));
+		let result = call(contract_address);
+		assert_eq!(result.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+		assert!(String::from_utf8_lossy(&result.value).contains("contract call is paused"));
+	});
+}
@@ -0,0 +1,204 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Flash Mint Module
+//!
+//! ## Overview
+//!
+//! The flash mint module issues the stable currency to a borrower without collateral, as long
+//! as the borrower repays it plus a fee within the same call. The minted amount is burned on
+//! repayment, and the fee goes to the surplus pool of the CDP treasury. If the borrower can not
+//! repay, the whole flash mint is reverted.
+//!
+//! Borrowers implement `FlashMintBorrower`, which is called back after the stable currency is
+//! minted. The outstanding flash minted amount, including nested flash mints, is capped by a
+//! global maximum set by governance. Flash mint is not available after emergency shutdown.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::pallet_prelude::*;
+use module_support::{CDPTreasury, EmergencyShutdown, FlashMintBorrower, Rate};
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{One, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Multi-currency support for asset management.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Stablecoin currency id.
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// CDP treasury to issue and burn the flash minted stable currency, and to receive the fee.
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// Emergency shutdown.
+		type EmergencyShutdown: EmergencyShutdown;
+
+		/// The origin which may update the flash mint parameters.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The fee rate is greater than one.
+		InvalidRate,
+		/// The amount to flash mint is zero.
+		ZeroAmount,
+		/// The outstanding flash minted amount exceeds the maximum.
+		ExceedMaxFlashMint,
+		/// The borrower did not repay the flash minted amount plus the fee.
+		RepaymentFailed,
+		/// The system has been shutdown.
+		AlreadyShutdown,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The flash mint parameters updated.
+		FlashMintParamsUpdated { fee_rate: Rate, max_amount: Balance },
+		/// Stable currency flash minted and repaid.
+		FlashMinted {
+			who: T::AccountId,
+			amount: Balance,
+			fee: Balance,
+		},
+	}
+
+	/// The fee rate of flash mint, charged on the minted amount.
+	///
+	/// FeeRate: Rate
+	#[pallet::storage]
+	#[pallet::getter(fn fee_rate)]
+	pub type FeeRate<T: Config> = StorageValue<_, Rate, ValueQuery>;
+
+	/// The maximum outstanding flash minted amount.
+	///
+	/// MaxFlashMint: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn max_flash_mint)]
+	pub type MaxFlashMint<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The flash minted amount not repaid yet, only non-zero during a flash mint.
+	///
+	/// OutstandingFlashMint: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn outstanding_flash_mint)]
+	pub type OutstandingFlashMint<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Update the flash mint parameters.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `fee_rate`: the fee rate charged on the minted amount.
+		/// - `max_amount`: the maximum outstanding flash minted amount.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_flash_mint_params())]
+		pub fn set_flash_mint_params(origin: OriginFor<T>, fee_rate: Rate, max_amount: Balance) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(fee_rate <= Rate::one(), Error::<T>::InvalidRate);
+			FeeRate::<T>::put(fee_rate);
+			MaxFlashMint::<T>::put(max_amount);
+			Self::deposit_event(Event::FlashMintParamsUpdated { fee_rate, max_amount });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The fee of flash minting `amount` of stable currency.
+	pub fn flash_fee(amount: Balance) -> Balance {
+		Self::fee_rate().saturating_mul_int(amount)
+	}
+
+	/// Mint `amount` of stable currency to `who` and call back `borrower`, then burn `amount` and
+	/// collect the fee from `who`. Returns the fee.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn flash_mint(
+		who: &T::AccountId,
+		amount: Balance,
+		borrower: &impl FlashMintBorrower<T::AccountId, Balance>,
+	) -> Result<Balance, DispatchError> {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+		let outstanding = Self::outstanding_flash_mint()
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(outstanding <= Self::max_flash_mint(), Error::<T>::ExceedMaxFlashMint);
+		OutstandingFlashMint::<T>::put(outstanding);
+
+		let fee = Self::flash_fee(amount);
+
+		// the minted amount is backed by the repayment within this call
+		T::CDPTreasury::issue_debit(who, amount, true)?;
+		borrower.on_flash_mint(who, amount, fee)?;
+
+		ensure!(
+			T::Currency::free_balance(T::GetStableCurrencyId::get(), who) >= amount.saturating_add(fee),
+			Error::<T>::RepaymentFailed
+		);
+		T::CDPTreasury::burn_debit(who, amount)?;
+		if !fee.is_zero() {
+			T::CDPTreasury::deposit_surplus(who, fee)?;
+		}
+
+		OutstandingFlashMint::<T>::mutate(|v| *v = v.saturating_sub(amount));
+
+		Self::deposit_event(Event::FlashMinted {
+			who: who.clone(),
+			amount,
+			fee,
+		});
+		Ok(fee)
+	}
+}
@@ -0,0 +1,145 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the flash mint module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::BadOrigin;
+
+fn set_params() {
+	assert_ok!(FlashMint::set_flash_mint_params(
+		RuntimeOrigin::signed(ALICE),
+		Rate::saturating_from_rational(1, 1000),
+		1_000_000
+	));
+}
+
+#[test]
+fn set_flash_mint_params_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FlashMint::set_flash_mint_params(RuntimeOrigin::signed(BOB), Rate::zero(), 1_000_000),
+			BadOrigin
+		);
+		assert_noop!(
+			FlashMint::set_flash_mint_params(
+				RuntimeOrigin::signed(ALICE),
+				Rate::saturating_from_rational(11, 10),
+				1_000_000
+			),
+			Error::<Runtime>::InvalidRate
+		);
+
+		set_params();
+		System::assert_last_event(RuntimeEvent::FlashMint(crate::Event::FlashMintParamsUpdated {
+			fee_rate: Rate::saturating_from_rational(1, 1000),
+			max_amount: 1_000_000,
+		}));
+		assert_eq!(FlashMint::fee_rate(), Rate::saturating_from_rational(1, 1000));
+		assert_eq!(FlashMint::max_flash_mint(), 1_000_000);
+		assert_eq!(FlashMint::flash_fee(100_000), 100);
+	});
+}
+
+#[test]
+fn flash_mint_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 100_000, &MockBorrower { spend: 0 }),
+			Error::<Runtime>::ExceedMaxFlashMint
+		);
+
+		set_params();
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 0, &MockBorrower { spend: 0 }),
+			Error::<Runtime>::ZeroAmount
+		);
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 1_000_001, &MockBorrower { spend: 0 }),
+			Error::<Runtime>::ExceedMaxFlashMint
+		);
+
+		assert_eq!(
+			FlashMint::flash_mint(&BOB, 100_000, &MockBorrower { spend: 500 }),
+			Ok(100)
+		);
+		System::assert_last_event(RuntimeEvent::FlashMint(crate::Event::FlashMinted {
+			who: BOB,
+			amount: 100_000,
+			fee: 100,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 400);
+		assert_eq!(Tokens::free_balance(AUSD, &CHARLIE), 1_500);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 100);
+		assert_eq!(Tokens::total_issuance(AUSD), 2_000);
+		assert_eq!(FlashMint::outstanding_flash_mint(), 0);
+	});
+}
+
+#[test]
+fn flash_mint_fails_without_repayment() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_params();
+
+		// the borrower can not afford the fee after spending 950
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 100_000, &MockBorrower { spend: 950 }),
+			Error::<Runtime>::RepaymentFailed
+		);
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 100_000, &MockBorrower { spend: 200_000 }),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn nested_flash_mint_limited_by_max() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_params();
+
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 600_000, &NestedBorrower { amount: 500_000 }),
+			Error::<Runtime>::ExceedMaxFlashMint
+		);
+
+		assert_eq!(
+			FlashMint::flash_mint(&BOB, 500_000, &NestedBorrower { amount: 500_000 }),
+			Ok(500)
+		);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 1_000);
+		assert_eq!(FlashMint::outstanding_flash_mint(), 0);
+	});
+}
+
+#[test]
+fn flash_mint_blocked_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_params();
+		mock_shutdown();
+		assert_noop!(
+			FlashMint::flash_mint(&BOB, 100_000, &MockBorrower { spend: 0 }),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
@@ -0,0 +1,110 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_flash_mint
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-04, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-34-61`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/acala
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=module_flash_mint
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./modules/flash-mint/src/weights.rs
+// --template=./templates/module-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_flash_mint.
+pub trait WeightInfo {
+	fn set_flash_mint_params() -> Weight;
+	fn flash_mint() -> Weight;
+}
+
+/// Weights for module_flash_mint using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Storage: `FlashMint::MaxFlashMint` (r:0 w:1)
+	// Proof: `FlashMint::MaxFlashMint` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `FlashMint::FeeRate` (r:0 w:1)
+	// Proof: `FlashMint::FeeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_flash_mint_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `691`
+		//  Estimated: `0`
+		// Minimum execution time: 14_900 nanoseconds.
+		Weight::from_parts(15_283_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `EmergencyShutdown::IsShutdown` (r:1 w:0)
+	// Proof: `EmergencyShutdown::IsShutdown` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	// Storage: `FlashMint::OutstandingFlashMint` (r:1 w:1)
+	// Proof: `FlashMint::OutstandingFlashMint` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `FlashMint::MaxFlashMint` (r:1 w:0)
+	// Proof: `FlashMint::MaxFlashMint` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `FlashMint::FeeRate` (r:1 w:0)
+	// Proof: `FlashMint::FeeRate` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn flash_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1944`
+		//  Estimated: `3612`
+		// Minimum execution time: 63_101 nanoseconds.
+		Weight::from_parts(64_719_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_flash_mint_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `691`
+		//  Estimated: `0`
+		// Minimum execution time: 14_900 nanoseconds.
+		Weight::from_parts(15_283_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn flash_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1944`
+		//  Estimated: `3612`
+		// Minimum execution time: 63_101 nanoseconds.
+		Weight::from_parts(64_719_000, 3612)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+}
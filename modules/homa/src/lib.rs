@@ -24,7 +24,7 @@
 use frame_support::{pallet_prelude::*, transactional, PalletId};
 use frame_system::{ensure_signed, pallet_prelude::*};
 use module_support::{ExchangeRate, ExchangeRateProvider, FractionalRate, HomaManager, HomaSubAccountXcm, Rate, Ratio};
-use orml_traits::MultiCurrency;
+use orml_traits::{Happened, MultiCurrency};
 use primitives::{Balance, CurrencyId, EraIndex};
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -150,6 +150,10 @@ pub mod module {
 		/// The XcmInterface to manage the staking of sub-account on relaychain.
 		type XcmInterface: HomaSubAccountXcm<Self::AccountId, Balance>;
 
+		/// Hook when the bonded amount of a subaccount ledger is reset, with
+		/// `(sub_account_index, old_bonded_amount, new_bonded_amount)`.
+		type OnLedgerBondedReset: Happened<(u16, Balance, Balance)>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -584,6 +588,7 @@ pub mod module {
 				Self::do_update_ledger(sub_account_index, |ledger| -> DispatchResult {
 					if let Some(change) = bonded_change {
 						if ledger.bonded != change {
+							T::OnLedgerBondedReset::happened(&(sub_account_index, ledger.bonded, change));
 							ledger.bonded = change;
 							Self::deposit_event(Event::<T>::LedgerBondedReset {
 								sub_account_index,
@@ -184,6 +184,14 @@ parameter_types! {
 	pub static MintThreshold: Balance = 0;
 	pub static RedeemThreshold: Balance = 0;
 	pub static MockRelayBlockNumberProvider: BlockNumber = 0;
+	pub static LedgerBondedResets: Vec<(u16, Balance, Balance)> = vec![];
+}
+
+pub struct MockOnLedgerBondedReset;
+impl Happened<(u16, Balance, Balance)> for MockOnLedgerBondedReset {
+	fn happened(reset: &(u16, Balance, Balance)) {
+		LedgerBondedResets::mutate(|resets| resets.push(*reset));
+	}
 }
 
 impl Config for Runtime {
@@ -201,6 +209,7 @@ impl Config for Runtime {
 	type RedeemThreshold = RedeemThreshold;
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type XcmInterface = MockHomaSubAccountXcm;
+	type OnLedgerBondedReset = MockOnLedgerBondedReset;
 	type WeightInfo = ();
 }
 
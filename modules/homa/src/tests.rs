@@ -344,6 +344,7 @@ fn reset_ledgers_works() {
 			sub_account_index: 0,
 			new_bonded_amount: 1_000_000,
 		}));
+		assert_eq!(LedgerBondedResets::get(), vec![(0, 0, 1_000_000)]);
 		System::assert_has_event(RuntimeEvent::Homa(crate::Event::LedgerUnlockingReset {
 			sub_account_index: 0,
 			new_unlocking: vec![
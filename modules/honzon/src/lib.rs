@@ -24,19 +24,41 @@
 //! position to loan/payback, and can also authorize others to manage the their
 //! CDP under specific collateral type.
 //!
+//! Users can also register a protection rule for their CDP under a collateral type, to
+//! deleverage or close the CDP by DEX when its collateral ratio falls below the trigger ratio.
+//! Anyone can execute a triggered rule and receive the deposit reserved for the rule as reward.
+//!
+//! A CDP can be wrapped into a token of the position class of `module_nft`. The wrapped CDP is
+//! owned by a vault account derived from the token id and controlled by the token holder, so the
+//! control of the CDP follows the token on any transfer, as do the incentive rewards earned by the
+//! vault. Unwrapping burns the token and returns the CDP and the unclaimed rewards to the holder.
+//!
 //! After system shutdown, some operations will be restricted.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, traits::NamedReservableCurrency};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		tokens::nonfungibles::{Inspect, Mutate},
+		BalanceStatus, Currency, ExistenceRequirement, NamedReservableCurrency,
+	},
+	transactional, PalletId,
+};
 use frame_system::pallet_prelude::*;
-use module_support::{CDPTreasury, EmergencyShutdown, ExchangeRate, HonzonManager, PriceProvider, Ratio};
-use primitives::{Amount, Balance, CurrencyId, Position, ReserveIdentifier};
+use module_nft::{ClassIdOf, TokenIdOf};
+use module_support::{
+	CDPTreasury, EmergencyShutdown, ExchangeRate, HonzonManager, IncentivesManager, PoolId, PriceProvider, Ratio,
+	WrappedPositionManager,
+};
+use orml_traits::{InspectExtended, MultiCurrency};
+use primitives::{nft::ClassProperty, Amount, Balance, CurrencyId, Position, ReserveIdentifier};
+use scale_info::TypeInfo;
 use sp_core::U256;
 use sp_runtime::{
-	traits::{StaticLookup, Zero},
-	ArithmeticError, DispatchResult,
+	traits::{AccountIdConversion, Bounded, One, Saturating, StaticLookup, Zero},
+	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -47,14 +69,35 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The action of a protection rule.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum ProtectionAction {
+	/// Sell collateral by DEX to repay debit, until the collateral ratio reaches `target_ratio`.
+	Deleverage { target_ratio: Ratio },
+	/// Sell collateral by DEX to repay all debit and close the CDP.
+	Close,
+}
+
+/// The protection rule of a CDP.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ProtectionRule<Balance> {
+	/// The rule can be executed once the collateral ratio is below it.
+	pub trigger_ratio: Ratio,
+	/// The action to execute.
+	pub action: ProtectionAction,
+	/// The reserved deposit, paid to the keeper who executes the rule.
+	pub deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::Honzon;
+	pub const PROTECTION_RESERVE_ID: ReserveIdentifier = ReserveIdentifier::HonzonProtection;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config + module_cdp_engine::Config {
+	pub trait Config: frame_system::Config + module_cdp_engine::Config + module_nft::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Currency for authorization reserved.
@@ -68,9 +111,20 @@ pub mod module {
 		#[pallet::constant]
 		type DepositPerAuthorization: Get<Balance>;
 
+		/// Reserved amount per protection rule, paid to the keeper who executes the rule.
+		#[pallet::constant]
+		type DepositPerProtectionRule: Get<Balance>;
+
 		/// The list of valid collateral currency types
 		type CollateralCurrencyIds: Get<Vec<CurrencyId>>;
 
+		/// The position module id, derives the vault accounts of wrapped CDPs.
+		#[pallet::constant]
+		type PositionPalletId: Get<PalletId>;
+
+		/// Claims the incentive rewards earned by the vault accounts of wrapped CDPs.
+		type Incentives: IncentivesManager<Self::AccountId, Balance, CurrencyId, PoolId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -85,6 +139,24 @@ pub mod module {
 		AuthorizationNotExists,
 		// Have authorized already
 		AlreadyAuthorized,
+		// The trigger ratio or target ratio of the protection rule is invalid
+		InvalidProtectionRule,
+		// Protection rule not exists
+		ProtectionRuleNotExists,
+		// The collateral ratio is not below the trigger ratio
+		ProtectionRuleNotTriggered,
+		// Feed price is invalid
+		InvalidFeedPrice,
+		// The position class is not set
+		PositionClassNotSet,
+		// The class is not transferable and mintable, or it is burnable or its properties are mutable
+		InvalidPositionClass,
+		// Positions of the current position class are still wrapped
+		PositionsStillWrapped,
+		// The CDP to wrap is empty
+		PositionNotExists,
+		// The token does not wrap a CDP
+		WrappedPositionNotExists,
 	}
 
 	#[pallet::event]
@@ -110,6 +182,40 @@ pub mod module {
 			to_currency: CurrencyId,
 			amount: Balance,
 		},
+		/// Set the protection rule of the CDP of specific collateral.
+		ProtectionRuleSet {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			trigger_ratio: Ratio,
+			action: ProtectionAction,
+		},
+		/// Remove the protection rule of the CDP of specific collateral.
+		ProtectionRuleRemoved {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+		},
+		/// The protection rule is executed by keeper.
+		ProtectionRuleExecuted {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			action: ProtectionAction,
+			keeper: T::AccountId,
+			reward: Balance,
+		},
+		/// The NFT class of wrapped CDPs updated.
+		PositionClassSet { class_id: ClassIdOf<T> },
+		/// The CDP of specific collateral is wrapped into the token.
+		PositionWrapped {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			token_id: TokenIdOf<T>,
+		},
+		/// The token is burned and the wrapped CDP returned to its holder.
+		PositionUnwrapped {
+			owner: T::AccountId,
+			collateral_type: CurrencyId,
+			token_id: TokenIdOf<T>,
+		},
 	}
 
 	/// The authorization relationship map from
@@ -128,6 +234,28 @@ pub mod module {
 		OptionQuery,
 	>;
 
+	/// The protection rules of CDPs.
+	///
+	/// ProtectionRules: double_map AccountId, CurrencyId => Option<ProtectionRule>
+	#[pallet::storage]
+	#[pallet::getter(fn protection_rules)]
+	pub type ProtectionRules<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, CurrencyId, ProtectionRule<Balance>, OptionQuery>;
+
+	/// The NFT class of the tokens wrapping CDPs.
+	///
+	/// PositionClassId: Option<ClassId>
+	#[pallet::storage]
+	#[pallet::getter(fn position_class_id)]
+	pub type PositionClassId<T: Config> = StorageValue<_, ClassIdOf<T>, OptionQuery>;
+
+	/// The collateral type of the CDPs wrapped in the tokens of the position class.
+	///
+	/// WrappedPositions: map TokenId => Option<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn wrapped_positions)]
+	pub type WrappedPositions<T: Config> = StorageMap<_, Twox64Concat, TokenIdOf<T>, CurrencyId, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -371,6 +499,202 @@ pub mod module {
 			});
 			Ok(())
 		}
+
+		/// Set the protection rule for caller's CDP under `currency_id`, reserve the deposit
+		/// for the new rule.
+		///
+		/// - `currency_id`: collateral currency id.
+		/// - `trigger_ratio`: the rule can be executed once the collateral ratio is below it, must
+		///   be greater than the liquidation ratio.
+		/// - `action`: deleverage to the target ratio, which must be greater than the trigger
+		///   ratio, or close the CDP.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_protection_rule())]
+		pub fn set_protection_rule(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			trigger_ratio: Ratio,
+			action: ProtectionAction,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+
+			let liquidation_ratio = <module_cdp_engine::Pallet<T>>::get_liquidation_ratio(currency_id)?;
+			ensure!(trigger_ratio > liquidation_ratio, Error::<T>::InvalidProtectionRule);
+			if let ProtectionAction::Deleverage { target_ratio } = action {
+				ensure!(
+					target_ratio > trigger_ratio && target_ratio > Ratio::one(),
+					Error::<T>::InvalidProtectionRule
+				);
+			}
+
+			ProtectionRules::<T>::try_mutate(&who, currency_id, |maybe_rule| -> DispatchResult {
+				let deposit = match maybe_rule {
+					Some(rule) => rule.deposit,
+					None => {
+						let deposit = T::DepositPerProtectionRule::get();
+						<T as Config>::Currency::reserve_named(&PROTECTION_RESERVE_ID, &who, deposit)?;
+						deposit
+					}
+				};
+				*maybe_rule = Some(ProtectionRule {
+					trigger_ratio,
+					action,
+					deposit,
+				});
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProtectionRuleSet {
+				owner: who,
+				collateral_type: currency_id,
+				trigger_ratio,
+				action,
+			});
+			Ok(())
+		}
+
+		/// Remove the protection rule for caller's CDP under `currency_id`, unreserve the
+		/// deposit.
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_protection_rule())]
+		pub fn remove_protection_rule(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let rule = ProtectionRules::<T>::take(&who, currency_id).ok_or(Error::<T>::ProtectionRuleNotExists)?;
+			<T as Config>::Currency::unreserve_named(&PROTECTION_RESERVE_ID, &who, rule.deposit);
+			Self::deposit_event(Event::ProtectionRuleRemoved {
+				owner: who,
+				collateral_type: currency_id,
+			});
+			Ok(())
+		}
+
+		/// Execute the protection rule of `owner`'s CDP under `currency_id` whose collateral
+		/// ratio is below the trigger ratio. The caller receives the deposit of the rule.
+		///
+		/// - `owner`: the owner of the CDP.
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_protection_rule())]
+		pub fn execute_protection_rule(
+			origin: OriginFor<T>,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+
+			let rule = Self::protection_rules(&owner, currency_id).ok_or(Error::<T>::ProtectionRuleNotExists)?;
+			Self::do_execute_protection_rule(&owner, currency_id, &rule)?;
+
+			ProtectionRules::<T>::remove(&owner, currency_id);
+			let unpaid = <T as Config>::Currency::repatriate_reserved_named(
+				&PROTECTION_RESERVE_ID,
+				&owner,
+				&keeper,
+				rule.deposit,
+				BalanceStatus::Free,
+			)?;
+
+			Self::deposit_event(Event::ProtectionRuleExecuted {
+				owner,
+				collateral_type: currency_id,
+				action: rule.action,
+				keeper,
+				reward: rule.deposit.saturating_sub(unpaid),
+			});
+			Ok(())
+		}
+
+		/// Set the NFT class of the tokens wrapping CDPs. The class must be transferable and
+		/// mintable, with immutable properties. It must not be burnable, otherwise the holder could
+		/// burn the token and leave the wrapped CDP without a controller.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `class_id`: the position class.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_position_class())]
+		pub fn set_position_class(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
+			<T as module_cdp_engine::Config>::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				WrappedPositions::<T>::iter_keys().next().is_none(),
+				Error::<T>::PositionsStillWrapped
+			);
+
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::InvalidPositionClass)?;
+			let properties = class_info.data.properties.0;
+			ensure!(
+				properties.contains(ClassProperty::Transferable | ClassProperty::Mintable)
+					&& !properties.contains(ClassProperty::Burnable)
+					&& !properties.contains(ClassProperty::ClassPropertiesMutable),
+				Error::<T>::InvalidPositionClass
+			);
+
+			PositionClassId::<T>::put(class_id);
+			Self::deposit_event(Event::PositionClassSet { class_id });
+			Ok(())
+		}
+
+		/// Wrap caller's CDP under `currency_id` into a new token of the position class minted
+		/// to caller. The token deposit of `module_nft` is paid by caller.
+		///
+		/// - `currency_id`: collateral currency id.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::wrap_position())]
+		pub fn wrap_position(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_wrap_position(&who, currency_id)?;
+			Ok(())
+		}
+
+		/// Burn the token held by caller and return the wrapped CDP to caller's CDP under the
+		/// same collateral type. The incentive rewards of the vault account are claimed to caller,
+		/// and the balances left in the vault account are also returned.
+		///
+		/// - `token_id`: the token of the position class.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::unwrap_position())]
+		pub fn unwrap_position(origin: OriginFor<T>, token_id: TokenIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_unwrap_position(&who, token_id)
+		}
+
+		/// Adjust the CDP wrapped in the token held by caller. The collateral and stablecoin
+		/// are transferred between caller and the vault account of the token.
+		///
+		/// - `token_id`: the token of the position class.
+		/// - `collateral_adjustment`: signed amount, positive means to deposit collateral currency
+		///   into CDP, negative means withdraw collateral currency from CDP.
+		/// - `debit_adjustment`: signed amount, positive means to issue some amount of stablecoin
+		///   to caller according to the debit adjustment, negative means caller will payback some
+		///   amount of stablecoin to CDP according to to the debit adjustment.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::adjust_wrapped_loan())]
+		pub fn adjust_wrapped_loan(
+			origin: OriginFor<T>,
+			token_id: TokenIdOf<T>,
+			collateral_adjustment: Amount,
+			debit_adjustment: Amount,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_adjust_wrapped_loan(&who, token_id, collateral_adjustment, debit_adjustment)
+		}
+
+		/// Claim the incentive rewards earned by the CDP wrapped in the token held by caller. The
+		/// rewards are claimed by the vault account of the token and paid to caller.
+		///
+		/// - `token_id`: the token of the position class.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_wrapped_position_rewards())]
+		pub fn claim_wrapped_position_rewards(origin: OriginFor<T>, token_id: TokenIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let currency_id = Self::ensure_position_holder(&who, token_id)?;
+			Self::do_claim_wrapped_position_rewards(&who, token_id, currency_id)
+		}
 	}
 }
 
@@ -398,6 +722,223 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Deleverage or close the CDP by DEX if the collateral ratio is below the trigger ratio of
+	/// `rule`. The collateral to sell is limited by `MaxSwapSlippageCompareToOracle`.
+	fn do_execute_protection_rule(
+		owner: &T::AccountId,
+		currency_id: CurrencyId,
+		rule: &ProtectionRule<Balance>,
+	) -> DispatchResult {
+		let Position { collateral, debit } = <module_loans::Pallet<T>>::positions(currency_id, owner);
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let price =
+			T::PriceSource::get_relative_price(currency_id, stable_currency_id).ok_or(Error::<T>::InvalidFeedPrice)?;
+		let stable_price =
+			T::PriceSource::get_relative_price(stable_currency_id, currency_id).ok_or(Error::<T>::InvalidFeedPrice)?;
+
+		let collateral_ratio =
+			<module_cdp_engine::Pallet<T>>::calculate_collateral_ratio(currency_id, collateral, debit, price);
+		ensure!(
+			!debit.is_zero() && collateral_ratio < rule.trigger_ratio,
+			Error::<T>::ProtectionRuleNotTriggered
+		);
+
+		let debit_value = <module_cdp_engine::Pallet<T>>::convert_to_debit_value(currency_id, debit);
+		let slippage = T::MaxSwapSlippageCompareToOracle::get();
+
+		match rule.action {
+			ProtectionAction::Deleverage { target_ratio } => {
+				// the debit value to repay to reach the target ratio at the oracle price:
+				// (target_ratio * debit_value - collateral_value) / (target_ratio - 1)
+				let collateral_value = price.saturating_mul_int(collateral);
+				let repay_value = target_ratio
+					.saturating_sub(Ratio::one())
+					.reciprocal()
+					.unwrap_or_else(Ratio::max_value)
+					.saturating_mul_int(
+						target_ratio
+							.saturating_mul_int(debit_value)
+							.saturating_sub(collateral_value),
+					)
+					.min(debit_value);
+				let decrease_collateral = stable_price.saturating_mul_int(repay_value).min(collateral);
+				let min_decrease_debit_value = Ratio::one().saturating_sub(slippage).saturating_mul_int(repay_value);
+
+				<module_cdp_engine::Pallet<T>>::shrink_position_debit(
+					owner,
+					currency_id,
+					decrease_collateral,
+					min_decrease_debit_value,
+				)
+			}
+			ProtectionAction::Close => {
+				let max_collateral_amount = Ratio::one()
+					.saturating_sub(slippage)
+					.reciprocal()
+					.unwrap_or_else(Ratio::max_value)
+					.saturating_mul_int(stable_price.saturating_mul_int(debit_value));
+
+				<module_cdp_engine::Pallet<T>>::close_cdp_has_debit_by_dex(
+					owner.clone(),
+					currency_id,
+					max_collateral_amount,
+				)
+			}
+		}
+	}
+
+	/// The vault account owning the CDP wrapped in `token_id`.
+	pub fn position_vault(token_id: TokenIdOf<T>) -> T::AccountId {
+		T::PositionPalletId::get().into_sub_account_truncating(token_id)
+	}
+
+	/// Ensure `who` holds `token_id`, returns the collateral type of the wrapped CDP.
+	fn ensure_position_holder(who: &T::AccountId, token_id: TokenIdOf<T>) -> Result<CurrencyId, DispatchError> {
+		let class_id = Self::position_class_id().ok_or(Error::<T>::PositionClassNotSet)?;
+		let currency_id = Self::wrapped_positions(token_id).ok_or(Error::<T>::WrappedPositionNotExists)?;
+		ensure!(
+			<module_nft::Pallet<T> as Inspect<T::AccountId>>::owner(&class_id, &token_id).as_ref() == Some(who),
+			Error::<T>::NoPermission
+		);
+		Ok(currency_id)
+	}
+
+	/// Transfer the collateral and stablecoin left in the vault account to `who`. For DEX share
+	/// collateral, the tokens refunded from its liquidation are also transferred.
+	fn sweep_vault(vault: &T::AccountId, who: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		let mut currency_ids = vec![currency_id, T::GetStableCurrencyId::get()];
+		if let Some((currency_id_0, currency_id_1)) = currency_id.split_dex_share_currency_id() {
+			currency_ids.extend([currency_id_0, currency_id_1]);
+		}
+		for currency_id in currency_ids {
+			let free = <T as module_cdp_engine::Config>::Currency::free_balance(currency_id, vault);
+			if !free.is_zero() {
+				<T as module_cdp_engine::Config>::Currency::transfer(currency_id, vault, who, free)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Claim the incentive rewards of the vault account of `token_id` and pay them to `who`. The
+	/// vault owns the shares of `PoolId::Loans`, and of `PoolId::Dex` for DEX share collateral.
+	fn do_claim_wrapped_position_rewards(
+		who: &T::AccountId,
+		token_id: TokenIdOf<T>,
+		currency_id: CurrencyId,
+	) -> DispatchResult {
+		let vault = Self::position_vault(token_id);
+		let mut pool_ids = vec![PoolId::Loans(currency_id)];
+		if currency_id.is_dex_share_currency_id() {
+			pool_ids.push(PoolId::Dex(currency_id));
+		}
+
+		for pool_id in pool_ids {
+			let payouts = T::Incentives::claim_rewards_and_get_payouts(vault.clone(), pool_id)?;
+			for (reward_currency_id, amount) in payouts {
+				if !amount.is_zero() {
+					<T as module_cdp_engine::Config>::Currency::transfer(reward_currency_id, &vault, who, amount)?;
+				}
+			}
+		}
+		Ok(())
+	}
+
+	#[transactional]
+	fn do_wrap_position(who: &T::AccountId, currency_id: CurrencyId) -> Result<TokenIdOf<T>, DispatchError> {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+		let class_id = Self::position_class_id().ok_or(Error::<T>::PositionClassNotSet)?;
+		let Position { collateral, debit } = <module_loans::Pallet<T>>::positions(currency_id, who);
+		ensure!(!collateral.is_zero() || !debit.is_zero(), Error::<T>::PositionNotExists);
+
+		// the token deposit is paid by the class owner on minting, fund it by `who`
+		let class_owner = <module_nft::Pallet<T> as Inspect<T::AccountId>>::collection_owner(&class_id)
+			.ok_or(Error::<T>::InvalidPositionClass)?;
+		<T as module_nft::Config>::Currency::transfer(
+			who,
+			&class_owner,
+			<T as module_nft::Config>::CreateTokenDeposit::get(),
+			ExistenceRequirement::KeepAlive,
+		)?;
+		let token_id = <module_nft::Pallet<T> as InspectExtended<T::AccountId>>::next_token_id(class_id);
+		<module_nft::Pallet<T> as Mutate<T::AccountId>>::mint_into(&class_id, &token_id, who)?;
+
+		let vault = Self::position_vault(token_id);
+		frame_system::Pallet::<T>::inc_providers(&vault);
+		<module_loans::Pallet<T>>::transfer_loan(who, &vault, currency_id)?;
+		WrappedPositions::<T>::insert(token_id, currency_id);
+
+		Self::deposit_event(Event::PositionWrapped {
+			owner: who.clone(),
+			collateral_type: currency_id,
+			token_id,
+		});
+		Ok(token_id)
+	}
+
+	#[transactional]
+	fn do_unwrap_position(who: &T::AccountId, token_id: TokenIdOf<T>) -> DispatchResult {
+		let currency_id = Self::ensure_position_holder(who, token_id)?;
+		let class_id = Self::position_class_id().ok_or(Error::<T>::PositionClassNotSet)?;
+		let vault = Self::position_vault(token_id);
+
+		// the rewards are claimed by the vault, pay them out before it's emptied
+		Self::do_claim_wrapped_position_rewards(who, token_id, currency_id)?;
+
+		// the CDP may have been liquidated or settled
+		let Position { collateral, debit } = <module_loans::Pallet<T>>::positions(currency_id, &vault);
+		if !collateral.is_zero() || !debit.is_zero() {
+			<module_loans::Pallet<T>>::transfer_loan(&vault, who, currency_id)?;
+		}
+		Self::sweep_vault(&vault, who, currency_id)?;
+		let _ = frame_system::Pallet::<T>::dec_providers(&vault);
+
+		<module_nft::Pallet<T> as Mutate<T::AccountId>>::burn(&class_id, &token_id, Some(who))?;
+		WrappedPositions::<T>::remove(token_id);
+
+		Self::deposit_event(Event::PositionUnwrapped {
+			owner: who.clone(),
+			collateral_type: currency_id,
+			token_id,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	fn do_adjust_wrapped_loan(
+		who: &T::AccountId,
+		token_id: TokenIdOf<T>,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		let currency_id = Self::ensure_position_holder(who, token_id)?;
+		let vault = Self::position_vault(token_id);
+
+		// fund the vault with the collateral to deposit and the stablecoin to payback
+		if collateral_adjustment.is_positive() {
+			<T as module_cdp_engine::Config>::Currency::transfer(
+				currency_id,
+				who,
+				&vault,
+				collateral_adjustment.unsigned_abs(),
+			)?;
+		}
+		if debit_adjustment.is_negative() {
+			let payback_value =
+				<module_cdp_engine::Pallet<T>>::convert_to_debit_value(currency_id, debit_adjustment.unsigned_abs());
+			<T as module_cdp_engine::Config>::Currency::transfer(
+				T::GetStableCurrencyId::get(),
+				who,
+				&vault,
+				payback_value,
+			)?;
+		}
+
+		Self::do_adjust_loan(&vault, currency_id, collateral_adjustment, debit_adjustment)?;
+
+		// the withdrawn collateral and the issued stablecoin are left in the vault
+		Self::sweep_vault(&vault, who, currency_id)
+	}
+
 	fn do_close_loan_by_dex(
 		who: T::AccountId,
 		currency_id: CurrencyId,
@@ -458,3 +999,35 @@ impl<T: Config> HonzonManager<T::AccountId, CurrencyId, Amount, Balance> for Pal
 		<module_cdp_engine::Pallet<T>>::get_debit_exchange_rate(currency_id)
 	}
 }
+
+impl<T: Config> WrappedPositionManager<T::AccountId, CurrencyId, Amount, ClassIdOf<T>, TokenIdOf<T>> for Pallet<T> {
+	fn wrap_position(who: &T::AccountId, currency_id: CurrencyId) -> Result<TokenIdOf<T>, DispatchError> {
+		Self::do_wrap_position(who, currency_id)
+	}
+
+	fn unwrap_position(who: &T::AccountId, token_id: TokenIdOf<T>) -> DispatchResult {
+		Self::do_unwrap_position(who, token_id)
+	}
+
+	fn adjust_wrapped_loan(
+		who: &T::AccountId,
+		token_id: TokenIdOf<T>,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		Self::do_adjust_wrapped_loan(who, token_id, collateral_adjustment, debit_adjustment)
+	}
+
+	fn get_wrapped_position(token_id: TokenIdOf<T>) -> Option<(CurrencyId, Position)> {
+		Self::wrapped_positions(token_id).map(|currency_id| {
+			(
+				currency_id,
+				<module_loans::Pallet<T>>::positions(currency_id, Self::position_vault(token_id)),
+			)
+		})
+	}
+
+	fn position_class_id() -> Option<ClassIdOf<T>> {
+		Self::position_class_id()
+	}
+}
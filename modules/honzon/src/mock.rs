@@ -23,24 +23,25 @@
 use super::*;
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
-	traits::{ConstU128, ConstU32, ConstU64, Everything, Nothing},
+	traits::{ConstU128, ConstU32, ConstU64, Everything, InstanceFilter, Nothing},
 	PalletId,
 };
 use frame_system::{offchain::SendTransactionTypes, EnsureSignedBy};
 use module_cdp_engine::CollateralCurrencyIds;
+use module_nft::{ClassData, TokenData};
 use module_support::{
 	mocks::MockStableAsset, AuctionManager, ExchangeRate, FractionalRate, Price, PriceProvider, Rate, Ratio,
 	SpecificJointsSwap,
 };
 use orml_traits::parameter_type_with_key;
-use primitives::{Balance, Moment, ReserveIdentifier, TokenSymbol};
+use primitives::{Balance, Moment, ReserveIdentifier, TokenSymbol, TradingPair};
 use sp_core::{crypto::AccountId32, H256};
 use sp_runtime::{
 	testing::TestXt,
-	traits::{AccountIdConversion, IdentityLookup, One as OneT},
+	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup, One as OneT},
 	BuildStorage, FixedPointNumber,
 };
-use sp_std::cell::RefCell;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 mod honzon {
 	pub use super::super::*;
@@ -146,14 +147,22 @@ impl module_loans::Config for Runtime {
 	type OnUpdateLoan = ();
 }
 
+thread_local! {
+	static BTC_PRICE: RefCell<Price> = RefCell::new(Price::one());
+}
+
 pub struct MockPriceSource;
-impl PriceProvider<CurrencyId> for MockPriceSource {
-	fn get_relative_price(_base: CurrencyId, _quote: CurrencyId) -> Option<Price> {
-		Some(Price::one())
+impl MockPriceSource {
+	pub fn set_btc_price(price: Price) {
+		BTC_PRICE.with(|v| *v.borrow_mut() = price);
 	}
-
-	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
-		Some(Price::one())
+}
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			BTC => Some(BTC_PRICE.with(|v| *v.borrow())),
+			_ => Some(Price::one()),
+		}
 	}
 }
 
@@ -172,6 +181,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -183,6 +196,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 thread_local! {
@@ -204,6 +221,28 @@ ord_parameter_types! {
 	pub const One: AccountId = AccountId32::new([1u8; 32]);
 }
 
+parameter_types! {
+	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
+	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub EnabledTradingPairs: Vec<TradingPair> = vec![
+		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
+	];
+}
+
+impl module_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = ConstU32<4>;
+	type PalletId = DEXPalletId;
+	type Erc20InfoMapping = ();
+	type DEXIncentives = ();
+	type WeightInfo = ();
+	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type ExtendedProvisioningBlocks = ConstU64<0>;
+	type OnLiquidityPoolUpdated = ();
+}
+
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
@@ -222,6 +261,7 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = ();
 	type Swap = SpecificJointsSwap<(), AlternativeSwapPathJointList>;
 	type MaxAuctionsCount = ConstU32<10_000>;
+	type DebitAuctionMintPeriod = ConstU64<10>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
@@ -275,24 +315,148 @@ impl module_cdp_engine::Config for Runtime {
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
-	type DEX = ();
+	type DEX = DEXModule;
+	type DEXPriceSource = ();
+	type StabilityFeeAdjustmentPeriod = ConstU64<0>;
 	type LiquidationContractsUpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxLiquidationContractFailures = ConstU32<5>;
 	type LiquidationEvmBridge = ();
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
-	type Swap = SpecificJointsSwap<(), AlternativeSwapPathJointList>;
+	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
+	type WeightInfo = ();
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum ProxyType {
+	Any,
+}
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, _c: &RuntimeCall) -> bool {
+		true
+	}
+	fn is_superset(&self, _o: &Self) -> bool {
+		true
+	}
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = PalletBalances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ConstU128<1>;
+	type ProxyDepositFactor = ConstU128<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type CallHasher = BlakeTwo256;
+	type MaxPending = ConstU32<2>;
+	type AnnouncementDepositBase = ConstU128<1>;
+	type AnnouncementDepositFactor = ConstU128<1>;
+}
+
+impl orml_nft::Config for Runtime {
+	type ClassId = u32;
+	type TokenId = u64;
+	type ClassData = ClassData<Balance>;
+	type TokenData = TokenData<Balance>;
+	type MaxClassMetadata = ConstU32<1024>;
+	type MaxTokenMetadata = ConstU32<1024>;
+}
+
+parameter_types! {
+	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
+}
+pub const CREATE_CLASS_DEPOSIT: u128 = 200;
+pub const CREATE_TOKEN_DEPOSIT: u128 = 100;
+
+impl module_nft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = PalletBalances;
+	type CreateClassDeposit = ConstU128<CREATE_CLASS_DEPOSIT>;
+	type CreateTokenDeposit = ConstU128<CREATE_TOKEN_DEPOSIT>;
+	type DataDepositPerByte = ConstU128<10>;
+	type PalletId = NftPalletId;
+	type MaxAttributesBytes = ConstU32<10>;
 	type WeightInfo = ();
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
+parameter_types! {
+	pub const PositionPalletId: PalletId = PalletId(*b"aca/hzps");
+}
+
+thread_local! {
+	static PENDING_REWARDS: RefCell<BTreeMap<(PoolId, AccountId), Balance>> = RefCell::new(BTreeMap::new());
+}
+
+/// Rewards are paid in DOT.
+pub struct MockIncentives;
+impl MockIncentives {
+	pub fn add_pending_rewards(pool_id: PoolId, who: &AccountId, amount: Balance) {
+		PENDING_REWARDS.with(|v| *v.borrow_mut().entry((pool_id, who.clone())).or_default() += amount);
+	}
+
+	pub fn pending_rewards(pool_id: PoolId, who: &AccountId) -> Balance {
+		PENDING_REWARDS.with(|v| v.borrow().get(&(pool_id, who.clone())).copied().unwrap_or_default())
+	}
+}
+
+impl IncentivesManager<AccountId, Balance, CurrencyId, PoolId> for MockIncentives {
+	fn get_incentive_reward_amount(_pool_id: PoolId, _currency_id: CurrencyId) -> Balance {
+		unimplemented!()
+	}
+
+	fn deposit_dex_share(_who: &AccountId, _lp_currency_id: CurrencyId, _amount: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_dex_share(_who: &AccountId, _lp_currency_id: CurrencyId, _amount: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn claim_rewards(who: AccountId, pool_id: PoolId) -> DispatchResult {
+		Self::claim_rewards_and_get_payouts(who, pool_id)?;
+		Ok(())
+	}
+
+	fn claim_rewards_and_get_payouts(
+		who: AccountId,
+		pool_id: PoolId,
+	) -> Result<Vec<(CurrencyId, Balance)>, DispatchError> {
+		let amount = PENDING_REWARDS.with(|v| v.borrow_mut().remove(&(pool_id, who.clone())).unwrap_or_default());
+		if amount.is_zero() {
+			return Ok(vec![]);
+		}
+		Currencies::deposit(DOT, &who, amount)?;
+		Ok(vec![(DOT, amount)])
+	}
+
+	fn get_claim_reward_deduction_rate(_pool_id: PoolId) -> Rate {
+		unimplemented!()
+	}
+
+	fn get_pending_rewards(_pool_id: PoolId, _who: AccountId, _reward_currencies: Vec<CurrencyId>) -> Vec<Balance> {
+		unimplemented!()
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = PalletBalances;
 	type DepositPerAuthorization = ConstU128<100>;
+	type DepositPerProtectionRule = ConstU128<50>;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type PositionPalletId = PositionPalletId;
+	type Incentives = MockIncentives;
 	type WeightInfo = ();
 }
 
@@ -308,6 +472,10 @@ construct_runtime!(
 		CDPEngineModule: module_cdp_engine,
 		Timestamp: pallet_timestamp,
 		EvmAccounts: module_evm_accounts,
+		DEXModule: module_dex,
+		Proxy: pallet_proxy,
+		OrmlNFT: orml_nft,
+		NFTModule: module_nft,
 	}
 );
 
@@ -336,6 +504,8 @@ impl Default for ExtBuilder {
 				(BOB, BTC, 1000),
 				(ALICE, DOT, 1000),
 				(BOB, DOT, 1000),
+				(CAROL, BTC, 10000),
+				(CAROL, AUSD, 10000),
 			],
 		}
 	}
@@ -343,6 +513,8 @@ impl Default for ExtBuilder {
 
 impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
+		MockPriceSource::set_btc_price(Price::one());
+
 		let mut t = frame_system::GenesisConfig::<Runtime>::default()
 			.build_storage()
 			.unwrap();
@@ -359,6 +531,14 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		module_dex::GenesisConfig::<Runtime> {
+			initial_listing_trading_pairs: vec![],
+			initial_enabled_trading_pairs: EnabledTradingPairs::get(),
+			initial_added_liquidity_pools: vec![],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		t.into()
 	}
 }
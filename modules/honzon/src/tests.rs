@@ -23,9 +23,10 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{RuntimeEvent, *};
-use module_support::{Rate, Ratio};
+use module_support::{Price, Rate, Ratio};
 use orml_traits::{Change, MultiCurrency};
-use sp_runtime::FixedPointNumber;
+use primitives::nft::Properties;
+use sp_runtime::{traits::BadOrigin, FixedPointNumber};
 
 #[test]
 fn authorize_should_work() {
@@ -341,3 +342,507 @@ fn transfer_debit_no_ausd() {
 		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
 	});
 }
+
+fn setup_btc_loan() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		RuntimeOrigin::signed(ALICE),
+		BTC,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(10000),
+	));
+	assert_ok!(DEXModule::add_liquidity(
+		RuntimeOrigin::signed(CAROL),
+		AUSD,
+		BTC,
+		1000,
+		1000,
+		0,
+		false
+	));
+	// collateral ratio is 200%
+	assert_ok!(HonzonModule::adjust_loan(RuntimeOrigin::signed(ALICE), BTC, 100, 500));
+}
+
+#[test]
+fn set_and_remove_protection_rule_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			HonzonModule::set_protection_rule(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_rational(19, 10),
+				ProtectionAction::Close
+			),
+			module_cdp_engine::Error::<Runtime>::InvalidCollateralType
+		);
+		setup_btc_loan();
+
+		assert_noop!(
+			HonzonModule::set_protection_rule(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_rational(3, 2),
+				ProtectionAction::Close
+			),
+			Error::<Runtime>::InvalidProtectionRule
+		);
+		assert_noop!(
+			HonzonModule::set_protection_rule(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_rational(19, 10),
+				ProtectionAction::Deleverage {
+					target_ratio: Ratio::saturating_from_rational(19, 10)
+				}
+			),
+			Error::<Runtime>::InvalidProtectionRule
+		);
+
+		assert_ok!(HonzonModule::set_protection_rule(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_rational(19, 10),
+			ProtectionAction::Close
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::ProtectionRuleSet {
+			owner: ALICE,
+			collateral_type: BTC,
+			trigger_ratio: Ratio::saturating_from_rational(19, 10),
+			action: ProtectionAction::Close,
+		}));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 50);
+
+		// update the rule without reserving again
+		assert_ok!(HonzonModule::set_protection_rule(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_rational(2, 1),
+			ProtectionAction::Deleverage {
+				target_ratio: Ratio::saturating_from_rational(3, 1)
+			}
+		));
+		assert_eq!(
+			HonzonModule::protection_rules(ALICE, BTC),
+			Some(ProtectionRule {
+				trigger_ratio: Ratio::saturating_from_rational(2, 1),
+				action: ProtectionAction::Deleverage {
+					target_ratio: Ratio::saturating_from_rational(3, 1)
+				},
+				deposit: 50,
+			})
+		);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 50);
+
+		// the authorization deposit is kept apart
+		assert_ok!(HonzonModule::authorize(RuntimeOrigin::signed(ALICE), BTC, BOB));
+		assert_ok!(HonzonModule::unauthorize_all(RuntimeOrigin::signed(ALICE)));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 50);
+
+		assert_ok!(HonzonModule::remove_protection_rule(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::ProtectionRuleRemoved {
+			owner: ALICE,
+			collateral_type: BTC,
+		}));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+		assert_eq!(HonzonModule::protection_rules(ALICE, BTC), None);
+		assert_noop!(
+			HonzonModule::remove_protection_rule(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::ProtectionRuleNotExists
+		);
+	});
+}
+
+#[test]
+fn execute_deleverage_protection_rule_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_btc_loan();
+		assert_noop!(
+			HonzonModule::execute_protection_rule(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::ProtectionRuleNotExists
+		);
+
+		let action = ProtectionAction::Deleverage {
+			target_ratio: Ratio::saturating_from_rational(3, 1),
+		};
+		assert_ok!(HonzonModule::set_protection_rule(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_rational(19, 10),
+			action
+		));
+		assert_noop!(
+			HonzonModule::execute_protection_rule(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::ProtectionRuleNotTriggered
+		);
+
+		// collateral ratio falls to 180%
+		MockPriceSource::set_btc_price(Price::saturating_from_rational(9, 10));
+		assert_ok!(HonzonModule::execute_protection_rule(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BTC
+		));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::ProtectionRuleExecuted {
+			owner: ALICE,
+			collateral_type: BTC,
+			action,
+			keeper: BOB,
+			reward: 50,
+		}));
+
+		// sell 33 BTC for 31 aUSD to repay debit
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 67);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 190);
+		assert_eq!(HonzonModule::protection_rules(ALICE, BTC), None);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+		assert_eq!(PalletBalances::free_balance(ALICE), 950);
+		assert_eq!(PalletBalances::free_balance(BOB), 50);
+	});
+}
+
+#[test]
+fn execute_close_protection_rule_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_btc_loan();
+		assert_ok!(HonzonModule::set_protection_rule(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_rational(19, 10),
+			ProtectionAction::Close
+		));
+
+		MockPriceSource::set_btc_price(Price::saturating_from_rational(9, 10));
+		assert_ok!(HonzonModule::execute_protection_rule(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			BTC
+		));
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(HonzonModule::protection_rules(ALICE, BTC), None);
+		assert_eq!(PalletBalances::free_balance(BOB), 50);
+
+		assert_noop!(
+			HonzonModule::execute_protection_rule(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::ProtectionRuleNotExists
+		);
+	});
+}
+
+#[test]
+fn protection_rule_not_allowed_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_btc_loan();
+		assert_ok!(HonzonModule::set_protection_rule(
+			RuntimeOrigin::signed(ALICE),
+			BTC,
+			Ratio::saturating_from_rational(19, 10),
+			ProtectionAction::Close
+		));
+		MockPriceSource::set_btc_price(Price::saturating_from_rational(9, 10));
+
+		mock_shutdown();
+		assert_noop!(
+			HonzonModule::set_protection_rule(
+				RuntimeOrigin::signed(ALICE),
+				BTC,
+				Ratio::saturating_from_rational(2, 1),
+				ProtectionAction::Close
+			),
+			Error::<Runtime>::AlreadyShutdown
+		);
+		assert_noop!(
+			HonzonModule::execute_protection_rule(RuntimeOrigin::signed(BOB), ALICE, BTC),
+			Error::<Runtime>::AlreadyShutdown
+		);
+
+		// the deposit can still be withdrawn
+		assert_ok!(HonzonModule::remove_protection_rule(RuntimeOrigin::signed(ALICE), BTC));
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+	});
+}
+
+fn create_position_class(properties: Properties) -> u32 {
+	let class_id = OrmlNFT::next_class_id();
+	assert_ok!(NFTModule::create_class(
+		RuntimeOrigin::signed(ALICE),
+		vec![1],
+		properties,
+		Default::default(),
+	));
+	class_id
+}
+
+fn setup_position_class() -> u32 {
+	let class_id = create_position_class(Properties(ClassProperty::Transferable | ClassProperty::Mintable));
+	assert_ok!(HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), class_id));
+	class_id
+}
+
+#[test]
+fn set_position_class_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let class_id = create_position_class(Properties(ClassProperty::Transferable | ClassProperty::Mintable));
+		let not_transferable = create_position_class(Properties(ClassProperty::Mintable.into()));
+		let burnable = create_position_class(Properties(
+			ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable,
+		));
+		let mutable = create_position_class(Properties(
+			ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::ClassPropertiesMutable,
+		));
+
+		assert_noop!(
+			HonzonModule::set_position_class(RuntimeOrigin::signed(BOB), class_id),
+			BadOrigin
+		);
+		assert_noop!(
+			HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), 10),
+			Error::<Runtime>::InvalidPositionClass
+		);
+		assert_noop!(
+			HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), not_transferable),
+			Error::<Runtime>::InvalidPositionClass
+		);
+		assert_noop!(
+			HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), burnable),
+			Error::<Runtime>::InvalidPositionClass
+		);
+		assert_noop!(
+			HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), mutable),
+			Error::<Runtime>::InvalidPositionClass
+		);
+
+		assert_ok!(HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), class_id));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::PositionClassSet { class_id }));
+		assert_eq!(HonzonModule::position_class_id(), Some(class_id));
+
+		// the class cannot be changed while positions are wrapped
+		setup_btc_loan();
+		assert_ok!(HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC));
+		assert_noop!(
+			HonzonModule::set_position_class(RuntimeOrigin::signed(ALICE), class_id),
+			Error::<Runtime>::PositionsStillWrapped
+		);
+	});
+}
+
+#[test]
+fn wrap_and_unwrap_position_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_btc_loan();
+		assert_noop!(
+			HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::PositionClassNotSet
+		);
+
+		let class_id = setup_position_class();
+		assert_noop!(
+			HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), DOT),
+			Error::<Runtime>::PositionNotExists
+		);
+
+		assert_ok!(HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::PositionWrapped {
+			owner: ALICE,
+			collateral_type: BTC,
+			token_id: 0,
+		}));
+		let vault = HonzonModule::position_vault(0);
+		assert_eq!(HonzonModule::wrapped_positions(0), Some(BTC));
+		assert_eq!(OrmlNFT::tokens(class_id, 0).map(|t| t.owner), Some(ALICE));
+		assert_eq!(LoansModule::positions(BTC, ALICE), Position::default());
+		assert_eq!(LoansModule::positions(BTC, &vault).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, &vault).debit, 500);
+		assert_eq!(
+			<HonzonModule as WrappedPositionManager<_, _, _, _, _>>::get_wrapped_position(0),
+			Some((
+				BTC,
+				Position {
+					collateral: 100,
+					debit: 500
+				}
+			))
+		);
+		// the token deposit is paid by the caller
+		assert_eq!(PalletBalances::reserved_balance(ALICE), CREATE_TOKEN_DEPOSIT);
+
+		assert_noop!(
+			HonzonModule::unwrap_position(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			HonzonModule::unwrap_position(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Runtime>::WrappedPositionNotExists
+		);
+
+		assert_ok!(HonzonModule::unwrap_position(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(RuntimeEvent::HonzonModule(crate::Event::PositionUnwrapped {
+			owner: ALICE,
+			collateral_type: BTC,
+			token_id: 0,
+		}));
+		assert_eq!(HonzonModule::wrapped_positions(0), None);
+		assert_eq!(OrmlNFT::tokens(class_id, 0), None);
+		assert_eq!(LoansModule::positions(BTC, &vault), Position::default());
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(PalletBalances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn wrapped_position_follows_token_holder() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_btc_loan();
+		let class_id = setup_position_class();
+		assert_ok!(HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC));
+		let vault = HonzonModule::position_vault(0);
+
+		assert_ok!(NFTModule::transfer(RuntimeOrigin::signed(ALICE), BOB, (class_id, 0)));
+		assert_noop!(
+			HonzonModule::adjust_wrapped_loan(RuntimeOrigin::signed(ALICE), 0, 100, 0),
+			Error::<Runtime>::NoPermission
+		);
+
+		let issued = CDPEngineModule::convert_to_debit_value(BTC, 100);
+		assert_ok!(HonzonModule::adjust_wrapped_loan(
+			RuntimeOrigin::signed(BOB),
+			0,
+			100,
+			100
+		));
+		assert_eq!(LoansModule::positions(BTC, &vault).collateral, 200);
+		assert_eq!(LoansModule::positions(BTC, &vault).debit, 600);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), issued);
+		assert_eq!(Currencies::free_balance(BTC, &vault), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &vault), 0);
+
+		// pay back and withdraw through the wrapped position
+		assert_ok!(HonzonModule::adjust_wrapped_loan(
+			RuntimeOrigin::signed(BOB),
+			0,
+			-50,
+			-100
+		));
+		assert_eq!(LoansModule::positions(BTC, &vault).collateral, 150);
+		assert_eq!(LoansModule::positions(BTC, &vault).debit, 500);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), 950);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+
+		assert_ok!(HonzonModule::unwrap_position(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(LoansModule::positions(BTC, BOB).collateral, 150);
+		assert_eq!(LoansModule::positions(BTC, BOB).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE), Position::default());
+	});
+}
+
+#[test]
+fn wrapped_position_rewards_follow_token_holder() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_btc_loan();
+		let class_id = setup_position_class();
+		assert_ok!(HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC));
+		let vault = HonzonModule::position_vault(0);
+		let alice_dot = Currencies::free_balance(DOT, &ALICE);
+		let bob_dot = Currencies::free_balance(DOT, &BOB);
+
+		// the vault owns the shares and earns the rewards
+		MockIncentives::add_pending_rewards(PoolId::Loans(BTC), &vault, 30);
+		assert_ok!(NFTModule::transfer(RuntimeOrigin::signed(ALICE), BOB, (class_id, 0)));
+		assert_noop!(
+			HonzonModule::claim_wrapped_position_rewards(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(HonzonModule::claim_wrapped_position_rewards(
+			RuntimeOrigin::signed(BOB),
+			0
+		));
+		assert_eq!(Currencies::free_balance(DOT, &BOB), bob_dot + 30);
+		assert_eq!(Currencies::free_balance(DOT, &vault), 0);
+
+		// the unclaimed rewards are paid out on unwrap
+		MockIncentives::add_pending_rewards(PoolId::Loans(BTC), &vault, 70);
+		assert_ok!(HonzonModule::unwrap_position(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(MockIncentives::pending_rewards(PoolId::Loans(BTC), &vault), 0);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), bob_dot + 100);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), alice_dot);
+		assert_eq!(Currencies::free_balance(DOT, &vault), 0);
+	});
+}
+
+#[test]
+fn position_token_cannot_be_burned_by_holder() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_btc_loan();
+		let class_id = setup_position_class();
+		assert_ok!(HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC));
+
+		assert_noop!(
+			NFTModule::burn(RuntimeOrigin::signed(ALICE), (class_id, 0)),
+			module_nft::Error::<Runtime>::NonBurnable
+		);
+		assert_noop!(
+			NFTModule::burn_with_remark(RuntimeOrigin::signed(ALICE), (class_id, 0), vec![1]),
+			module_nft::Error::<Runtime>::NonBurnable
+		);
+
+		// the position can still be unwrapped
+		assert_ok!(HonzonModule::unwrap_position(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(HonzonModule::wrapped_positions(0), None);
+		assert_eq!(OrmlNFT::tokens(class_id, 0), None);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+	});
+}
+
+#[test]
+fn sweep_vault_returns_refunded_dex_share_tokens() {
+	ExtBuilder::default().build().execute_with(|| {
+		let lp_currency_id = CurrencyId::join_dex_share_currency_id(DOT, BTC).unwrap();
+		let vault = HonzonModule::position_vault(0);
+		assert_ok!(Currencies::deposit(lp_currency_id, &vault, 10));
+		assert_ok!(Currencies::deposit(AUSD, &vault, 20));
+		assert_ok!(Currencies::deposit(DOT, &vault, 30));
+		assert_ok!(Currencies::deposit(BTC, &vault, 40));
+		assert_ok!(Currencies::deposit(ACA, &vault, 50));
+		let dot_balance = Currencies::free_balance(DOT, &BOB);
+		let btc_balance = Currencies::free_balance(BTC, &BOB);
+
+		assert_ok!(HonzonModule::sweep_vault(&vault, &BOB, lp_currency_id));
+		assert_eq!(Currencies::free_balance(lp_currency_id, &BOB), 10);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 20);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), dot_balance + 30);
+		assert_eq!(Currencies::free_balance(BTC, &BOB), btc_balance + 40);
+		assert_eq!(Currencies::free_balance(lp_currency_id, &vault), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &vault), 0);
+		assert_eq!(Currencies::free_balance(DOT, &vault), 0);
+		assert_eq!(Currencies::free_balance(BTC, &vault), 0);
+		// other currencies are left in the vault
+		assert_eq!(Currencies::free_balance(ACA, &vault), 50);
+	});
+}
+
+#[test]
+fn wrap_position_not_allowed_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_btc_loan();
+		setup_position_class();
+		mock_shutdown();
+		assert_noop!(
+			HonzonModule::wrap_position(RuntimeOrigin::signed(ALICE), BTC),
+			Error::<Runtime>::AlreadyShutdown
+		);
+	});
+}
@@ -57,6 +57,14 @@ pub trait WeightInfo {
 	fn shrink_position_debit() -> Weight;
 	fn transfer_debit() -> Weight;
 	fn precompile_get_current_collateral_ratio() -> Weight;
+	fn set_protection_rule() -> Weight;
+	fn remove_protection_rule() -> Weight;
+	fn execute_protection_rule() -> Weight;
+	fn set_position_class() -> Weight;
+	fn wrap_position() -> Weight;
+	fn unwrap_position() -> Weight;
+	fn adjust_wrapped_loan() -> Weight;
+	fn claim_wrapped_position_rewards() -> Weight;
 }
 
 /// Weights for module_honzon using the Acala node and recommended hardware.
@@ -93,20 +101,22 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
 	// Storage: CdpEngine CollateralParams (r:1 w:0)
 	// Storage: Loans Positions (r:1 w:1)
-	// Storage: Rewards PoolInfos (r:1 w:1)
-	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:2 w:2)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:2 w:2)
+	// Storage: Incentives CollateralDexShares (r:1 w:1)
 	// Storage: Loans TotalPositions (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
 	// Storage: Tokens Accounts (r:1 w:1)
 	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Dex LiquidityPool (r:1 w:0)
 	// Storage: Prices LockedPrice (r:2 w:0)
 	// Storage: AcalaOracle Values (r:1 w:0)
 	// Storage: AssetRegistry AssetMetadatas (r:2 w:0)
 	fn adjust_loan() -> Weight {
-		Weight::from_parts(142_855_000, 0)
-			.saturating_add(T::DbWeight::get().reads(16 as u64))
-			.saturating_add(T::DbWeight::get().writes(8 as u64))
+		Weight::from_parts(174_283_000, 0)
+			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
@@ -226,6 +236,46 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		Weight::from_parts(44_244_000, 0)
 			.saturating_add(T::DbWeight::get().reads(11 as u64))
 	}
+	fn set_protection_rule() -> Weight {
+		Weight::from_parts(41_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn remove_protection_rule() -> Weight {
+		Weight::from_parts(39_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn execute_protection_rule() -> Weight {
+		Weight::from_parts(412_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(42 as u64))
+			.saturating_add(T::DbWeight::get().writes(19 as u64))
+	}
+	fn set_position_class() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn wrap_position() -> Weight {
+		Weight::from_parts(152_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	fn unwrap_position() -> Weight {
+		Weight::from_parts(270_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().writes(16 as u64))
+	}
+	fn adjust_wrapped_loan() -> Weight {
+		Weight::from_parts(186_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
+	}
+	fn claim_wrapped_position_rewards() -> Weight {
+		Weight::from_parts(132_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -249,9 +299,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
 	}
 	fn adjust_loan() -> Weight {
-		Weight::from_parts(142_855_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(16 as u64))
-			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+		Weight::from_parts(174_283_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(20 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
 	}
 	fn transfer_loan_from() -> Weight {
 		Weight::from_parts(120_478_000, 0)
@@ -282,4 +332,44 @@ impl WeightInfo for () {
 		Weight::from_parts(44_244_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(11 as u64))
 	}
+	fn set_protection_rule() -> Weight {
+		Weight::from_parts(41_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn remove_protection_rule() -> Weight {
+		Weight::from_parts(39_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn execute_protection_rule() -> Weight {
+		Weight::from_parts(412_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(42 as u64))
+			.saturating_add(RocksDbWeight::get().writes(19 as u64))
+	}
+	fn set_position_class() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn wrap_position() -> Weight {
+		Weight::from_parts(152_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(14 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	fn unwrap_position() -> Weight {
+		Weight::from_parts(270_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(21 as u64))
+			.saturating_add(RocksDbWeight::get().writes(16 as u64))
+	}
+	fn adjust_wrapped_loan() -> Weight {
+		Weight::from_parts(186_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(24 as u64))
+			.saturating_add(RocksDbWeight::get().writes(15 as u64))
+	}
+	fn claim_wrapped_position_rewards() -> Weight {
+		Weight::from_parts(132_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
 }
@@ -28,7 +28,9 @@
 //!
 //! Pool types:
 //! 1. Loans: record the shares and rewards for users of Loans(Honzon protocol).
-//! 2. Dex: record the shares and rewards for DEX makers who staking LP token.
+//! 2. Dex: record the shares and rewards for DEX makers who staking LP token. LP token locked as
+//! collateral of CDPs is staked on behalf of the CDP owner, and cannot be unstaked until it's
+//! withdrawn from the CDP.
 //!
 //! Rewards accumulation:
 //! 1. Incentives: periodicly(AccumulatePeriod), accumulate fixed amount according to Incentive.
@@ -46,10 +48,11 @@ use orml_traits::{Happened, MultiCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
 	traits::{AccountIdConversion, UniqueSaturatedInto, Zero},
-	DispatchResult, FixedPointNumber,
+	DispatchError, DispatchResult, FixedPointNumber,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -179,7 +182,20 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// The shares of Pool::Dex staked by the LP token locked as collateral of CDPs. The LP token
+	/// is kept by the loans module.
+	///
+	/// CollateralDexShares: double_map LPCurrencyId, AccountId => Shares
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_dex_shares)]
+	pub type CollateralDexShares<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CurrencyId, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -265,7 +281,8 @@ pub mod module {
 		pub fn claim_rewards(origin: OriginFor<T>, pool_id: PoolId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			Self::do_claim_rewards(who, pool_id)
+			Self::do_claim_rewards(who, pool_id)?;
+			Ok(())
 		}
 
 		/// Update incentive reward amount for specific PoolId
@@ -413,10 +430,13 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn do_claim_rewards(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
+	/// Claim the rewards of `who` for `pool_id`, returns the rewards paid out to `who`.
+	fn do_claim_rewards(who: T::AccountId, pool_id: PoolId) -> Result<Vec<(CurrencyId, Balance)>, DispatchError> {
 		// orml_rewards will claim rewards for all currencies rewards
 		<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
 
+		let mut payouts: Vec<(CurrencyId, Balance)> = vec![];
+
 		PendingMultiRewards::<T>::mutate_exists(pool_id, &who, |maybe_pending_multi_rewards| {
 			if let Some(pending_multi_rewards) = maybe_pending_multi_rewards {
 				let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
@@ -458,6 +478,7 @@ impl<T: Config> Pallet<T> {
 						Ok(_) => {
 							// update state
 							*pending_reward = Zero::zero();
+							payouts.push((*currency_id, payout_amount));
 
 							Self::deposit_event(Event::ClaimRewards {
 								who: who.clone(),
@@ -487,7 +508,7 @@ impl<T: Config> Pallet<T> {
 			}
 		});
 
-		Ok(())
+		Ok(payouts)
 	}
 
 	/// Ensure atomic
@@ -524,8 +545,12 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 
 	fn do_withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
+		// the shares staked by CDP collateral can only be removed by the loans module
 		ensure!(
-			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&PoolId::Dex(lp_currency_id), &who).0 >= amount,
+			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&PoolId::Dex(lp_currency_id), &who)
+				.0
+				.saturating_sub(Self::collateral_dex_shares(lp_currency_id, who))
+				>= amount,
 			Error::<T>::NotEnough,
 		);
 
@@ -555,6 +580,14 @@ impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId> for
 	}
 
 	fn claim_rewards(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
+		Self::do_claim_rewards(who, pool_id)?;
+		Ok(())
+	}
+
+	fn claim_rewards_and_get_payouts(
+		who: T::AccountId,
+		pool_id: PoolId,
+	) -> Result<Vec<(CurrencyId, Balance)>, DispatchError> {
 		Self::do_claim_rewards(who, pool_id)
 	}
 
@@ -584,6 +617,23 @@ impl<T: Config> Happened<(T::AccountId, CurrencyId, Amount, Balance)> for OnUpda
 		} else {
 			<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::Loans(*currency_id), adjustment_abs);
 		};
+
+		// LP token collateral keeps earning the rewards of Pool::Dex for the CDP owner
+		if currency_id.is_dex_share_currency_id() {
+			let pool_id = PoolId::Dex(*currency_id);
+			CollateralDexShares::<T>::mutate_exists(currency_id, who, |maybe_shares| {
+				let shares = maybe_shares.unwrap_or_default();
+				let new_shares = if adjustment.is_positive() {
+					<orml_rewards::Pallet<T>>::add_share(who, &pool_id, adjustment_abs);
+					shares.saturating_add(adjustment_abs)
+				} else {
+					// the LP token locked before it's staked has no shares to remove
+					<orml_rewards::Pallet<T>>::remove_share(who, &pool_id, adjustment_abs.min(shares));
+					shares.saturating_sub(adjustment_abs)
+				};
+				*maybe_shares = Some(new_shares).filter(|v| !v.is_zero());
+			});
+		}
 	}
 }
 
@@ -0,0 +1,75 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{module::STORAGE_VERSION, CollateralDexShares, Config, Pallet};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use module_support::PoolId;
+use primitives::{Balance, CurrencyId};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Stake the LP token locked as collateral of CDPs before the collateral was auto-staked into
+/// `PoolId::Dex` on behalf of the CDP owner. The shares of `PoolId::Loans` are the collateral
+/// amounts of the CDPs.
+pub struct StakeExistingLpCollateral<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for StakeExistingLpCollateral<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let lp_currency_ids: Vec<CurrencyId> = orml_rewards::PoolInfos::<T>::iter_keys()
+			.filter_map(|pool_id| match pool_id {
+				PoolId::Loans(currency_id) if currency_id.is_dex_share_currency_id() => Some(currency_id),
+				_ => None,
+			})
+			.collect();
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+
+		for lp_currency_id in lp_currency_ids {
+			let collaterals: Vec<(T::AccountId, Balance)> =
+				orml_rewards::SharesAndWithdrawnRewards::<T>::iter_prefix(PoolId::Loans(lp_currency_id))
+					.map(|(who, (collateral, _))| (who, collateral))
+					.collect();
+			reads = reads.saturating_add(1).saturating_add(collaterals.len() as u64);
+
+			for (who, collateral) in collaterals {
+				let staked = CollateralDexShares::<T>::get(lp_currency_id, &who);
+				reads = reads.saturating_add(1);
+				if collateral > staked {
+					<orml_rewards::Pallet<T>>::add_share(&who, &PoolId::Dex(lp_currency_id), collateral - staked);
+					CollateralDexShares::<T>::insert(lp_currency_id, &who, collateral);
+					reads = reads.saturating_add(2);
+					writes = writes.saturating_add(3);
+				}
+			}
+		}
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		log::info!(
+			target: "incentives",
+			"StakeExistingLpCollateral::on_runtime_upgrade execute, reads: {:?}, writes: {:?}",
+			reads, writes
+		);
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -21,7 +21,10 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use mock::{RuntimeEvent, *};
 use orml_rewards::PoolInfo;
 use orml_traits::MultiCurrency;
@@ -410,6 +413,102 @@ fn on_update_loan_works() {
 	});
 }
 
+#[test]
+fn on_update_loan_of_lp_collateral_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 10000));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC_AUSD_LP, 500, 0));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(BTC_AUSD_LP), ALICE::get()),
+			(500, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(1500, Default::default())
+		);
+		assert_eq!(IncentivesModule::collateral_dex_shares(BTC_AUSD_LP, ALICE::get()), 500);
+
+		// the shares staked by collateral cannot be unstaked
+		assert_noop!(
+			IncentivesModule::withdraw_dex_share(RuntimeOrigin::signed(ALICE::get()), BTC_AUSD_LP, 1001),
+			Error::<Runtime>::NotEnough,
+		);
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			RuntimeOrigin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(500, Default::default())
+		);
+
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC_AUSD_LP, -200, 500));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(300, Default::default())
+		);
+		assert_eq!(IncentivesModule::collateral_dex_shares(BTC_AUSD_LP, ALICE::get()), 300);
+
+		// the collateral locked before it's staked only removes the staked shares
+		OnUpdateLoan::<Runtime>::happened(&(ALICE::get(), BTC_AUSD_LP, -400, 400));
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 0,
+				..Default::default()
+			}
+		);
+		assert_eq!(IncentivesModule::collateral_dex_shares(BTC_AUSD_LP, ALICE::get()), 0);
+	});
+}
+
+#[test]
+fn stake_existing_lp_collateral_migration_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the LP collateral locked before it's auto-staked only has the shares of Pool::Loans
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC_AUSD_LP), 500);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC_AUSD_LP), 100);
+		OnUpdateLoan::<Runtime>::happened(&(BOB::get(), BTC_AUSD_LP, 200, 100));
+		OnUpdateLoan::<Runtime>::happened(&(BOB::get(), BTC, 300, 0));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), BOB::get()),
+			(200, Default::default())
+		);
+		StorageVersion::new(0).put::<IncentivesModule>();
+
+		migrations::StakeExistingLpCollateral::<Runtime>::on_runtime_upgrade();
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			(500, Default::default())
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), BOB::get()),
+			(300, Default::default())
+		);
+		assert_eq!(IncentivesModule::collateral_dex_shares(BTC_AUSD_LP, ALICE::get()), 500);
+		assert_eq!(IncentivesModule::collateral_dex_shares(BTC_AUSD_LP, BOB::get()), 300);
+		assert_eq!(RewardsModule::pool_infos(PoolId::Dex(BTC)), PoolInfo::default());
+		assert_eq!(IncentivesModule::on_chain_storage_version(), 1);
+
+		// the migration only runs once
+		migrations::StakeExistingLpCollateral::<Runtime>::on_runtime_upgrade();
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 800,
+				..Default::default()
+			}
+		);
+	});
+}
+
 #[test]
 fn payout_works() {
 	ExtBuilder::default().build().execute_with(|| {
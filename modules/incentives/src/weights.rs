@@ -100,6 +100,8 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::CollateralDexShares` (r:1 w:0)
+	// Proof: `Incentives::CollateralDexShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:0)
@@ -108,11 +110,11 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn withdraw_dex_share() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2945`
-		//  Estimated: `6410`
-		// Minimum execution time: 97_599 nanoseconds.
-		Weight::from_parts(99_107_000, 6410)
-			.saturating_add(T::DbWeight::get().reads(5))
+		//  Measured:  `3020`
+		//  Estimated: `6485`
+		// Minimum execution time: 101_014 nanoseconds.
+		Weight::from_parts(102_575_000, 6485)
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
@@ -225,6 +227,8 @@ impl WeightInfo for () {
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::CollateralDexShares` (r:1 w:0)
+	// Proof: `Incentives::CollateralDexShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:0)
@@ -233,11 +237,11 @@ impl WeightInfo for () {
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn withdraw_dex_share() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2945`
-		//  Estimated: `6410`
-		// Minimum execution time: 97_599 nanoseconds.
-		Weight::from_parts(99_107_000, 6410)
-			.saturating_add(RocksDbWeight::get().reads(5))
+		//  Measured:  `3020`
+		//  Estimated: `6485`
+		// Minimum execution time: 101_014 nanoseconds.
+		Weight::from_parts(102_575_000, 6485)
+			.saturating_add(RocksDbWeight::get().reads(6))
 			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
@@ -0,0 +1,657 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # NFT Marketplace Module
+//!
+//! ## Overview
+//!
+//! On-chain marketplace for the tokens of `module_nft`. Tokens can be sold through:
+//!   - `fixed-price listing`: the token is escrowed and sold to the first buyer paying the price
+//!   - `offer`: anyone can reserve an amount to buy a token, which its owner can accept
+//!   - `English auction`: the token is escrowed and auctioned through `orml_auction`
+//!
+//! Payments can be made in any `CurrencyId`. The royalty of the class, stored in its
+//! `ClassData`, is paid to the class owner on every sale. It is read when the sale executes,
+//! and only classes with `ClassPropertiesMutable` can change it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::tokens::nonfungibles::Inspect, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use module_nft::{ClassIdOf, TokenIdOf};
+use module_support::Rate;
+use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, MultiReservableCurrency, OnNewBidResult};
+use primitives::{AuctionId, Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	DispatchError, DispatchResult, FixedPointNumber, Permill, RuntimeDebug,
+};
+use sp_std::marker::PhantomData;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// A fixed-price listing of an escrowed token.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Listing<AccountId> {
+	/// The seller, who receives the price minus the royalty
+	pub seller: AccountId,
+	/// The currency to pay the price in
+	pub currency_id: CurrencyId,
+	/// The asking price
+	pub price: Balance,
+}
+
+/// An offer to buy a token. The amount is reserved from the offerer.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Offer {
+	/// The currency of the offer
+	pub currency_id: CurrencyId,
+	/// The offered amount
+	pub amount: Balance,
+}
+
+/// An English auction of an escrowed token.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AuctionItem<AccountId, ClassId, TokenId> {
+	/// The seller, who receives the winning bid minus the royalty
+	pub seller: AccountId,
+	/// The auctioned token
+	pub token: (ClassId, TokenId),
+	/// The currency to bid in
+	pub currency_id: CurrencyId,
+	/// The minimum accepted bid
+	pub min_bid: Balance,
+}
+
+pub type AuctionItemOf<T> = AuctionItem<<T as frame_system::Config>::AccountId, ClassIdOf<T>, TokenIdOf<T>>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + module_nft::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency to pay for tokens. Offers and bids are reserved in it.
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Auction to manage the English auctions
+		type Auction: Auction<Self::AccountId, BlockNumberFor<Self>, AuctionId = AuctionId, Balance = Balance>;
+
+		/// The minimum increment size of each bid compared to the previous one
+		#[pallet::constant]
+		type MinimumIncrementSize: Get<Rate>;
+
+		/// The auction is extended to end at least this long after each
+		/// successful bid
+		#[pallet::constant]
+		type AuctionTimeToClose: Get<BlockNumberFor<Self>>;
+
+		/// The marketplace's module id, keep the escrowed tokens.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The price or amount is zero
+		InvalidPrice,
+		/// The listing does not exist
+		ListingNotFound,
+		/// The listing price is higher than the accepted maximum
+		PriceTooHigh,
+		/// The token does not exist
+		TokenNotFound,
+		/// The offer does not exist
+		OfferNotFound,
+		/// The auction duration is zero
+		InvalidDuration,
+		/// The auction does not exist
+		AuctionNotFound,
+		/// The auction already has a bid
+		AuctionHasBid,
+		/// The bid price is invalid
+		InvalidBidPrice,
+		/// The operator is not the seller
+		NoPermission,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A token is listed for a fixed price.
+		Listed {
+			seller: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			currency_id: CurrencyId,
+			price: Balance,
+		},
+		/// A listing is cancelled.
+		ListingCancelled {
+			seller: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// An offer is made.
+		OfferMade {
+			offerer: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			currency_id: CurrencyId,
+			amount: Balance,
+		},
+		/// An offer is cancelled.
+		OfferCancelled {
+			offerer: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// A token is sold, through a listing, an offer or an auction.
+		Sold {
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			currency_id: CurrencyId,
+			price: Balance,
+			royalty: Balance,
+		},
+		/// An auction is created.
+		AuctionCreated {
+			auction_id: AuctionId,
+			seller: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			currency_id: CurrencyId,
+			min_bid: Balance,
+		},
+		/// An auction is cancelled by the seller.
+		AuctionCancelled { auction_id: AuctionId },
+		/// An auction ended without bids, the token is returned to the seller.
+		AuctionAborted { auction_id: AuctionId },
+	}
+
+	/// The fixed-price listings.
+	///
+	/// Listings: double_map ClassId, TokenId => Option<Listing>
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, Listing<T::AccountId>, OptionQuery>;
+
+	/// The offers to buy tokens.
+	///
+	/// Offers: nmap (ClassId, TokenId, AccountId) => Option<Offer>
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, ClassIdOf<T>>,
+			NMapKey<Twox64Concat, TokenIdOf<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		Offer,
+		OptionQuery,
+	>;
+
+	/// The marketplace auctions.
+	///
+	/// Auctions: map AuctionId => Option<AuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> = StorageMap<_, Twox64Concat, AuctionId, AuctionItemOf<T>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List a token for a fixed price. The token is escrowed until it is sold or the
+		/// listing is cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency to pay the price in
+		/// - `price`: the asking price
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::list())]
+		pub fn list(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+
+			module_nft::Pallet::<T>::do_transfer(&who, &Self::account_id(), token)?;
+			Listings::<T>::insert(
+				token.0,
+				token.1,
+				Listing {
+					seller: who.clone(),
+					currency_id,
+					price,
+				},
+			);
+
+			Self::deposit_event(Event::Listed {
+				seller: who,
+				class_id: token.0,
+				token_id: token.1,
+				currency_id,
+				price,
+			});
+			Ok(())
+		}
+
+		/// Cancel a listing and return the token to the seller.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		pub fn cancel_listing(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::ListingNotFound)?;
+			ensure!(listing.seller == who, Error::<T>::NoPermission);
+
+			Listings::<T>::remove(token.0, token.1);
+			module_nft::Pallet::<T>::do_transfer(&Self::account_id(), &who, token)?;
+
+			Self::deposit_event(Event::ListingCancelled {
+				seller: who,
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
+
+		/// Buy a listed token.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `max_price`: the maximum price the buyer accepts to pay
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] max_price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Listings::<T>::take(token.0, token.1).ok_or(Error::<T>::ListingNotFound)?;
+			ensure!(listing.price <= max_price, Error::<T>::PriceTooHigh);
+
+			Self::settle(
+				&Self::account_id(),
+				&listing.seller,
+				&who,
+				token,
+				listing.currency_id,
+				listing.price,
+			)
+		}
+
+		/// Make an offer to buy a token, reserving `amount` of `currency_id`. An existing
+		/// offer of the caller for the token is replaced.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency of the offer
+		/// - `amount`: the offered amount
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::make_offer())]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidPrice);
+			ensure!(
+				<module_nft::Pallet<T> as Inspect<T::AccountId>>::owner(&token.0, &token.1).is_some(),
+				Error::<T>::TokenNotFound
+			);
+
+			if let Some(offer) = Offers::<T>::take((token.0, token.1, &who)) {
+				<T as Config>::Currency::unreserve(offer.currency_id, &who, offer.amount);
+			}
+			<T as Config>::Currency::reserve(currency_id, &who, amount)?;
+			Offers::<T>::insert((token.0, token.1, &who), Offer { currency_id, amount });
+
+			Self::deposit_event(Event::OfferMade {
+				offerer: who,
+				class_id: token.0,
+				token_id: token.1,
+				currency_id,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Cancel an offer and unreserve the offered amount.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Offers::<T>::take((token.0, token.1, &who)).ok_or(Error::<T>::OfferNotFound)?;
+			<T as Config>::Currency::unreserve(offer.currency_id, &who, offer.amount);
+
+			Self::deposit_event(Event::OfferCancelled {
+				offerer: who,
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
+
+		/// Accept an offer for a token owned by the caller, or escrowed by a listing of
+		/// the caller, which is then cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `offerer`: the account made the offer
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_offer())]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			offerer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = Offers::<T>::take((token.0, token.1, &offerer)).ok_or(Error::<T>::OfferNotFound)?;
+
+			let holder = match Listings::<T>::take(token.0, token.1) {
+				Some(listing) => {
+					ensure!(listing.seller == who, Error::<T>::NoPermission);
+					Self::account_id()
+				}
+				None => who.clone(),
+			};
+
+			<T as Config>::Currency::unreserve(offer.currency_id, &offerer, offer.amount);
+			Self::settle(&holder, &who, &offerer, token, offer.currency_id, offer.amount)
+		}
+
+		/// Create an English auction for a token. The token is escrowed until the auction
+		/// ends or is cancelled. Bids are placed through `orml_auction`.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency to bid in
+		/// - `min_bid`: the minimum accepted bid
+		/// - `duration`: the number of blocks until the auction ends
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] min_bid: Balance,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!min_bid.is_zero(), Error::<T>::InvalidPrice);
+			ensure!(!duration.is_zero(), Error::<T>::InvalidDuration);
+
+			module_nft::Pallet::<T>::do_transfer(&who, &Self::account_id(), token)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let auction_id = T::Auction::new_auction(now, Some(now.saturating_add(duration)))?;
+			Auctions::<T>::insert(
+				auction_id,
+				AuctionItem {
+					seller: who.clone(),
+					token,
+					currency_id,
+					min_bid,
+				},
+			);
+
+			Self::deposit_event(Event::AuctionCreated {
+				auction_id,
+				seller: who,
+				class_id: token.0,
+				token_id: token.1,
+				currency_id,
+				min_bid,
+			});
+			Ok(())
+		}
+
+		/// Cancel an auction without bids and return the token to the seller.
+		///
+		/// - `auction_id`: the auction to cancel
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let auction_item = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(auction_item.seller == who, Error::<T>::NoPermission);
+			let auction_info = T::Auction::auction_info(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(auction_info.bid.is_none(), Error::<T>::AuctionHasBid);
+
+			T::Auction::remove_auction(auction_id);
+			Auctions::<T>::remove(auction_id);
+			module_nft::Pallet::<T>::do_transfer(&Self::account_id(), &who, auction_item.token)?;
+
+			Self::deposit_event(Event::AuctionCancelled { auction_id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account escrowing the listed and auctioned tokens.
+	pub fn account_id() -> T::AccountId {
+		<T as Config>::PalletId::get().into_account_truncating()
+	}
+
+	/// Returns the royalty paid on a sale of a token of `class_id` for `price`.
+	pub fn royalty_amount(class_id: ClassIdOf<T>, price: Balance) -> Balance {
+		module_nft::Pallet::<T>::royalty_info(class_id)
+			.map_or(Zero::zero(), |(_, royalty)| Self::calculate_royalty(royalty, price))
+	}
+
+	fn calculate_royalty(royalty: u16, price: Balance) -> Balance {
+		// 1 basis point is 100 parts per million
+		Permill::from_parts(u32::from(royalty).saturating_mul(100)).mul_floor(price)
+	}
+
+	/// Pay `price` from `buyer` to `seller` and the royalty to the class owner, then
+	/// transfer `token` from `holder` to `buyer`.
+	fn settle(
+		holder: &T::AccountId,
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		currency_id: CurrencyId,
+		price: Balance,
+	) -> DispatchResult {
+		let (royalty_recipient, royalty) =
+			module_nft::Pallet::<T>::royalty_info(token.0).ok_or(module_nft::Error::<T>::ClassIdNotFound)?;
+		let royalty = Self::calculate_royalty(royalty, price);
+
+		<T as Config>::Currency::transfer(currency_id, buyer, &royalty_recipient, royalty)?;
+		<T as Config>::Currency::transfer(currency_id, buyer, seller, price.saturating_sub(royalty))?;
+		module_nft::Pallet::<T>::do_transfer(holder, buyer, token)?;
+
+		Self::deposit_event(Event::Sold {
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			class_id: token.0,
+			token_id: token.1,
+			currency_id,
+			price,
+			royalty,
+		});
+		Ok(())
+	}
+
+	/// Handles a new bid, reserving it from the new bidder and unreserving the last bid.
+	/// Returns the new end of the auction, if it needs to be extended.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	fn auction_bid_handler(
+		now: BlockNumberFor<T>,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> Result<Option<BlockNumberFor<T>>, DispatchError> {
+		let auction_item = Self::auctions(id).ok_or(Error::<T>::AuctionNotFound)?;
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(new_bid_price >= auction_item.min_bid, Error::<T>::InvalidBidPrice);
+
+		if let Some((last_bidder, last_bid_price)) = last_bid {
+			let minimum_bid_price = Rate::one()
+				.saturating_add(<T as Config>::MinimumIncrementSize::get())
+				.saturating_mul_int(last_bid_price);
+			ensure!(
+				new_bid_price > last_bid_price && new_bid_price >= minimum_bid_price,
+				Error::<T>::InvalidBidPrice
+			);
+			<T as Config>::Currency::unreserve(auction_item.currency_id, &last_bidder, last_bid_price);
+		}
+		<T as Config>::Currency::reserve(auction_item.currency_id, &new_bidder, new_bid_price)?;
+
+		let new_end = now.saturating_add(<T as Config>::AuctionTimeToClose::get());
+		let extended = T::Auction::auction_info(id)
+			.and_then(|info| info.end)
+			.map_or(false, |end| end < new_end);
+		Ok(if extended { Some(new_end) } else { None })
+	}
+
+	#[transactional]
+	fn auction_end_handler(
+		auction_id: AuctionId,
+		auction_item: AuctionItemOf<T>,
+		winner: Option<(T::AccountId, Balance)>,
+	) -> DispatchResult {
+		match winner {
+			Some((bidder, bid_price)) => {
+				<T as Config>::Currency::unreserve(auction_item.currency_id, &bidder, bid_price);
+				Self::settle(
+					&Self::account_id(),
+					&auction_item.seller,
+					&bidder,
+					auction_item.token,
+					auction_item.currency_id,
+					bid_price,
+				)
+			}
+			None => {
+				module_nft::Pallet::<T>::do_transfer(&Self::account_id(), &auction_item.seller, auction_item.token)?;
+				Self::deposit_event(Event::AuctionAborted { auction_id });
+				Ok(())
+			}
+		}
+	}
+}
+
+impl<T: Config> AuctionHandler<T::AccountId, Balance, BlockNumberFor<T>, AuctionId> for Pallet<T> {
+	fn on_new_bid(
+		now: BlockNumberFor<T>,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> OnNewBidResult<BlockNumberFor<T>> {
+		match Self::auction_bid_handler(now, id, new_bid, last_bid) {
+			Ok(new_end) => OnNewBidResult {
+				accept_bid: true,
+				auction_end_change: new_end.map_or(Change::NoChange, |end| Change::NewValue(Some(end))),
+			},
+			Err(_) => OnNewBidResult {
+				accept_bid: false,
+				auction_end_change: Change::NoChange,
+			},
+		}
+	}
+
+	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
+		if let Some(auction_item) = Auctions::<T>::take(id) {
+			if let Err(e) = Self::auction_end_handler(id, auction_item.clone(), winner.clone()) {
+				log::warn!(
+					target: "nft-marketplace",
+					"auction_end_handler: failed to settle auction {:?}: {:?}. \
+					Return the token to the seller and the bid to the bidder",
+					id, e
+				);
+
+				// the failed settlement is reverted, abort the auction instead so that neither
+				// the token nor the bid is stuck
+				if let Some((bidder, bid_price)) = winner {
+					<T as Config>::Currency::unreserve(auction_item.currency_id, &bidder, bid_price);
+				}
+				if let Err(e) =
+					module_nft::Pallet::<T>::do_transfer(&Self::account_id(), &auction_item.seller, auction_item.token)
+				{
+					log::warn!(
+						target: "nft-marketplace",
+						"on_auction_ended: failed to return the token of auction {:?}: {:?}. \
+						This is unexpected but should be safe",
+						id, e
+					);
+				}
+				Self::deposit_event(Event::AuctionAborted { auction_id: id });
+			}
+		}
+	}
+}
+
+/// Routes the `orml_auction` callbacks of marketplace auctions to this module, and the
+/// ones of all other auctions to `Fallback`.
+pub struct AuctionHandlerRouter<T, Fallback>(PhantomData<(T, Fallback)>);
+
+impl<T, Fallback> AuctionHandler<T::AccountId, Balance, BlockNumberFor<T>, AuctionId>
+	for AuctionHandlerRouter<T, Fallback>
+where
+	T: Config,
+	Fallback: AuctionHandler<T::AccountId, Balance, BlockNumberFor<T>, AuctionId>,
+{
+	fn on_new_bid(
+		now: BlockNumberFor<T>,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> OnNewBidResult<BlockNumberFor<T>> {
+		if Auctions::<T>::contains_key(id) {
+			Pallet::<T>::on_new_bid(now, id, new_bid, last_bid)
+		} else {
+			Fallback::on_new_bid(now, id, new_bid, last_bid)
+		}
+	}
+
+	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
+		if Auctions::<T>::contains_key(id) {
+			Pallet::<T>::on_auction_ended(id, winner)
+		} else {
+			Fallback::on_auction_ended(id, winner)
+		}
+	}
+}
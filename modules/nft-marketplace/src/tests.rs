@@ -0,0 +1,374 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the nft marketplace module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::OnFinalize};
+use mock::{RuntimeEvent, *};
+use primitives::nft::{ClassProperty, Properties};
+
+const CLASS_ID: u32 = 0;
+const TOKEN_ID: u64 = 0;
+const TOKEN: (u32, u64) = (CLASS_ID, TOKEN_ID);
+
+fn class_account() -> AccountId {
+	<Runtime as module_nft::Config>::PalletId::get().into_sub_account_truncating(CLASS_ID)
+}
+
+fn owner_of(token: (u32, u64)) -> Option<AccountId> {
+	<module_nft::Pallet<Runtime> as Inspect<AccountId>>::owner(&token.0, &token.1)
+}
+
+/// Create a transferable class with 5% royalty and mint a token to ALICE.
+fn setup_token() {
+	assert_ok!(NFTModule::create_class(
+		RuntimeOrigin::signed(ALICE),
+		vec![1],
+		Properties(ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::ClassPropertiesMutable),
+		Default::default(),
+	));
+	assert_ok!(NFTModule::update_class_royalty(
+		RuntimeOrigin::signed(class_account()),
+		CLASS_ID,
+		500
+	));
+	assert_ok!(Balances::transfer_allow_death(
+		RuntimeOrigin::signed(ALICE),
+		class_account(),
+		1_000
+	));
+	assert_ok!(NFTModule::mint(
+		RuntimeOrigin::signed(class_account()),
+		ALICE,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		1
+	));
+	assert_eq!(owner_of(TOKEN), Some(ALICE));
+}
+
+#[test]
+fn royalty_amount_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(NFTMarketplace::royalty_amount(CLASS_ID, 1_000), 0);
+		setup_token();
+		assert_eq!(NFTMarketplace::royalty_amount(CLASS_ID, 1_000), 50);
+		assert_eq!(NFTMarketplace::royalty_amount(CLASS_ID, 19), 0);
+	});
+}
+
+#[test]
+fn list_and_buy_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_noop!(
+			NFTMarketplace::list(RuntimeOrigin::signed(ALICE), TOKEN, AUSD, 0),
+			Error::<Runtime>::InvalidPrice
+		);
+		assert_noop!(
+			NFTMarketplace::list(RuntimeOrigin::signed(BOB), TOKEN, AUSD, 1_000),
+			orml_nft::Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTMarketplace::list(RuntimeOrigin::signed(ALICE), TOKEN, AUSD, 1_000));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::Listed {
+			seller: ALICE,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id: AUSD,
+			price: 1_000,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(NFTMarketplace::account_id()));
+		assert_eq!(
+			NFTMarketplace::listings(CLASS_ID, TOKEN_ID),
+			Some(Listing {
+				seller: ALICE,
+				currency_id: AUSD,
+				price: 1_000,
+			})
+		);
+
+		assert_noop!(
+			NFTMarketplace::buy(RuntimeOrigin::signed(BOB), TOKEN, 999),
+			Error::<Runtime>::PriceTooHigh
+		);
+		assert_ok!(NFTMarketplace::buy(RuntimeOrigin::signed(BOB), TOKEN, 1_000));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::Sold {
+			seller: ALICE,
+			buyer: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id: AUSD,
+			price: 1_000,
+			royalty: 50,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(BOB));
+		assert_eq!(NFTMarketplace::listings(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 9_000);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 950);
+		assert_eq!(Currencies::free_balance(AUSD, &class_account()), 50);
+
+		assert_noop!(
+			NFTMarketplace::buy(RuntimeOrigin::signed(CAROL), TOKEN, 1_000),
+			Error::<Runtime>::ListingNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_listing_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTMarketplace::list(RuntimeOrigin::signed(ALICE), TOKEN, AUSD, 1_000));
+
+		assert_noop!(
+			NFTMarketplace::cancel_listing(RuntimeOrigin::signed(BOB), TOKEN),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTMarketplace::cancel_listing(RuntimeOrigin::signed(ALICE), TOKEN));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::ListingCancelled {
+			seller: ALICE,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(ALICE));
+		assert_noop!(
+			NFTMarketplace::cancel_listing(RuntimeOrigin::signed(ALICE), TOKEN),
+			Error::<Runtime>::ListingNotFound
+		);
+	});
+}
+
+#[test]
+fn offer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NFTMarketplace::make_offer(RuntimeOrigin::signed(BOB), TOKEN, AUSD, 500),
+			Error::<Runtime>::TokenNotFound
+		);
+		setup_token();
+
+		assert_ok!(NFTMarketplace::make_offer(RuntimeOrigin::signed(BOB), TOKEN, AUSD, 400));
+		assert_ok!(NFTMarketplace::make_offer(RuntimeOrigin::signed(BOB), TOKEN, AUSD, 500));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::OfferMade {
+			offerer: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id: AUSD,
+			amount: 500,
+		}));
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 500);
+
+		assert_ok!(NFTMarketplace::make_offer(
+			RuntimeOrigin::signed(CAROL),
+			TOKEN,
+			AUSD,
+			600
+		));
+		assert_ok!(NFTMarketplace::cancel_offer(RuntimeOrigin::signed(CAROL), TOKEN));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::OfferCancelled {
+			offerer: CAROL,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(Currencies::reserved_balance(AUSD, &CAROL), 0);
+		assert_noop!(
+			NFTMarketplace::accept_offer(RuntimeOrigin::signed(ALICE), TOKEN, CAROL),
+			Error::<Runtime>::OfferNotFound
+		);
+
+		assert_noop!(
+			NFTMarketplace::accept_offer(RuntimeOrigin::signed(CAROL), TOKEN, BOB),
+			orml_nft::Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTMarketplace::accept_offer(RuntimeOrigin::signed(ALICE), TOKEN, BOB));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::Sold {
+			seller: ALICE,
+			buyer: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id: AUSD,
+			price: 500,
+			royalty: 25,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(BOB));
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 9_500);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 475);
+		assert_eq!(Currencies::free_balance(AUSD, &class_account()), 25);
+	});
+}
+
+#[test]
+fn accept_offer_for_listed_token_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTMarketplace::list(RuntimeOrigin::signed(ALICE), TOKEN, AUSD, 1_000));
+		assert_ok!(NFTMarketplace::make_offer(RuntimeOrigin::signed(BOB), TOKEN, AUSD, 800));
+
+		assert_noop!(
+			NFTMarketplace::accept_offer(RuntimeOrigin::signed(CAROL), TOKEN, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTMarketplace::accept_offer(RuntimeOrigin::signed(ALICE), TOKEN, BOB));
+		assert_eq!(owner_of(TOKEN), Some(BOB));
+		assert_eq!(NFTMarketplace::listings(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 760);
+		assert_eq!(Currencies::free_balance(AUSD, &class_account()), 40);
+	});
+}
+
+#[test]
+fn auction_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_noop!(
+			NFTMarketplace::create_auction(RuntimeOrigin::signed(ALICE), TOKEN, AUSD, 100, 0),
+			Error::<Runtime>::InvalidDuration
+		);
+		assert_ok!(NFTMarketplace::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			TOKEN,
+			AUSD,
+			100,
+			10
+		));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::AuctionCreated {
+			auction_id: 0,
+			seller: ALICE,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id: AUSD,
+			min_bid: 100,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(NFTMarketplace::account_id()));
+
+		assert_noop!(
+			AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 99),
+			orml_auction::Error::<Runtime>::BidNotAccepted
+		);
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 100));
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 100);
+		assert_noop!(
+			NFTMarketplace::cancel_auction(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Runtime>::AuctionHasBid
+		);
+
+		// below minimum increment
+		assert_noop!(
+			AuctionModule::bid(RuntimeOrigin::signed(CAROL), 0, 104),
+			orml_auction::Error::<Runtime>::BidNotAccepted
+		);
+		System::set_block_number(9);
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(CAROL), 0, 105));
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 0);
+		assert_eq!(Currencies::reserved_balance(AUSD, &CAROL), 105);
+		// extended by the bid
+		assert_eq!(AuctionModule::auctions(0).unwrap().end, Some(14));
+
+		AuctionModule::on_finalize(14);
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::Sold {
+			seller: ALICE,
+			buyer: CAROL,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			currency_id: AUSD,
+			price: 105,
+			royalty: 5,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(CAROL));
+		assert_eq!(NFTMarketplace::auctions(0), None);
+		assert_eq!(Currencies::reserved_balance(AUSD, &CAROL), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 9_895);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_eq!(Currencies::free_balance(AUSD, &class_account()), 5);
+	});
+}
+
+#[test]
+fn auction_returns_token_and_bid_when_settlement_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTMarketplace::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			TOKEN,
+			AUSD,
+			100,
+			10
+		));
+		assert_ok!(AuctionModule::bid(RuntimeOrigin::signed(BOB), 0, 100));
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 100);
+
+		// BOB can no longer pay the royalty and the price
+		assert_ok!(Currencies::transfer(AUSD, &BOB, &CAROL, 9_900));
+		assert_eq!(Currencies::slash_reserved(AUSD, &BOB, 50), 0);
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 50);
+
+		AuctionModule::on_finalize(11);
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::AuctionAborted {
+			auction_id: 0,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(ALICE));
+		assert_eq!(NFTMarketplace::auctions(0), None);
+		assert_eq!(Currencies::reserved_balance(AUSD, &BOB), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 50);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 0);
+	});
+}
+
+#[test]
+fn auction_without_bid_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_token();
+		assert_ok!(NFTMarketplace::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			TOKEN,
+			AUSD,
+			100,
+			10
+		));
+		assert_noop!(
+			NFTMarketplace::cancel_auction(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTMarketplace::cancel_auction(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::AuctionCancelled {
+			auction_id: 0,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(ALICE));
+		assert_eq!(AuctionModule::auctions(0), None);
+
+		assert_ok!(NFTMarketplace::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			TOKEN,
+			AUSD,
+			100,
+			10
+		));
+		AuctionModule::on_finalize(11);
+		System::assert_last_event(RuntimeEvent::NFTMarketplace(crate::Event::AuctionAborted {
+			auction_id: 1,
+		}));
+		assert_eq!(owner_of(TOKEN), Some(ALICE));
+		assert_eq!(NFTMarketplace::auctions(1), None);
+	});
+}
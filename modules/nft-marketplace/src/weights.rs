@@ -0,0 +1,320 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_nft_marketplace
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-04, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-34-61`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/acala
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=module_nft_marketplace
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./modules/nft-marketplace/src/weights.rs
+// --template=./templates/module-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_nft_marketplace.
+pub trait WeightInfo {
+	fn list() -> Weight;
+	fn cancel_listing() -> Weight;
+	fn buy() -> Weight;
+	fn make_offer() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn create_auction() -> Weight;
+	fn cancel_auction() -> Weight;
+}
+
+/// Weights for module_nft_marketplace using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Tokens` (r:1 w:1)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:2)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `NFTMarketplace::Listings` (r:0 w:1)
+	// Proof: `NFTMarketplace::Listings` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	fn list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5004`
+		//  Estimated: `3633`
+		// Minimum execution time: 109_824 nanoseconds.
+		Weight::from_parts(112_641_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `NFTMarketplace::Listings` (r:1 w:1)
+	// Proof: `NFTMarketplace::Listings` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Tokens` (r:1 w:1)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:2)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_listing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5168`
+		//  Estimated: `3633`
+		// Minimum execution time: 107_128 nanoseconds.
+		Weight::from_parts(109_875_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: `NFTMarketplace::Listings` (r:1 w:1)
+	// Proof: `NFTMarketplace::Listings` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Tokens` (r:1 w:1)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:2)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5797`
+		//  Estimated: `3633`
+		// Minimum execution time: 164_084 nanoseconds.
+		Weight::from_parts(168_292_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	// Storage: `OrmlNFT::Tokens` (r:1 w:0)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `NFTMarketplace::Offers` (r:1 w:1)
+	// Proof: `NFTMarketplace::Offers` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn make_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3202`
+		//  Estimated: `3612`
+		// Minimum execution time: 57_262 nanoseconds.
+		Weight::from_parts(58_731_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `NFTMarketplace::Offers` (r:1 w:1)
+	// Proof: `NFTMarketplace::Offers` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:1)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3314`
+		//  Estimated: `3612`
+		// Minimum execution time: 44_764 nanoseconds.
+		Weight::from_parts(45_912_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `NFTMarketplace::Offers` (r:1 w:1)
+	// Proof: `NFTMarketplace::Offers` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	// Storage: `NFTMarketplace::Listings` (r:1 w:1)
+	// Proof: `NFTMarketplace::Listings` (`max_values`: None, `max_size`: Some(119), added: 2594, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Tokens` (r:1 w:1)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:2)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6011`
+		//  Estimated: `3633`
+		// Minimum execution time: 176_870 nanoseconds.
+		Weight::from_parts(181_406_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Tokens` (r:1 w:1)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:2)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Auction::AuctionsIndex` (r:1 w:1)
+	// Proof: `Auction::AuctionsIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionEndTime` (r:0 w:1)
+	// Proof: `Auction::AuctionEndTime` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	// Storage: `Auction::Auctions` (r:0 w:1)
+	// Proof: `Auction::Auctions` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	// Storage: `NFTMarketplace::Auctions` (r:0 w:1)
+	// Proof: `NFTMarketplace::Auctions` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5004`
+		//  Estimated: `3633`
+		// Minimum execution time: 118_031 nanoseconds.
+		Weight::from_parts(121_058_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	// Storage: `NFTMarketplace::Auctions` (r:1 w:1)
+	// Proof: `NFTMarketplace::Auctions` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	// Storage: `Auction::Auctions` (r:1 w:1)
+	// Proof: `Auction::Auctions` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionEndTime` (r:0 w:1)
+	// Proof: `Auction::AuctionEndTime` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::Classes` (r:1 w:0)
+	// Proof: `OrmlNFT::Classes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `OrmlNFT::Tokens` (r:1 w:1)
+	// Proof: `OrmlNFT::Tokens` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Balances::Reserves` (r:2 w:2)
+	// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(168), added: 2643, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `EvmAccounts::EvmAddresses` (r:1 w:0)
+	// Proof: `EvmAccounts::EvmAddresses` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	// Storage: `OrmlNFT::TokensByOwner` (r:0 w:2)
+	// Proof: `OrmlNFT::TokensByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5363`
+		//  Estimated: `3633`
+		// Minimum execution time: 115_434 nanoseconds.
+		Weight::from_parts(118_394_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5004`
+		//  Estimated: `3633`
+		// Minimum execution time: 109_824 nanoseconds.
+		Weight::from_parts(112_641_000, 3633)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	fn cancel_listing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5168`
+		//  Estimated: `3633`
+		// Minimum execution time: 107_128 nanoseconds.
+		Weight::from_parts(109_875_000, 3633)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	fn buy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5797`
+		//  Estimated: `3633`
+		// Minimum execution time: 164_084 nanoseconds.
+		Weight::from_parts(168_292_000, 3633)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(12))
+	}
+	fn make_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3202`
+		//  Estimated: `3612`
+		// Minimum execution time: 57_262 nanoseconds.
+		Weight::from_parts(58_731_000, 3612)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3314`
+		//  Estimated: `3612`
+		// Minimum execution time: 44_764 nanoseconds.
+		Weight::from_parts(45_912_000, 3612)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6011`
+		//  Estimated: `3633`
+		// Minimum execution time: 176_870 nanoseconds.
+		Weight::from_parts(181_406_000, 3633)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(13))
+	}
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5004`
+		//  Estimated: `3633`
+		// Minimum execution time: 118_031 nanoseconds.
+		Weight::from_parts(121_058_000, 3633)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5363`
+		//  Estimated: `3633`
+		// Minimum execution time: 115_434 nanoseconds.
+		Weight::from_parts(118_394_000, 3633)
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+}
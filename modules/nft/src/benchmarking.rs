@@ -148,6 +148,36 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	update_class_royalty {
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), 500)
+
+	// set NFT token attributes by class owner
+	set_token_attributes {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::update_class_properties(RawOrigin::Signed(module_account.clone()).into(), 0u32.into(), Properties(ClassProperty::AttributesMutableByClassOwner.into()))?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], Default::default(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), test_attr())
+
+	// remove NFT token attribute by class owner
+	remove_token_attribute {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let to_lookup = T::Lookup::unlookup(to);
+
+		let module_account = create_token_class::<T>(caller)?;
+
+		crate::Pallet::<T>::update_class_properties(RawOrigin::Signed(module_account.clone()).into(), 0u32.into(), Properties(ClassProperty::AttributesMutableByClassOwner.into()))?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account.clone()).into(), to_lookup, 0u32.into(), vec![1], test_attr(), 1)?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()), vec![0])
 }
 
 #[cfg(test)]
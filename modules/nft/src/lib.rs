@@ -48,6 +48,7 @@ use sp_runtime::{
 use sp_std::prelude::*;
 
 pub mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -63,6 +64,8 @@ pub struct ClassData<Balance> {
 	pub properties: Properties,
 	/// Class attributes
 	pub attributes: Attributes,
+	/// Royalty paid to the class owner on every marketplace sale, in basis points
+	pub royalty: u16,
 }
 
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, Serialize, Deserialize)]
@@ -84,6 +87,9 @@ pub mod module {
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::Nft;
 
+	/// The maximum royalty in basis points, i.e. 100%
+	pub const MAX_ROYALTY: u16 = 10_000;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config
@@ -148,6 +154,10 @@ pub mod module {
 		AttributesTooLarge,
 		/// The given token ID is not correct
 		IncorrectTokenId,
+		/// Royalty exceeds `MAX_ROYALTY`
+		InvalidRoyalty,
+		/// The token attribute does not exist
+		AttributeNotFound,
 	}
 
 	#[pallet::event]
@@ -190,9 +200,28 @@ pub mod module {
 			owner: T::AccountId,
 			class_id: ClassIdOf<T>,
 		},
+		/// Updated NFT class royalty.
+		UpdatedClassRoyalty { class_id: ClassIdOf<T>, royalty: u16 },
+		/// Updated NFT token attributes.
+		UpdatedTokenAttributes {
+			who: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Removed NFT token attribute.
+		RemovedTokenAttribute {
+			who: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			key: Vec<u8>,
+		},
 	}
 
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -241,6 +270,7 @@ pub mod module {
 				deposit,
 				properties,
 				attributes,
+				royalty: Zero::zero(),
 			};
 			orml_nft::Pallet::<T>::create_class(&owner, metadata, data)?;
 
@@ -296,6 +326,7 @@ pub mod module {
 		#[pallet::weight(<T as Config>::WeightInfo::burn())]
 		pub fn burn(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_burnable(token.0)?;
 			Self::do_burn(who, token, None)
 		}
 
@@ -311,6 +342,7 @@ pub mod module {
 			remark: Vec<u8>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			Self::ensure_burnable(token.0)?;
 			Self::do_burn(who, token, Some(remark))
 		}
 
@@ -383,6 +415,71 @@ pub mod module {
 				Ok(())
 			})
 		}
+
+		/// Update NFT class royalty, which is paid to the class owner on every
+		/// marketplace sale. The class properties must contain `ClassPropertiesMutable`,
+		/// remove it with `update_class_properties` to make the royalty final.
+		///
+		/// - `class_id`: The class ID to update
+		/// - `royalty`: The new royalty in basis points, at most `MAX_ROYALTY`
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_class_royalty())]
+		pub fn update_class_royalty(origin: OriginFor<T>, class_id: ClassIdOf<T>, royalty: u16) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(royalty <= MAX_ROYALTY, Error::<T>::InvalidRoyalty);
+
+			orml_nft::Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+				let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+				ensure!(who == class_info.owner, Error::<T>::NoPermission);
+				ensure!(
+					class_info
+						.data
+						.properties
+						.0
+						.contains(ClassProperty::ClassPropertiesMutable),
+					Error::<T>::Immutable
+				);
+
+				class_info.data.royalty = royalty;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::UpdatedClassRoyalty { class_id, royalty });
+			Ok(())
+		}
+
+		/// Set NFT token attributes, overwriting the existing values of the same keys.
+		/// The class properties must contain `AttributesMutableByClassOwner` for the
+		/// class owner, or `AttributesMutableByTokenOwner` for the token owner.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `attributes`: The attributes to set
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_attributes())]
+		pub fn set_token_attributes(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			attributes: Attributes,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_set_token_attributes(&who, token, attributes)
+		}
+
+		/// Remove a NFT token attribute. Requires the same permission as
+		/// `set_token_attributes`.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `key`: The key of the attribute to remove
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_token_attribute())]
+		pub fn remove_token_attribute(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_remove_token_attribute(&who, token, key)
+		}
 	}
 }
 
@@ -488,14 +585,16 @@ impl<T: Config> Pallet<T> {
 		Ok(token_ids)
 	}
 
-	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
-		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
-		let data = class_info.data;
+	fn ensure_burnable(class_id: ClassIdOf<T>) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
 		ensure!(
-			data.properties.0.contains(ClassProperty::Burnable),
+			class_info.data.properties.0.contains(ClassProperty::Burnable),
 			Error::<T>::NonBurnable
 		);
+		Ok(())
+	}
 
+	fn do_burn(who: T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), remark: Option<Vec<u8>>) -> DispatchResult {
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
 
@@ -522,6 +621,119 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	#[require_transactional]
+	pub fn do_set_token_attributes(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		attributes: Attributes,
+	) -> DispatchResult {
+		Self::mutate_token_attributes(who, token, |token_attributes| {
+			token_attributes.extend(attributes);
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::UpdatedTokenAttributes {
+			who: who.clone(),
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	#[require_transactional]
+	pub fn do_remove_token_attribute(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		key: Vec<u8>,
+	) -> DispatchResult {
+		Self::mutate_token_attributes(who, token, |token_attributes| {
+			token_attributes
+				.remove(&key)
+				.map(|_| ())
+				.ok_or_else(|| Error::<T>::AttributeNotFound.into())
+		})?;
+
+		Self::deposit_event(Event::RemovedTokenAttribute {
+			who: who.clone(),
+			class_id: token.0,
+			token_id: token.1,
+			key,
+		});
+		Ok(())
+	}
+
+	/// Mutate the attributes of `token` on behalf of `who` and re-compute the token deposit.
+	/// An increase is paid by `who` and reserved on the token owner, a decrease is
+	/// unreserved to the token owner.
+	fn mutate_token_attributes(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		f: impl FnOnce(&mut Attributes) -> DispatchResult,
+	) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let properties = class_info.data.properties.0;
+		ensure!(
+			properties.intersects(
+				ClassProperty::AttributesMutableByClassOwner | ClassProperty::AttributesMutableByTokenOwner
+			),
+			Error::<T>::Immutable
+		);
+
+		orml_nft::Tokens::<T>::try_mutate(token.0, token.1, |maybe_token_info| -> DispatchResult {
+			let token_info = maybe_token_info.as_mut().ok_or(Error::<T>::TokenIdNotFound)?;
+			let is_permitted = (*who == class_info.owner
+				&& properties.contains(ClassProperty::AttributesMutableByClassOwner))
+				|| (*who == token_info.owner && properties.contains(ClassProperty::AttributesMutableByTokenOwner));
+			ensure!(is_permitted, Error::<T>::NoPermission);
+
+			f(&mut token_info.data.attributes)?;
+
+			let data_deposit = Self::data_deposit(&token_info.metadata, &token_info.data.attributes)?;
+			let deposit = T::CreateTokenDeposit::get().saturating_add(data_deposit);
+			let old_deposit = token_info.data.deposit;
+			if deposit > old_deposit {
+				let increase = deposit.saturating_sub(old_deposit);
+				if *who != token_info.owner {
+					// ensure the token owner keeps ED after the reserve, same as `do_mint`
+					let total_transfer_amount = if <T as module::Config>::Currency::free_balance(&token_info.owner)
+						< <T as module::Config>::Currency::minimum_balance()
+					{
+						increase.saturating_add(<T as module::Config>::Currency::minimum_balance())
+					} else {
+						increase
+					};
+					<T as module::Config>::Currency::transfer(
+						who,
+						&token_info.owner,
+						total_transfer_amount,
+						KeepAlive,
+					)?;
+				}
+				<T as module::Config>::Currency::reserve_named(&RESERVE_ID, &token_info.owner, increase)?;
+			} else {
+				<T as module::Config>::Currency::unreserve_named(
+					&RESERVE_ID,
+					&token_info.owner,
+					old_deposit.saturating_sub(deposit),
+				);
+			}
+			token_info.data.deposit = deposit;
+
+			Ok(())
+		})
+	}
+
+	/// Returns the value of the attribute `key` of `token`.
+	pub fn token_attribute(token: (ClassIdOf<T>, TokenIdOf<T>), key: &[u8]) -> Option<Vec<u8>> {
+		orml_nft::Pallet::<T>::tokens(token.0, token.1)
+			.and_then(|token_info| token_info.data.attributes.get(key).cloned())
+	}
+
+	/// Returns the account receiving royalties of `class_id` and the royalty in basis points.
+	pub fn royalty_info(class_id: ClassIdOf<T>) -> Option<(T::AccountId, u16)> {
+		orml_nft::Pallet::<T>::classes(class_id).map(|class_info| (class_info.owner, class_info.data.royalty))
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -584,7 +796,8 @@ impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
 		Ok(())
 	}
 
-	/// Burn some asset `instance` of `class`.
+	/// Burn some asset `instance` of `class`. The `Burnable` property only restricts the burn
+	/// calls of the holder.
 	fn burn(
 		class: &Self::CollectionId,
 		instance: &Self::ItemId,
@@ -0,0 +1,70 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{module::STORAGE_VERSION, BalanceOf, ClassData, Config, Pallet};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use orml_nft::ClassInfo;
+use primitives::nft::{Attributes, Properties};
+use sp_std::marker::PhantomData;
+
+/// `ClassData` before the royalty was introduced.
+#[derive(Decode)]
+struct OldClassData<Balance> {
+	deposit: Balance,
+	properties: Properties,
+	attributes: Attributes,
+}
+
+type OldClassInfoOf<T> = ClassInfo<
+	<T as orml_nft::Config>::TokenId,
+	<T as frame_system::Config>::AccountId,
+	OldClassData<BalanceOf<T>>,
+	BoundedVec<u8, <T as orml_nft::Config>::MaxClassMetadata>,
+>;
+
+/// Add the `royalty` field, defaulting to zero, to the `ClassData` of every existing class.
+pub struct MigrateClassDataRoyalty<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateClassDataRoyalty<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut count: u64 = 0;
+		orml_nft::Classes::<T>::translate::<OldClassInfoOf<T>, _>(|_, old| {
+			count = count.saturating_add(1);
+			Some(ClassInfo {
+				metadata: old.metadata,
+				total_issuance: old.total_issuance,
+				owner: old.owner,
+				data: ClassData {
+					deposit: old.data.deposit,
+					properties: old.data.properties,
+					attributes: old.data.attributes,
+					royalty: 0,
+				},
+			})
+		});
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+}
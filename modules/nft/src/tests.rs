@@ -79,6 +79,7 @@ fn create_class_should_work() {
 				deposit: cls_deposit,
 				properties: Default::default(),
 				attributes: test_attr(1),
+				royalty: 0,
 			}
 		)
 	});
@@ -510,6 +511,15 @@ fn burn_should_fail() {
 			NFTModule::burn(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID)),
 			Error::<Runtime>::NonBurnable
 		);
+		assert_noop!(
+			NFTModule::burn_with_remark(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID), vec![1]),
+			Error::<Runtime>::NonBurnable
+		);
+
+		// burning by pallets is not restricted
+		assert_ok!(<NFTModule as Mutate<AccountId>>::burn(&CLASS_ID, &TOKEN_ID, None));
+		assert!(<NFTModule as Inspect<AccountId>>::owner(&CLASS_ID, &TOKEN_ID).is_none());
+		assert_eq!(reserved_balance(&BOB), 0);
 	});
 }
 
@@ -736,3 +746,185 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+#[test]
+fn update_class_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			vec![1],
+			Properties(ClassProperty::ClassPropertiesMutable.into()),
+			Default::default(),
+		));
+		assert_eq!(NFTModule::royalty_info(CLASS_ID), Some((class_id_account(), 0)));
+
+		assert_noop!(
+			NFTModule::update_class_royalty(RuntimeOrigin::signed(ALICE), CLASS_ID, 500),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::update_class_royalty(RuntimeOrigin::signed(class_id_account()), CLASS_ID, MAX_ROYALTY + 1),
+			Error::<Runtime>::InvalidRoyalty
+		);
+		assert_noop!(
+			NFTModule::update_class_royalty(RuntimeOrigin::signed(class_id_account()), CLASS_ID_NOT_EXIST, 500),
+			Error::<Runtime>::ClassIdNotFound
+		);
+
+		assert_ok!(NFTModule::update_class_royalty(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			500
+		));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::UpdatedClassRoyalty {
+			class_id: CLASS_ID,
+			royalty: 500,
+		}));
+		assert_eq!(NFTModule::royalty_info(CLASS_ID), Some((class_id_account(), 500)));
+		assert_eq!(NFTModule::royalty_info(CLASS_ID_NOT_EXIST), None);
+
+		// the royalty is final once the class properties are immutable
+		assert_ok!(NFTModule::update_class_properties(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			Default::default()
+		));
+		assert_noop!(
+			NFTModule::update_class_royalty(RuntimeOrigin::signed(class_id_account()), CLASS_ID, MAX_ROYALTY),
+			Error::<Runtime>::Immutable
+		);
+		assert_eq!(NFTModule::royalty_info(CLASS_ID), Some((class_id_account(), 500)));
+	});
+}
+
+#[test]
+fn set_and_remove_token_attributes_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+
+		assert_ok!(NFTModule::create_class(
+			RuntimeOrigin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::ClassPropertiesMutable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			CREATE_TOKEN_DEPOSIT + ((metadata.len() as u128 + TEST_ATTR_LEN) * DATA_DEPOSIT_PER_BYTE) + 100
+		));
+		assert_ok!(NFTModule::mint(
+			RuntimeOrigin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata.clone(),
+			test_attr(1),
+			1
+		));
+		assert_eq!(reserved_balance(&BOB), CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * 8);
+		assert_ok!(Balances::deposit_into_existing(&BOB, 100));
+
+		let mut attr: Attributes = BTreeMap::new();
+		attr.insert(vec![3], vec![4, 5]);
+
+		assert_noop!(
+			NFTModule::set_token_attributes(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID), attr.clone()),
+			Error::<Runtime>::Immutable
+		);
+
+		assert_ok!(NFTModule::update_class_properties(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			Properties(ClassProperty::ClassPropertiesMutable | ClassProperty::AttributesMutableByTokenOwner)
+		));
+
+		assert_noop!(
+			NFTModule::set_token_attributes(
+				RuntimeOrigin::signed(class_id_account()),
+				(CLASS_ID, TOKEN_ID),
+				attr.clone()
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_token_attributes(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID_NOT_EXIST), attr.clone()),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::set_token_attributes(
+			RuntimeOrigin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			attr
+		));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::UpdatedTokenAttributes {
+			who: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		let mut expected_attr = test_attr(1);
+		expected_attr.insert(vec![3], vec![4, 5]);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().data,
+			TokenData {
+				deposit: CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * 11,
+				attributes: expected_attr,
+			}
+		);
+		assert_eq!(
+			reserved_balance(&BOB),
+			CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * 11
+		);
+
+		let mut too_large_attr: Attributes = BTreeMap::new();
+		too_large_attr.insert(vec![4], vec![1]);
+		assert_noop!(
+			NFTModule::set_token_attributes(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID), too_large_attr),
+			Error::<Runtime>::AttributesTooLarge
+		);
+
+		assert_ok!(NFTModule::remove_token_attribute(
+			RuntimeOrigin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			vec![1, 11]
+		));
+		System::assert_last_event(RuntimeEvent::NFTModule(crate::Event::RemovedTokenAttribute {
+			who: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			key: vec![1, 11],
+		}));
+		assert_eq!(reserved_balance(&BOB), CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * 6);
+
+		assert_noop!(
+			NFTModule::remove_token_attribute(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID), vec![1, 11]),
+			Error::<Runtime>::AttributeNotFound
+		);
+
+		// class owner pays for the increased deposit of the token owner
+		assert_ok!(NFTModule::update_class_properties(
+			RuntimeOrigin::signed(class_id_account()),
+			CLASS_ID,
+			Properties(ClassProperty::AttributesMutableByClassOwner.into())
+		));
+		assert_noop!(
+			NFTModule::remove_token_attribute(RuntimeOrigin::signed(BOB), (CLASS_ID, TOKEN_ID), vec![3]),
+			Error::<Runtime>::NoPermission
+		);
+
+		let class_free = free_balance(&class_id_account());
+		let bob_free = free_balance(&BOB);
+		assert_ok!(NFTModule::set_token_attributes(
+			RuntimeOrigin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID),
+			test_attr(1)
+		));
+		assert_eq!(
+			free_balance(&class_id_account()),
+			class_free - DATA_DEPOSIT_PER_BYTE * 5
+		);
+		assert_eq!(free_balance(&BOB), bob_free);
+		assert_eq!(
+			reserved_balance(&BOB),
+			CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE * 11
+		);
+	});
+}
@@ -0,0 +1,348 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Peg Stability Module
+//!
+//! ## Overview
+//!
+//! The peg stability module lets anyone swap whitelisted external stablecoins with the stable
+//! currency of the chain at a fixed rate, adjusted only for decimals and the mint/redeem fees set
+//! by governance. It supersedes the 1:1 swap of `module_honzon_bridge`.
+//!
+//! Stable currency is issued through the CDP treasury against the reserves deposited to this
+//! module, up to the debt ceiling of each external stablecoin, rather than paid from a
+//! pre-funded account. The reserves and the stable currency issued against them are reported to
+//! the CDP treasury, and the fees go to its surplus pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use module_support::{CDPTreasury, Erc20InfoMapping, Rate};
+use orml_traits::{Change, MultiCurrency};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Saturating, Zero},
+	ArithmeticError, FixedPointNumber,
+};
+
+pub mod migrations;
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+// typedef to help polkadot.js disambiguate Change with different generic
+// parameters.
+type ChangeRate = Change<Rate>;
+type ChangeBalance = Change<Balance>;
+
+/// The parameters of an external stablecoin accepted by the peg stability module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PegAssetParams {
+	/// The fee rate charged when minting stable currency with the external stablecoin.
+	pub mint_fee: Rate,
+	/// The fee rate charged when redeeming the external stablecoin with stable currency.
+	pub redeem_fee: Rate,
+	/// The maximum amount of stable currency that can be issued against the external
+	/// stablecoin.
+	pub debt_ceiling: Balance,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Multi-currency support for asset management.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Stablecoin currency id.
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// CDP treasury to issue and burn stable currency, and to keep the reserve accounting.
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// Mapping to get the decimals of currencies.
+		type Erc20InfoMapping: Erc20InfoMapping;
+
+		/// The origin which may update the parameters of external stablecoins.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The peg stability module's id, keeps the reserves of external stablecoins.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The currency is not an accepted external stablecoin.
+		PegAssetNotEnabled,
+		/// The currency can't be used as an external stablecoin.
+		InvalidPegAsset,
+		/// The fee rate is greater than 100%.
+		InvalidFeeRate,
+		/// The stable currency issued against the external stablecoin would exceed the debt
+		/// ceiling.
+		ExceedDebtCeiling,
+		/// Not enough reserve of the external stablecoin to redeem.
+		InsufficientReserve,
+		/// The amount is too small to swap.
+		AmountTooSmall,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The parameters of the external stablecoin updated.
+		PegAssetParamsUpdated {
+			currency_id: CurrencyId,
+			mint_fee: Rate,
+			redeem_fee: Rate,
+			debt_ceiling: Balance,
+		},
+		/// Stable currency minted by depositing the external stablecoin.
+		Minted {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			reserve_amount: Balance,
+			stable_amount: Balance,
+			fee: Balance,
+		},
+		/// The external stablecoin redeemed by burning stable currency.
+		Redeemed {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			stable_amount: Balance,
+			reserve_amount: Balance,
+			fee: Balance,
+		},
+	}
+
+	/// The parameters of accepted external stablecoins.
+	///
+	/// PegAssets: map CurrencyId => Option<PegAssetParams>
+	#[pallet::storage]
+	#[pallet::getter(fn peg_assets)]
+	pub type PegAssets<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, PegAssetParams, OptionQuery>;
+
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Update the parameters of an external stablecoin, accepting it if it's not yet.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: the external stablecoin.
+		/// - `mint_fee`: mint fee rate, `NoChange` means do not update.
+		/// - `redeem_fee`: redeem fee rate, `NoChange` means do not update.
+		/// - `debt_ceiling`: the maximum stable currency issued against the external stablecoin,
+		///   `NoChange` means do not update.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_peg_asset_params())]
+		pub fn set_peg_asset_params(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			mint_fee: ChangeRate,
+			redeem_fee: ChangeRate,
+			debt_ceiling: ChangeBalance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				currency_id != T::GetStableCurrencyId::get()
+					&& T::Erc20InfoMapping::decimals(currency_id).is_some()
+					&& T::Erc20InfoMapping::decimals(T::GetStableCurrencyId::get()).is_some(),
+				Error::<T>::InvalidPegAsset
+			);
+
+			let mut params = Self::peg_assets(currency_id).unwrap_or_default();
+			if let Change::NewValue(rate) = mint_fee {
+				ensure!(rate <= Rate::one(), Error::<T>::InvalidFeeRate);
+				params.mint_fee = rate;
+			}
+			if let Change::NewValue(rate) = redeem_fee {
+				ensure!(rate <= Rate::one(), Error::<T>::InvalidFeeRate);
+				params.redeem_fee = rate;
+			}
+			if let Change::NewValue(val) = debt_ceiling {
+				params.debt_ceiling = val;
+			}
+			PegAssets::<T>::insert(currency_id, params);
+
+			Self::deposit_event(Event::PegAssetParamsUpdated {
+				currency_id,
+				mint_fee: params.mint_fee,
+				redeem_fee: params.redeem_fee,
+				debt_ceiling: params.debt_ceiling,
+			});
+			Ok(())
+		}
+
+		/// Deposit `amount` of the external stablecoin as reserve, and mint the equivalent stable
+		/// currency minus the mint fee.
+		///
+		/// - `currency_id`: the external stablecoin.
+		/// - `amount`: the amount of the external stablecoin to deposit.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_mint(&who, currency_id, amount)?;
+			Ok(())
+		}
+
+		/// Burn `amount` of stable currency minus the redeem fee, and withdraw the equivalent
+		/// external stablecoin from the reserve.
+		///
+		/// - `currency_id`: the external stablecoin.
+		/// - `amount`: the amount of stable currency to pay, including the redeem fee.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		pub fn redeem(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem(&who, currency_id, amount)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get account of the peg stability module.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Mint stable currency by depositing `amount` of `currency_id`, returns the stable currency
+	/// received by `who`.
+	#[transactional]
+	pub fn do_mint(who: &T::AccountId, currency_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError> {
+		let params = Self::peg_assets(currency_id).ok_or(Error::<T>::PegAssetNotEnabled)?;
+		let stable_amount = Self::reserve_to_stable(currency_id, amount)?;
+		ensure!(!stable_amount.is_zero(), Error::<T>::AmountTooSmall);
+
+		let (_, issued) = T::CDPTreasury::get_peg_reserve(currency_id);
+		ensure!(
+			issued.saturating_add(stable_amount) <= params.debt_ceiling,
+			Error::<T>::ExceedDebtCeiling
+		);
+
+		let fee = params.mint_fee.saturating_mul_int(stable_amount);
+		let receive_amount = stable_amount.saturating_sub(fee);
+
+		T::Currency::transfer(currency_id, who, &Self::account_id(), amount)?;
+		T::CDPTreasury::on_peg_reserve_deposited(currency_id, amount, stable_amount)?;
+		T::CDPTreasury::issue_debit(who, receive_amount, true)?;
+		if !fee.is_zero() {
+			T::CDPTreasury::on_system_surplus(fee)?;
+		}
+
+		Self::deposit_event(Event::Minted {
+			who: who.clone(),
+			currency_id,
+			reserve_amount: amount,
+			stable_amount: receive_amount,
+			fee,
+		});
+		Ok(receive_amount)
+	}
+
+	/// Redeem `currency_id` by paying `amount` of stable currency, returns the amount of
+	/// `currency_id` received by `who`.
+	#[transactional]
+	pub fn do_redeem(who: &T::AccountId, currency_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError> {
+		let params = Self::peg_assets(currency_id).ok_or(Error::<T>::PegAssetNotEnabled)?;
+		let fee = params.redeem_fee.saturating_mul_int(amount);
+		let burn_amount = amount.saturating_sub(fee);
+		let reserve_amount = Self::stable_to_reserve(currency_id, burn_amount)?;
+		ensure!(!reserve_amount.is_zero(), Error::<T>::AmountTooSmall);
+
+		let (reserve, issued) = T::CDPTreasury::get_peg_reserve(currency_id);
+		ensure!(
+			burn_amount <= issued && reserve_amount <= reserve,
+			Error::<T>::InsufficientReserve
+		);
+
+		if !fee.is_zero() {
+			T::CDPTreasury::deposit_surplus(who, fee)?;
+		}
+		T::CDPTreasury::burn_debit(who, burn_amount)?;
+		T::CDPTreasury::on_peg_reserve_withdrawn(currency_id, reserve_amount, burn_amount)?;
+		T::Currency::transfer(currency_id, &Self::account_id(), who, reserve_amount)?;
+
+		Self::deposit_event(Event::Redeemed {
+			who: who.clone(),
+			currency_id,
+			stable_amount: burn_amount,
+			reserve_amount,
+			fee,
+		});
+		Ok(reserve_amount)
+	}
+
+	/// Convert `amount` of the external stablecoin to the stable currency with the same value.
+	pub fn reserve_to_stable(currency_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError> {
+		Self::convert_decimals(amount, currency_id, T::GetStableCurrencyId::get())
+	}
+
+	/// Convert `amount` of the stable currency to the external stablecoin with the same value.
+	pub fn stable_to_reserve(currency_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError> {
+		Self::convert_decimals(amount, T::GetStableCurrencyId::get(), currency_id)
+	}
+
+	/// Convert `amount` between the decimals of `from` and `to`, rounding down.
+	fn convert_decimals(amount: Balance, from: CurrencyId, to: CurrencyId) -> Result<Balance, DispatchError> {
+		let from_decimals = T::Erc20InfoMapping::decimals(from).ok_or(Error::<T>::InvalidPegAsset)?;
+		let to_decimals = T::Erc20InfoMapping::decimals(to).ok_or(Error::<T>::InvalidPegAsset)?;
+
+		if to_decimals >= from_decimals {
+			let scale = 10u128
+				.checked_pow((to_decimals - from_decimals).into())
+				.ok_or(ArithmeticError::Overflow)?;
+			Ok(amount.checked_mul(scale).ok_or(ArithmeticError::Overflow)?)
+		} else {
+			let scale = 10u128
+				.checked_pow((from_decimals - to_decimals).into())
+				.ok_or(ArithmeticError::Overflow)?;
+			Ok(amount / scale)
+		}
+	}
+}
@@ -0,0 +1,96 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{module::STORAGE_VERSION, Config, Pallet, PegAssetParams, PegAssets};
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use module_support::CDPTreasury;
+use orml_traits::MultiCurrency;
+use primitives::CurrencyId;
+use sp_runtime::DispatchResult;
+use sp_std::marker::PhantomData;
+
+/// The bridged stablecoin of the removed `module_honzon_bridge`.
+#[frame_support::storage_alias]
+type BridgedStableCoinCurrencyId = StorageValue<HonzonBridge, CurrencyId, OptionQuery>;
+
+/// Move the balances of the removed `module_honzon_bridge`, which this module supersedes.
+///
+/// The bridged stablecoin held by `HonzonBridgeAccount` backs the stable currency swapped out
+/// of the bridge, so it becomes the reserve of that stablecoin, which is accepted without fees
+/// and with a debt ceiling of the migrated amount unless it already has parameters. The stable
+/// currency left in `HonzonBridgeAccount` goes to the surplus pool of the CDP treasury.
+pub struct MigrateHonzonBridgeReserve<T, HonzonBridgeAccount>(PhantomData<(T, HonzonBridgeAccount)>);
+impl<T: Config, HonzonBridgeAccount: Get<T::AccountId>> OnRuntimeUpgrade
+	for MigrateHonzonBridgeReserve<T, HonzonBridgeAccount>
+{
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let bridge_account = HonzonBridgeAccount::get();
+		if let Some(currency_id) = BridgedStableCoinCurrencyId::take() {
+			let _ = with_storage_layer(|| Self::move_reserve(&bridge_account, currency_id)).map_err(|e| {
+				log::warn!(
+					target: "peg-stability",
+					"MigrateHonzonBridgeReserve: failed to move the reserve {:?}: {:?}. \
+					This is unexpected but should be safe",
+					currency_id, e
+				);
+			});
+		}
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let stable_amount = T::Currency::free_balance(stable_currency_id, &bridge_account);
+		let _ = T::CDPTreasury::deposit_surplus(&bridge_account, stable_amount).map_err(|e| {
+			log::warn!(
+				target: "peg-stability",
+				"MigrateHonzonBridgeReserve: failed to deposit surplus {:?}: {:?}. \
+				This is unexpected but should be safe",
+				stable_amount, e
+			);
+		});
+		STORAGE_VERSION.put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(10, 10)
+	}
+}
+
+impl<T: Config, HonzonBridgeAccount: Get<T::AccountId>> MigrateHonzonBridgeReserve<T, HonzonBridgeAccount> {
+	fn move_reserve(bridge_account: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		let reserve = T::Currency::free_balance(currency_id, bridge_account);
+		let issued = Pallet::<T>::reserve_to_stable(currency_id, reserve)?;
+
+		T::Currency::transfer(currency_id, bridge_account, &Pallet::<T>::account_id(), reserve)?;
+		T::CDPTreasury::on_peg_reserve_deposited(currency_id, reserve, issued)?;
+		if !PegAssets::<T>::contains_key(currency_id) {
+			PegAssets::<T>::insert(
+				currency_id,
+				PegAssetParams {
+					debt_ceiling: T::CDPTreasury::get_peg_reserve(currency_id).1,
+					..Default::default()
+				},
+			);
+		}
+		Ok(())
+	}
+}
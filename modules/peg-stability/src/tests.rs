@@ -0,0 +1,275 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the peg stability module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::BadOrigin;
+
+fn set_params(currency_id: CurrencyId, mint_fee: Rate, redeem_fee: Rate, debt_ceiling: Balance) {
+	assert_ok!(PegStability::set_peg_asset_params(
+		RuntimeOrigin::signed(ALICE),
+		currency_id,
+		Change::NewValue(mint_fee),
+		Change::NewValue(redeem_fee),
+		Change::NewValue(debt_ceiling),
+	));
+}
+
+#[test]
+fn set_peg_asset_params_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PegStability::set_peg_asset_params(
+				RuntimeOrigin::signed(BOB),
+				KUSD,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(1_000),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			PegStability::set_peg_asset_params(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(1_000),
+			),
+			Error::<Runtime>::InvalidPegAsset
+		);
+		assert_noop!(
+			PegStability::set_peg_asset_params(
+				RuntimeOrigin::signed(ALICE),
+				CurrencyId::ForeignAsset(0),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(1_000),
+			),
+			Error::<Runtime>::InvalidPegAsset
+		);
+		assert_noop!(
+			PegStability::set_peg_asset_params(
+				RuntimeOrigin::signed(ALICE),
+				KUSD,
+				Change::NewValue(Rate::saturating_from_rational(101, 100)),
+				Change::NoChange,
+				Change::NoChange,
+			),
+			Error::<Runtime>::InvalidFeeRate
+		);
+
+		assert_eq!(PegStability::peg_assets(KUSD), None);
+		set_params(
+			KUSD,
+			Rate::saturating_from_rational(1, 100),
+			Rate::saturating_from_rational(2, 100),
+			1_000,
+		);
+		System::assert_last_event(RuntimeEvent::PegStability(crate::Event::PegAssetParamsUpdated {
+			currency_id: KUSD,
+			mint_fee: Rate::saturating_from_rational(1, 100),
+			redeem_fee: Rate::saturating_from_rational(2, 100),
+			debt_ceiling: 1_000,
+		}));
+
+		assert_ok!(PegStability::set_peg_asset_params(
+			RuntimeOrigin::signed(ALICE),
+			KUSD,
+			Change::NoChange,
+			Change::NewValue(Rate::zero()),
+			Change::NoChange,
+		));
+		assert_eq!(
+			PegStability::peg_assets(KUSD),
+			Some(PegAssetParams {
+				mint_fee: Rate::saturating_from_rational(1, 100),
+				redeem_fee: Rate::zero(),
+				debt_ceiling: 1_000,
+			})
+		);
+	});
+}
+
+#[test]
+fn mint_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 100_000),
+			Error::<Runtime>::PegAssetNotEnabled
+		);
+
+		set_params(KUSD, Rate::saturating_from_rational(1, 100), Rate::zero(), 1_000_000);
+		assert_noop!(
+			PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 0),
+			Error::<Runtime>::AmountTooSmall
+		);
+
+		assert_ok!(PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 100_000));
+		System::assert_last_event(RuntimeEvent::PegStability(crate::Event::Minted {
+			who: BOB,
+			currency_id: KUSD,
+			reserve_amount: 100_000,
+			stable_amount: 99_000,
+			fee: 1_000,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_099_000);
+		assert_eq!(Tokens::free_balance(KUSD, &BOB), 999_999_999_900_000);
+		assert_eq!(Tokens::free_balance(KUSD, &PegStability::account_id()), 100_000);
+		assert_eq!(MockCDPTreasury::get_surplus_pool(), 1_000);
+		assert_eq!(MockCDPTreasury::get_peg_reserve(KUSD), (100_000, 100_000));
+
+		assert_noop!(
+			PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 900_001),
+			Error::<Runtime>::ExceedDebtCeiling
+		);
+		assert_ok!(PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 900_000));
+		assert_eq!(MockCDPTreasury::get_peg_reserve(KUSD), (1_000_000, 1_000_000));
+	});
+}
+
+#[test]
+fn mint_and_redeem_with_different_decimals_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_params(DOT, Rate::zero(), Rate::zero(), 1_000_000);
+
+		// DOT has 10 decimals and AUSD has 12 decimals
+		assert_eq!(PegStability::do_mint(&BOB, DOT, 100), Ok(10_000));
+		assert_eq!(MockCDPTreasury::get_peg_reserve(DOT), (100, 10_000));
+
+		assert_noop!(
+			PegStability::redeem(RuntimeOrigin::signed(BOB), DOT, 99),
+			Error::<Runtime>::AmountTooSmall
+		);
+		assert_noop!(
+			PegStability::redeem(RuntimeOrigin::signed(BOB), DOT, 10_100),
+			Error::<Runtime>::InsufficientReserve
+		);
+
+		// rounds down the reserve to withdraw
+		assert_eq!(PegStability::do_redeem(&BOB, DOT, 9_999), Ok(99));
+		assert_eq!(MockCDPTreasury::get_peg_reserve(DOT), (1, 1));
+		assert_eq!(Tokens::free_balance(DOT, &PegStability::account_id()), 1);
+	});
+}
+
+#[test]
+fn redeem_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			PegStability::redeem(RuntimeOrigin::signed(BOB), KUSD, 50_000),
+			Error::<Runtime>::PegAssetNotEnabled
+		);
+
+		set_params(KUSD, Rate::zero(), Rate::saturating_from_rational(2, 100), 1_000_000);
+		assert_noop!(
+			PegStability::redeem(RuntimeOrigin::signed(BOB), KUSD, 50_000),
+			Error::<Runtime>::InsufficientReserve
+		);
+
+		assert_ok!(PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 100_000));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_100_000);
+		assert_eq!(MockCDPTreasury::get_surplus_pool(), 0);
+
+		assert_ok!(PegStability::redeem(RuntimeOrigin::signed(BOB), KUSD, 50_000));
+		System::assert_last_event(RuntimeEvent::PegStability(crate::Event::Redeemed {
+			who: BOB,
+			currency_id: KUSD,
+			stable_amount: 49_000,
+			reserve_amount: 49_000,
+			fee: 1_000,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_050_000);
+		assert_eq!(Tokens::free_balance(KUSD, &BOB), 999_999_999_949_000);
+		assert_eq!(Tokens::free_balance(KUSD, &PegStability::account_id()), 51_000);
+		assert_eq!(MockCDPTreasury::get_surplus_pool(), 1_000);
+		assert_eq!(MockCDPTreasury::get_peg_reserve(KUSD), (51_000, 51_000));
+		assert_eq!(Tokens::total_issuance(AUSD), 1_000_000_051_000);
+	});
+}
+
+#[test]
+fn migrate_honzon_bridge_reserve_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(BOB),
+			HONZON_BRIDGE,
+			KUSD,
+			30_000
+		));
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(BOB),
+			HONZON_BRIDGE,
+			AUSD,
+			70_000
+		));
+		frame_support::storage::migration::put_storage_value(
+			b"HonzonBridge",
+			b"BridgedStableCoinCurrencyId",
+			&[],
+			KUSD,
+		);
+
+		migrations::MigrateHonzonBridgeReserve::<Runtime, HonzonBridgeAccount>::on_runtime_upgrade();
+
+		assert_eq!(Tokens::free_balance(KUSD, &HONZON_BRIDGE), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &HONZON_BRIDGE), 0);
+		assert_eq!(Tokens::free_balance(KUSD, &PegStability::account_id()), 30_000);
+		assert_eq!(MockCDPTreasury::get_peg_reserve(KUSD), (30_000, 30_000));
+		assert_eq!(MockCDPTreasury::get_surplus_pool(), 70_000);
+		assert_eq!(
+			PegStability::peg_assets(KUSD),
+			Some(PegAssetParams {
+				mint_fee: Rate::zero(),
+				redeem_fee: Rate::zero(),
+				debt_ceiling: 30_000,
+			})
+		);
+		assert_eq!(
+			frame_support::storage::migration::get_storage_value::<CurrencyId>(
+				b"HonzonBridge",
+				b"BridgedStableCoinCurrencyId",
+				&[],
+			),
+			None
+		);
+		assert_eq!(Pallet::<Runtime>::on_chain_storage_version(), 1);
+
+		// the stable currency swapped out of the bridge can be redeemed, no more can be minted
+		assert_ok!(PegStability::redeem(RuntimeOrigin::signed(BOB), KUSD, 10_000));
+		assert_eq!(MockCDPTreasury::get_peg_reserve(KUSD), (20_000, 20_000));
+		assert_ok!(PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 10_000));
+		assert_noop!(
+			PegStability::mint(RuntimeOrigin::signed(BOB), KUSD, 1),
+			Error::<Runtime>::ExceedDebtCeiling
+		);
+
+		// runs only once
+		assert_ok!(Tokens::transfer(RuntimeOrigin::signed(BOB), HONZON_BRIDGE, AUSD, 1_000));
+		migrations::MigrateHonzonBridgeReserve::<Runtime, HonzonBridgeAccount>::on_runtime_upgrade();
+		assert_eq!(Tokens::free_balance(AUSD, &HONZON_BRIDGE), 1_000);
+	});
+}
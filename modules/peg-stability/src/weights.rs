@@ -0,0 +1,137 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_peg_stability
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-04, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-34-61`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/acala
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=module_peg_stability
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./modules/peg-stability/src/weights.rs
+// --template=./templates/module-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_peg_stability.
+pub trait WeightInfo {
+	fn set_peg_asset_params() -> Weight;
+	fn mint() -> Weight;
+	fn redeem() -> Weight;
+}
+
+/// Weights for module_peg_stability using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Storage: `PegStability::PegAssets` (r:1 w:1)
+	// Proof: `PegStability::PegAssets` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	fn set_peg_asset_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `936`
+		//  Estimated: `3564`
+		// Minimum execution time: 21_190 nanoseconds.
+		Weight::from_parts(21_734_000, 3564)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `PegStability::PegAssets` (r:1 w:0)
+	// Proof: `PegStability::PegAssets` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::PegReserves` (r:1 w:1)
+	// Proof: `CdpTreasury::PegReserves` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2872`
+		//  Estimated: `3612`
+		// Minimum execution time: 94_004 nanoseconds.
+		Weight::from_parts(96_415_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: `PegStability::PegAssets` (r:1 w:0)
+	// Proof: `PegStability::PegAssets` (`max_values`: None, `max_size`: Some(99), added: 2574, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::PegReserves` (r:1 w:1)
+	// Proof: `CdpTreasury::PegReserves` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:4 w:4)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3250`
+		//  Estimated: `3612`
+		// Minimum execution time: 92_456 nanoseconds.
+		Weight::from_parts(94_827_000, 3612)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_peg_asset_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `936`
+		//  Estimated: `3564`
+		// Minimum execution time: 21_190 nanoseconds.
+		Weight::from_parts(21_734_000, 3564)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2872`
+		//  Estimated: `3612`
+		// Minimum execution time: 94_004 nanoseconds.
+		Weight::from_parts(96_415_000, 3612)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3250`
+		//  Estimated: `3612`
+		// Minimum execution time: 92_456 nanoseconds.
+		Weight::from_parts(94_827_000, 3612)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+}
@@ -0,0 +1,340 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Savings Module
+//!
+//! ## Overview
+//!
+//! The savings module lets holders of the stable currency deposit it and receive the savings
+//! currency, a share token of the deposits. The deposits accrue the savings rate set by
+//! governance, paid every block out of the surplus pool of the CDP treasury. The accrual stops
+//! when the surplus pool runs out, and resumes at the savings rate once it's refilled.
+//!
+//! The total savings, the stable currency owed to the holders of the savings currency, are
+//! always covered by the stable currency held by this module.
+//!
+//! After emergency shutdown, the deposits stop accruing and no new deposits are accepted, while
+//! the savings can still be withdrawn.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::UnixTime, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use module_support::{CDPTreasury, EmergencyShutdown, ExchangeRate, Rate};
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, FixedPointNumber,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Multi-currency support for asset management.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Stablecoin currency id.
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// The savings currency id, the share token of the deposits.
+		#[pallet::constant]
+		type GetSavingsCurrencyId: Get<CurrencyId>;
+
+		/// CDP treasury to pay the savings interest from its surplus pool.
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// Emergency shutdown.
+		type EmergencyShutdown: EmergencyShutdown;
+
+		/// Time used for computing the savings interest.
+		type UnixTime: UnixTime;
+
+		/// The maximum savings rate per sec.
+		#[pallet::constant]
+		type MaxSavingsRatePerSec: Get<Rate>;
+
+		/// The origin which may update the savings rate.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The savings module's id, keeps the deposits of stable currency.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The savings rate exceeds `MaxSavingsRatePerSec`.
+		InvalidRate,
+		/// The amount is too small to deposit or withdraw.
+		AmountTooSmall,
+		/// The system has been shutdown.
+		AlreadyShutdown,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The savings rate per sec updated.
+		SavingsRateUpdated { savings_rate_per_sec: Rate },
+		/// Stable currency deposited to the savings.
+		Deposited {
+			who: T::AccountId,
+			amount: Balance,
+			share_amount: Balance,
+		},
+		/// Stable currency withdrawn from the savings.
+		Withdrawn {
+			who: T::AccountId,
+			share_amount: Balance,
+			amount: Balance,
+		},
+		/// The savings interest paid from the surplus pool of the CDP treasury.
+		InterestPaid { amount: Balance },
+	}
+
+	/// The savings rate per sec.
+	///
+	/// SavingsRatePerSec: Rate
+	#[pallet::storage]
+	#[pallet::getter(fn savings_rate_per_sec)]
+	pub type SavingsRatePerSec<T: Config> = StorageValue<_, Rate, ValueQuery>;
+
+	/// The total stable currency owed to the holders of the savings currency, including the
+	/// accrued interest.
+	///
+	/// TotalSavings: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_savings)]
+	pub type TotalSavings<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Timestamp in seconds of the last interest accrual
+	///
+	/// LastAccrualSecs: u64
+	#[pallet::storage]
+	#[pallet::getter(fn last_accrual_secs)]
+	pub type LastAccrualSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Pay the savings interest accrued since the last block from the surplus pool.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// only after the block #1, `T::UnixTime::now()` will not report error.
+			let now_as_secs: u64 = if now > One::one() {
+				T::UnixTime::now().as_secs()
+			} else {
+				Default::default()
+			};
+			Self::accrue_interest(now_as_secs, Self::last_accrual_secs());
+			<T as Config>::WeightInfo::on_initialize()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Update the savings rate per sec.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `savings_rate_per_sec`: the savings rate per sec.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_savings_rate())]
+		pub fn set_savings_rate(origin: OriginFor<T>, savings_rate_per_sec: Rate) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				savings_rate_per_sec <= T::MaxSavingsRatePerSec::get(),
+				Error::<T>::InvalidRate
+			);
+
+			SavingsRatePerSec::<T>::put(savings_rate_per_sec);
+			Self::deposit_event(Event::SavingsRateUpdated { savings_rate_per_sec });
+			Ok(())
+		}
+
+		/// Deposit `amount` of stable currency to the savings, and receive the savings currency.
+		///
+		/// - `amount`: the amount of stable currency to deposit.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		pub fn deposit(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_deposit(&who, amount)?;
+			Ok(())
+		}
+
+		/// Burn `share_amount` of the savings currency, and withdraw the stable currency it's worth.
+		///
+		/// - `share_amount`: the amount of the savings currency to burn.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, #[pallet::compact] share_amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw(&who, share_amount)?;
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Get account of the savings module.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The total issuance of the savings currency.
+	pub fn total_shares() -> Balance {
+		T::Currency::total_issuance(T::GetSavingsCurrencyId::get())
+	}
+
+	/// The stable currency one savings currency is worth.
+	pub fn current_exchange_rate() -> ExchangeRate {
+		ExchangeRate::checked_from_rational(Self::total_savings(), Self::total_shares())
+			.unwrap_or_else(ExchangeRate::one)
+	}
+
+	/// Convert `amount` of stable currency to the savings currency, rounding down.
+	pub fn convert_stable_to_shares(amount: Balance) -> Result<Balance, DispatchError> {
+		let total_savings = Self::total_savings();
+		let total_shares = Self::total_shares();
+		if total_savings.is_zero() || total_shares.is_zero() {
+			return Ok(amount);
+		}
+
+		ExchangeRate::checked_from_rational(total_shares, total_savings)
+			.and_then(|rate| rate.checked_mul_int(amount))
+			.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))
+	}
+
+	/// Convert `share_amount` of the savings currency to stable currency, rounding down.
+	pub fn convert_shares_to_stable(share_amount: Balance) -> Result<Balance, DispatchError> {
+		Self::current_exchange_rate()
+			.checked_mul_int(share_amount)
+			.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))
+	}
+
+	/// Deposit `amount` of stable currency to the savings, returns the savings currency received
+	/// by `who`.
+	#[transactional]
+	pub fn do_deposit(who: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+
+		let share_amount = Self::convert_stable_to_shares(amount)?;
+		ensure!(!share_amount.is_zero(), Error::<T>::AmountTooSmall);
+
+		T::Currency::transfer(T::GetStableCurrencyId::get(), who, &Self::account_id(), amount)?;
+		T::Currency::deposit(T::GetSavingsCurrencyId::get(), who, share_amount)?;
+		TotalSavings::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::Deposited {
+			who: who.clone(),
+			amount,
+			share_amount,
+		});
+		Ok(share_amount)
+	}
+
+	/// Withdraw the stable currency `share_amount` of the savings currency is worth, returns the
+	/// stable currency received by `who`.
+	#[transactional]
+	pub fn do_withdraw(who: &T::AccountId, share_amount: Balance) -> Result<Balance, DispatchError> {
+		let amount = Self::convert_shares_to_stable(share_amount)?;
+		ensure!(!amount.is_zero(), Error::<T>::AmountTooSmall);
+
+		T::Currency::withdraw(T::GetSavingsCurrencyId::get(), who, share_amount)?;
+		TotalSavings::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_sub(amount).ok_or(ArithmeticError::Underflow)?;
+			Ok(())
+		})?;
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), who, amount)?;
+
+		Self::deposit_event(Event::Withdrawn {
+			who: who.clone(),
+			share_amount,
+			amount,
+		});
+		Ok(amount)
+	}
+
+	/// Pay the savings interest accrued between `last_accrual_secs` and `now_secs`, as much as
+	/// the surplus pool can afford. Returns the interest paid.
+	fn accrue_interest(now_secs: u64, last_accrual_secs: u64) -> Balance {
+		let mut paid: Balance = Zero::zero();
+
+		if !T::EmergencyShutdown::is_shutdown() && !now_secs.is_zero() && !last_accrual_secs.is_zero() {
+			let interval_secs = now_secs.saturating_sub(last_accrual_secs);
+			let interest = Self::compound_interest_rate(Self::savings_rate_per_sec(), interval_secs)
+				.saturating_mul_int(Self::total_savings());
+			let amount = interest.min(T::CDPTreasury::get_surplus_pool());
+
+			if !amount.is_zero() && T::CDPTreasury::withdraw_surplus(&Self::account_id(), amount).is_ok() {
+				TotalSavings::<T>::mutate(|total| *total = total.saturating_add(amount));
+				paid = amount;
+				Self::deposit_event(Event::InterestPaid { amount });
+			}
+		}
+
+		// update last accrual timestamp
+		LastAccrualSecs::<T>::put(now_secs);
+		paid
+	}
+
+	pub fn compound_interest_rate(rate_per_sec: Rate, secs: u64) -> Rate {
+		rate_per_sec
+			.saturating_add(Rate::one())
+			.saturating_pow(secs.unique_saturated_into())
+			.saturating_sub(Rate::one())
+	}
+
+	/// Ensure the total savings are covered by the stable currency held by this module.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> DispatchResult {
+		ensure!(
+			T::Currency::free_balance(T::GetStableCurrencyId::get(), &Self::account_id()) >= Self::total_savings(),
+			"total savings are not covered"
+		);
+		Ok(())
+	}
+}
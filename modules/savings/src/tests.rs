@@ -0,0 +1,203 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the savings module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{RuntimeEvent, *};
+use sp_runtime::traits::BadOrigin;
+
+fn run_to_block(n: u64, timestamp: u64) {
+	System::set_block_number(n);
+	Timestamp::set_timestamp(timestamp);
+	Savings::on_initialize(n);
+	assert_ok!(Savings::do_try_state());
+}
+
+#[test]
+fn set_savings_rate_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Savings::set_savings_rate(RuntimeOrigin::signed(BOB), Rate::saturating_from_rational(1, 1_000_000)),
+			BadOrigin
+		);
+		assert_noop!(
+			Savings::set_savings_rate(
+				RuntimeOrigin::signed(ALICE),
+				Rate::saturating_from_rational(2, 1_000_000)
+			),
+			Error::<Runtime>::InvalidRate
+		);
+
+		assert_ok!(Savings::set_savings_rate(
+			RuntimeOrigin::signed(ALICE),
+			Rate::saturating_from_rational(1, 1_000_000)
+		));
+		System::assert_last_event(RuntimeEvent::Savings(crate::Event::SavingsRateUpdated {
+			savings_rate_per_sec: Rate::saturating_from_rational(1, 1_000_000),
+		}));
+		assert_eq!(
+			Savings::savings_rate_per_sec(),
+			Rate::saturating_from_rational(1, 1_000_000)
+		);
+	});
+}
+
+#[test]
+fn deposit_and_withdraw_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Savings::deposit(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::AmountTooSmall
+		);
+
+		assert_ok!(Savings::deposit(RuntimeOrigin::signed(BOB), 1_000_000));
+		System::assert_last_event(RuntimeEvent::Savings(crate::Event::Deposited {
+			who: BOB,
+			amount: 1_000_000,
+			share_amount: 1_000_000,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_000_000);
+		assert_eq!(Tokens::free_balance(SAUSD, &BOB), 1_000_000);
+		assert_eq!(Tokens::free_balance(AUSD, &Savings::account_id()), 1_000_000);
+		assert_eq!(Savings::total_savings(), 1_000_000);
+		assert_eq!(Savings::current_exchange_rate(), ExchangeRate::one());
+		assert_ok!(Savings::do_try_state());
+
+		assert_noop!(
+			Savings::withdraw(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::AmountTooSmall
+		);
+		assert_noop!(
+			Savings::withdraw(RuntimeOrigin::signed(CHARLIE), 1_000),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(Savings::withdraw(RuntimeOrigin::signed(BOB), 400_000));
+		System::assert_last_event(RuntimeEvent::Savings(crate::Event::Withdrawn {
+			who: BOB,
+			share_amount: 400_000,
+			amount: 400_000,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_400_000);
+		assert_eq!(Tokens::free_balance(SAUSD, &BOB), 600_000);
+		assert_eq!(Tokens::free_balance(AUSD, &Savings::account_id()), 600_000);
+		assert_eq!(Savings::total_savings(), 600_000);
+		assert_ok!(Savings::do_try_state());
+	});
+}
+
+#[test]
+fn savings_accrue_interest_from_surplus() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Savings::set_savings_rate(
+			RuntimeOrigin::signed(ALICE),
+			Rate::saturating_from_rational(1, 1_000_000)
+		));
+		assert_ok!(Savings::deposit(RuntimeOrigin::signed(BOB), 1_000_000_000_000));
+
+		// the first accrual only records the timestamp
+		run_to_block(2, 10_000);
+		assert_eq!(Savings::last_accrual_secs(), 10);
+		assert_eq!(Savings::total_savings(), 1_000_000_000_000);
+
+		run_to_block(3, 11_000);
+		System::assert_last_event(RuntimeEvent::Savings(crate::Event::InterestPaid { amount: 1_000_000 }));
+		assert_eq!(Savings::total_savings(), 1_000_001_000_000);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 999_000_000);
+		assert_eq!(Tokens::free_balance(AUSD, &Savings::account_id()), 1_000_001_000_000);
+		assert_eq!(
+			Savings::current_exchange_rate(),
+			ExchangeRate::saturating_from_rational(1_000_001, 1_000_000)
+		);
+
+		// the later depositor receives less shares
+		assert_ok!(Savings::deposit(RuntimeOrigin::signed(CHARLIE), 1_000_001));
+		assert_eq!(Tokens::free_balance(SAUSD, &CHARLIE), 999_999);
+		assert_eq!(Savings::total_savings(), 1_000_002_000_001);
+
+		assert_ok!(Savings::withdraw(RuntimeOrigin::signed(BOB), 1_000_000_000_000));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_001_000_001);
+		assert_ok!(Savings::withdraw(RuntimeOrigin::signed(CHARLIE), 999_999));
+		assert_eq!(Tokens::free_balance(AUSD, &CHARLIE), 999_999_999_998);
+
+		// the rounding is always in favor of the savings
+		assert_eq!(Savings::total_savings(), 1);
+		assert_eq!(Tokens::free_balance(AUSD, &Savings::account_id()), 1);
+		assert_ok!(Savings::do_try_state());
+	});
+}
+
+#[test]
+fn accrual_stops_when_surplus_runs_out() {
+	ExtBuilder::default()
+		.balances(vec![(BOB, AUSD, 1_000_000_000_000), (CDP_TREASURY, AUSD, 1_500_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Savings::set_savings_rate(
+				RuntimeOrigin::signed(ALICE),
+				Rate::saturating_from_rational(1, 1_000_000)
+			));
+			assert_ok!(Savings::deposit(RuntimeOrigin::signed(BOB), 1_000_000_000_000));
+			run_to_block(2, 10_000);
+
+			run_to_block(3, 11_000);
+			assert_eq!(Savings::total_savings(), 1_000_001_000_000);
+			assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 500_000);
+
+			// only the remaining surplus is paid
+			run_to_block(4, 12_000);
+			System::assert_last_event(RuntimeEvent::Savings(crate::Event::InterestPaid { amount: 500_000 }));
+			assert_eq!(Savings::total_savings(), 1_000_001_500_000);
+			assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 0);
+
+			// no surplus, no accrual
+			run_to_block(5, 13_000);
+			assert_eq!(Savings::total_savings(), 1_000_001_500_000);
+			assert_eq!(Savings::last_accrual_secs(), 13);
+		});
+}
+
+#[test]
+fn emergency_shutdown_stops_deposit_and_accrual() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Savings::set_savings_rate(
+			RuntimeOrigin::signed(ALICE),
+			Rate::saturating_from_rational(1, 1_000_000)
+		));
+		assert_ok!(Savings::deposit(RuntimeOrigin::signed(BOB), 1_000_000_000_000));
+		run_to_block(2, 10_000);
+
+		mock_shutdown();
+		assert_noop!(
+			Savings::deposit(RuntimeOrigin::signed(CHARLIE), 1_000_000),
+			Error::<Runtime>::AlreadyShutdown
+		);
+
+		run_to_block(3, 11_000);
+		assert_eq!(Savings::total_savings(), 1_000_000_000_000);
+		assert_eq!(Tokens::free_balance(AUSD, &CDP_TREASURY), 1_000_000_000);
+
+		assert_ok!(Savings::withdraw(RuntimeOrigin::signed(BOB), 1_000_000_000_000));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_000_000);
+		assert_eq!(Savings::total_savings(), 0);
+	});
+}
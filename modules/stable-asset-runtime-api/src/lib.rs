@@ -0,0 +1,76 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use nutsfinance_stable_asset::{PoolTokenIndex, StableAssetPoolId, StableAssetPoolInfo};
+use sp_runtime::{codec::Codec, FixedU128};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Quotes of stable asset pools. Amounts are in the units of the pool assets, the same as
+	/// the aggregated DEX.
+	pub trait StableAssetApi<CurrencyId, Balance, AccountId, BlockNumber> where
+		CurrencyId: Codec,
+		Balance: Codec,
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The pool info, including the amplification ramp state. The balances are in the units
+		/// of the pool assets.
+		fn pool_info(
+			pool_id: StableAssetPoolId,
+		) -> Option<StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>>;
+
+		/// The value of the pool token in the invariant of the pool.
+		fn get_virtual_price(pool_id: StableAssetPoolId) -> Option<FixedU128>;
+
+		/// The output amount of swapping `input_amount` of the `input_index` asset.
+		fn get_swap_output_amount(
+			pool_id: StableAssetPoolId,
+			input_index: PoolTokenIndex,
+			output_index: PoolTokenIndex,
+			input_amount: Balance,
+		) -> Option<Balance>;
+
+		/// The input amount of the `input_index` asset required to receive `output_amount`.
+		fn get_swap_input_amount(
+			pool_id: StableAssetPoolId,
+			input_index: PoolTokenIndex,
+			output_index: PoolTokenIndex,
+			output_amount: Balance,
+		) -> Option<Balance>;
+
+		/// The pool token amount minted for `amounts` of the pool assets.
+		fn get_mint_amount(pool_id: StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance>;
+
+		/// The amounts of the pool assets received for redeeming `amount` proportionally.
+		fn get_redeem_proportion_amount(pool_id: StableAssetPoolId, amount: Balance) -> Option<Vec<Balance>>;
+
+		/// The amount of the `output_index` asset received for redeeming `amount`.
+		fn get_redeem_single_amount(
+			pool_id: StableAssetPoolId,
+			amount: Balance,
+			output_index: PoolTokenIndex,
+		) -> Option<Balance>;
+
+		/// The pool token amount required to redeem `amounts` of the pool assets.
+		fn get_redeem_multi_amount(pool_id: StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance>;
+	}
+}
@@ -40,6 +40,17 @@ pub trait PrecompileCallerFilter {
 /// Return true if the EVM precompile is paused.
 pub trait PrecompilePauseFilter {
 	fn is_paused(address: H160) -> bool;
+
+	/// Return true if calling the EVM contract `address` with `input` is paused.
+	fn is_contract_call_paused(_address: H160, _input: &[u8]) -> bool {
+		false
+	}
+}
+
+impl PrecompilePauseFilter for () {
+	fn is_paused(_address: H160) -> bool {
+		false
+	}
 }
 
 /// An abstraction of EVM for EVMBridge
@@ -104,6 +115,12 @@ pub trait EVMBridge<AccountId, Balance> {
 	fn balance_of(context: InvokeContext, address: EvmAddress) -> Result<Balance, DispatchError>;
 	/// Execute ERC20.transfer(address, uint256) to transfer value to `to`
 	fn transfer(context: InvokeContext, to: EvmAddress, value: Balance) -> DispatchResult;
+	/// Execute ERC20.allowance(address, address) to read the amount `spender` is allowed to
+	/// spend on behalf of `owner`
+	fn allowance(context: InvokeContext, owner: EvmAddress, spender: EvmAddress) -> Result<Balance, DispatchError>;
+	/// Execute ERC20.transferFrom(address, address, uint256) to transfer value from `from` to `to`
+	/// using the allowance of `context.sender`
+	fn transfer_from(context: InvokeContext, from: EvmAddress, to: EvmAddress, value: Balance) -> DispatchResult;
 	/// Get the real origin account and charge storage rent from the origin.
 	fn get_origin() -> Option<AccountId>;
 	/// Set the EVM origin
@@ -140,6 +157,12 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 	fn transfer(_context: InvokeContext, _to: EvmAddress, _value: Balance) -> DispatchResult {
 		Err(DispatchError::Other("unimplemented evm bridge"))
 	}
+	fn allowance(_context: InvokeContext, _owner: EvmAddress, _spender: EvmAddress) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
+	fn transfer_from(_context: InvokeContext, _from: EvmAddress, _to: EvmAddress, _value: Balance) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
 	fn get_origin() -> Option<AccountId> {
 		None
 	}
@@ -155,6 +178,14 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 
 /// EVM bridge for collateral liquidation.
 pub trait LiquidationEvmBridge {
+	/// Ask for a quote of liquidation. Returns the collateral amount, not above `amount`, required
+	/// by the liquidation contract to repay `min_repayment`.
+	fn quote(
+		context: InvokeContext,
+		collateral: EvmAddress,
+		amount: Balance,
+		min_repayment: Balance,
+	) -> Result<Balance, DispatchError>;
 	/// Execute liquidation. Sufficient repayment is expected to be transferred to `repay_dest`,
 	/// if not received or below `min_repayment`, the liquidation would be seen as failed.
 	fn liquidate(
@@ -170,6 +201,14 @@ pub trait LiquidationEvmBridge {
 	fn on_repayment_refund(context: InvokeContext, collateral: EvmAddress, repayment: Balance);
 }
 impl LiquidationEvmBridge for () {
+	fn quote(
+		_context: InvokeContext,
+		_collateral: EvmAddress,
+		_amount: Balance,
+		_min_repayment: Balance,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unimplemented evm bridge"))
+	}
 	fn liquidate(
 		_context: InvokeContext,
 		_collateral: EvmAddress,
@@ -317,13 +356,22 @@ pub mod limits {
 		pub const TOTAL_SUPPLY: Limit = Limit::new(100_000, 0);
 		pub const BALANCE_OF: Limit = Limit::new(100_000, 0);
 		pub const TRANSFER: Limit = Limit::new(200_000, 960);
+		pub const ALLOWANCE: Limit = Limit::new(100_000, 0);
+		pub const TRANSFER_FROM: Limit = Limit::new(200_000, 960);
 	}
 
 	pub mod liquidation {
 		use super::*;
 
+		pub const QUOTE: Limit = Limit::new(100_000, 0);
 		pub const LIQUIDATE: Limit = Limit::new(200_000, 1_000);
 		pub const ON_COLLATERAL_TRANSFER: Limit = Limit::new(200_000, 1_000);
 		pub const ON_REPAYMENT_REFUND: Limit = Limit::new(200_000, 1_000);
 	}
+
+	pub mod flash_mint {
+		use super::*;
+
+		pub const ON_FLASH_MINT: Limit = Limit::new(1_000_000, 10_000);
+	}
 }
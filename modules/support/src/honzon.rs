@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use parity_scale_codec::FullCodec;
-use primitives::Position;
+use primitives::{CurrencyId, Position};
 use sp_core::U256;
 use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::{
@@ -74,9 +74,23 @@ pub trait AuctionManager<AccountId> {
 		amount: Self::Balance,
 		target: Self::Balance,
 	) -> DispatchResult;
+	/// new collateral auction liquidating CDPs of `collateral_type`, of which `penalty` of the
+	/// `target` is the liquidation penalty
+	fn new_liquidation_auction(
+		refund_recipient: &AccountId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		_collateral_type: Self::CurrencyId,
+		_penalty: Self::Balance,
+	) -> DispatchResult {
+		Self::new_collateral_auction(refund_recipient, currency_id, amount, target)
+	}
+	fn new_debit_auction(initial_amount: Self::Balance, fix_debit: Self::Balance) -> DispatchResult;
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult;
 	fn get_total_collateral_in_auction(id: Self::CurrencyId) -> Self::Balance;
 	fn get_total_target_in_auction() -> Self::Balance;
+	fn get_total_debit_in_auction() -> Self::Balance;
 }
 
 /// An abstraction of cdp treasury for Honzon Protocol.
@@ -121,6 +135,27 @@ pub trait CDPTreasury<AccountId> {
 
 	/// withdraw collateral assets of cdp treasury to `who`
 	fn withdraw_collateral(to: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
+
+	/// get the reserve amount of `id` held by the peg stability module and the stable currency
+	/// issued against it
+	fn get_peg_reserve(id: Self::CurrencyId) -> (Self::Balance, Self::Balance);
+
+	/// record `reserve` amount of `id` deposited to the peg stability module, backing `issued`
+	/// amount of stable currency
+	fn on_peg_reserve_deposited(id: Self::CurrencyId, reserve: Self::Balance, issued: Self::Balance) -> DispatchResult;
+
+	/// record `reserve` amount of `id` withdrawn from the peg stability module, after `burned`
+	/// amount of stable currency burned
+	fn on_peg_reserve_withdrawn(id: Self::CurrencyId, reserve: Self::Balance, burned: Self::Balance) -> DispatchResult;
+
+	/// set aside the share of the liquidation `penalty` of collateral `id` configured for the
+	/// insurance fund of `id` from the surplus pool, after the penalty has been received
+	fn on_liquidation_penalty(id: Self::CurrencyId, penalty: Self::Balance) -> DispatchResult;
+
+	/// cover the `loss` of debit that the liquidation of collateral `id` can not recover, by the
+	/// insurance fund of `id` first, then the surplus pool, then debit auctions or socialised
+	/// dilution
+	fn on_liquidation_loss(id: Self::CurrencyId, loss: Self::Balance) -> DispatchResult;
 }
 
 pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
@@ -138,6 +173,26 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 		splited: bool,
 	) -> sp_std::result::Result<u32, DispatchError>;
 
+	fn create_liquidation_auctions(
+		collateral_type: Self::CurrencyId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		penalty: Self::Balance,
+		refund_receiver: AccountId,
+	) -> sp_std::result::Result<u32, DispatchError>;
+
+	/// keep the liquidation of CDPs of `collateral_type` unsettled after its collateral auction of
+	/// `amount` of `currency_id` for `target`, which includes `penalty`, has been aborted, it's
+	/// settled by the re-auction of the collateral
+	fn on_liquidation_auction_aborted(
+		collateral_type: Self::CurrencyId,
+		currency_id: Self::CurrencyId,
+		amount: Self::Balance,
+		target: Self::Balance,
+		penalty: Self::Balance,
+	);
+
 	fn remove_liquidity_for_lp_collateral(
 		currency_id: Self::CurrencyId,
 		amount: Self::Balance,
@@ -148,6 +203,17 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 
 pub trait EmergencyShutdown {
 	fn is_shutdown() -> bool;
+
+	/// Whether the collateral `currency_id` is being wound down on its own while the rest of
+	/// the system keeps running.
+	fn is_collateral_shutdown(_currency_id: CurrencyId) -> bool {
+		false
+	}
+
+	/// Whether any collateral is being wound down on its own.
+	fn has_collateral_shutdown() -> bool {
+		false
+	}
 }
 
 /// Functionality of Honzon Protocol to be exposed to EVM+.
@@ -170,3 +236,50 @@ pub trait HonzonManager<AccountId, CurrencyId, Amount, Balance> {
 	/// Get exchange rate of debit units to debit value for a currency_id
 	fn get_debit_exchange_rate(currency_id: CurrencyId) -> ExchangeRate;
 }
+
+/// Wrap CDPs into NFTs, the holder of the token controls the wrapped CDP.
+pub trait WrappedPositionManager<AccountId, CurrencyId, Amount, ClassId, TokenId> {
+	/// Wrap the CDP of `who` under `currency_id` into a new token minted to `who`.
+	fn wrap_position(who: &AccountId, currency_id: CurrencyId) -> Result<TokenId, DispatchError>;
+	/// Burn the token held by `who` and return the wrapped CDP to `who`.
+	fn unwrap_position(who: &AccountId, token_id: TokenId) -> DispatchResult;
+	/// Adjust the CDP wrapped in the token held by `who`.
+	fn adjust_wrapped_loan(
+		who: &AccountId,
+		token_id: TokenId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult;
+	/// Get the collateral type and the CDP wrapped in the token.
+	fn get_wrapped_position(token_id: TokenId) -> Option<(CurrencyId, Position)>;
+	/// Get the NFT class of the tokens wrapping CDPs.
+	fn position_class_id() -> Option<ClassId>;
+}
+
+/// The borrower of a flash mint of stable currency.
+pub trait FlashMintBorrower<AccountId, Balance> {
+	/// Called after `amount` of stable currency is minted to `who`. Before returning, `who` must
+	/// hold at least `amount + fee` of stable currency to repay the flash mint.
+	fn on_flash_mint(&self, who: &AccountId, amount: Balance, fee: Balance) -> DispatchResult;
+}
+
+/// Rewards keepers who liquidate unsafe CDPs or cancel collateral auctions.
+pub trait LiquidationKeeperReward<AccountId, CurrencyId, Balance> {
+	/// Pay `keeper` a portion of `penalty`, the liquidation penalty of `currency_id` collected by
+	/// the liquidation. Returns the reward paid.
+	fn reward_keeper(keeper: &AccountId, currency_id: CurrencyId, penalty: Balance) -> Balance;
+
+	/// Pay `keeper` the flat reward for cancelling a collateral auction of `currency_id` after
+	/// shutdown. Returns the reward paid.
+	fn reward_cancellation(keeper: &AccountId, currency_id: CurrencyId) -> Balance;
+}
+
+impl<AccountId, CurrencyId, Balance: Default> LiquidationKeeperReward<AccountId, CurrencyId, Balance> for () {
+	fn reward_keeper(_keeper: &AccountId, _currency_id: CurrencyId, _penalty: Balance) -> Balance {
+		Default::default()
+	}
+
+	fn reward_cancellation(_keeper: &AccountId, _currency_id: CurrencyId) -> Balance {
+		Default::default()
+	}
+}
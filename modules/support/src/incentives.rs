@@ -20,7 +20,7 @@ use crate::Rate;
 use parity_scale_codec::{Decode, Encode};
 use primitives::CurrencyId;
 use scale_info::TypeInfo;
-use sp_runtime::{DispatchResult, RuntimeDebug};
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
 use sp_std::prelude::*;
 
 /// PoolId for various rewards pools
@@ -45,6 +45,11 @@ pub trait IncentivesManager<AccountId, Balance, CurrencyId, PoolId> {
 	fn withdraw_dex_share(who: &AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult;
 	/// Claim all available rewards for specific `PoolId`
 	fn claim_rewards(who: AccountId, pool_id: PoolId) -> DispatchResult;
+	/// Claim all available rewards for specific `PoolId`, returns the rewards paid out to `who`
+	fn claim_rewards_and_get_payouts(
+		who: AccountId,
+		pool_id: PoolId,
+	) -> Result<Vec<(CurrencyId, Balance)>, DispatchError>;
 	/// Gets deduction reate for claiming reward early
 	fn get_claim_reward_deduction_rate(pool_id: PoolId) -> Rate;
 	/// Gets the pending rewards for a pool, for an account
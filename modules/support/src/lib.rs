@@ -22,9 +22,13 @@
 #![allow(clippy::type_complexity)]
 
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
+use parity_scale_codec::{Decode, Encode};
 use primitives::{task::TaskResult, Balance, CurrencyId, Multiplier, Nonce, ReserveIdentifier};
+use scale_info::TypeInfo;
+use sp_core::H160;
 use sp_runtime::{
 	traits::CheckedDiv, transaction_validity::TransactionValidityError, DispatchError, DispatchResult, FixedU128,
+	RuntimeDebug,
 };
 use sp_std::{prelude::*, result::Result};
 use xcm::prelude::*;
@@ -104,6 +108,12 @@ pub trait DEXPriceProvider<CurrencyId> {
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<ExchangeRate>;
 }
 
+impl<CurrencyId> DEXPriceProvider<CurrencyId> for () {
+	fn get_relative_price(_base: CurrencyId, _quote: CurrencyId) -> Option<ExchangeRate> {
+		None
+	}
+}
+
 pub trait LockablePrice<CurrencyId> {
 	fn lock_price(currency_id: CurrencyId) -> DispatchResult;
 	fn unlock_price(currency_id: CurrencyId) -> DispatchResult;
@@ -199,6 +209,54 @@ impl<AccountId> LiquidateCollateral<AccountId> for Tuple {
 	}
 }
 
+/// Move funds out of an account on behalf of a spender.
+///
+/// ERC20 tokens are pulled with `transferFrom` and are limited by the allowance the owner granted
+/// to the spender, other currencies are transferred directly.
+pub trait TransferFrom<AccountId> {
+	type CurrencyId;
+	type Balance;
+
+	/// The amount `spender` is allowed to move out of `owner`, the free balance of `owner` for
+	/// non-ERC20 currencies.
+	fn allowance(currency_id: Self::CurrencyId, owner: &AccountId, spender: &AccountId) -> Self::Balance;
+	/// Transfer `amount` from `from` to `to`, spent by `spender`.
+	fn transfer_from(
+		currency_id: Self::CurrencyId,
+		spender: &AccountId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+}
+
 pub trait BuyWeightRate {
 	fn calculate_rate(location: MultiLocation) -> Option<Ratio>;
 }
+
+/// An item that can be paused by `module_transaction_pause`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PauseItem {
+	/// The call `(pallet_name, function_name)`, a `*` function name pauses all calls of the pallet.
+	Transaction(Vec<u8>, Vec<u8>),
+	/// The EVM precompile at the address.
+	EvmPrecompile(H160),
+	/// All calls to the EVM contract at the address.
+	EvmContract(H160),
+	/// Calls to the EVM contract at the address with the function selector.
+	EvmContractFunction(H160, [u8; 4]),
+}
+
+/// Pause items automatically for a limited time, used by circuit breakers such as oracle
+/// deviation, DEX price move or Homa ledger mismatch checks.
+pub trait AutoPause<BlockNumber> {
+	/// Pause `item` for `duration` blocks. The duration is capped by the implementation and an
+	/// existing pause is never shortened.
+	fn auto_pause(item: PauseItem, duration: BlockNumber) -> DispatchResult;
+}
+
+impl<BlockNumber> AutoPause<BlockNumber> for () {
+	fn auto_pause(_item: PauseItem, _duration: BlockNumber) -> DispatchResult {
+		Ok(())
+	}
+}
@@ -16,14 +16,19 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::Ratio;
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{fungibles, Get},
+};
 use nutsfinance_stable_asset::{
 	traits::StableAsset as StableAssetT, PoolTokenIndex, RedeemProportionResult, StableAssetPoolId,
 	StableAssetPoolInfo, SwapResult,
 };
 use orml_tokens::ConvertBalance;
 use sp_runtime::{
-	traits::{Bounded, Convert},
-	DispatchError, DispatchResult,
+	traits::{AtLeast32BitUnsigned, Bounded, CheckedDiv, Convert},
+	DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand,
 };
 use sp_std::vec::Vec;
 
@@ -408,3 +413,240 @@ where
 		})
 	}
 }
+
+/// Estimates of stable asset pool operations.
+///
+/// Amounts are in the units of the pool assets as used by `RebasedStableAsset`, the same as the
+/// aggregated DEX.
+pub trait StableAssetQuote<CurrencyId, Balance, AccountId, BlockNumber> {
+	/// The pool info, including the amplification ramp state. The balances are in the units of the
+	/// pool assets, the other amounts as stored by the pool.
+	fn pool_info(
+		pool_id: StableAssetPoolId,
+	) -> Option<StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>>;
+
+	/// The value of the pool token in the invariant of the pool, including the yield and fees not
+	/// collected yet.
+	fn get_virtual_price(pool_id: StableAssetPoolId) -> Option<Ratio>;
+
+	/// The output amount of swapping `input_amount` of the `input_index` asset.
+	fn get_swap_output_amount(
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		input_amount: Balance,
+	) -> Option<Balance>;
+
+	/// The input amount of the `input_index` asset required to receive `output_amount`.
+	fn get_swap_input_amount(
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		output_amount: Balance,
+	) -> Option<Balance>;
+
+	/// The pool token amount minted for `amounts` of the pool assets, after the mint fee.
+	fn get_mint_amount(pool_id: StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance>;
+
+	/// The amounts of the pool assets received for redeeming `amount` of the pool token
+	/// proportionally.
+	fn get_redeem_proportion_amount(pool_id: StableAssetPoolId, amount: Balance) -> Option<Vec<Balance>>;
+
+	/// The amount of the `output_index` asset received for redeeming `amount` of the pool token.
+	fn get_redeem_single_amount(
+		pool_id: StableAssetPoolId,
+		amount: Balance,
+		output_index: PoolTokenIndex,
+	) -> Option<Balance>;
+
+	/// The pool token amount required to redeem `amounts` of the pool assets.
+	fn get_redeem_multi_amount(pool_id: StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance>;
+}
+
+impl<CurrencyId, Balance, AccountId, BlockNumber> StableAssetQuote<CurrencyId, Balance, AccountId, BlockNumber> for () {
+	fn pool_info(
+		_pool_id: StableAssetPoolId,
+	) -> Option<StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>> {
+		None
+	}
+
+	fn get_virtual_price(_pool_id: StableAssetPoolId) -> Option<Ratio> {
+		None
+	}
+
+	fn get_swap_output_amount(
+		_pool_id: StableAssetPoolId,
+		_input_index: PoolTokenIndex,
+		_output_index: PoolTokenIndex,
+		_input_amount: Balance,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn get_swap_input_amount(
+		_pool_id: StableAssetPoolId,
+		_input_index: PoolTokenIndex,
+		_output_index: PoolTokenIndex,
+		_output_amount: Balance,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn get_mint_amount(_pool_id: StableAssetPoolId, _amounts: Vec<Balance>) -> Option<Balance> {
+		None
+	}
+
+	fn get_redeem_proportion_amount(_pool_id: StableAssetPoolId, _amount: Balance) -> Option<Vec<Balance>> {
+		None
+	}
+
+	fn get_redeem_single_amount(
+		_pool_id: StableAssetPoolId,
+		_amount: Balance,
+		_output_index: PoolTokenIndex,
+	) -> Option<Balance> {
+		None
+	}
+
+	fn get_redeem_multi_amount(_pool_id: StableAssetPoolId, _amounts: Vec<Balance>) -> Option<Balance> {
+		None
+	}
+}
+
+/// Quote stable asset pools by executing the operation on `QuoteAccount` and rolling back, so
+/// that estimates match execution exactly.
+///
+/// `Assets` must be the assets of the stable asset pallet and `StableAsset` the rebased stable
+/// asset using `RebaseTokenAmountConvertor`.
+pub struct StableAssetQuoter<StableAsset, Assets, RebaseTokenAmountConvertor, QuoteAccount>(
+	sp_std::marker::PhantomData<(StableAsset, Assets, RebaseTokenAmountConvertor, QuoteAccount)>,
+);
+
+impl<StableAsset, Assets, RebaseTokenAmountConvertor, QuoteAccount>
+	StableAssetQuoter<StableAsset, Assets, RebaseTokenAmountConvertor, QuoteAccount>
+{
+	fn dry_run<R>(f: impl FnOnce() -> Result<R, DispatchError>) -> Option<R> {
+		with_transaction(|| TransactionOutcome::Rollback(f())).ok()
+	}
+}
+
+impl<AccountId, Balance, BlockNumber, CurrencyId, StableAsset, Assets, RebaseTokenAmountConvertor, QuoteAccount>
+	StableAssetQuote<CurrencyId, Balance, AccountId, BlockNumber>
+	for StableAssetQuoter<StableAsset, Assets, RebaseTokenAmountConvertor, QuoteAccount>
+where
+	StableAsset: StableAssetT<
+		AssetId = CurrencyId,
+		AtLeast64BitUnsigned = Balance,
+		Balance = Balance,
+		AccountId = AccountId,
+		BlockNumber = BlockNumber,
+	>,
+	Assets: fungibles::Mutate<AccountId, AssetId = CurrencyId, Balance = Balance>,
+	RebaseTokenAmountConvertor: ConvertBalance<Balance, Balance, AssetId = CurrencyId>,
+	QuoteAccount: Get<AccountId>,
+	CurrencyId: Copy,
+	Balance: AtLeast32BitUnsigned + FixedPointOperand + Copy,
+{
+	fn pool_info(
+		pool_id: StableAssetPoolId,
+	) -> Option<StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>> {
+		let mut pool_info = StableAsset::pool(pool_id)?;
+		// the pool stores the balances multiplied by the precisions, in the rebased units
+		pool_info.balances = pool_info
+			.balances
+			.iter()
+			.zip(pool_info.precisions.iter())
+			.zip(pool_info.assets.iter())
+			.map(|((balance, precision), currency_id)| {
+				RebaseTokenAmountConvertor::convert_balance_back(balance.checked_div(precision)?, *currency_id).ok()
+			})
+			.collect::<Option<Vec<_>>>()?;
+		Some(pool_info)
+	}
+
+	fn get_virtual_price(pool_id: StableAssetPoolId) -> Option<Ratio> {
+		let pool_info = StableAsset::pool(pool_id)?;
+		let updated_pool_info = StableAsset::get_balance_update_amount(&pool_info)?;
+		// the total supply of the collected pool is the invariant of the current balances
+		let collected_pool_info = StableAsset::get_collect_yield_amount(&updated_pool_info)?;
+		Ratio::checked_from_rational(collected_pool_info.total_supply, pool_info.total_supply)
+	}
+
+	fn get_swap_output_amount(
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		input_amount: Balance,
+	) -> Option<Balance> {
+		StableAsset::get_swap_output_amount(pool_id, input_index, output_index, input_amount)
+			.map(|swap_result| swap_result.dy)
+	}
+
+	fn get_swap_input_amount(
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		output_amount: Balance,
+	) -> Option<Balance> {
+		StableAsset::get_swap_input_amount(pool_id, input_index, output_index, output_amount)
+			.map(|swap_result| swap_result.dx)
+	}
+
+	fn get_mint_amount(pool_id: StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+		let pool_info = StableAsset::pool(pool_id)?;
+		let who = QuoteAccount::get();
+
+		Self::dry_run(|| {
+			for (currency_id, amount) in pool_info.assets.iter().zip(amounts.iter()) {
+				Assets::mint_into(
+					*currency_id,
+					&who,
+					RebaseTokenAmountConvertor::convert_balance(*amount, *currency_id)?,
+				)?;
+			}
+
+			let before = Assets::balance(pool_info.pool_asset, &who);
+			StableAsset::mint(&who, pool_id, amounts, Balance::zero())?;
+			Ok(Assets::balance(pool_info.pool_asset, &who).saturating_sub(before))
+		})
+	}
+
+	fn get_redeem_proportion_amount(pool_id: StableAssetPoolId, amount: Balance) -> Option<Vec<Balance>> {
+		let pool_info = StableAsset::pool(pool_id)?;
+		StableAsset::get_redeem_proportion_amount(&pool_info, amount).map(|result| result.amounts)
+	}
+
+	fn get_redeem_single_amount(
+		pool_id: StableAssetPoolId,
+		amount: Balance,
+		output_index: PoolTokenIndex,
+	) -> Option<Balance> {
+		let pool_info = StableAsset::pool(pool_id)?;
+		let currency_id = *pool_info.assets.get(output_index as usize)?;
+		let asset_length = pool_info.assets.len() as u32;
+		let who = QuoteAccount::get();
+
+		Self::dry_run(|| {
+			Assets::mint_into(pool_info.pool_asset, &who, amount)?;
+
+			let before = Assets::balance(currency_id, &who);
+			StableAsset::redeem_single(&who, pool_id, amount, output_index, Balance::zero(), asset_length)?;
+			let received = Assets::balance(currency_id, &who).saturating_sub(before);
+			Ok(RebaseTokenAmountConvertor::convert_balance_back(received, currency_id)?)
+		})
+	}
+
+	fn get_redeem_multi_amount(pool_id: StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+		let pool_info = StableAsset::pool(pool_id)?;
+		let who = QuoteAccount::get();
+
+		Self::dry_run(|| {
+			// enough pool token to redeem the whole pool
+			Assets::mint_into(pool_info.pool_asset, &who, pool_info.total_supply)?;
+
+			let before = Assets::balance(pool_info.pool_asset, &who);
+			StableAsset::redeem_multi(&who, pool_id, amounts, Balance::max_value())?;
+			Ok(before.saturating_sub(Assets::balance(pool_info.pool_asset, &who)))
+		})
+	}
+}
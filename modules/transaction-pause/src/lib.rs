@@ -24,8 +24,12 @@ use frame_support::{
 	traits::{CallMetadata, Contains, GetCallMetadata, PalletInfoAccess},
 };
 use frame_system::pallet_prelude::*;
+use module_support::AutoPause;
 use sp_core::H160;
-use sp_runtime::DispatchResult;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchResult,
+};
 use sp_std::{prelude::*, vec::Vec};
 
 pub mod migrations;
@@ -34,8 +38,12 @@ mod tests;
 pub mod weights;
 
 pub use module::*;
+pub use module_support::PauseItem;
 pub use weights::WeightInfo;
 
+/// The function name which pauses all calls of a pallet.
+pub const WILDCARD_FUNCTION_NAME: &[u8] = b"*";
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -47,6 +55,13 @@ pub mod module {
 		/// The origin which may set filter.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which may pause items for a limited duration.
+		type GuardianOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum duration of a pause by `GuardianOrigin` or `AutoPause`.
+		#[pallet::constant]
+		type MaxGuardianPauseDuration: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -57,6 +72,10 @@ pub mod module {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// The expiry block must be in the future
+		InvalidExpiry,
+		/// The pause duration exceeds `MaxGuardianPauseDuration`
+		ExceedMaxPauseDuration,
 	}
 
 	#[pallet::event]
@@ -76,6 +95,21 @@ pub mod module {
 		EvmPrecompilePaused { address: H160 },
 		/// Unpaused EVM precompile
 		EvmPrecompileUnpaused { address: H160 },
+		/// Paused EVM contract
+		EvmContractPaused { address: H160 },
+		/// Unpaused EVM contract
+		EvmContractUnpaused { address: H160 },
+		/// Paused EVM contract function
+		EvmContractFunctionPaused { address: H160, selector: [u8; 4] },
+		/// Unpaused EVM contract function
+		EvmContractFunctionUnpaused { address: H160, selector: [u8; 4] },
+		/// The pause of the item will expire at the block
+		PauseExpirySet {
+			item: PauseItem,
+			expire_at: BlockNumberFor<T>,
+		},
+		/// The pause of the item expired
+		PauseExpired { item: PauseItem },
 	}
 
 	/// The paused transaction map
@@ -92,37 +126,64 @@ pub mod module {
 	#[pallet::getter(fn paused_evm_precompiles)]
 	pub type PausedEvmPrecompiles<T: Config> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
 
+	/// The paused EVM contract map
+	///
+	/// map (ContractAddress) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_evm_contracts)]
+	pub type PausedEvmContracts<T: Config> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+	/// The paused EVM contract function map
+	///
+	/// double_map (ContractAddress, FunctionSelector) => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_evm_contract_functions)]
+	pub type PausedEvmContractFunctions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, H160, Twox64Concat, [u8; 4], (), OptionQuery>;
+
+	/// The expiry block of time-limited pauses, items without expiry are paused until unpaused.
+	///
+	/// map (PauseItem) => Option<ExpireAt>
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expiries)]
+	pub type PauseExpiries<T: Config> = StorageMap<_, Twox64Concat, PauseItem, BlockNumberFor<T>, OptionQuery>;
+
+	/// The time-limited pauses to expire at the block. Entries whose expiry has been changed since
+	/// are ignored.
+	///
+	/// double_map (ExpireAt, PauseItem) => Option<()>
+	#[pallet::storage]
+	pub type ExpiringPauses<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, PauseItem, (), OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut count: u32 = 0;
+			for (item, _) in ExpiringPauses::<T>::drain_prefix(now) {
+				count = count.saturating_add(1);
+				if PauseExpiries::<T>::get(&item) == Some(now) {
+					Self::do_unpause(&item);
+					Self::deposit_event(Event::PauseExpired { item });
+				}
+			}
+			T::WeightInfo::on_initialize(count)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Pause the call `function_name` of `pallet_name` until unpaused, a `*` function name
+		/// pauses all calls of the pallet.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::pause_transaction())]
 		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
-
-			PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused {
-						pallet_name_bytes: pallet_name,
-						function_name_bytes: function_name,
-					});
-				}
-			});
-			Ok(())
+			Self::do_pause_permanently(PauseItem::Transaction(pallet_name, function_name))
 		}
 
 		#[pallet::call_index(1)]
@@ -133,12 +194,7 @@ pub mod module {
 			function_name: Vec<u8>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
-				Self::deposit_event(Event::TransactionUnpaused {
-					pallet_name_bytes: pallet_name,
-					function_name_bytes: function_name,
-				});
-			};
+			Self::do_unpause(&PauseItem::Transaction(pallet_name, function_name));
 			Ok(())
 		}
 
@@ -146,24 +202,218 @@ pub mod module {
 		#[pallet::weight(T::WeightInfo::pause_evm_precompile())]
 		pub fn pause_evm_precompile(origin: OriginFor<T>, address: H160) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			PausedEvmPrecompiles::<T>::mutate_exists(address, |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::EvmPrecompilePaused { address });
-				}
-			});
-			Ok(())
+			Self::do_pause_permanently(PauseItem::EvmPrecompile(address))
 		}
 
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::unpause_evm_precompile())]
 		pub fn unpause_evm_precompile(origin: OriginFor<T>, address: H160) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			if PausedEvmPrecompiles::<T>::take(address).is_some() {
-				Self::deposit_event(Event::EvmPrecompileUnpaused { address });
-			};
+			Self::do_unpause(&PauseItem::EvmPrecompile(address));
+			Ok(())
+		}
+
+		/// Pause all calls to the EVM contract `address` until unpaused.
+		///
+		/// Calls to precompiles are paused everywhere, calls to other contracts are only paused
+		/// as the target of an EVM extrinsic.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::pause_evm_contract())]
+		pub fn pause_evm_contract(origin: OriginFor<T>, address: H160) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_pause_permanently(PauseItem::EvmContract(address))
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::unpause_evm_contract())]
+		pub fn unpause_evm_contract(origin: OriginFor<T>, address: H160) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_unpause(&PauseItem::EvmContract(address));
+			Ok(())
+		}
+
+		/// Pause calls to the EVM contract `address` with the function `selector` until unpaused.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::pause_evm_contract_function())]
+		pub fn pause_evm_contract_function(origin: OriginFor<T>, address: H160, selector: [u8; 4]) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_pause_permanently(PauseItem::EvmContractFunction(address, selector))
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::unpause_evm_contract_function())]
+		pub fn unpause_evm_contract_function(origin: OriginFor<T>, address: H160, selector: [u8; 4]) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_unpause(&PauseItem::EvmContractFunction(address, selector));
+			Ok(())
+		}
+
+		/// Pause the item until the block `expire_at`, replacing any existing expiry.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::pause_until())]
+		pub fn pause_until(origin: OriginFor<T>, item: PauseItem, expire_at: BlockNumberFor<T>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				expire_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+			Self::ensure_pausable(&item)?;
+			Self::do_pause_until(item, expire_at);
 			Ok(())
 		}
+
+		/// Pause the item for `duration` blocks, limited by `MaxGuardianPauseDuration`.
+		///
+		/// Pauses set by `UpdateOrigin` without expiry or with a later expiry are kept.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::guardian_pause())]
+		pub fn guardian_pause(origin: OriginFor<T>, item: PauseItem, duration: BlockNumberFor<T>) -> DispatchResult {
+			T::GuardianOrigin::ensure_origin(origin)?;
+			ensure!(
+				duration <= T::MaxGuardianPauseDuration::get(),
+				Error::<T>::ExceedMaxPauseDuration
+			);
+			Self::do_guardian_pause(item, duration)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn ensure_pausable(item: &PauseItem) -> DispatchResult {
+		if let PauseItem::Transaction(pallet_name, _) = item {
+			// not allowed to pause calls of this pallet to ensure safe
+			let pallet_name_string = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+			ensure!(
+				pallet_name_string != <Self as PalletInfoAccess>::name(),
+				Error::<T>::CannotPause
+			);
+		}
+		Ok(())
+	}
+
+	fn is_item_paused(item: &PauseItem) -> bool {
+		match item {
+			PauseItem::Transaction(pallet_name, function_name) => {
+				PausedTransactions::<T>::contains_key((pallet_name, function_name))
+			}
+			PauseItem::EvmPrecompile(address) => PausedEvmPrecompiles::<T>::contains_key(address),
+			PauseItem::EvmContract(address) => PausedEvmContracts::<T>::contains_key(address),
+			PauseItem::EvmContractFunction(address, selector) => {
+				PausedEvmContractFunctions::<T>::contains_key(address, selector)
+			}
+		}
+	}
+
+	fn do_pause_permanently(item: PauseItem) -> DispatchResult {
+		Self::ensure_pausable(&item)?;
+		PauseExpiries::<T>::remove(&item);
+		Self::do_pause(item);
+		Ok(())
+	}
+
+	fn do_pause_until(item: PauseItem, expire_at: BlockNumberFor<T>) {
+		PauseExpiries::<T>::insert(&item, expire_at);
+		ExpiringPauses::<T>::insert(expire_at, &item, ());
+		Self::deposit_event(Event::PauseExpirySet {
+			item: item.clone(),
+			expire_at,
+		});
+		Self::do_pause(item);
+	}
+
+	fn do_guardian_pause(item: PauseItem, duration: BlockNumberFor<T>) -> DispatchResult {
+		ensure!(!duration.is_zero(), Error::<T>::InvalidExpiry);
+		Self::ensure_pausable(&item)?;
+
+		let expire_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+		if Self::is_item_paused(&item) {
+			match Self::pause_expiries(&item) {
+				// never shorten a pause without expiry or with a later expiry
+				None => return Ok(()),
+				Some(current) if current >= expire_at => return Ok(()),
+				_ => {}
+			}
+		}
+		Self::do_pause_until(item, expire_at);
+		Ok(())
+	}
+
+	fn do_pause(item: PauseItem) {
+		match item {
+			PauseItem::Transaction(pallet_name, function_name) => {
+				PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
+					if maybe_paused.is_none() {
+						*maybe_paused = Some(());
+						Self::deposit_event(Event::TransactionPaused {
+							pallet_name_bytes: pallet_name,
+							function_name_bytes: function_name,
+						});
+					}
+				});
+			}
+			PauseItem::EvmPrecompile(address) => {
+				PausedEvmPrecompiles::<T>::mutate_exists(address, |maybe_paused| {
+					if maybe_paused.is_none() {
+						*maybe_paused = Some(());
+						Self::deposit_event(Event::EvmPrecompilePaused { address });
+					}
+				});
+			}
+			PauseItem::EvmContract(address) => {
+				PausedEvmContracts::<T>::mutate_exists(address, |maybe_paused| {
+					if maybe_paused.is_none() {
+						*maybe_paused = Some(());
+						Self::deposit_event(Event::EvmContractPaused { address });
+					}
+				});
+			}
+			PauseItem::EvmContractFunction(address, selector) => {
+				PausedEvmContractFunctions::<T>::mutate_exists(address, selector, |maybe_paused| {
+					if maybe_paused.is_none() {
+						*maybe_paused = Some(());
+						Self::deposit_event(Event::EvmContractFunctionPaused { address, selector });
+					}
+				});
+			}
+		}
+	}
+
+	fn do_unpause(item: &PauseItem) {
+		PauseExpiries::<T>::remove(item);
+		match item {
+			PauseItem::Transaction(pallet_name, function_name) => {
+				if PausedTransactions::<T>::take((pallet_name, function_name)).is_some() {
+					Self::deposit_event(Event::TransactionUnpaused {
+						pallet_name_bytes: pallet_name.clone(),
+						function_name_bytes: function_name.clone(),
+					});
+				}
+			}
+			PauseItem::EvmPrecompile(address) => {
+				if PausedEvmPrecompiles::<T>::take(address).is_some() {
+					Self::deposit_event(Event::EvmPrecompileUnpaused { address: *address });
+				}
+			}
+			PauseItem::EvmContract(address) => {
+				if PausedEvmContracts::<T>::take(address).is_some() {
+					Self::deposit_event(Event::EvmContractUnpaused { address: *address });
+				}
+			}
+			PauseItem::EvmContractFunction(address, selector) => {
+				if PausedEvmContractFunctions::<T>::take(address, selector).is_some() {
+					Self::deposit_event(Event::EvmContractFunctionUnpaused {
+						address: *address,
+						selector: *selector,
+					});
+				}
+			}
+		}
+	}
+}
+
+impl<T: Config> AutoPause<BlockNumberFor<T>> for Pallet<T> {
+	fn auto_pause(item: PauseItem, duration: BlockNumberFor<T>) -> DispatchResult {
+		Self::do_guardian_pause(item, duration.min(T::MaxGuardianPauseDuration::get()))
 	}
 }
 
@@ -178,6 +428,7 @@ where
 			pallet_name,
 		} = call.get_call_metadata();
 		PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+			|| PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), WILDCARD_FUNCTION_NAME))
 	}
 }
 
@@ -186,4 +437,14 @@ impl<T: Config> module_support::PrecompilePauseFilter for PausedPrecompileFilter
 	fn is_paused(address: H160) -> bool {
 		PausedEvmPrecompiles::<T>::contains_key(address)
 	}
+
+	fn is_contract_call_paused(address: H160, input: &[u8]) -> bool {
+		PausedEvmContracts::<T>::contains_key(address)
+			|| input
+				.get(0..4)
+				.and_then(|selector| <[u8; 4]>::try_from(selector).ok())
+				.map_or(false, |selector| {
+					PausedEvmContractFunctions::<T>::contains_key(address, selector)
+				})
+	}
 }
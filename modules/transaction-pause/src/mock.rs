@@ -33,6 +33,7 @@ use sp_runtime::{traits::IdentityLookup, BuildStorage};
 
 pub type AccountId = u128;
 pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 
 mod transaction_pause {
@@ -104,11 +105,14 @@ impl orml_tokens::Config for Runtime {
 
 ord_parameter_types! {
 	pub const One: AccountId = 1;
+	pub const Two: AccountId = 2;
 }
 
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type GuardianOrigin = EnsureSignedBy<Two, AccountId>;
+	type MaxGuardianPauseDuration = ConstU64<100>;
 	type WeightInfo = ();
 }
 
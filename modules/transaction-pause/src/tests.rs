@@ -178,3 +178,265 @@ fn pause_and_unpause_evm_precompile_works() {
 		assert!(!PausedPrecompileFilter::<Runtime>::is_paused(one));
 	});
 }
+
+#[test]
+fn wildcard_pause_transaction_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			WILDCARD_FUNCTION_NAME.to_vec()
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(1),
+				b"TransactionPause".to_vec(),
+				WILDCARD_FUNCTION_NAME.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			WILDCARD_FUNCTION_NAME.to_vec()
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_and_unpause_evm_contract_works() {
+	use module_support::PrecompilePauseFilter;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract = H160::from_low_u64_be(0x1000);
+		let selector = [0xa9, 0x05, 0x9c, 0xbb];
+		let input = [&selector[..], &[0u8; 64][..]].concat();
+
+		assert_noop!(
+			TransactionPause::pause_evm_contract(RuntimeOrigin::signed(2), contract),
+			BadOrigin
+		);
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract, &input
+		));
+
+		assert_ok!(TransactionPause::pause_evm_contract(RuntimeOrigin::signed(1), contract));
+		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::EvmContractPaused {
+			address: contract,
+		}));
+		assert!(PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract,
+			&[]
+		));
+		assert!(!PausedPrecompileFilter::<Runtime>::is_paused(contract));
+
+		assert_ok!(TransactionPause::unpause_evm_contract(
+			RuntimeOrigin::signed(1),
+			contract
+		));
+		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::EvmContractUnpaused {
+			address: contract,
+		}));
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract, &input
+		));
+
+		assert_ok!(TransactionPause::pause_evm_contract_function(
+			RuntimeOrigin::signed(1),
+			contract,
+			selector
+		));
+		System::assert_last_event(RuntimeEvent::TransactionPause(
+			crate::Event::EvmContractFunctionPaused {
+				address: contract,
+				selector,
+			},
+		));
+		assert!(PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract, &input
+		));
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract,
+			&[0x70, 0xa0, 0x82, 0x31]
+		));
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract,
+			&selector[..3]
+		));
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			H160::from_low_u64_be(0x1001),
+			&input
+		));
+
+		assert_ok!(TransactionPause::unpause_evm_contract_function(
+			RuntimeOrigin::signed(1),
+			contract,
+			selector
+		));
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract, &input
+		));
+	});
+}
+
+#[test]
+fn pause_until_expires() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let item = PauseItem::Transaction(b"Balances".to_vec(), b"transfer_allow_death".to_vec());
+
+		assert_noop!(
+			TransactionPause::pause_until(RuntimeOrigin::signed(2), item.clone(), 10),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_until(RuntimeOrigin::signed(1), item.clone(), 1),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			TransactionPause::pause_until(
+				RuntimeOrigin::signed(1),
+				PauseItem::Transaction(b"TransactionPause".to_vec(), b"unpause_transaction".to_vec()),
+				10
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		assert_ok!(TransactionPause::pause_until(
+			RuntimeOrigin::signed(1),
+			item.clone(),
+			10
+		));
+		System::assert_has_event(RuntimeEvent::TransactionPause(crate::Event::PauseExpirySet {
+			item: item.clone(),
+			expire_at: 10,
+		}));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiries(&item), Some(10));
+
+		TransactionPause::on_initialize(9);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		TransactionPause::on_initialize(10);
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiries(&item), None);
+		System::assert_last_event(RuntimeEvent::TransactionPause(crate::Event::PauseExpired {
+			item: item.clone(),
+		}));
+
+		// a later permanent pause clears the expiry
+		assert_ok!(TransactionPause::pause_until(
+			RuntimeOrigin::signed(1),
+			item.clone(),
+			20
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer_allow_death".to_vec()
+		));
+		assert_eq!(TransactionPause::pause_expiries(&item), None);
+		TransactionPause::on_initialize(20);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(ExpiringPauses::<Runtime>::iter_prefix(20).count(), 0);
+	});
+}
+
+#[test]
+fn guardian_pause_works() {
+	use module_support::PrecompilePauseFilter;
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract = H160::from_low_u64_be(0x1000);
+		let item = PauseItem::EvmContract(contract);
+
+		assert_noop!(
+			TransactionPause::guardian_pause(RuntimeOrigin::signed(1), item.clone(), 10),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::guardian_pause(RuntimeOrigin::signed(BOB), item.clone(), 101),
+			Error::<Runtime>::ExceedMaxPauseDuration
+		);
+		assert_noop!(
+			TransactionPause::guardian_pause(RuntimeOrigin::signed(BOB), item.clone(), 0),
+			Error::<Runtime>::InvalidExpiry
+		);
+
+		assert_ok!(TransactionPause::guardian_pause(
+			RuntimeOrigin::signed(BOB),
+			item.clone(),
+			20
+		));
+		assert!(PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract,
+			&[]
+		));
+		assert_eq!(TransactionPause::pause_expiries(&item), Some(21));
+
+		// a shorter pause does not shorten the existing one
+		assert_ok!(TransactionPause::guardian_pause(
+			RuntimeOrigin::signed(BOB),
+			item.clone(),
+			10
+		));
+		assert_eq!(TransactionPause::pause_expiries(&item), Some(21));
+
+		// a longer pause extends it
+		assert_ok!(TransactionPause::guardian_pause(
+			RuntimeOrigin::signed(BOB),
+			item.clone(),
+			30
+		));
+		assert_eq!(TransactionPause::pause_expiries(&item), Some(31));
+
+		TransactionPause::on_initialize(21);
+		assert!(PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract,
+			&[]
+		));
+		TransactionPause::on_initialize(31);
+		assert!(!PausedPrecompileFilter::<Runtime>::is_contract_call_paused(
+			contract,
+			&[]
+		));
+
+		// guardian can not turn a pause without expiry into a time-limited one
+		assert_ok!(TransactionPause::pause_evm_contract(RuntimeOrigin::signed(1), contract));
+		assert_ok!(TransactionPause::guardian_pause(
+			RuntimeOrigin::signed(BOB),
+			item.clone(),
+			30
+		));
+		assert_eq!(TransactionPause::pause_expiries(&item), None);
+	});
+}
+
+#[test]
+fn auto_pause_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let item = PauseItem::Transaction(b"Tokens".to_vec(), WILDCARD_FUNCTION_NAME.to_vec());
+
+		// duration is capped by MaxGuardianPauseDuration
+		assert_ok!(<TransactionPause as AutoPause<u64>>::auto_pause(item.clone(), 1_000));
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiries(&item), Some(101));
+
+		assert_noop!(
+			<TransactionPause as AutoPause<u64>>::auto_pause(
+				PauseItem::Transaction(b"TransactionPause".to_vec(), WILDCARD_FUNCTION_NAME.to_vec()),
+				10
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		TransactionPause::on_initialize(101);
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+	});
+}
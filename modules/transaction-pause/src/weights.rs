@@ -51,6 +51,13 @@ pub trait WeightInfo {
 	fn unpause_transaction() -> Weight;
 	fn pause_evm_precompile() -> Weight;
 	fn unpause_evm_precompile() -> Weight;
+	fn pause_evm_contract() -> Weight;
+	fn unpause_evm_contract() -> Weight;
+	fn pause_evm_contract_function() -> Weight;
+	fn unpause_evm_contract_function() -> Weight;
+	fn pause_until() -> Weight;
+	fn guardian_pause() -> Weight;
+	fn on_initialize(c: u32, ) -> Weight;
 }
 
 /// Weights for module_transaction_pause using the Acala node and recommended hardware.
@@ -76,6 +83,43 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	fn pause_evm_contract() -> Weight {
+		Weight::from_parts(19_102_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn unpause_evm_contract() -> Weight {
+		Weight::from_parts(20_314_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn pause_evm_contract_function() -> Weight {
+		Weight::from_parts(19_563_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn unpause_evm_contract_function() -> Weight {
+		Weight::from_parts(20_587_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	fn pause_until() -> Weight {
+		Weight::from_parts(24_871_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn guardian_pause() -> Weight {
+		Weight::from_parts(27_412_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(3_152_000, 0)
+			.saturating_add(Weight::from_parts(9_846_000, 0).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -100,4 +144,41 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	fn pause_evm_contract() -> Weight {
+		Weight::from_parts(19_102_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn unpause_evm_contract() -> Weight {
+		Weight::from_parts(20_314_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn pause_evm_contract_function() -> Weight {
+		Weight::from_parts(19_563_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn unpause_evm_contract_function() -> Weight {
+		Weight::from_parts(20_587_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	fn pause_until() -> Weight {
+		Weight::from_parts(24_871_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn guardian_pause() -> Weight {
+		Weight::from_parts(27_412_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn on_initialize(c: u32, ) -> Weight {
+		Weight::from_parts(3_152_000, 0)
+			.saturating_add(Weight::from_parts(9_846_000, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
+	}
 }
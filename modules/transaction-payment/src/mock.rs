@@ -198,9 +198,12 @@ impl module_dex::Config for Runtime {
 impl module_aggregated_dex::Config for Runtime {
 	type DEX = DEXModule;
 	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+	type StableAssetQuote = ();
 	type GovernanceOrigin = EnsureSignedBy<Zero, AccountId>;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxRouteCandidates = ConstU32<16>;
+	type MaxRouteSearchNodes = ConstU32<64>;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,441 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Vesting Module
+//!
+//! Vesting schedules for any `CurrencyId`. Vesting balances are locked with `MultiLockableCurrency`
+//! per `(AccountId, CurrencyId)`, and released linearly after an optional cliff. Schedules of the
+//! same currency can be merged, and the unvested balance can be revoked by `RevokeOrigin`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use orml_traits::{LockIdentifier, MultiCurrency, MultiLockableCurrency};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32Bit, BlockNumberProvider, CheckedAdd, Saturating, StaticLookup, Zero},
+	ArithmeticError, Rounding, SaturatedConversion,
+};
+use sp_std::vec::Vec;
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub const VESTING_LOCK_ID: LockIdentifier = *b"aca/vest";
+
+/// The vesting schedule.
+///
+/// Nothing is released before `start + cliff`. From then on the balance is released linearly
+/// as if it had started vesting at `start`, and is fully released at `start + duration`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<BlockNumber, Balance> {
+	/// Vesting starting block
+	pub start: BlockNumber,
+	/// Number of blocks after `start` before anything is released
+	pub cliff: BlockNumber,
+	/// Number of blocks after `start` until the whole balance is released
+	pub duration: BlockNumber,
+	/// Total amount of the vesting schedule
+	pub total: Balance,
+}
+
+impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32Bit + Copy> VestingSchedule<BlockNumber, Balance> {
+	/// The block at which the whole balance is released.
+	pub fn end(&self) -> BlockNumber {
+		self.start.saturating_add(self.duration)
+	}
+
+	/// Returns the balance still locked at `time`.
+	pub fn locked_amount(&self, time: BlockNumber) -> Balance {
+		if time < self.start.saturating_add(self.cliff) {
+			return self.total;
+		}
+		if time >= self.end() {
+			return Zero::zero();
+		}
+
+		let elapsed: u128 = time.saturating_sub(self.start).saturated_into();
+		let duration: u128 = self.duration.saturated_into();
+		let total: u128 = self.total.saturated_into();
+		// duration is not zero since `time < end`
+		let released = multiply_by_rational_with_rounding(total, elapsed, duration, Rounding::Down).unwrap_or(total);
+		total.saturating_sub(released).saturated_into()
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub type VestingScheduleOf<T> = VestingSchedule<BlockNumberFor<T>, Balance>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency to lock the vesting balances.
+		type Currency: MultiLockableCurrency<
+			Self::AccountId,
+			CurrencyId = CurrencyId,
+			Balance = Balance,
+			Moment = BlockNumberFor<Self>,
+		>;
+
+		/// The minimum amount transferred to call `vested_transfer`.
+		#[pallet::constant]
+		type MinVestedTransfer: Get<Balance>;
+
+		/// Required origin for vested transfer.
+		type VestedTransferOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The origin which may revoke the unvested balance of vesting schedules.
+		type RevokeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum vesting schedules per `(AccountId, CurrencyId)`.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// The block number provider.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Vesting duration is zero or shorter than the cliff.
+		InvalidVestingSchedule,
+		/// The vested transfer amount is too low.
+		AmountLow,
+		/// Failed because the maximum vesting schedules was exceeded.
+		MaxVestingSchedulesExceeded,
+		/// The vesting schedule doesn't exist.
+		VestingScheduleNotFound,
+		/// Can not merge a vesting schedule with itself.
+		SameVestingSchedule,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Added a new vesting schedule.
+		VestingScheduleAdded {
+			from: T::AccountId,
+			to: T::AccountId,
+			currency_id: CurrencyId,
+			vesting_schedule: VestingScheduleOf<T>,
+		},
+		/// Claimed vesting, `locked` is the balance still locked.
+		Claimed {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			locked: Balance,
+		},
+		/// Two vesting schedules were merged into one.
+		VestingSchedulesMerged {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			vesting_schedule: VestingScheduleOf<T>,
+		},
+		/// The unvested balance was revoked and transferred to `dest`.
+		VestingRevoked {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			dest: T::AccountId,
+			amount: Balance,
+		},
+	}
+
+	/// Vesting schedules of an account under a currency.
+	///
+	/// VestingSchedules: double_map AccountId, CurrencyId => Vec<VestingSchedule>
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		CurrencyId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Release the vested balance of `currency_id` of the caller.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+		pub fn claim(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_claim(&who, currency_id)?;
+			Ok(())
+		}
+
+		/// Release the vested balance of `currency_id` of `dest`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+		pub fn claim_for(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(dest)?;
+			Self::do_claim(&who, currency_id)?;
+			Ok(())
+		}
+
+		/// Transfer `schedule.total` of `currency_id` to `dest` and lock it under the vesting
+		/// schedule.
+		///
+		/// This call requires `VestedTransferOrigin`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let from = T::VestedTransferOrigin::ensure_origin(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_vested_transfer(&from, &to, currency_id, schedule)
+		}
+
+		/// Merge two vesting schedules of `currency_id` of the caller into one.
+		///
+		/// The merged schedule locks the sum of the balances still locked by both schedules,
+		/// starts now, keeps the later cliff and ends at the later end.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_schedules(T::MaxVestingSchedules::get()))]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_merge_schedules(&who, currency_id, schedule1_index, schedule2_index)
+		}
+
+		/// Remove all vesting schedules of `currency_id` of `who`, and transfer the unvested
+		/// balance to `dest`. The vested balance stays with `who`.
+		///
+		/// This call requires `RevokeOrigin`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke(T::MaxVestingSchedules::get()))]
+		pub fn revoke(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			dest: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::RevokeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_revoke(&who, currency_id, &dest)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the balance of `currency_id` still locked by the vesting schedules of `who`.
+	pub fn locked_balance(who: &T::AccountId, currency_id: CurrencyId) -> Balance {
+		let now = T::BlockNumberProvider::current_block_number();
+		Self::vesting_schedules(who, currency_id)
+			.iter()
+			.fold(Zero::zero(), |acc: Balance, schedule| {
+				acc.saturating_add(schedule.locked_amount(now))
+			})
+	}
+
+	/// Update the vesting lock of `who` and remove finished schedules. Returns the balance
+	/// still locked.
+	pub fn do_claim(who: &T::AccountId, currency_id: CurrencyId) -> Result<Balance, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut schedules = Self::vesting_schedules(who, currency_id);
+		schedules.retain(|schedule| !schedule.locked_amount(now).is_zero());
+		let locked = schedules.iter().fold(Zero::zero(), |acc: Balance, schedule| {
+			acc.saturating_add(schedule.locked_amount(now))
+		});
+
+		Self::update_lock(who, currency_id, locked)?;
+		if schedules.is_empty() {
+			VestingSchedules::<T>::remove(who, currency_id);
+		} else {
+			VestingSchedules::<T>::insert(who, currency_id, schedules);
+		}
+
+		Self::deposit_event(Event::Claimed {
+			who: who.clone(),
+			currency_id,
+			locked,
+		});
+		Ok(locked)
+	}
+
+	pub fn do_vested_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		currency_id: CurrencyId,
+		schedule: VestingScheduleOf<T>,
+	) -> DispatchResult {
+		Self::ensure_valid_schedule(&schedule)?;
+		ensure!(schedule.total >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+
+		let locked = Self::locked_balance(to, currency_id)
+			.checked_add(schedule.total)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		VestingSchedules::<T>::try_mutate(to, currency_id, |schedules| -> DispatchResult {
+			schedules
+				.try_push(schedule.clone())
+				.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+			<T::Currency as MultiCurrency<T::AccountId>>::transfer(currency_id, from, to, schedule.total)?;
+			Self::update_lock(to, currency_id, locked)
+		})?;
+
+		Self::deposit_event(Event::VestingScheduleAdded {
+			from: from.clone(),
+			to: to.clone(),
+			currency_id,
+			vesting_schedule: schedule,
+		});
+		Ok(())
+	}
+
+	fn do_merge_schedules(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		schedule1_index: u32,
+		schedule2_index: u32,
+	) -> DispatchResult {
+		ensure!(schedule1_index != schedule2_index, Error::<T>::SameVestingSchedule);
+		let now = T::BlockNumberProvider::current_block_number();
+
+		let merged = VestingSchedules::<T>::try_mutate(
+			who,
+			currency_id,
+			|schedules| -> Result<VestingScheduleOf<T>, DispatchError> {
+				let (index1, index2) = (schedule1_index as usize, schedule2_index as usize);
+				let schedule1 = schedules
+					.get(index1)
+					.ok_or(Error::<T>::VestingScheduleNotFound)?
+					.clone();
+				let schedule2 = schedules
+					.get(index2)
+					.ok_or(Error::<T>::VestingScheduleNotFound)?
+					.clone();
+
+				// remove the higher index first to keep the lower one valid
+				schedules.remove(index1.max(index2));
+				schedules.remove(index1.min(index2));
+
+				let start = now.max(schedule1.start).max(schedule2.start);
+				let end = schedule1.end().max(schedule2.end()).max(start);
+				let cliff_end = schedule1
+					.start
+					.saturating_add(schedule1.cliff)
+					.max(schedule2.start.saturating_add(schedule2.cliff));
+				let duration = end.saturating_sub(start);
+				let merged = VestingSchedule {
+					start,
+					cliff: cliff_end.saturating_sub(start).min(duration),
+					duration,
+					total: schedule1
+						.locked_amount(now)
+						.saturating_add(schedule2.locked_amount(now)),
+				};
+
+				// a fully vested merged schedule is dropped, the lock is updated below
+				if !merged.total.is_zero() && !merged.duration.is_zero() {
+					schedules
+						.try_push(merged.clone())
+						.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+				}
+				Ok(merged)
+			},
+		)?;
+
+		Self::do_claim(who, currency_id)?;
+
+		Self::deposit_event(Event::VestingSchedulesMerged {
+			who: who.clone(),
+			currency_id,
+			vesting_schedule: merged,
+		});
+		Ok(())
+	}
+
+	fn do_revoke(who: &T::AccountId, currency_id: CurrencyId, dest: &T::AccountId) -> DispatchResult {
+		ensure!(
+			VestingSchedules::<T>::contains_key(who, currency_id),
+			Error::<T>::VestingScheduleNotFound
+		);
+		let unvested = Self::locked_balance(who, currency_id);
+
+		VestingSchedules::<T>::remove(who, currency_id);
+		T::Currency::remove_lock(VESTING_LOCK_ID, currency_id, who)?;
+		<T::Currency as MultiCurrency<T::AccountId>>::transfer(currency_id, who, dest, unvested)?;
+
+		Self::deposit_event(Event::VestingRevoked {
+			who: who.clone(),
+			currency_id,
+			dest: dest.clone(),
+			amount: unvested,
+		});
+		Ok(())
+	}
+
+	fn update_lock(who: &T::AccountId, currency_id: CurrencyId, locked: Balance) -> DispatchResult {
+		if locked.is_zero() {
+			T::Currency::remove_lock(VESTING_LOCK_ID, currency_id, who)
+		} else {
+			T::Currency::set_lock(VESTING_LOCK_ID, currency_id, who, locked)
+		}
+	}
+
+	fn ensure_valid_schedule(schedule: &VestingScheduleOf<T>) -> DispatchResult {
+		ensure!(
+			!schedule.duration.is_zero() && schedule.cliff <= schedule.duration,
+			Error::<T>::InvalidVestingSchedule
+		);
+		ensure!(!schedule.total.is_zero(), Error::<T>::AmountLow);
+		Ok(())
+	}
+
+	/// All vesting schedules of `who` under `currency_id`.
+	pub fn schedules_of(who: &T::AccountId, currency_id: CurrencyId) -> Vec<VestingScheduleOf<T>> {
+		Self::vesting_schedules(who, currency_id).into_inner()
+	}
+}
@@ -0,0 +1,254 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the vesting module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+fn schedule(
+	start: BlockNumber,
+	cliff: BlockNumber,
+	duration: BlockNumber,
+	total: Balance,
+) -> VestingScheduleOf<Runtime> {
+	VestingSchedule {
+		start,
+		cliff,
+		duration,
+		total,
+	}
+}
+
+#[test]
+fn locked_amount_works() {
+	let s = schedule(10, 20, 100, 1_000);
+	assert_eq!(s.end(), 110);
+	assert_eq!(s.locked_amount(0), 1_000);
+	assert_eq!(s.locked_amount(29), 1_000);
+	// released linearly from start once the cliff has passed
+	assert_eq!(s.locked_amount(30), 800);
+	assert_eq!(s.locked_amount(60), 500);
+	assert_eq!(s.locked_amount(109), 10);
+	assert_eq!(s.locked_amount(110), 0);
+	assert_eq!(s.locked_amount(1_000), 0);
+}
+
+#[test]
+fn vested_transfer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(ALICE), BOB, DOT, schedule(1, 0, 10, 100)),
+			BadOrigin
+		);
+
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			DOT,
+			schedule(1, 5, 10, 100)
+		));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingScheduleAdded {
+			from: TREASURY,
+			to: BOB,
+			currency_id: DOT,
+			vesting_schedule: schedule(1, 5, 10, 100),
+		}));
+		assert_eq!(
+			Vesting::vesting_schedules(&BOB, DOT).into_inner(),
+			vec![schedule(1, 5, 10, 100)]
+		);
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 100);
+		assert_eq!(Tokens::locks(&BOB, DOT)[0].amount, 100);
+		assert_noop!(
+			Currencies::transfer(RuntimeOrigin::signed(BOB), ALICE, DOT, 1),
+			orml_tokens::Error::<Runtime>::LiquidityRestrictions
+		);
+
+		// native currency works the same way
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			ACA,
+			schedule(1, 0, 10, 100)
+		));
+		assert_eq!(Balances::locks(&BOB)[0].amount, 100);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_for_invalid_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(TREASURY), BOB, DOT, schedule(1, 0, 0, 100)),
+			Error::<Runtime>::InvalidVestingSchedule
+		);
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(TREASURY), BOB, DOT, schedule(1, 11, 10, 100)),
+			Error::<Runtime>::InvalidVestingSchedule
+		);
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(TREASURY), BOB, DOT, schedule(1, 0, 10, 4)),
+			Error::<Runtime>::AmountLow
+		);
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(TREASURY), BOB, DOT, schedule(1, 0, 10, 10_000)),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_if_max_schedules_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(Vesting::vested_transfer(
+				RuntimeOrigin::signed(TREASURY),
+				BOB,
+				DOT,
+				schedule(1, 0, 10, 10)
+			));
+		}
+		assert_noop!(
+			Vesting::vested_transfer(RuntimeOrigin::signed(TREASURY), BOB, DOT, schedule(1, 0, 10, 10)),
+			Error::<Runtime>::MaxVestingSchedulesExceeded
+		);
+		// the limit is per currency
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			ACA,
+			schedule(1, 0, 10, 10)
+		));
+	});
+}
+
+#[test]
+fn claim_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			DOT,
+			schedule(1, 4, 10, 100)
+		));
+
+		// still in cliff
+		System::set_block_number(4);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB), DOT));
+		assert_eq!(Tokens::locks(&BOB, DOT)[0].amount, 100);
+
+		System::set_block_number(6);
+		assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB), DOT));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::Claimed {
+			who: BOB,
+			currency_id: DOT,
+			locked: 50,
+		}));
+		assert_eq!(Tokens::locks(&BOB, DOT)[0].amount, 50);
+		assert_ok!(Currencies::transfer(RuntimeOrigin::signed(BOB), ALICE, DOT, 50));
+
+		// anyone can claim for others
+		System::set_block_number(11);
+		assert_ok!(Vesting::claim_for(RuntimeOrigin::signed(ALICE), BOB, DOT));
+		assert_eq!(Tokens::locks(&BOB, DOT).len(), 0);
+		assert!(!VestingSchedules::<Runtime>::contains_key(&BOB, DOT));
+		assert_eq!(Vesting::locked_balance(&BOB, DOT), 0);
+	});
+}
+
+#[test]
+fn merge_schedules_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 100)
+		));
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			DOT,
+			schedule(5, 10, 20, 200)
+		));
+
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), DOT, 0, 0),
+			Error::<Runtime>::SameVestingSchedule
+		);
+		assert_noop!(
+			Vesting::merge_schedules(RuntimeOrigin::signed(BOB), DOT, 0, 2),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+
+		System::set_block_number(6);
+		// 50 locked by the first, 200 locked by the second
+		assert_eq!(Vesting::locked_balance(&BOB, DOT), 250);
+		assert_ok!(Vesting::merge_schedules(RuntimeOrigin::signed(BOB), DOT, 1, 0));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingSchedulesMerged {
+			who: BOB,
+			currency_id: DOT,
+			vesting_schedule: schedule(6, 9, 19, 250),
+		}));
+		assert_eq!(
+			Vesting::vesting_schedules(&BOB, DOT).into_inner(),
+			vec![schedule(6, 9, 19, 250)]
+		);
+		assert_eq!(Vesting::locked_balance(&BOB, DOT), 250);
+		assert_eq!(Tokens::locks(&BOB, DOT)[0].amount, 250);
+	});
+}
+
+#[test]
+fn revoke_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::signed(TREASURY),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 100)
+		));
+
+		System::set_block_number(4);
+		assert_noop!(
+			Vesting::revoke(RuntimeOrigin::signed(TREASURY), BOB, DOT, TREASURY),
+			BadOrigin
+		);
+		assert_noop!(
+			Vesting::revoke(RuntimeOrigin::root(), CHARLIE, DOT, TREASURY),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+
+		assert_ok!(Vesting::revoke(RuntimeOrigin::root(), BOB, DOT, TREASURY));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingRevoked {
+			who: BOB,
+			currency_id: DOT,
+			dest: TREASURY,
+			amount: 70,
+		}));
+		assert_eq!(Currencies::free_balance(DOT, &BOB), 30);
+		assert_eq!(Currencies::free_balance(DOT, &TREASURY), 970);
+		assert_eq!(Tokens::locks(&BOB, DOT).len(), 0);
+		assert!(!VestingSchedules::<Runtime>::contains_key(&BOB, DOT));
+	});
+}
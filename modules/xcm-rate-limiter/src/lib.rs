@@ -0,0 +1,573 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # XCM Rate Limiter Module
+//!
+//! ## Overview
+//!
+//! Limits how fast assets can flow into and out of the chain through XCM. Every `CurrencyId` can
+//! have an inflow and an outflow quota, recovering linearly over a window of blocks, and a
+//! circuit breaker which halts all of its cross-chain transfers.
+//!
+//! The limits are enforced by `RateLimitedTransactor`, which wraps the asset transactor of the
+//! XCM executor:
+//!   - `inflow`: assets deposited by messages from other chains
+//!   - `outflow`: assets withdrawn or transferred by messages originated on this chain, e.g. the
+//!     transfers of `orml_xtokens`, `pallet_xcm` and the xtokens precompile
+//!
+//! Inflows over the quota are either rejected or queued in the module account until governance
+//! releases or cancels them. Outflows over the quota are always rejected.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, Convert, One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, FixedPointNumber, FixedU128, RuntimeDebug,
+};
+use sp_std::marker::PhantomData;
+use xcm::v3::{Error as XcmError, Fungibility, MultiAsset, MultiLocation, Result as XcmResult, XcmContext};
+use xcm_executor::{
+	traits::{ConvertLocation, Error as XcmExecutorError, TransactAsset},
+	Assets,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "xcm::rate-limiter";
+
+pub type QueueId = u64;
+
+/// The direction of a cross-chain flow.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum FlowDirection {
+	/// Assets entering the chain
+	Inflow,
+	/// Assets leaving the chain
+	Outflow,
+}
+
+/// The action for a flow over the quota.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum OverLimitAction {
+	/// Fail the XCM instruction
+	Reject,
+	/// Hold the assets in the module account until governance releases them. Inflows only.
+	Queue,
+}
+
+/// The quota of a flow.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RateLimit<BlockNumber> {
+	/// The maximum amount that can flow within `window`
+	pub quota: Balance,
+	/// The number of blocks for the used quota to fully recover
+	pub window: BlockNumber,
+	/// The action for a flow over the quota
+	pub over_limit: OverLimitAction,
+}
+
+/// The used quota of a flow.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct FlowState<BlockNumber> {
+	/// The used quota at `last_updated`
+	pub used: Balance,
+	/// The block at which `used` was last updated
+	pub last_updated: BlockNumber,
+}
+
+/// An inflow held in the module account until governance releases it.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct QueuedInflow<AccountId, BlockNumber> {
+	/// The currency of the inflow
+	pub currency_id: CurrencyId,
+	/// The amount of the inflow
+	pub amount: Balance,
+	/// The account the inflow was deposited to
+	pub beneficiary: AccountId,
+	/// The block at which the inflow was queued
+	pub queued_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency to hold the queued inflows.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The origin which may update the rate limits and release the queued inflows.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The module account holding the queued inflows.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The account receiving the cancelled inflows.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The window is zero, or an outflow is set to be queued
+		InvalidRateLimit,
+		/// The queued inflow does not exist
+		QueuedInflowNotFound,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The rate limit of a flow is updated.
+		RateLimitUpdated {
+			currency_id: CurrencyId,
+			direction: FlowDirection,
+			limit: Option<RateLimit<BlockNumberFor<T>>>,
+		},
+		/// The circuit breaker of a currency is tripped or reset.
+		CircuitBreakerUpdated { currency_id: CurrencyId, tripped: bool },
+		/// An inflow over the quota is queued.
+		InflowQueued {
+			id: QueueId,
+			currency_id: CurrencyId,
+			amount: Balance,
+			beneficiary: T::AccountId,
+		},
+		/// A queued inflow is released to its beneficiary.
+		QueuedInflowReleased {
+			id: QueueId,
+			currency_id: CurrencyId,
+			amount: Balance,
+			beneficiary: T::AccountId,
+		},
+		/// A queued inflow is cancelled and sent to the treasury.
+		QueuedInflowCancelled {
+			id: QueueId,
+			currency_id: CurrencyId,
+			amount: Balance,
+		},
+	}
+
+	/// The rate limits of flows.
+	///
+	/// RateLimits: double_map CurrencyId, FlowDirection => Option<RateLimit>
+	#[pallet::storage]
+	#[pallet::getter(fn rate_limits)]
+	pub type RateLimits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		Twox64Concat,
+		FlowDirection,
+		RateLimit<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The used quotas of the rate limited flows.
+	///
+	/// Flows: double_map CurrencyId, FlowDirection => FlowState
+	#[pallet::storage]
+	#[pallet::getter(fn flows)]
+	pub type Flows<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CurrencyId,
+		Twox64Concat,
+		FlowDirection,
+		FlowState<BlockNumberFor<T>>,
+		ValueQuery,
+	>;
+
+	/// The currencies whose cross-chain transfers are halted.
+	///
+	/// CircuitBreakers: map CurrencyId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breakers)]
+	pub type CircuitBreakers<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, bool, ValueQuery>;
+
+	/// The next id of queued inflows.
+	///
+	/// NextQueueId: QueueId
+	#[pallet::storage]
+	#[pallet::getter(fn next_queue_id)]
+	pub type NextQueueId<T: Config> = StorageValue<_, QueueId, ValueQuery>;
+
+	/// The inflows held for governance release.
+	///
+	/// QueuedInflows: map QueueId => Option<QueuedInflow>
+	#[pallet::storage]
+	#[pallet::getter(fn queued_inflows)]
+	pub type QueuedInflows<T: Config> =
+		StorageMap<_, Twox64Concat, QueueId, QueuedInflow<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set or remove the rate limit of a flow. The used quota of the flow is reset.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_rate_limit())]
+		pub fn set_rate_limit(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			direction: FlowDirection,
+			limit: Option<RateLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if let Some(limit) = &limit {
+				ensure!(
+					!limit.window.is_zero()
+						&& !(direction == FlowDirection::Outflow && limit.over_limit == OverLimitAction::Queue),
+					Error::<T>::InvalidRateLimit
+				);
+			}
+
+			RateLimits::<T>::set(currency_id, direction, limit.clone());
+			Flows::<T>::remove(currency_id, direction);
+
+			Self::deposit_event(Event::RateLimitUpdated {
+				currency_id,
+				direction,
+				limit,
+			});
+			Ok(())
+		}
+
+		/// Trip or reset the circuit breaker of a currency. All the cross-chain transfers of a
+		/// currency are rejected while its circuit breaker is tripped.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_circuit_breaker())]
+		pub fn set_circuit_breaker(origin: OriginFor<T>, currency_id: CurrencyId, tripped: bool) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			if tripped {
+				CircuitBreakers::<T>::insert(currency_id, true);
+			} else {
+				CircuitBreakers::<T>::remove(currency_id);
+			}
+
+			Self::deposit_event(Event::CircuitBreakerUpdated { currency_id, tripped });
+			Ok(())
+		}
+
+		/// Release a queued inflow to its beneficiary.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::release_queued_inflow())]
+		pub fn release_queued_inflow(origin: OriginFor<T>, #[pallet::compact] id: QueueId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let inflow = QueuedInflows::<T>::take(id).ok_or(Error::<T>::QueuedInflowNotFound)?;
+			T::Currency::transfer(
+				inflow.currency_id,
+				&Self::account_id(),
+				&inflow.beneficiary,
+				inflow.amount,
+			)?;
+
+			Self::deposit_event(Event::QueuedInflowReleased {
+				id,
+				currency_id: inflow.currency_id,
+				amount: inflow.amount,
+				beneficiary: inflow.beneficiary,
+			});
+			Ok(())
+		}
+
+		/// Cancel a queued inflow and send it to the treasury.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::cancel_queued_inflow())]
+		pub fn cancel_queued_inflow(origin: OriginFor<T>, #[pallet::compact] id: QueueId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let inflow = QueuedInflows::<T>::take(id).ok_or(Error::<T>::QueuedInflowNotFound)?;
+			T::Currency::transfer(
+				inflow.currency_id,
+				&Self::account_id(),
+				&T::TreasuryAccount::get(),
+				inflow.amount,
+			)?;
+
+			Self::deposit_event(Event::QueuedInflowCancelled {
+				id,
+				currency_id: inflow.currency_id,
+				amount: inflow.amount,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The amount that can still flow in `direction` in the current window, `None` if the flow
+	/// is not limited.
+	pub fn remaining_quota(currency_id: CurrencyId, direction: FlowDirection) -> Option<Balance> {
+		if Self::circuit_breakers(currency_id) {
+			return Some(Zero::zero());
+		}
+
+		Self::rate_limits(currency_id, direction).map(|limit| {
+			let used = Self::current_usage(&limit, &Self::flows(currency_id, direction));
+			limit.quota.saturating_sub(used)
+		})
+	}
+
+	/// The used quota decays linearly and is fully recovered after `window` blocks.
+	fn current_usage(limit: &RateLimit<BlockNumberFor<T>>, state: &FlowState<BlockNumberFor<T>>) -> Balance {
+		let elapsed = frame_system::Pallet::<T>::block_number().saturating_sub(state.last_updated);
+		if elapsed >= limit.window {
+			return Zero::zero();
+		}
+
+		let recovered = FixedU128::saturating_from_rational(
+			UniqueSaturatedInto::<u128>::unique_saturated_into(elapsed),
+			UniqueSaturatedInto::<u128>::unique_saturated_into(limit.window),
+		)
+		.saturating_mul_int(limit.quota);
+		state.used.saturating_sub(recovered)
+	}
+
+	/// Check a flow against the circuit breaker and the rate limit. Returns the used quota to
+	/// record once the flow succeeded, or the action to take if the flow is over the quota.
+	pub(crate) fn check_flow(
+		currency_id: CurrencyId,
+		direction: FlowDirection,
+		amount: Balance,
+	) -> Result<Option<FlowState<BlockNumberFor<T>>>, OverLimitAction> {
+		if Self::circuit_breakers(currency_id) {
+			return Err(OverLimitAction::Reject);
+		}
+
+		match Self::rate_limits(currency_id, direction) {
+			Some(limit) => {
+				let used = Self::current_usage(&limit, &Self::flows(currency_id, direction)).saturating_add(amount);
+				if used > limit.quota {
+					Err(limit.over_limit)
+				} else {
+					Ok(Some(FlowState {
+						used,
+						last_updated: frame_system::Pallet::<T>::block_number(),
+					}))
+				}
+			}
+			None => Ok(None),
+		}
+	}
+
+	pub(crate) fn record_flow(
+		currency_id: CurrencyId,
+		direction: FlowDirection,
+		state: Option<FlowState<BlockNumberFor<T>>>,
+	) {
+		if let Some(state) = state {
+			Flows::<T>::insert(currency_id, direction, state);
+		}
+	}
+
+	pub(crate) fn queue_inflow(currency_id: CurrencyId, amount: Balance, beneficiary: T::AccountId) -> DispatchResult {
+		T::Currency::deposit(currency_id, &Self::account_id(), amount)?;
+
+		let id = NextQueueId::<T>::try_mutate(|current| -> Result<QueueId, DispatchError> {
+			let id = *current;
+			*current = current.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+			Ok(id)
+		})?;
+		QueuedInflows::<T>::insert(
+			id,
+			QueuedInflow {
+				currency_id,
+				amount,
+				beneficiary: beneficiary.clone(),
+				queued_at: frame_system::Pallet::<T>::block_number(),
+			},
+		);
+
+		Self::deposit_event(Event::InflowQueued {
+			id,
+			currency_id,
+			amount,
+			beneficiary,
+		});
+		Ok(())
+	}
+}
+
+/// Wraps the asset transactor of the XCM executor to enforce the rate limits and the circuit
+/// breakers on the fungible assets.
+///
+/// Deposits of messages not originated on this chain are inflows. Withdrawals and transfers of
+/// messages originated on this chain are outflows.
+pub struct RateLimitedTransactor<T, Transactor, CurrencyIdConvert, AccountIdConvert>(
+	PhantomData<(T, Transactor, CurrencyIdConvert, AccountIdConvert)>,
+);
+
+impl<T, Transactor, CurrencyIdConvert, AccountIdConvert>
+	RateLimitedTransactor<T, Transactor, CurrencyIdConvert, AccountIdConvert>
+where
+	T: Config,
+	CurrencyIdConvert: Convert<MultiAsset, Option<CurrencyId>>,
+{
+	fn fungible(what: &MultiAsset) -> Option<(CurrencyId, Balance)> {
+		match what.fun {
+			Fungibility::Fungible(amount) => {
+				CurrencyIdConvert::convert(what.clone()).map(|currency_id| (currency_id, amount))
+			}
+			Fungibility::NonFungible(_) => None,
+		}
+	}
+
+	fn is_local_origin(context: Option<&XcmContext>) -> bool {
+		matches!(
+			context.and_then(|context| context.origin),
+			Some(MultiLocation { parents: 0, .. })
+		)
+	}
+
+	fn check_outflow(what: &MultiAsset) -> Result<Option<(CurrencyId, FlowState<BlockNumberFor<T>>)>, XcmError> {
+		match Self::fungible(what) {
+			Some((currency_id, amount)) => Pallet::<T>::check_flow(currency_id, FlowDirection::Outflow, amount)
+				.map(|state| state.map(|state| (currency_id, state)))
+				.map_err(|_| {
+					log::debug!(target: LOG_TARGET, "outflow of {:?} {:?} is rejected", amount, currency_id);
+					XcmError::FailedToTransactAsset("RateLimited")
+				}),
+			None => Ok(None),
+		}
+	}
+
+	fn record_outflow(outflow: Option<(CurrencyId, FlowState<BlockNumberFor<T>>)>) {
+		if let Some((currency_id, state)) = outflow {
+			Pallet::<T>::record_flow(currency_id, FlowDirection::Outflow, Some(state));
+		}
+	}
+}
+
+impl<T, Transactor, CurrencyIdConvert, AccountIdConvert> TransactAsset
+	for RateLimitedTransactor<T, Transactor, CurrencyIdConvert, AccountIdConvert>
+where
+	T: Config,
+	Transactor: TransactAsset,
+	CurrencyIdConvert: Convert<MultiAsset, Option<CurrencyId>>,
+	AccountIdConvert: ConvertLocation<T::AccountId>,
+{
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Transactor::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Transactor::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, context: Option<&XcmContext>) -> XcmResult {
+		let (currency_id, amount) = match Self::fungible(what) {
+			Some(inflow) if !Self::is_local_origin(context) => inflow,
+			_ => return Transactor::deposit_asset(what, who, context),
+		};
+
+		match Pallet::<T>::check_flow(currency_id, FlowDirection::Inflow, amount) {
+			Ok(state) => {
+				Transactor::deposit_asset(what, who, context)?;
+				Pallet::<T>::record_flow(currency_id, FlowDirection::Inflow, state);
+				Ok(())
+			}
+			Err(OverLimitAction::Queue) => {
+				let beneficiary =
+					AccountIdConvert::convert_location(who).ok_or(XcmExecutorError::AccountIdConversionFailed)?;
+				Pallet::<T>::queue_inflow(currency_id, amount, beneficiary)
+					.map_err(|e| XcmError::FailedToTransactAsset(e.into()))
+			}
+			Err(OverLimitAction::Reject) => {
+				log::debug!(target: LOG_TARGET, "inflow of {:?} {:?} is rejected", amount, currency_id);
+				Err(XcmError::FailedToTransactAsset("RateLimited"))
+			}
+		}
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		if !Self::is_local_origin(maybe_context) {
+			return Transactor::withdraw_asset(what, who, maybe_context);
+		}
+
+		let outflow = Self::check_outflow(what)?;
+		let assets = Transactor::withdraw_asset(what, who, maybe_context)?;
+		Self::record_outflow(outflow);
+		Ok(assets)
+	}
+
+	fn internal_transfer_asset(
+		asset: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		Transactor::internal_transfer_asset(asset, from, to, context)
+	}
+
+	fn transfer_asset(
+		asset: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		if !Self::is_local_origin(Some(context)) {
+			return Transactor::transfer_asset(asset, from, to, context);
+		}
+
+		let outflow = Self::check_outflow(asset)?;
+		let assets = Transactor::transfer_asset(asset, from, to, context)?;
+		Self::record_outflow(outflow);
+		Ok(assets)
+	}
+}
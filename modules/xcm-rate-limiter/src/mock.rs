@@ -0,0 +1,222 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the xcm rate limiter module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64, Everything, Nothing},
+};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage};
+use xcm::v3::{AssetId, Junction, Junctions};
+
+pub type AccountId = AccountId32;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const TREASURY: AccountId = AccountId32::new([3u8; 32]);
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+
+mod xcm_rate_limiter {
+	pub use super::super::*;
+}
+
+impl frame_system::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = ALICE;
+}
+
+parameter_types! {
+	pub const XcmRateLimiterPalletId: PalletId = PalletId(*b"aca/xrlm");
+	pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+impl Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type UpdateOrigin = EnsureSignedBy<Admin, AccountId>;
+	type PalletId = XcmRateLimiterPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type WeightInfo = ();
+}
+
+pub struct MockCurrencyIdConvert;
+impl Convert<MultiAsset, Option<CurrencyId>> for MockCurrencyIdConvert {
+	fn convert(asset: MultiAsset) -> Option<CurrencyId> {
+		match asset.id {
+			AssetId::Concrete(location) if location == MultiLocation::parent() => Some(DOT),
+			AssetId::Concrete(location)
+				if location == MultiLocation::new(0, Junctions::X1(Junction::GeneralIndex(0))) =>
+			{
+				Some(AUSD)
+			}
+			_ => None,
+		}
+	}
+}
+
+pub struct MockAccountIdConvert;
+impl ConvertLocation<AccountId> for MockAccountIdConvert {
+	fn convert_location(location: &MultiLocation) -> Option<AccountId> {
+		match location {
+			MultiLocation {
+				parents: 0,
+				interior: Junctions::X1(Junction::AccountId32 { id, .. }),
+			} => Some((*id).into()),
+			_ => None,
+		}
+	}
+}
+
+/// Moves the assets with `Tokens`.
+pub struct MockTransactor;
+impl TransactAsset for MockTransactor {
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: Option<&XcmContext>) -> XcmResult {
+		let (currency_id, amount, who) = mock_transact_args(what, who)?;
+		Tokens::deposit(currency_id, &who, amount).map_err(|e| XcmError::FailedToTransactAsset(e.into()))
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		_maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		let (currency_id, amount, who) = mock_transact_args(what, who)?;
+		Tokens::withdraw(currency_id, &who, amount).map_err(|e| XcmError::FailedToTransactAsset(e.into()))?;
+		Ok(what.clone().into())
+	}
+
+	fn internal_transfer_asset(
+		asset: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		_context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		let (currency_id, amount, from) = mock_transact_args(asset, from)?;
+		let to = MockAccountIdConvert::convert_location(to).ok_or(XcmError::AssetNotFound)?;
+		<Tokens as MultiCurrency<AccountId>>::transfer(currency_id, &from, &to, amount)
+			.map_err(|e| XcmError::FailedToTransactAsset(e.into()))?;
+		Ok(asset.clone().into())
+	}
+}
+
+fn mock_transact_args(what: &MultiAsset, who: &MultiLocation) -> Result<(CurrencyId, Balance, AccountId), XcmError> {
+	let currency_id = MockCurrencyIdConvert::convert(what.clone()).ok_or(XcmError::AssetNotFound)?;
+	let amount = match what.fun {
+		Fungibility::Fungible(amount) => amount,
+		Fungibility::NonFungible(_) => return Err(XcmError::AssetNotFound),
+	};
+	let who = MockAccountIdConvert::convert_location(who).ok_or(XcmError::AssetNotFound)?;
+	Ok((currency_id, amount, who))
+}
+
+pub type LocalAssetTransactor =
+	RateLimitedTransactor<Runtime, MockTransactor, MockCurrencyIdConvert, MockAccountIdConvert>;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		XcmRateLimiter: xcm_rate_limiter,
+		Tokens: orml_tokens,
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, AUSD, 1_000), (ALICE, DOT, 1_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
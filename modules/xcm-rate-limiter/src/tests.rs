@@ -0,0 +1,438 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the xcm rate limiter module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+use xcm::v3::{AssetId, Junction, Junctions};
+
+fn location(who: &AccountId) -> MultiLocation {
+	MultiLocation::new(
+		0,
+		Junctions::X1(Junction::AccountId32 {
+			network: None,
+			id: who.clone().into(),
+		}),
+	)
+}
+
+fn ausd(amount: Balance) -> MultiAsset {
+	MultiAsset {
+		id: AssetId::Concrete(MultiLocation::new(0, Junctions::X1(Junction::GeneralIndex(0)))),
+		fun: Fungibility::Fungible(amount),
+	}
+}
+
+fn context(origin: MultiLocation) -> XcmContext {
+	XcmContext {
+		origin: Some(origin),
+		message_id: [0; 32],
+		topic: None,
+	}
+}
+
+fn limit(quota: Balance, window: u64, over_limit: OverLimitAction) -> Option<RateLimit<u64>> {
+	Some(RateLimit {
+		quota,
+		window,
+		over_limit,
+	})
+}
+
+#[test]
+fn set_rate_limit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(
+				RuntimeOrigin::signed(BOB),
+				AUSD,
+				FlowDirection::Inflow,
+				limit(100, 10, OverLimitAction::Reject)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				FlowDirection::Inflow,
+				limit(100, 0, OverLimitAction::Reject)
+			),
+			Error::<Runtime>::InvalidRateLimit
+		);
+		assert_noop!(
+			XcmRateLimiter::set_rate_limit(
+				RuntimeOrigin::signed(ALICE),
+				AUSD,
+				FlowDirection::Outflow,
+				limit(100, 10, OverLimitAction::Queue)
+			),
+			Error::<Runtime>::InvalidRateLimit
+		);
+
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), None);
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			limit(100, 10, OverLimitAction::Reject)
+		));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::RateLimitUpdated {
+			currency_id: AUSD,
+			direction: FlowDirection::Inflow,
+			limit: limit(100, 10, OverLimitAction::Reject),
+		}));
+		assert_eq!(
+			XcmRateLimiter::rate_limits(AUSD, FlowDirection::Inflow),
+			limit(100, 10, OverLimitAction::Reject)
+		);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(100));
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Outflow), None);
+
+		// updating the rate limit resets the used quota
+		assert_ok!(LocalAssetTransactor::deposit_asset(
+			&ausd(60),
+			&location(&BOB),
+			Some(&context(MultiLocation::parent()))
+		));
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(40));
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			limit(200, 10, OverLimitAction::Queue)
+		));
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(200));
+
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			None
+		));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::RateLimitUpdated {
+			currency_id: AUSD,
+			direction: FlowDirection::Inflow,
+			limit: None,
+		}));
+		assert_eq!(XcmRateLimiter::rate_limits(AUSD, FlowDirection::Inflow), None);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), None);
+	});
+}
+
+#[test]
+fn set_circuit_breaker_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			XcmRateLimiter::set_circuit_breaker(RuntimeOrigin::signed(BOB), AUSD, true),
+			BadOrigin
+		);
+
+		assert_ok!(XcmRateLimiter::set_circuit_breaker(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			true
+		));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::CircuitBreakerUpdated {
+			currency_id: AUSD,
+			tripped: true,
+		}));
+		assert!(XcmRateLimiter::circuit_breakers(AUSD));
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(0));
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Outflow), Some(0));
+
+		assert_ok!(XcmRateLimiter::set_circuit_breaker(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			false
+		));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::CircuitBreakerUpdated {
+			currency_id: AUSD,
+			tripped: false,
+		}));
+		assert!(!CircuitBreakers::<Runtime>::contains_key(AUSD));
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), None);
+	});
+}
+
+#[test]
+fn inflow_rate_limit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let remote = context(MultiLocation::parent());
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			limit(100, 10, OverLimitAction::Reject)
+		));
+
+		assert_ok!(LocalAssetTransactor::deposit_asset(
+			&ausd(60),
+			&location(&BOB),
+			Some(&remote)
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 60);
+		assert_eq!(
+			XcmRateLimiter::flows(AUSD, FlowDirection::Inflow),
+			FlowState {
+				used: 60,
+				last_updated: 1
+			}
+		);
+
+		assert_eq!(
+			LocalAssetTransactor::deposit_asset(&ausd(50), &location(&BOB), Some(&remote)),
+			Err(XcmError::FailedToTransactAsset("RateLimited"))
+		);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 60);
+
+		// deposits of local messages are not inflows
+		assert_ok!(LocalAssetTransactor::deposit_asset(
+			&ausd(50),
+			&location(&BOB),
+			Some(&context(location(&ALICE)))
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 110);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(40));
+
+		// half of the quota recovers after half of the window
+		System::set_block_number(6);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(90));
+		assert_ok!(LocalAssetTransactor::deposit_asset(
+			&ausd(50),
+			&location(&BOB),
+			Some(&remote)
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 160);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(40));
+
+		// fully recovered after the window
+		System::set_block_number(16);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(100));
+
+		// other currencies are not limited
+		assert_ok!(LocalAssetTransactor::deposit_asset(
+			&(MultiLocation::parent(), 1_000u128).into(),
+			&location(&BOB),
+			None
+		));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000);
+	});
+}
+
+#[test]
+fn inflow_over_quota_can_be_queued() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			limit(100, 10, OverLimitAction::Queue)
+		));
+
+		// the origin is cleared before depositing
+		assert_ok!(LocalAssetTransactor::deposit_asset(&ausd(60), &location(&BOB), None));
+		assert_ok!(LocalAssetTransactor::deposit_asset(&ausd(50), &location(&BOB), None));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::InflowQueued {
+			id: 0,
+			currency_id: AUSD,
+			amount: 50,
+			beneficiary: BOB,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 60);
+		assert_eq!(Tokens::free_balance(AUSD, &XcmRateLimiter::account_id()), 50);
+		assert_eq!(
+			XcmRateLimiter::queued_inflows(0),
+			Some(QueuedInflow {
+				currency_id: AUSD,
+				amount: 50,
+				beneficiary: BOB,
+				queued_at: 1,
+			})
+		);
+		assert_eq!(XcmRateLimiter::next_queue_id(), 1);
+		// queued inflows do not use the quota
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(40));
+
+		assert_ok!(LocalAssetTransactor::deposit_asset(&ausd(70), &location(&BOB), None));
+		assert_eq!(Tokens::free_balance(AUSD, &XcmRateLimiter::account_id()), 120);
+		assert_eq!(XcmRateLimiter::next_queue_id(), 2);
+
+		// beneficiary must be a local account
+		assert_eq!(
+			LocalAssetTransactor::deposit_asset(&ausd(70), &MultiLocation::parent(), None),
+			Err(XcmError::FailedToTransactAsset("AccountIdConversionFailed"))
+		);
+	});
+}
+
+#[test]
+fn release_and_cancel_queued_inflow_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			limit(100, 10, OverLimitAction::Queue)
+		));
+		assert_ok!(LocalAssetTransactor::deposit_asset(&ausd(150), &location(&BOB), None));
+		assert_ok!(LocalAssetTransactor::deposit_asset(&ausd(200), &location(&BOB), None));
+		assert_eq!(Tokens::free_balance(AUSD, &XcmRateLimiter::account_id()), 350);
+
+		assert_noop!(
+			XcmRateLimiter::release_queued_inflow(RuntimeOrigin::signed(BOB), 0),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmRateLimiter::release_queued_inflow(RuntimeOrigin::signed(ALICE), 2),
+			Error::<Runtime>::QueuedInflowNotFound
+		);
+
+		assert_ok!(XcmRateLimiter::release_queued_inflow(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::QueuedInflowReleased {
+			id: 0,
+			currency_id: AUSD,
+			amount: 150,
+			beneficiary: BOB,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 150);
+		assert_eq!(XcmRateLimiter::queued_inflows(0), None);
+		// released inflows do not use the quota
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Inflow), Some(100));
+
+		assert_noop!(
+			XcmRateLimiter::cancel_queued_inflow(RuntimeOrigin::signed(BOB), 1),
+			BadOrigin
+		);
+		assert_ok!(XcmRateLimiter::cancel_queued_inflow(RuntimeOrigin::signed(ALICE), 1));
+		System::assert_last_event(RuntimeEvent::XcmRateLimiter(crate::Event::QueuedInflowCancelled {
+			id: 1,
+			currency_id: AUSD,
+			amount: 200,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 200);
+		assert_eq!(Tokens::free_balance(AUSD, &XcmRateLimiter::account_id()), 0);
+		assert_noop!(
+			XcmRateLimiter::cancel_queued_inflow(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Runtime>::QueuedInflowNotFound
+		);
+	});
+}
+
+#[test]
+fn outflow_rate_limit_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let local = context(location(&ALICE));
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Outflow,
+			limit(100, 10, OverLimitAction::Reject)
+		));
+
+		assert_ok!(LocalAssetTransactor::withdraw_asset(
+			&ausd(60),
+			&location(&ALICE),
+			Some(&local)
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 940);
+		assert_eq!(
+			LocalAssetTransactor::withdraw_asset(&ausd(50), &location(&ALICE), Some(&local)),
+			Err(XcmError::FailedToTransactAsset("RateLimited"))
+		);
+		assert_eq!(
+			LocalAssetTransactor::transfer_asset(&ausd(50), &location(&ALICE), &location(&BOB), &local),
+			Err(XcmError::FailedToTransactAsset("RateLimited"))
+		);
+		assert_ok!(LocalAssetTransactor::transfer_asset(
+			&ausd(40),
+			&location(&ALICE),
+			&location(&BOB),
+			&local
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 40);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Outflow), Some(0));
+
+		// failed withdrawals do not use the quota
+		System::set_block_number(11);
+		assert!(LocalAssetTransactor::withdraw_asset(&ausd(60), &location(&BOB), Some(&local)).is_err());
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Outflow), Some(100));
+
+		// withdrawals of remote messages are not outflows
+		assert_ok!(LocalAssetTransactor::withdraw_asset(
+			&ausd(200),
+			&location(&ALICE),
+			Some(&context(MultiLocation::parent()))
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 700);
+		assert_eq!(XcmRateLimiter::remaining_quota(AUSD, FlowDirection::Outflow), Some(100));
+	});
+}
+
+#[test]
+fn circuit_breaker_halts_flows() {
+	ExtBuilder::default().build().execute_with(|| {
+		let local = context(location(&ALICE));
+		assert_ok!(XcmRateLimiter::set_rate_limit(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			FlowDirection::Inflow,
+			limit(100, 10, OverLimitAction::Queue)
+		));
+		assert_ok!(XcmRateLimiter::set_circuit_breaker(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			true
+		));
+
+		assert_eq!(
+			LocalAssetTransactor::deposit_asset(&ausd(10), &location(&BOB), None),
+			Err(XcmError::FailedToTransactAsset("RateLimited"))
+		);
+		assert_eq!(XcmRateLimiter::next_queue_id(), 0);
+		assert_eq!(
+			LocalAssetTransactor::withdraw_asset(&ausd(10), &location(&ALICE), Some(&local)),
+			Err(XcmError::FailedToTransactAsset("RateLimited"))
+		);
+		assert_eq!(
+			LocalAssetTransactor::transfer_asset(&ausd(10), &location(&ALICE), &location(&BOB), &local),
+			Err(XcmError::FailedToTransactAsset("RateLimited"))
+		);
+
+		// other currencies are not halted
+		assert_ok!(LocalAssetTransactor::withdraw_asset(
+			&(MultiLocation::parent(), 10u128).into(),
+			&location(&ALICE),
+			Some(&local)
+		));
+
+		assert_ok!(XcmRateLimiter::set_circuit_breaker(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			false
+		));
+		assert_ok!(LocalAssetTransactor::deposit_asset(&ausd(10), &location(&BOB), None));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 10);
+	});
+}
@@ -25,24 +25,54 @@ where
 	TokenIdOf<T>: TryFrom<u128>,
 	ClassIdOf<T>: TryFrom<u128>,
 {
+	/// Teleported tokens are held by the pallet account while they are away, so a token of a
+	/// local class can only be checked in if it was checked out before. The trust of `origin` is
+	/// checked by `NftTeleporters`.
 	fn can_check_in(
 		_origin: &xcm::v3::MultiLocation,
-		_what: &MultiAsset,
+		what: &MultiAsset,
 		_context: &xcm::v3::XcmContext,
 	) -> xcm::v3::Result {
-		Err(xcm::v3::Error::Unimplemented)
+		let Fungibility::NonFungible(asset_instance) = what.fun else {
+			return Err(xcm::v3::Error::Unimplemented);
+		};
+
+		let class_locality = Self::asset_to_collection(&what.id).map_err(|_| xcm::v3::Error::Unimplemented)?;
+		let is_local = matches!(class_locality, ClassLocality::Local(_));
+
+		match Self::asset_instance_to_token(class_locality, &asset_instance) {
+			Some(token) => {
+				ensure!(
+					<OrmlNftPallet<T>>::is_owner(&Self::account_id(), token),
+					xcm::v3::Error::NotDepositable
+				);
+				Ok(())
+			}
+			// foreign tokens which have never been here are minted on deposit
+			None if !is_local => Ok(()),
+			None => Err(XcmExecutorError::InstanceConversionFailed.into()),
+		}
 	}
 
+	/// The pallet account acts as the checking account, `deposit_asset` takes the token from it.
 	fn check_in(_origin: &xcm::v3::MultiLocation, _what: &MultiAsset, _context: &xcm::v3::XcmContext) {}
 
 	fn can_check_out(
 		_dest: &xcm::v3::MultiLocation,
-		_what: &MultiAsset,
+		what: &MultiAsset,
 		_context: &xcm::v3::XcmContext,
 	) -> xcm::v3::Result {
-		Err(xcm::v3::Error::Unimplemented)
+		let Fungibility::NonFungible(_) = what.fun else {
+			return Err(xcm::v3::Error::Unimplemented);
+		};
+
+		Self::asset_to_collection(&what.id)
+			.map(|_| ())
+			.map_err(|_| xcm::v3::Error::Unimplemented)
 	}
 
+	/// The pallet account acts as the checking account, `withdraw_asset` already moved the token
+	/// to it.
 	fn check_out(_dest: &xcm::v3::MultiLocation, _what: &MultiAsset, _context: &xcm::v3::XcmContext) {}
 
 	fn deposit_asset(
@@ -20,19 +20,25 @@
 #![allow(clippy::unused_unit)]
 
 use cumulus_primitives_core::ParaId;
-use frame_support::{ensure, pallet_prelude::*, PalletId};
+use frame_support::{ensure, pallet_prelude::*, traits::ContainsPair, PalletId};
 use frame_system::pallet_prelude::*;
 use module_nft::{ClassIdOf, TokenIdOf};
-use sp_runtime::{traits::AccountIdConversion, DispatchResult};
-use sp_std::boxed::Box;
+use orml_traits::location::{AbsoluteReserveProvider, Parse, Reserve};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, Convert, Zero},
+	DispatchResult,
+};
+use sp_std::{boxed::Box, marker::PhantomData, vec};
 use xcm::{
 	v3::{
-		AssetId, AssetInstance, Error as XcmError, Fungibility, InteriorMultiLocation, Junction::*, MultiAsset,
-		Result as XcmResult,
+		AssetId, AssetInstance, Error as XcmError, ExecuteXcm, Fungibility, Instruction::*, InteriorMultiLocation,
+		Junction::*, Junctions::X1, MultiAsset, MultiAssetFilter::Wild, MultiAssets, MultiLocation,
+		Result as XcmResult, WeightLimit, WildMultiAsset::AllCounted, Xcm,
 	},
-	VersionedAssetId,
+	VersionedAssetId, VersionedMultiLocation,
 };
-use xcm_executor::traits::{ConvertLocation, Error as XcmExecutorError, TransactAsset};
+use xcm_executor::traits::{ConvertLocation, Error as XcmExecutorError, TransactAsset, WeightBounds};
 
 pub mod impl_transactor;
 pub mod xcm_helpers;
@@ -43,6 +49,8 @@ pub type ConverterOf<T> = <T as Config>::LocationToAccountId;
 pub type ModuleNftPallet<T> = module_nft::Pallet<T>;
 pub type OrmlNftPallet<T> = orml_nft::Pallet<T>;
 
+const LOG_TARGET: &str = "xcm::module_xnft";
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -63,6 +71,21 @@ pub mod pallet {
 		type NtfPalletLocation: Get<InteriorMultiLocation>;
 
 		type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Convert `T::AccountId` to `MultiLocation`.
+		type AccountIdToMultiLocation: Convert<Self::AccountId, MultiLocation>;
+
+		/// Convert `CurrencyId` to `MultiLocation`, used to build the fee asset.
+		type CurrencyIdConvert: Convert<CurrencyId, Option<MultiLocation>>;
+
+		/// The location of this chain in the consensus universe.
+		type UniversalLocation: Get<InteriorMultiLocation>;
+
+		/// XCM executor used to execute the outbound transfer message.
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Means of measuring the weight consumed by an XCM message locally.
+		type Weigher: WeightBounds<<Self as frame_system::Config>::RuntimeCall>;
 	}
 
 	/// Error for non-fungible-token module.
@@ -73,6 +96,42 @@ pub mod pallet {
 
 		/// The given asset ID could not be converted into the current XCM version.
 		BadAssetId,
+
+		/// The given location could not be converted into the current XCM version.
+		BadVersion,
+
+		/// The sender is not the owner of the token.
+		NotTokenOwner,
+
+		/// The token has no corresponding asset instance.
+		AssetInstanceNotFound,
+
+		/// The destination is invalid.
+		InvalidDest,
+
+		/// The fee currency is not cross-chain transferable.
+		NotCrossChainTransferableCurrency,
+
+		/// The fee amount is zero.
+		ZeroFee,
+
+		/// The asset has no reserve location.
+		AssetHasNoReserve,
+
+		/// The token and the fee asset have different reserves.
+		DistinctReserveForAssetAndFee,
+
+		/// The reserve of the token is neither this chain nor the destination.
+		NotSupportedMultiLocation,
+
+		/// The outbound XCM message could not be weighed.
+		UnweighableMessage,
+
+		/// The outbound XCM message execution failed.
+		XcmExecutionFailed,
+
+		/// The class does not exist.
+		ClassNotFound,
 	}
 
 	#[pallet::event]
@@ -82,6 +141,24 @@ pub mod pallet {
 			asset_id: Box<VersionedAssetId>,
 			collection_id: ClassIdOf<T>,
 		},
+		TransferredNft {
+			sender: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			fee: MultiAsset,
+			dest: MultiLocation,
+		},
+		TeleportedNft {
+			sender: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			dest: MultiLocation,
+		},
+		TeleportLocationUpdated {
+			class_id: ClassIdOf<T>,
+			location: MultiLocation,
+			trusted: bool,
+		},
 	}
 
 	#[pallet::storage]
@@ -116,6 +193,14 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The locations trusted to teleport tokens of a class to and from this chain.
+	///
+	/// TeleportLocations: double_map ClassId, MultiLocation => ()
+	#[pallet::storage]
+	#[pallet::getter(fn teleport_locations)]
+	pub type TeleportLocations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Blake2_128Concat, MultiLocation, (), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -146,6 +231,7 @@ pub mod pallet {
 				deposit: Default::default(),
 				properties,
 				attributes: Default::default(),
+				royalty: Default::default(),
 			};
 			let collection_id = orml_nft::Pallet::<T>::create_class(&Self::account_id(), Default::default(), data)?;
 
@@ -159,6 +245,98 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Transfer a NFT token to another chain.
+		///
+		/// If the destination chain is a teleport location of the class, the token is teleported:
+		/// it is held by the pallet account, which acts as the checking account, until it is
+		/// teleported back. The destination must allow unpaid execution from this chain, the fee
+		/// is not used for teleports.
+		///
+		/// Otherwise tokens of local classes are transferred with this chain as the reserve, tokens
+		/// of registered foreign classes are withdrawn back to their reserve chain. The fee asset
+		/// must have the same reserve as the token.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `fee_currency_id`: The currency used to buy execution on the destination
+		/// - `fee_amount`: The amount of the fee
+		/// - `dest`: The destination chain and the beneficiary
+		/// - `dest_weight_limit`: The weight limit of the execution on the destination
+		#[pallet::call_index(1)]
+		#[pallet::weight(Pallet::<T>::transfer_nft_weight())]
+		pub fn transfer_nft(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			fee_currency_id: CurrencyId,
+			fee_amount: Balance,
+			dest: Box<VersionedMultiLocation>,
+			dest_weight_limit: WeightLimit,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			Self::do_transfer_nft(who, token, fee_currency_id, fee_amount, dest, dest_weight_limit)
+		}
+
+		/// Trust or untrust a location to teleport tokens of a class.
+		///
+		/// The dispatch origin of this call must be `RegisterOrigin`.
+		///
+		/// - `class_id`: the class whose tokens are teleported
+		/// - `location`: the chain the tokens are teleported to and from
+		/// - `trusted`: whether the location is trusted
+		#[pallet::call_index(2)]
+		#[pallet::weight(Weight::from_parts(1_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1)))]
+		pub fn set_teleport_location(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			location: Box<VersionedMultiLocation>,
+			trusted: bool,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			let location: MultiLocation = (*location).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			ensure!(
+				<OrmlNftPallet<T>>::classes(class_id).is_some(),
+				Error::<T>::ClassNotFound
+			);
+
+			if trusted {
+				<TeleportLocations<T>>::insert(class_id, location, ());
+			} else {
+				<TeleportLocations<T>>::remove(class_id, location);
+			}
+
+			Self::deposit_event(Event::TeleportLocationUpdated {
+				class_id,
+				location,
+				trusted,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+/// Trusts the teleport locations set for a class as teleporters of its tokens.
+pub struct NftTeleporters<T>(PhantomData<T>);
+impl<T: Config> ContainsPair<MultiAsset, MultiLocation> for NftTeleporters<T>
+where
+	TokenIdOf<T>: TryFrom<u128>,
+	ClassIdOf<T>: TryFrom<u128>,
+{
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		if !matches!(asset.fun, Fungibility::NonFungible(_)) {
+			return false;
+		}
+
+		match Pallet::<T>::asset_to_collection(&asset.id) {
+			Ok(xcm_helpers::ClassLocality::Local(class_id)) | Ok(xcm_helpers::ClassLocality::Foreign(class_id)) => {
+				<TeleportLocations<T>>::contains_key(class_id, origin)
+			}
+			Err(_) => false,
+		}
 	}
 }
 
@@ -166,4 +344,155 @@ impl<T: Config> Pallet<T> {
 	pub fn account_id() -> T::AccountId {
 		<T as Config>::PalletId::get().into_account_truncating()
 	}
+
+	pub fn self_location() -> MultiLocation {
+		MultiLocation::new(1, X1(Parachain(T::SelfParaId::get().into())))
+	}
+
+	/// Transfer `token` of `who` to `dest`, paying `fee_amount` of `fee_currency_id` to buy
+	/// execution on the destination chain, or teleport it if `dest` is a teleport location of
+	/// the class.
+	pub fn do_transfer_nft(
+		who: T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		fee_currency_id: CurrencyId,
+		fee_amount: Balance,
+		dest: MultiLocation,
+		dest_weight_limit: WeightLimit,
+	) -> DispatchResult {
+		ensure!(<OrmlNftPallet<T>>::is_owner(&who, token), Error::<T>::NotTokenOwner);
+
+		let (dest_chain, beneficiary) = match (dest.chain_part(), dest.non_chain_part()) {
+			(Some(dest_chain), Some(beneficiary)) => (dest_chain, beneficiary),
+			_ => return Err(Error::<T>::InvalidDest.into()),
+		};
+
+		let asset = Self::token_to_asset(token).ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+		if <TeleportLocations<T>>::contains_key(token.0, dest_chain) {
+			let msg = Xcm(vec![
+				WithdrawAsset(asset.into()),
+				InitiateTeleport {
+					assets: Wild(AllCounted(1)),
+					dest: dest_chain,
+					xcm: Xcm(vec![DepositAsset {
+						assets: Wild(AllCounted(1)),
+						beneficiary,
+					}]),
+				},
+			]);
+			Self::execute_transfer(&who, msg)?;
+
+			Self::deposit_event(Event::TeleportedNft {
+				sender: who,
+				class_id: token.0,
+				token_id: token.1,
+				dest,
+			});
+
+			return Ok(());
+		}
+
+		ensure!(!fee_amount.is_zero(), Error::<T>::ZeroFee);
+		let fee_location =
+			T::CurrencyIdConvert::convert(fee_currency_id).ok_or(Error::<T>::NotCrossChainTransferableCurrency)?;
+		let fee = MultiAsset {
+			id: AssetId::Concrete(fee_location),
+			fun: Fungibility::Fungible(fee_amount),
+		};
+
+		let reserve = AbsoluteReserveProvider::reserve(&asset).ok_or(Error::<T>::AssetHasNoReserve)?;
+		ensure!(
+			AbsoluteReserveProvider::reserve(&fee) == Some(reserve),
+			Error::<T>::DistinctReserveForAssetAndFee
+		);
+
+		let remote_fee = fee
+			.clone()
+			.reanchored(&dest_chain, T::UniversalLocation::get())
+			.map_err(|_| Error::<T>::InvalidDest)?;
+		let remote_xcm = Xcm(vec![
+			BuyExecution {
+				fees: remote_fee,
+				weight_limit: dest_weight_limit,
+			},
+			DepositAsset {
+				assets: Wild(AllCounted(2)),
+				beneficiary,
+			},
+		]);
+
+		let transfer = if reserve == Self::self_location() {
+			DepositReserveAsset {
+				assets: Wild(AllCounted(2)),
+				dest: dest_chain,
+				xcm: remote_xcm,
+			}
+		} else if reserve == dest_chain {
+			InitiateReserveWithdraw {
+				assets: Wild(AllCounted(2)),
+				reserve: dest_chain,
+				xcm: remote_xcm,
+			}
+		} else {
+			return Err(Error::<T>::NotSupportedMultiLocation.into());
+		};
+
+		let assets: MultiAssets = vec![asset, fee.clone()].into();
+		Self::execute_transfer(&who, Xcm(vec![WithdrawAsset(assets), transfer]))?;
+
+		Self::deposit_event(Event::TransferredNft {
+			sender: who,
+			class_id: token.0,
+			token_id: token.1,
+			fee,
+			dest,
+		});
+
+		Ok(())
+	}
+
+	fn execute_transfer(who: &T::AccountId, mut msg: Xcm<<T as frame_system::Config>::RuntimeCall>) -> DispatchResult {
+		let hash = msg.using_encoded(sp_io::hashing::blake2_256);
+		let weight = T::Weigher::weight(&mut msg).map_err(|()| Error::<T>::UnweighableMessage)?;
+		let origin_location = T::AccountIdToMultiLocation::convert(who.clone());
+
+		T::XcmExecutor::execute_xcm_in_credit(origin_location, msg, hash, weight, weight)
+			.ensure_complete()
+			.map_err(|error| {
+				log::error!(target: LOG_TARGET, "Failed execute transfer message with {:?}", error);
+				Error::<T>::XcmExecutionFailed
+			})?;
+
+		Ok(())
+	}
+
+	/// The weight of `transfer_nft`, measured on the shape of the outbound message.
+	pub fn transfer_nft_weight() -> Weight {
+		let asset = MultiAsset {
+			id: AssetId::Concrete(MultiLocation::here()),
+			fun: Fungibility::Fungible(1),
+		};
+		let mut msg = Xcm(vec![
+			WithdrawAsset(vec![asset.clone()].into()),
+			DepositReserveAsset {
+				assets: Wild(AllCounted(2)),
+				dest: MultiLocation::here(),
+				xcm: Xcm(vec![
+					BuyExecution {
+						fees: asset,
+						weight_limit: WeightLimit::Unlimited,
+					},
+					DepositAsset {
+						assets: Wild(AllCounted(2)),
+						beneficiary: MultiLocation::here(),
+					},
+				]),
+			},
+		]);
+
+		T::Weigher::weight(&mut msg).map_or(Weight::MAX, |weight| {
+			weight.saturating_add(T::DbWeight::get().reads_writes(4, 4))
+		})
+	}
 }
@@ -19,6 +19,7 @@
 use crate::*;
 use module_nft::BalanceOf;
 use primitives::nft::Attributes;
+use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::vec::Vec;
 use xcm::v3::AssetId::Concrete;
 use xcm_executor::traits::Error as MatchError;
@@ -108,6 +109,31 @@ where
 		}
 	}
 
+	/// Returns the asset of `token` in its absolute location, as seen from this chain.
+	pub fn token_to_asset(token: (ClassIdOf<T>, TokenIdOf<T>)) -> Option<MultiAsset> {
+		let (class_id, token_id) = token;
+		match Self::class_to_foreign_asset(class_id) {
+			Some(asset_id) => {
+				let asset_instance = Self::item_to_asset_instance(class_id, token_id)?;
+				Some(MultiAsset {
+					id: asset_id,
+					fun: Fungibility::NonFungible(asset_instance),
+				})
+			}
+			None => {
+				let class_location = Self::self_location()
+					.appended_with(T::NtfPalletLocation::get())
+					.ok()?
+					.pushed_with_interior(GeneralIndex(class_id.unique_saturated_into()))
+					.ok()?;
+				Some(MultiAsset {
+					id: Concrete(class_location),
+					fun: Fungibility::NonFungible(AssetInstance::Index(token_id.unique_saturated_into())),
+				})
+			}
+		}
+	}
+
 	fn convert_asset_instance(asset: &AssetInstance) -> Result<TokenIdOf<T>, MatchError> {
 		let AssetInstance::Index(index) = asset else {
 			return Err(MatchError::InstanceConversionFailed);
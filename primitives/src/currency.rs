@@ -411,6 +411,7 @@ create_currency_id! {
 		DOT("Polkadot", 10) = 2,
 		LDOT("Liquid DOT", 10) = 3,
 		TAP("Tapio", 12) = 4,
+		SAUSD("Savings Acala Dollar", 12) = 5,
 		// 20 - 127: Reserved for future usage
 
 		// 128 - 147: Karura & Kusama native tokens
@@ -419,6 +420,7 @@ create_currency_id! {
 		KSM("Kusama", 12) = 130,
 		LKSM("Liquid KSM", 12) = 131,
 		TAI("Taiga", 12) = 132,
+		SKUSD("Savings Karura Dollar", 12) = 133,
 		// 148 - 167: Reserved for future usage
 		// 168 - 255: Kusama parachain tokens
 		BNC("Bifrost Native Token", 12) = 168,
@@ -41,6 +41,10 @@ pub enum ClassProperty {
 	Mintable = 0b00000100,
 	/// Is class properties mutable
 	ClassPropertiesMutable = 0b00001000,
+	/// Is token attributes mutable by the class owner
+	AttributesMutableByClassOwner = 0b00010000,
+	/// Is token attributes mutable by the token owner
+	AttributesMutableByTokenOwner = 0b00100000,
 }
 
 #[derive(Clone, Copy, PartialEq, Default, RuntimeDebug, Serialize, Deserialize)]
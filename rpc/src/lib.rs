@@ -20,13 +20,16 @@
 
 #![warn(missing_docs)]
 
-use primitives::{AccountId, Balance, Block, Hash, Nonce};
+use primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, Hash, Nonce};
 pub use sc_rpc::SubscriptionTaskExecutor;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use std::sync::Arc;
 
+mod stable_asset;
+pub use stable_asset::{PoolInfo, StableAsset, StableAssetApiServer};
+
 /// substrate rpc
 use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
@@ -58,6 +61,7 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: stable_asset::StableAssetRuntimeApi<Block, CurrencyId, Balance, AccountId, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
@@ -71,6 +75,7 @@ where
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(StableAsset::new(client.clone()).into_rpc())?;
 
 	module.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
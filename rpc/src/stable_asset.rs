@@ -0,0 +1,323 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface of stable asset pool quotes.
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use module_stable_asset_runtime_api::StableAssetApi as StableAssetRuntimeApi;
+use nutsfinance_stable_asset::{PoolTokenIndex, StableAssetPoolId, StableAssetPoolInfo};
+use primitives::{AccountId, Balance, Block, BlockNumber, CurrencyId, Hash};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::FixedPointNumber;
+use std::sync::Arc;
+
+/// Error code of a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code of an amount that does not fit into `Balance`.
+const INVALID_AMOUNT: i32 = 2;
+
+/// Stable asset pool info, with the balances encoded as `NumberOrHex`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolInfo {
+	/// The pool token.
+	pub pool_asset: CurrencyId,
+	/// The pool assets.
+	pub assets: Vec<CurrencyId>,
+	/// The precision multipliers of the pool assets.
+	pub precisions: Vec<NumberOrHex>,
+	/// The mint fee rate.
+	pub mint_fee: NumberOrHex,
+	/// The swap fee rate.
+	pub swap_fee: NumberOrHex,
+	/// The redeem fee rate.
+	pub redeem_fee: NumberOrHex,
+	/// The total supply of the pool token.
+	pub total_supply: NumberOrHex,
+	/// The amplification coefficient at the start of the ramp.
+	pub a: NumberOrHex,
+	/// The block the amplification ramp starts.
+	pub a_block: BlockNumber,
+	/// The amplification coefficient at the end of the ramp.
+	pub future_a: NumberOrHex,
+	/// The block the amplification ramp ends.
+	pub future_a_block: BlockNumber,
+	/// The balances of the pool assets, in the units of the pool assets.
+	pub balances: Vec<NumberOrHex>,
+	/// The account receiving the fees.
+	pub fee_recipient: AccountId,
+	/// The pool account.
+	pub account_id: AccountId,
+	/// The account receiving the yield.
+	pub yield_recipient: AccountId,
+	/// The precision of the pool token.
+	pub precision: NumberOrHex,
+}
+
+impl From<StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>> for PoolInfo {
+	fn from(pool_info: StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>) -> Self {
+		Self {
+			pool_asset: pool_info.pool_asset,
+			assets: pool_info.assets,
+			precisions: pool_info.precisions.into_iter().map(Into::into).collect(),
+			mint_fee: pool_info.mint_fee.into(),
+			swap_fee: pool_info.swap_fee.into(),
+			redeem_fee: pool_info.redeem_fee.into(),
+			total_supply: pool_info.total_supply.into(),
+			a: pool_info.a.into(),
+			a_block: pool_info.a_block,
+			future_a: pool_info.future_a.into(),
+			future_a_block: pool_info.future_a_block,
+			balances: pool_info.balances.into_iter().map(Into::into).collect(),
+			fee_recipient: pool_info.fee_recipient,
+			account_id: pool_info.account_id,
+			yield_recipient: pool_info.yield_recipient,
+			precision: pool_info.precision.into(),
+		}
+	}
+}
+
+/// Stable asset RPC methods. Amounts are in the units of the pool assets, the same as the
+/// aggregated DEX.
+#[rpc(server)]
+pub trait StableAssetApi<BlockHash> {
+	/// The pool info, including the amplification ramp state. The balances are in the units of
+	/// the pool assets.
+	#[method(name = "stableAsset_getPoolInfo")]
+	fn get_pool_info(&self, pool_id: StableAssetPoolId, at: Option<BlockHash>) -> RpcResult<Option<PoolInfo>>;
+
+	/// The value of the pool token in the invariant of the pool, with 18 decimals.
+	#[method(name = "stableAsset_getVirtualPrice")]
+	fn get_virtual_price(&self, pool_id: StableAssetPoolId, at: Option<BlockHash>) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The output amount of swapping `input_amount` of the `input_index` asset.
+	#[method(name = "stableAsset_getSwapOutputAmount")]
+	fn get_swap_output_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		input_amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The input amount of the `input_index` asset required to receive `output_amount`.
+	#[method(name = "stableAsset_getSwapInputAmount")]
+	fn get_swap_input_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		output_amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The pool token amount minted for `amounts` of the pool assets.
+	#[method(name = "stableAsset_getMintAmount")]
+	fn get_mint_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amounts: Vec<NumberOrHex>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The amounts of the pool assets received for redeeming `amount` proportionally.
+	#[method(name = "stableAsset_getRedeemProportionAmount")]
+	fn get_redeem_proportion_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<NumberOrHex>>>;
+
+	/// The amount of the `output_index` asset received for redeeming `amount`.
+	#[method(name = "stableAsset_getRedeemSingleAmount")]
+	fn get_redeem_single_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amount: NumberOrHex,
+		output_index: PoolTokenIndex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The pool token amount required to redeem `amounts` of the pool assets.
+	#[method(name = "stableAsset_getRedeemMultiAmount")]
+	fn get_redeem_multi_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amounts: Vec<NumberOrHex>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+}
+
+/// Provides RPC methods to query stable asset pools.
+pub struct StableAsset<C> {
+	client: Arc<C>,
+}
+
+impl<C> StableAsset<C> {
+	/// Create new `StableAsset` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query stable asset.",
+		Some(e.to_string()),
+	))
+	.into()
+}
+
+fn to_balance(amount: NumberOrHex) -> RpcResult<Balance> {
+	let amount = amount.into_u256();
+	amount.try_into().map_err(|_| {
+		CallError::Custom(ErrorObject::owned(
+			INVALID_AMOUNT,
+			"Amount exceeds the range of Balance.",
+			Some(amount.to_string()),
+		))
+		.into()
+	})
+}
+
+fn to_balances(amounts: Vec<NumberOrHex>) -> RpcResult<Vec<Balance>> {
+	amounts.into_iter().map(to_balance).collect()
+}
+
+impl<C> StableAssetApiServer<Hash> for StableAsset<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StableAssetRuntimeApi<Block, CurrencyId, Balance, AccountId, BlockNumber>,
+{
+	fn get_pool_info(&self, pool_id: StableAssetPoolId, at: Option<Hash>) -> RpcResult<Option<PoolInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.pool_info(at, pool_id)
+			.map(|pool_info| pool_info.map(Into::into))
+			.map_err(runtime_error)
+	}
+
+	fn get_virtual_price(&self, pool_id: StableAssetPoolId, at: Option<Hash>) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_virtual_price(at, pool_id)
+			.map(|price| price.map(|price| price.into_inner().into()))
+			.map_err(runtime_error)
+	}
+
+	fn get_swap_output_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		input_amount: NumberOrHex,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_swap_output_amount(at, pool_id, input_index, output_index, to_balance(input_amount)?)
+			.map(|amount| amount.map(Into::into))
+			.map_err(runtime_error)
+	}
+
+	fn get_swap_input_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		input_index: PoolTokenIndex,
+		output_index: PoolTokenIndex,
+		output_amount: NumberOrHex,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_swap_input_amount(at, pool_id, input_index, output_index, to_balance(output_amount)?)
+			.map(|amount| amount.map(Into::into))
+			.map_err(runtime_error)
+	}
+
+	fn get_mint_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amounts: Vec<NumberOrHex>,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_mint_amount(at, pool_id, to_balances(amounts)?)
+			.map(|amount| amount.map(Into::into))
+			.map_err(runtime_error)
+	}
+
+	fn get_redeem_proportion_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amount: NumberOrHex,
+		at: Option<Hash>,
+	) -> RpcResult<Option<Vec<NumberOrHex>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_redeem_proportion_amount(at, pool_id, to_balance(amount)?)
+			.map(|amounts| amounts.map(|amounts| amounts.into_iter().map(Into::into).collect()))
+			.map_err(runtime_error)
+	}
+
+	fn get_redeem_single_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amount: NumberOrHex,
+		output_index: PoolTokenIndex,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_redeem_single_amount(at, pool_id, to_balance(amount)?, output_index)
+			.map(|amount| amount.map(Into::into))
+			.map_err(runtime_error)
+	}
+
+	fn get_redeem_multi_amount(
+		&self,
+		pool_id: StableAssetPoolId,
+		amounts: Vec<NumberOrHex>,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.get_redeem_multi_amount(at, pool_id, to_balances(amounts)?)
+			.map(|amount| amount.map(Into::into))
+			.map_err(runtime_error)
+	}
+}
@@ -93,6 +93,9 @@ pub mod nutsfinance_stable_asset {
 pub mod aggregated_dex {
 	include!("../../../mandala/src/benchmarking/aggregated_dex.rs");
 }
+pub mod xcm_rate_limiter {
+	include!("../../../mandala/src/benchmarking/xcm_rate_limiter.rs");
+}
 
 // orml benchmarking
 pub mod auction {
@@ -111,6 +114,8 @@ pub mod vesting {
 	include!("../../../mandala/src/benchmarking/vesting.rs");
 }
 
+pub mod xcm;
+
 pub fn get_vesting_account() -> super::AccountId {
 	super::AcalaFoundationAccounts::get()[0].clone()
 }
@@ -57,7 +57,7 @@ use module_currencies::BasicCurrencyAdapter;
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 use module_relaychain::RelayChainCallBuilder;
-use module_support::{AssetIdMapping, DispatchableTask, PoolId};
+use module_support::{AssetIdMapping, DispatchableTask, PoolId, StableAssetQuote};
 use module_transaction_payment::TargetedFeeAdjustment;
 
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
@@ -105,14 +105,14 @@ pub use primitives::{
 use runtime_common::{
 	cent, dollar, millicent, AllPrecompiles, CheckRelayNumber, CurrencyHooks, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
-	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneThirdsTechnicalCommittee,
-	EnsureRootOrThreeFourthsGeneralCouncil, EnsureRootOrTwoThirdsGeneralCouncil,
-	EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate, ExistentialDepositsTimesOneHundred,
-	FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance,
-	GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance, MaxTipsOfPriority,
-	OperationalFeeMultiplier, OperatorMembershipInstanceAcala, Price, ProxyType, Rate, Ratio, RuntimeBlockLength,
-	RuntimeBlockWeights, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice,
-	TipPerWeightStep, ACA, AUSD, DOT, LCDOT, LDOT, TAP,
+	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneTechnicalCommittee,
+	EnsureRootOrOneThirdsTechnicalCommittee, EnsureRootOrThreeFourthsGeneralCouncil,
+	EnsureRootOrTwoThirdsGeneralCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate,
+	ExistentialDepositsTimesOneHundred, FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight,
+	GeneralCouncilInstance, GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
+	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, PausedEvmCallFilter, Price,
+	ProxyType, Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance,
+	TechnicalCommitteeMembershipInstance, TimeStampedPrice, TipPerWeightStep, ACA, AUSD, DOT, LCDOT, LDOT, TAP,
 };
 use xcm::v3::prelude::*;
 
@@ -164,6 +164,7 @@ parameter_types! {
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const HomaPalletId: PalletId = PalletId(*b"aca/homa");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
+	pub const HonzonPositionPalletId: PalletId = PalletId(*b"aca/hzps");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
 	pub const CollatorPotId: PalletId = PalletId(*b"aca/cpot");
@@ -177,6 +178,7 @@ parameter_types! {
 	pub const TransactionPaymentPalletId: PalletId = PalletId(*b"aca/fees");
 	pub const LiquidCrowdloanPalletId: PalletId = PalletId(*b"aca/lqcl");
 	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
+	pub const XcmRateLimiterPalletId: PalletId = PalletId(*b"aca/xrlm");
 }
 
 pub fn get_all_module_accounts() -> Vec<AccountId> {
@@ -184,6 +186,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		LoansPalletId::get().into_account_truncating(),
 		CDPEnginePalletId::get().into_account_truncating(),
 		CDPTreasuryPalletId::get().into_account_truncating(),
+		CdpTreasury::insurance_fund_account(),
 		CollatorPotId::get().into_account_truncating(),
 		DEXPalletId::get().into_account_truncating(),
 		HomaPalletId::get().into_account_truncating(),
@@ -194,6 +197,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		TreasuryReservePalletId::get().into_account_truncating(),
 		UnreleasedNativeVaultAccountId::get(),
 		StableAssetPalletId::get().into_account_truncating(),
+		XcmRateLimiterPalletId::get().into_account_truncating(),
 	]
 }
 
@@ -215,7 +219,8 @@ impl Contains<RuntimeCall> for BaseCallFilter {
 			return true;
 		}
 
-		let is_paused = module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call);
+		let is_paused = module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
+			|| matches!(call, RuntimeCall::EVM(evm_call) if PausedEvmCallFilter::<Runtime>::contains(evm_call));
 		if is_paused {
 			// no paused call
 			return false;
@@ -741,7 +746,13 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnNewData = ();
+	type OnNewData = runtime_common::auto_pause::OracleDeviationAutoPause<
+		Runtime,
+		AcalaDataProvider,
+		TransactionPause,
+		MaxOracleDeviation,
+		AutoPauseDuration,
+	>;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -775,6 +786,7 @@ parameter_type_with_key! {
 				TokenSymbol::DOT => cent(*currency_id),
 				TokenSymbol::LDOT => 5 * cent(*currency_id),
 				TokenSymbol::TAP => dollar(*currency_id),
+				TokenSymbol::SAUSD => 10 * cent(*currency_id),
 
 				TokenSymbol::KAR |
 				TokenSymbol::KUSD |
@@ -786,7 +798,8 @@ parameter_type_with_key! {
 				TokenSymbol::ACA |
 				TokenSymbol::KBTC |
 				TokenSymbol::KINT |
-				TokenSymbol::TAI => Balance::max_value() // unsupported
+				TokenSymbol::TAI |
+				TokenSymbol::SKUSD => Balance::max_value() // unsupported
 			},
 			CurrencyId::DexShare(dex_share_0, _) => {
 				let currency_id_0: CurrencyId = (*dex_share_0).into();
@@ -1002,10 +1015,12 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type KeeperReward = CdpEngine;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1105,9 +1120,12 @@ impl module_cdp_engine::Config for Runtime {
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = Dex;
+	type DEXPriceSource = module_dex_oracle::AverageDEXPriceProvider<Runtime>;
+	type StabilityFeeAdjustmentPeriod = ConstU32<HOURS>;
 	type LiquidationContractsUpdateOrigin = EnsureRootOrHalfGeneralCouncil;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxLiquidationContractFailures = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Runtime>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
@@ -1117,13 +1135,18 @@ impl module_cdp_engine::Config for Runtime {
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = deposit(1, 64);
+	// paid to the keeper who executes the protection rule
+	pub DepositPerProtectionRule: Balance = 10 * dollar(ACA);
 }
 
 impl module_honzon::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type DepositPerProtectionRule = DepositPerProtectionRule;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type PositionPalletId = HonzonPositionPalletId;
+	type Incentives = Incentives;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1131,6 +1154,8 @@ impl module_emergency_shutdown::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
 	type PriceSource = Prices;
+	type LockedPriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRoot<AccountId>;
@@ -1154,15 +1179,23 @@ impl module_dex::Config for Runtime {
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
-	type OnLiquidityPoolUpdated = ();
+	type OnLiquidityPoolUpdated = runtime_common::auto_pause::DexPriceMoveAutoPause<
+		Runtime,
+		TransactionPause,
+		MaxDexPriceMove,
+		AutoPauseDuration,
+	>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
+	type StableAssetQuote = StableAssetQuoter;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxRouteCandidates = ConstU32<16>;
+	type MaxRouteSearchNodes = ConstU32<64>;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1200,15 +1233,26 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = Dex;
 	type Swap = AcalaSwap;
 	type MaxAuctionsCount = ConstU32<50>;
+	type DebitAuctionMintPeriod = ConstU32<DAYS>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 	type StableAsset = RebasedStableAsset;
 }
 
+parameter_types! {
+	pub const MaxGuardianPauseDuration: BlockNumber = DAYS;
+	pub const AutoPauseDuration: BlockNumber = 6 * HOURS;
+	pub MaxOracleDeviation: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub MaxDexPriceMove: Ratio = Ratio::saturating_from_rational(20, 100);
+	pub MaxHomaLedgerMismatch: Ratio = Ratio::saturating_from_rational(10, 100);
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type GuardianOrigin = EnsureRootOrOneThirdsTechnicalCommittee;
+	type MaxGuardianPauseDuration = MaxGuardianPauseDuration;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
@@ -1271,12 +1315,19 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub AssetRegistrationDeposit: Balance = 1000 * dollar(ACA);
+}
+
 impl module_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type StakingCurrencyId = GetStakingCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type RegistrationDeposit = AssetRegistrationDeposit;
+	type TreasuryAccount = AcalaTreasuryAccount;
+	type AssetInUse = runtime_common::AssetInUse<Runtime>;
 	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
 }
 
@@ -1487,6 +1538,7 @@ impl module_evm::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = module_transaction_pause::PausedPrecompileFilter<Self>;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 }
 
@@ -1547,6 +1599,12 @@ impl module_homa::Config for Runtime {
 	type RedeemThreshold = RedeemThreshold;
 	type RelayChainBlockNumber = RelaychainDataProvider<Runtime>;
 	type XcmInterface = XcmInterface;
+	type OnLedgerBondedReset = runtime_common::auto_pause::HomaLedgerMismatchAutoPause<
+		Runtime,
+		TransactionPause,
+		MaxHomaLedgerMismatch,
+		AutoPauseDuration,
+	>;
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 }
 
@@ -1584,6 +1642,15 @@ impl module_xcm_interface::Config for Runtime {
 	type AccountIdToMultiLocation = runtime_common::xcm_config::AccountIdToMultiLocation;
 }
 
+impl module_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type PalletId = XcmRateLimiterPalletId;
+	type TreasuryAccount = AcalaTreasuryAccount;
+	type WeightInfo = weights::module_xcm_rate_limiter::WeightInfo<Runtime>;
+}
+
 impl orml_unknown_tokens::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
@@ -1679,6 +1746,13 @@ type RebaseTokens = orml_tokens::Combiner<
 	Currencies,
 >;
 
+parameter_types! {
+	pub StableAssetQuoteAccount: AccountId = StableAssetPalletId::get().into_sub_account_truncating(b"quote");
+}
+
+pub type StableAssetQuoter =
+	module_support::StableAssetQuoter<RebasedStableAsset, RebaseTokens, ConvertBalanceHoma, StableAssetQuoteAccount>;
+
 impl nutsfinance_stable_asset::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = CurrencyId;
@@ -1792,6 +1866,7 @@ construct_runtime!(
 		XTokens: orml_xtokens = 54,
 		UnknownTokens: orml_unknown_tokens = 55,
 		OrmlXcm: orml_xcm = 56,
+		XcmRateLimiter: module_xcm_rate_limiter = 57,
 
 		// Governance
 		Authority: orml_authority = 60,
@@ -1888,9 +1963,23 @@ pub type UncheckedExtrinsic =
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+
+/// All migrations that will run on the next runtime upgrade.
+pub type Migrations = (
+	module_nft::migrations::MigrateClassDataRoyalty<Runtime>,
+	module_incentives::migrations::StakeExistingLpCollateral<Runtime>,
+	module_dex::migrations::CountActiveTradingPairs<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
@@ -1925,6 +2014,7 @@ mod benches {
 		[nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset]
 		[module_idle_scheduler, benchmarking::idle_scheduler]
 		[module_aggregated_dex, benchmarking::aggregated_dex]
+		[module_xcm_rate_limiter, benchmarking::xcm_rate_limiter]
 		[module_liquid_crowdloan, benchmarking::liquid_crowdloan]
 	);
 }
@@ -2090,6 +2180,102 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl module_cdp_treasury_runtime_api::CdpTreasuryApi<
+		Block,
+		CurrencyId,
+		Balance,
+		module_cdp_treasury::LossRecord,
+	> for Runtime {
+		fn surplus_pool() -> Balance {
+			CdpTreasury::surplus_pool()
+		}
+
+		fn debit_pool() -> Balance {
+			CdpTreasury::debit_pool()
+		}
+
+		fn insurance_fund(currency_id: CurrencyId) -> Balance {
+			CdpTreasury::insurance_funds(currency_id)
+		}
+
+		fn loss_record(currency_id: CurrencyId) -> module_cdp_treasury::LossRecord {
+			CdpTreasury::loss_records(currency_id)
+		}
+	}
+
+	impl module_xcm_rate_limiter_runtime_api::XcmRateLimiterApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn remaining_inflow_quota(currency_id: CurrencyId) -> Option<Balance> {
+			XcmRateLimiter::remaining_quota(currency_id, module_xcm_rate_limiter::FlowDirection::Inflow)
+		}
+
+		fn remaining_outflow_quota(currency_id: CurrencyId) -> Option<Balance> {
+			XcmRateLimiter::remaining_quota(currency_id, module_xcm_rate_limiter::FlowDirection::Outflow)
+		}
+	}
+
+	impl module_stable_asset_runtime_api::StableAssetApi<
+		Block,
+		CurrencyId,
+		Balance,
+		AccountId,
+		BlockNumber,
+	> for Runtime {
+		fn pool_info(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+		) -> Option<nutsfinance_stable_asset::StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>> {
+			StableAssetQuoter::pool_info(pool_id)
+		}
+
+		fn get_virtual_price(pool_id: nutsfinance_stable_asset::StableAssetPoolId) -> Option<Ratio> {
+			StableAssetQuoter::get_virtual_price(pool_id)
+		}
+
+		fn get_swap_output_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			input_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+			input_amount: Balance,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_swap_output_amount(pool_id, input_index, output_index, input_amount)
+		}
+
+		fn get_swap_input_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			input_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_amount: Balance,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_swap_input_amount(pool_id, input_index, output_index, output_amount)
+		}
+
+		fn get_mint_amount(pool_id: nutsfinance_stable_asset::StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+			StableAssetQuoter::get_mint_amount(pool_id, amounts)
+		}
+
+		fn get_redeem_proportion_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			amount: Balance,
+		) -> Option<Vec<Balance>> {
+			StableAssetQuoter::get_redeem_proportion_amount(pool_id, amount)
+		}
+
+		fn get_redeem_single_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			amount: Balance,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_redeem_single_amount(pool_id, amount, output_index)
+		}
+
+		fn get_redeem_multi_amount(pool_id: nutsfinance_stable_asset::StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+			StableAssetQuoter::get_redeem_multi_amount(pool_id, amounts)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
@@ -2220,10 +2406,14 @@ sp_api::impl_runtime_apis! {
 			use frame_benchmarking::{list_benchmark as frame_list_benchmark, Benchmarking, BenchmarkList};
 			use frame_support::traits::StorageInfoTrait;
 			use module_nft::benchmarking::Pallet as NftBench;
+			use pallet_xcm_benchmarks::fungible::Pallet as XcmFungibleBench;
+			use pallet_xcm_benchmarks::generic::Pallet as XcmGenericBench;
 
 			let mut list = Vec::<BenchmarkList>::new();
 
 			frame_list_benchmark!(list, extra, module_nft, NftBench::<Runtime>);
+			frame_list_benchmark!(list, extra, pallet_xcm_benchmarks::fungible, XcmFungibleBench::<Runtime>);
+			frame_list_benchmark!(list, extra, pallet_xcm_benchmarks::generic, XcmGenericBench::<Runtime>);
 			list_benchmarks!(list, extra);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
@@ -2236,6 +2426,8 @@ sp_api::impl_runtime_apis! {
 		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
 			use frame_benchmarking::{Benchmarking, BenchmarkBatch, add_benchmark as frame_add_benchmark};
 			use module_nft::benchmarking::Pallet as NftBench;
+			use pallet_xcm_benchmarks::fungible::Pallet as XcmFungibleBench;
+			use pallet_xcm_benchmarks::generic::Pallet as XcmGenericBench;
 			use frame_support::traits::{WhitelistedStorageKeys, TrackedStorageKey};
 
 			let mut whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
@@ -2250,6 +2442,8 @@ sp_api::impl_runtime_apis! {
 			let params = (&config, &whitelist);
 
 			frame_add_benchmark!(params, batches, module_nft, NftBench::<Runtime>);
+			frame_add_benchmark!(params, batches, pallet_xcm_benchmarks::fungible, XcmFungibleBench::<Runtime>);
+			frame_add_benchmark!(params, batches, pallet_xcm_benchmarks::generic, XcmGenericBench::<Runtime>);
 			add_benchmarks!(params, batches);
 
 			if batches.is_empty() { return Err("Benchmark not found for this module.".into()) }
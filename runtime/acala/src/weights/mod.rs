@@ -40,6 +40,7 @@ pub mod module_prices;
 pub mod module_session_manager;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
+pub mod module_xcm_rate_limiter;
 
 pub mod orml_auction;
 pub mod orml_authority;
@@ -50,3 +51,5 @@ pub mod orml_vesting;
 pub mod nutsfinance_stable_asset;
 
 pub mod pallet_xcm;
+
+pub mod xcm;
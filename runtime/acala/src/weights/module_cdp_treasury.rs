@@ -47,7 +47,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions for module_cdp_treasury.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T> {
-	// Storage: `Tokens::Accounts` (r:1 w:0)
+	// Storage: `Tokens::Accounts` (r:3 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	// Storage: `AuctionManager::TotalCollateralInAuction` (r:1 w:1)
 	// Proof: `AuctionManager::TotalCollateralInAuction` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
@@ -59,6 +59,22 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 	// Proof: `Auction::AuctionsIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:1)
 	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::AbortedLiquidations` (r:2 w:1)
+	// Proof: `CdpTreasury::AbortedLiquidations` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::InsuranceFunds` (r:1 w:1)
+	// Proof: `CdpTreasury::InsuranceFunds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitOffsetBuffer` (r:1 w:0)
+	// Proof: `CdpTreasury::DebitOffsetBuffer` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitAuctionParameters` (r:1 w:0)
+	// Proof: `CdpTreasury::DebitAuctionParameters` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::LossRecords` (r:1 w:1)
+	// Proof: `CdpTreasury::LossRecords` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::LiquidationAuctions` (r:0 w:50)
+	// Proof: `AuctionManager::LiquidationAuctions` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
 	// Storage: `AuctionManager::CollateralAuctions` (r:0 w:50)
 	// Proof: `AuctionManager::CollateralAuctions` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	// Storage: `Auction::AuctionEndTime` (r:0 w:50)
@@ -69,14 +85,14 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 	fn auction_collateral(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2043`
-		//  Estimated: `3612`
+		//  Estimated: `6234`
 		// Minimum execution time: 45_378 nanoseconds.
-		Weight::from_parts(41_737_451, 3612)
+		Weight::from_parts(72_216_451, 6234)
 			// Standard Error: 18_050
 			.saturating_add(Weight::from_parts(12_589_626, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
 	}
 	// Storage: `Tokens::Accounts` (r:8 w:8)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
@@ -136,4 +152,87 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: `CdpTreasury::DebitAuctionParameters` (r:0 w:1)
+	// Proof: `CdpTreasury::DebitAuctionParameters` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	fn set_debit_auction_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1004`
+		//  Estimated: `0`
+		// Minimum execution time: 15_872 nanoseconds.
+		Weight::from_parts(15_890_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpTreasury::DebitAuctionParameters` (r:1 w:0)
+	// Proof: `CdpTreasury::DebitAuctionParameters` (`max_values`: Some(1), `max_size`: Some(48), added: 543, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitPool` (r:1 w:0)
+	// Proof: `CdpTreasury::DebitPool` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitOffsetBuffer` (r:1 w:0)
+	// Proof: `CdpTreasury::DebitOffsetBuffer` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:1 w:0)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::TotalDebitInAuction` (r:1 w:1)
+	// Proof: `AuctionManager::TotalDebitInAuction` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	// Storage: `CdpTreasury::DebitAuctionMintInPeriod` (r:1 w:1)
+	// Proof: `CdpTreasury::DebitAuctionMintInPeriod` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionsIndex` (r:1 w:1)
+	// Proof: `Auction::AuctionsIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	// Storage: `AuctionManager::DebitAuctions` (r:0 w:50)
+	// Proof: `AuctionManager::DebitAuctions` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	// Storage: `Auction::AuctionEndTime` (r:0 w:50)
+	// Proof: `Auction::AuctionEndTime` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	// Storage: `Auction::Auctions` (r:0 w:50)
+	// Proof: `Auction::Auctions` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 50]`.
+	fn on_initialize(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1592`
+		//  Estimated: `3612`
+		// Minimum execution time: 9_412 nanoseconds.
+		Weight::from_parts(14_205_385, 3612)
+			// Standard Error: 12_604
+			.saturating_add(Weight::from_parts(10_734_811, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+	}
+	// Storage: `CdpTreasury::InsuranceFundShares` (r:0 w:1)
+	// Proof: `CdpTreasury::InsuranceFundShares` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn set_insurance_fund_share() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1004`
+		//  Estimated: `0`
+		// Minimum execution time: 15_872 nanoseconds.
+		Weight::from_parts(15_890_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `CdpTreasury::InsuranceFunds` (r:1 w:1)
+	// Proof: `CdpTreasury::InsuranceFunds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn deposit_insurance_fund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1504`
+		//  Estimated: `6234`
+		// Minimum execution time: 46_260 nanoseconds.
+		Weight::from_parts(47_533_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `CdpTreasury::InsuranceFunds` (r:1 w:1)
+	// Proof: `CdpTreasury::InsuranceFunds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_insurance_fund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1613`
+		//  Estimated: `6234`
+		// Minimum execution time: 45_788 nanoseconds.
+		Weight::from_parts(46_890_000, 6234)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -49,25 +49,29 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ActiveTradingPairCount` (r:2 w:2)
+	// Proof: `Dex::ActiveTradingPairCount` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
 	fn enable_trading_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1106`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 18_496 nanoseconds.
-		Weight::from_parts(19_132_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(19_132_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ActiveTradingPairCount` (r:2 w:2)
+	// Proof: `Dex::ActiveTradingPairCount` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
 	fn disable_trading_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1143`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 19_965 nanoseconds.
-		Weight::from_parts(20_521_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(20_521_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
@@ -75,14 +79,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	// Storage: `Dex::ProvisioningPool` (r:1 w:0)
 	// Proof: `Dex::ProvisioningPool` (`max_values`: None, `max_size`: Some(166), added: 2641, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ActiveTradingPairCount` (r:2 w:2)
+	// Proof: `Dex::ActiveTradingPairCount` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
 	fn list_provisioning() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1148`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 25_691 nanoseconds.
-		Weight::from_parts(26_230_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(26_230_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
@@ -307,13 +313,15 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: `Dex::TradingPairStatuses` (r:1 w:1)
 	// Proof: `Dex::TradingPairStatuses` (`max_values`: None, `max_size`: Some(195), added: 2670, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ActiveTradingPairCount` (r:2 w:2)
+	// Proof: `Dex::ActiveTradingPairCount` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
 	fn abort_provisioning() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1280`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 24_260 nanoseconds.
-		Weight::from_parts(24_909_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(24_909_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 }
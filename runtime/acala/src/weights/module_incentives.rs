@@ -89,6 +89,8 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
 	// Proof: `Rewards::SharesAndWithdrawnRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `Incentives::CollateralDexShares` (r:1 w:0)
+	// Proof: `Incentives::CollateralDexShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	// Storage: `Tokens::Accounts` (r:2 w:2)
 	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
 	// Storage: `System::Account` (r:1 w:0)
@@ -97,11 +99,11 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 	// Proof: `Rewards::PoolInfos` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn withdraw_dex_share() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1880`
-		//  Estimated: `6234`
-		// Minimum execution time: 64_811 nanoseconds.
-		Weight::from_parts(66_403_000, 6234)
-			.saturating_add(T::DbWeight::get().reads(5))
+		//  Measured:  `1955`
+		//  Estimated: `6309`
+		// Minimum execution time: 67_079 nanoseconds.
+		Weight::from_parts(68_727_000, 6309)
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: `Rewards::SharesAndWithdrawnRewards` (r:1 w:1)
@@ -91,4 +91,105 @@ impl<T: frame_system::Config> module_transaction_pause::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `TransactionPause::PausedEvmContracts` (r:1 w:1)
+	// Proof: `TransactionPause::PausedEvmContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:0 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn pause_evm_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1172`
+		//  Estimated: `4637`
+		// Minimum execution time: 18_690 nanoseconds.
+		Weight::from_parts(19_102_000, 4637)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `TransactionPause::PausedEvmContracts` (r:1 w:1)
+	// Proof: `TransactionPause::PausedEvmContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:0 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unpause_evm_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1233`
+		//  Estimated: `4698`
+		// Minimum execution time: 19_902 nanoseconds.
+		Weight::from_parts(20_314_000, 4698)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `TransactionPause::PausedEvmContractFunctions` (r:1 w:1)
+	// Proof: `TransactionPause::PausedEvmContractFunctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:0 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn pause_evm_contract_function() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1172`
+		//  Estimated: `4637`
+		// Minimum execution time: 19_151 nanoseconds.
+		Weight::from_parts(19_563_000, 4637)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `TransactionPause::PausedEvmContractFunctions` (r:1 w:1)
+	// Proof: `TransactionPause::PausedEvmContractFunctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:0 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unpause_evm_contract_function() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1233`
+		//  Estimated: `4698`
+		// Minimum execution time: 20_175 nanoseconds.
+		Weight::from_parts(20_587_000, 4698)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `TransactionPause::PausedTransactions` (r:1 w:1)
+	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:0 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::ExpiringPauses` (r:0 w:1)
+	// Proof: `TransactionPause::ExpiringPauses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn pause_until() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1172`
+		//  Estimated: `4637`
+		// Minimum execution time: 24_459 nanoseconds.
+		Weight::from_parts(24_871_000, 4637)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `TransactionPause::PausedTransactions` (r:1 w:1)
+	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:1 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::ExpiringPauses` (r:0 w:1)
+	// Proof: `TransactionPause::ExpiringPauses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn guardian_pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1172`
+		//  Estimated: `4637`
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_parts(27_412_000, 4637)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: `TransactionPause::ExpiringPauses` (r:1 w:1)
+	// Proof: `TransactionPause::ExpiringPauses` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PauseExpiries` (r:1 w:1)
+	// Proof: `TransactionPause::PauseExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `TransactionPause::PausedTransactions` (r:1 w:1)
+	// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn on_initialize(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9 + c * (94 ±0)`
+		//  Estimated: `3474 + c * (2569 ±0)`
+		// Minimum execution time: 2_740 nanoseconds.
+		Weight::from_parts(3_152_000, 3474)
+			// Standard Error: 12_407
+			.saturating_add(Weight::from_parts(9_846_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(c.into()))
+	}
 }
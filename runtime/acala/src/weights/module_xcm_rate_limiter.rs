@@ -0,0 +1,102 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_xcm_rate_limiter
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-05, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-42-209`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! WASM-EXECUTION: Compiled, CHAIN: Some("acala-dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/production/acala
+// benchmark
+// pallet
+// --chain=acala-dev
+// --steps=50
+// --repeat=20
+// --pallet=*
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./templates/runtime-weight-template.hbs
+// --output=./runtime/acala/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_xcm_rate_limiter.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_xcm_rate_limiter::WeightInfo for WeightInfo<T> {
+	// Storage: `XcmRateLimiter::Flows` (r:0 w:1)
+	// Proof: `XcmRateLimiter::Flows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	// Storage: `XcmRateLimiter::RateLimits` (r:0 w:1)
+	// Proof: `XcmRateLimiter::RateLimits` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `691`
+		//  Estimated: `0`
+		// Minimum execution time: 15_568 nanoseconds.
+		Weight::from_parts(15_967_520, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `XcmRateLimiter::CircuitBreakers` (r:0 w:1)
+	// Proof: `XcmRateLimiter::CircuitBreakers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_circuit_breaker() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `691`
+		//  Estimated: `0`
+		// Minimum execution time: 11_757 nanoseconds.
+		Weight::from_parts(12_059_360, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `XcmRateLimiter::QueuedInflows` (r:1 w:1)
+	// Proof: `XcmRateLimiter::QueuedInflows` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_queued_inflow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2051`
+		//  Estimated: `3612`
+		// Minimum execution time: 51_973 nanoseconds.
+		Weight::from_parts(53_306_640, 3612)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `XcmRateLimiter::QueuedInflows` (r:1 w:1)
+	// Proof: `XcmRateLimiter::QueuedInflows` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::Accounts` (r:2 w:2)
+	// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(147), added: 2622, mode: `MaxEncodedLen`)
+	// Storage: `System::Account` (r:1 w:0)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_queued_inflow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2115`
+		//  Estimated: `3612`
+		// Minimum execution time: 44_506 nanoseconds.
+		Weight::from_parts(45_647_640, 3612)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -18,10 +18,10 @@
 
 use super::{
 	constants::{fee::*, parachains},
-	AcalaTreasuryAccount, AccountId, AllPalletsWithSystem, AssetIdMapping, AssetIdMaps, Balance, Balances, Convert,
-	Currencies, CurrencyId, EvmAddressMapping, ExistentialDeposits, GetNativeCurrencyId, NativeTokenExistentialDeposit,
-	ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, UnknownTokens,
-	XcmInterface, XcmpQueue, ACA, AUSD, TAP,
+	AcalaSwap, AcalaTreasuryAccount, AccountId, AllPalletsWithSystem, AssetIdMapping, AssetIdMaps, Balance, Balances,
+	Convert, Currencies, CurrencyId, EvmAddressMapping, ExistentialDeposits, GetNativeCurrencyId,
+	NativeTokenExistentialDeposit, ParachainInfo, ParachainSystem, PolkadotXcm, Ratio, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, UnknownTokens, XcmInterface, XcmpQueue, ACA, AUSD, TAP,
 };
 use cumulus_primitives_core::ParaId;
 use frame_support::{
@@ -29,21 +29,24 @@ use frame_support::{
 	traits::{ConstU32, Everything, Get, Nothing},
 };
 use module_asset_registry::{
-	BuyWeightRateOfErc20, BuyWeightRateOfForeignAsset, BuyWeightRateOfLiquidCrowdloan, BuyWeightRateOfStableAsset,
+	BuyWeightRateOfDexPrice, BuyWeightRateOfErc20, BuyWeightRateOfForeignAsset, BuyWeightRateOfLiquidCrowdloan,
+	BuyWeightRateOfStableAsset,
 };
 use module_support::HomaSubAccountXcm;
 use module_transaction_payment::BuyWeightRateOfTransactionFeePool;
+use module_xcm_rate_limiter::RateLimitedTransactor;
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
 use parity_scale_codec::{Decode, Encode};
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use primitives::evm::is_system_contract;
 use runtime_common::{
-	local_currency_location, native_currency_location, AcalaDropAssets, EnsureRootOrHalfGeneralCouncil,
-	EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
+	local_currency_location, native_currency_location, AcalaDropAssets, DexAssetExchange,
+	EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
 };
+use sp_runtime::FixedPointNumber;
 use xcm::{prelude::*, v3::Weight as XcmWeight};
-use xcm_builder::{EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, SignedToAccountId32};
+use xcm_builder::{EnsureXcmOrigin, FixedRateOfFungible, SignedToAccountId32, WeightInfoBounds};
 
 parameter_types! {
 	pub const RelayNetwork: NetworkId = NetworkId::Polkadot;
@@ -66,9 +69,9 @@ pub type Barrier = runtime_common::xcm_config::Barrier<PolkadotXcm, UniversalLoc
 
 pub type ToTreasury = runtime_common::xcm_config::ToTreasury<CurrencyIdConvert, AcalaTreasuryAccount, Currencies>;
 
+pub type XcmWeigher = WeightInfoBounds<crate::weights::xcm::AcalaXcmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
+
 parameter_types! {
-	// One XCM operation is 200_000_000 weight, cross-chain transfer ~= 2x of transfer.
-	pub const UnitWeightCost: XcmWeight = XcmWeight::from_parts(200_000_000, 0);
 	pub const MaxInstructions: u32 = 100;
 	pub DotPerSecond: (AssetId, u128, u128) = (MultiLocation::parent().into(), dot_per_second(), 0);
 	pub AusdPerSecond: (AssetId, u128, u128) = (
@@ -90,6 +93,7 @@ parameter_types! {
 		0
 	);
 	pub BaseRate: u128 = aca_per_second();
+	pub XcmFeeSafetyMargin: Ratio = Ratio::saturating_from_rational(10, 100);
 }
 
 pub type Trader = (
@@ -102,6 +106,17 @@ pub type Trader = (
 	FixedRateOfFungible<DotPerSecond, ToTreasury>,
 	FixedRateOfFungible<AusdPerSecond, ToTreasury>,
 	FixedRateOfFungible<TapPerSecond, ToTreasury>,
+	FixedRateOfAsset<
+		BaseRate,
+		ToTreasury,
+		BuyWeightRateOfDexPrice<
+			Runtime,
+			CurrencyIdConvert,
+			module_dex_oracle::AverageDEXPriceProvider<Runtime>,
+			GetNativeCurrencyId,
+			XcmFeeSafetyMargin,
+		>,
+	>,
 );
 
 pub struct XcmConfig;
@@ -116,7 +131,7 @@ impl xcm_executor::Config for XcmConfig {
 	type IsTeleporter = ();
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type Trader = Trader;
 	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = AcalaDropAssets<
@@ -128,7 +143,7 @@ impl xcm_executor::Config for XcmConfig {
 		ExistentialDeposits,
 	>;
 	type AssetLocker = ();
-	type AssetExchanger = ();
+	type AssetExchanger = DexAssetExchange<AccountId, AcalaTreasuryAccount, CurrencyIdConvert, Currencies, AcalaSwap>;
 	type AssetClaims = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
@@ -174,7 +189,7 @@ impl pallet_xcm::Config for Runtime {
 	type XcmExecutor = XcmExecutor;
 	type XcmTeleportFilter = Nothing;
 	type XcmReserveTransferFilter = Everything;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type UniversalLocation = UniversalLocation;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
@@ -216,15 +231,20 @@ impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type ExecuteOverweightOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
-pub type LocalAssetTransactor = MultiCurrencyAdapter<
-	Currencies,
-	UnknownTokens,
-	IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-	AccountId,
-	LocationToAccountId,
-	CurrencyId,
+pub type LocalAssetTransactor = RateLimitedTransactor<
+	Runtime,
+	MultiCurrencyAdapter<
+		Currencies,
+		UnknownTokens,
+		IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+		AccountId,
+		LocationToAccountId,
+		CurrencyId,
+		CurrencyIdConvert,
+		DepositToAlternative<AcalaTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	>,
 	CurrencyIdConvert,
-	DepositToAlternative<AcalaTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	LocationToAccountId,
 >;
 
 pub struct CurrencyIdConvert;
@@ -344,7 +364,7 @@ impl orml_xtokens::Config for Runtime {
 	type AccountIdToMultiLocation = runtime_common::xcm_config::AccountIdToMultiLocation;
 	type SelfLocation = SelfLocation;
 	type XcmExecutor = XcmExecutor;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type BaseXcmWeight = BaseXcmWeight;
 	type UniversalLocation = UniversalLocation;
 	type MaxAssetsForTransfer = MaxAssetsForTransfer;
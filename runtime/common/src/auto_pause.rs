@@ -0,0 +1,139 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Circuit breakers which pause calls through `AutoPause` for a limited time.
+
+use frame_support::traits::{Get, PalletInfoAccess};
+use frame_system::pallet_prelude::BlockNumberFor;
+use module_support::{AutoPause, PauseItem, Price, PriceProvider, Ratio};
+use orml_traits::{DataProvider, Happened, OnNewData};
+use primitives::{Balance, CurrencyId, TradingPair};
+use sp_runtime::{traits::CheckedDiv, FixedPointNumber, Saturating};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The deviation of `value` from `reference`, `None` if `reference` is zero.
+fn deviation(value: Price, reference: Price) -> Option<Ratio> {
+	let diff = value.max(reference).saturating_sub(value.min(reference));
+	diff.checked_div(&reference)
+}
+
+fn pause_calls<T: frame_system::Config, P: AutoPause<BlockNumberFor<T>>>(
+	pallet_name: &str,
+	function_names: &[&str],
+	duration: BlockNumberFor<T>,
+) {
+	for function_name in function_names {
+		let item = PauseItem::Transaction(pallet_name.as_bytes().to_vec(), function_name.as_bytes().to_vec());
+		if let Err(e) = P::auto_pause(item, duration) {
+			log::warn!(
+				target: "auto-pause",
+				"auto pause {:?}::{:?} failed: {:?}",
+				pallet_name, function_name, e
+			);
+		}
+	}
+}
+
+/// Pauses loans and liquidations when an oracle feed deviates from the current price by more than
+/// `MaxDeviation`.
+pub struct OracleDeviationAutoPause<T, I, P, MaxDeviation, Duration>(PhantomData<(T, I, P, MaxDeviation, Duration)>);
+impl<T, I, P, MaxDeviation, Duration> OnNewData<T::AccountId, CurrencyId, Price>
+	for OracleDeviationAutoPause<T, I, P, MaxDeviation, Duration>
+where
+	T: orml_oracle::Config<I, OracleKey = CurrencyId, OracleValue = Price>
+		+ module_honzon::Config
+		+ module_cdp_engine::Config,
+	I: 'static,
+	P: AutoPause<BlockNumberFor<T>>,
+	MaxDeviation: Get<Ratio>,
+	Duration: Get<BlockNumberFor<T>>,
+{
+	fn on_new_data(_who: &T::AccountId, key: &CurrencyId, value: &Price) {
+		let current = <orml_oracle::Pallet<T, I> as DataProvider<CurrencyId, Price>>::get(key);
+		if current
+			.and_then(|current| deviation(*value, current))
+			.map_or(false, |d| d > MaxDeviation::get())
+		{
+			pause_calls::<T, P>(
+				<module_honzon::Pallet<T> as PalletInfoAccess>::name(),
+				&["adjust_loan", "adjust_loan_by_debit_value"],
+				Duration::get(),
+			);
+			pause_calls::<T, P>(
+				<module_cdp_engine::Pallet<T> as PalletInfoAccess>::name(),
+				&["liquidate"],
+				Duration::get(),
+			);
+		}
+	}
+}
+
+/// Pauses DEX swaps when the price of a liquidity pool moves away from the oracle price by more
+/// than `MaxDeviation`.
+pub struct DexPriceMoveAutoPause<T, P, MaxDeviation, Duration>(PhantomData<(T, P, MaxDeviation, Duration)>);
+impl<T, P, MaxDeviation, Duration> Happened<(TradingPair, Balance, Balance)>
+	for DexPriceMoveAutoPause<T, P, MaxDeviation, Duration>
+where
+	T: module_dex::Config + module_prices::Config,
+	P: AutoPause<BlockNumberFor<T>>,
+	MaxDeviation: Get<Ratio>,
+	Duration: Get<BlockNumberFor<T>>,
+{
+	fn happened((trading_pair, pool_0, pool_1): &(TradingPair, Balance, Balance)) {
+		let pool_price = Price::checked_from_rational(*pool_1, *pool_0);
+		let oracle_price =
+			module_prices::RealTimePriceProvider::<T>::get_relative_price(trading_pair.first(), trading_pair.second());
+		if let (Some(pool_price), Some(oracle_price)) = (pool_price, oracle_price) {
+			if deviation(pool_price, oracle_price).map_or(false, |d| d > MaxDeviation::get()) {
+				pause_calls::<T, P>(
+					<module_dex::Pallet<T> as PalletInfoAccess>::name(),
+					&["swap_with_exact_supply", "swap_with_exact_target"],
+					Duration::get(),
+				);
+			}
+		}
+	}
+}
+
+/// Pauses Homa minting and redeeming when the bonded amount of a sub account ledger is reset by
+/// more than `MaxMismatch`.
+pub struct HomaLedgerMismatchAutoPause<T, P, MaxMismatch, Duration>(PhantomData<(T, P, MaxMismatch, Duration)>);
+impl<T, P, MaxMismatch, Duration> Happened<(u16, Balance, Balance)>
+	for HomaLedgerMismatchAutoPause<T, P, MaxMismatch, Duration>
+where
+	T: module_homa::Config,
+	P: AutoPause<BlockNumberFor<T>>,
+	MaxMismatch: Get<Ratio>,
+	Duration: Get<BlockNumberFor<T>>,
+{
+	fn happened((_sub_account_index, old_bonded, new_bonded): &(u16, Balance, Balance)) {
+		let mismatch = Ratio::checked_from_rational(
+			(*old_bonded)
+				.max(*new_bonded)
+				.saturating_sub((*old_bonded).min(*new_bonded)),
+			*old_bonded,
+		);
+		if mismatch.map_or(false, |m| m > MaxMismatch::get()) {
+			pause_calls::<T, P>(
+				<module_homa::Pallet<T> as PalletInfoAccess>::name(),
+				&["mint", "request_redeem", "fast_match_redeems"],
+				Duration::get(),
+			);
+		}
+	}
+}
@@ -32,7 +32,8 @@ use frame_support::{
 	},
 };
 use frame_system::{limits, EnsureRoot};
-use orml_traits::{currency::MutationHooks, GetByKey};
+use module_support::PrecompilePauseFilter;
+use orml_traits::{currency::MutationHooks, GetByKey, MultiCurrency};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use polkadot_parachain_primitives::primitives::RelayChainBlockNumber;
 use primitives::{
@@ -41,7 +42,11 @@ use primitives::{
 };
 use scale_info::TypeInfo;
 use sp_core::{Bytes, H160};
-use sp_runtime::{traits::Convert, transaction_validity::TransactionPriority, Perbill, RuntimeDebug};
+use sp_runtime::{
+	traits::{Convert, Zero},
+	transaction_validity::TransactionPriority,
+	Perbill, RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 use static_assertions::const_assert;
 
@@ -52,10 +57,15 @@ pub use precompile::{
 	SchedulePrecompile, StableAssetPrecompile,
 };
 pub use primitives::{
-	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KBTC, KINT, KSM, KUSD, LCDOT, LDOT, LKSM, PHA, TAI, TAP, VSKSM},
+	currency::{
+		TokenInfo, ACA, AUSD, BNC, DOT, KAR, KBTC, KINT, KSM, KUSD, LCDOT, LDOT, LKSM, PHA, SAUSD, SKUSD, TAI, TAP,
+		VSKSM,
+	},
 	AccountId,
 };
-pub use xcm_impl::{local_currency_location, native_currency_location, AcalaDropAssets, FixedRateOfAsset, XcmExecutor};
+pub use xcm_impl::{
+	local_currency_location, native_currency_location, AcalaDropAssets, DexAssetExchange, FixedRateOfAsset, XcmExecutor,
+};
 
 #[cfg(feature = "std")]
 use module_evm::GenesisAccount;
@@ -64,6 +74,7 @@ use sp_core::bytes::from_hex;
 #[cfg(feature = "std")]
 use std::{collections::btree_map::BTreeMap, str::FromStr};
 
+pub mod auto_pause;
 pub mod bench;
 pub mod check_nonce;
 pub mod precompile;
@@ -391,6 +402,56 @@ where
 	type OnKilledTokenAccount = ();
 }
 
+/// An asset is in use if it still has issuance, an active DEX trading pair or a charge fee pool.
+///
+/// ERC20 balances live in the contract, so their issuance doesn't prevent deregistering.
+pub struct AssetInUse<T>(PhantomData<T>);
+impl<T> Contains<CurrencyId> for AssetInUse<T>
+where
+	T: module_currencies::Config + module_dex::Config + module_transaction_payment::Config,
+{
+	fn contains(currency_id: &CurrencyId) -> bool {
+		if !currency_id.is_erc20_currency_id()
+			&& !<module_currencies::Pallet<T> as MultiCurrency<T::AccountId>>::total_issuance(*currency_id).is_zero()
+		{
+			return true;
+		}
+
+		module_transaction_payment::TokenExchangeRate::<T>::contains_key(currency_id)
+			|| !module_dex::ActiveTradingPairCount::<T>::get(currency_id).is_zero()
+	}
+}
+
+/// EVM calls to a paused contract or contract function.
+///
+/// Rejects the extrinsic before it is dispatched, every call frame is checked again by the EVM runner through
+/// `module_evm::Config::ContractPauseFilter`.
+pub struct PausedEvmCallFilter<T>(PhantomData<T>);
+impl<T> Contains<module_evm::Call<T>> for PausedEvmCallFilter<T>
+where
+	T: module_evm::Config + module_transaction_pause::Config,
+{
+	fn contains(call: &module_evm::Call<T>) -> bool {
+		let (target, input) = match call {
+			module_evm::Call::call { target, input, .. }
+			| module_evm::Call::eth_call {
+				action: module_evm::TransactionAction::Call(target),
+				input,
+				..
+			}
+			| module_evm::Call::eth_call_v2 {
+				action: module_evm::TransactionAction::Call(target),
+				input,
+				..
+			} => (target, input),
+			_ => return false,
+		};
+		<module_transaction_pause::PausedPrecompileFilter<T> as PrecompilePauseFilter>::is_contract_call_paused(
+			*target, input,
+		)
+	}
+}
+
 pub struct EvmLimits<T>(PhantomData<T>);
 impl<T> EvmLimits<T>
 where
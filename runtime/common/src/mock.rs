@@ -222,6 +222,7 @@ impl module_evm::Config for TestRuntime {
 	type FindAuthor = AuthorGiven;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = ();
 	type WeightInfo = ();
 }
 
@@ -21,14 +21,15 @@ use super::{
 	target_gas_limit,
 };
 use crate::WeightToGas;
-use frame_support::traits::Get;
+use frame_support::{traits::Get, weights::Weight};
+use module_aggregated_dex::WeightInfo as AggregatedDexWeightInfo;
 use module_dex::WeightInfo;
 use module_evm::{
 	precompiles::Precompile,
 	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
 	Context, ExitError, ExitRevert, ExitSucceed,
 };
-use module_support::{DEXManager, SwapLimit};
+use module_support::{DEXManager, Swap, SwapLimit};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{traits::Convert, RuntimeDebug};
@@ -43,6 +44,10 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Get liquidity. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
 /// - Swap with exact supply. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
 ///   `supply_amount`, `min_target_amount`.
+/// - Get best route target amount. Rest `input` bytes: `supply_currency_id`, `target_currency_id`,
+///   `supply_amount`.
+/// - Swap with best route. Rest `input` bytes: `who`, `supply_currency_id`, `target_currency_id`,
+///   `supply_amount`, `min_target_amount`.
 pub struct DEXPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -57,11 +62,13 @@ pub enum Action {
 	SwapWithExactTarget = "swapWithExactTarget(address,address[],uint256,uint256)",
 	AddLiquidity = "addLiquidity(address,address,address,uint256,uint256,uint256)",
 	RemoveLiquidity = "removeLiquidity(address,address,address,uint256,uint256,uint256)",
+	GetBestRouteTargetAmount = "getBestRouteTargetAmount(address,address,uint256)",
+	SwapWithBestRoute = "swapWithBestRoute(address,address,address,uint256,uint256)",
 }
 
 impl<Runtime> Precompile for DEXPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_dex::Config + module_prices::Config,
+	Runtime: module_evm::Config + module_dex::Config + module_prices::Config + module_aggregated_dex::Config,
 	module_dex::Pallet<Runtime>: DEXManager<Runtime::AccountId, Balance, CurrencyId>,
 {
 	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
@@ -315,6 +322,63 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::GetBestRouteTargetAmount => {
+				let supply_currency_id = input.currency_id_at(1)?;
+				let target_currency_id = input.currency_id_at(2)?;
+				let supply_amount = input.balance_at(3)?;
+				log::debug!(
+					target: "evm",
+					"dex: get_best_route_target_amount supply_currency_id: {:?}, target_currency_id: {:?}, supply_amount: {:?}",
+					supply_currency_id, target_currency_id, supply_amount
+				);
+
+				// If there is no route, return 0.
+				let target = module_aggregated_dex::RoutedSwap::<Runtime>::get_swap_amount(
+					supply_currency_id,
+					target_currency_id,
+					SwapLimit::ExactSupply(supply_amount, Balance::MIN),
+				)
+				.map(|(_, target)| target)
+				.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(target),
+					logs: Default::default(),
+				})
+			}
+			Action::SwapWithBestRoute => {
+				let who = input.account_id_at(1)?;
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+				let supply_amount = input.balance_at(4)?;
+				let min_target_amount = input.balance_at(5)?;
+				log::debug!(
+					target: "evm",
+					"dex: swap_with_best_route who: {:?}, supply_currency_id: {:?}, target_currency_id: {:?}, supply_amount: {:?}, min_target_amount: {:?}",
+					who, supply_currency_id, target_currency_id, supply_amount, min_target_amount
+				);
+
+				let (_, value) = module_aggregated_dex::RoutedSwap::<Runtime>::swap(
+					&who,
+					supply_currency_id,
+					target_currency_id,
+					SwapLimit::ExactSupply(supply_amount, min_target_amount),
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("DEX SwapWithBestRoute failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(value),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -323,10 +387,21 @@ struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
 where
-	Runtime: module_evm::Config + module_dex::Config,
+	Runtime: module_evm::Config + module_dex::Config + module_aggregated_dex::Config,
 {
 	const BASE_COST: u64 = 200;
 
+	/// `RoutedSwap` quotes `AggregatedSwap` and the best route of the router, then swaps along one
+	/// of them.
+	fn routed_swap_weight() -> Weight {
+		<Runtime as module_aggregated_dex::Config>::WeightInfo::route_swap(
+			<Runtime as module_aggregated_dex::Config>::MaxRouteSearchNodes::get(),
+		)
+		.saturating_add(<Runtime as module_dex::Config>::WeightInfo::swap_with_exact_supply(
+			<Runtime as module_dex::Config>::TradingPathLimit::get(),
+		))
+	}
+
 	fn cost(
 		input: &Input<
 			Action,
@@ -471,6 +546,36 @@ where
 					.saturating_add(read_currency_b)
 					.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::GetBestRouteTargetAmount => {
+				let supply_currency_id = input.currency_id_at(1)?;
+				let target_currency_id = input.currency_id_at(2)?;
+
+				let read_supply_currency = InputPricer::<Runtime>::read_currency(supply_currency_id);
+				let read_target_currency = InputPricer::<Runtime>::read_currency(target_currency_id);
+
+				let weight = Self::routed_swap_weight();
+
+				Self::BASE_COST
+					.saturating_add(read_supply_currency)
+					.saturating_add(read_target_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SwapWithBestRoute => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let supply_currency_id = input.currency_id_at(2)?;
+				let target_currency_id = input.currency_id_at(3)?;
+
+				let read_supply_currency = InputPricer::<Runtime>::read_currency(supply_currency_id);
+				let read_target_currency = InputPricer::<Runtime>::read_currency(target_currency_id);
+
+				let weight = Self::routed_swap_weight();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_supply_currency)
+					.saturating_add(read_target_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(cost)
 	}
@@ -790,4 +895,112 @@ mod tests {
 			assert_eq!(resp.output, expected_output.to_vec());
 		});
 	}
+
+	#[test]
+	fn get_best_route_target_amount_works() {
+		new_test_ext().execute_with(|| {
+			// enable DOT/AUSD
+			assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				1_000,
+				1_000_000,
+				0,
+				true
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getBestRouteTargetAmount(address,address,uint256) -> 0xeaa6c974
+			// DOT
+			// AUSD
+			// supply_amount
+			let input = hex! {"
+				eaa6c974
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			// 989
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003dd
+			"};
+
+			let resp = DEXPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+
+			// no route from AUSD to LDOT
+			// getBestRouteTargetAmount(address,address,uint256) -> 0xeaa6c974
+			// AUSD
+			// LDOT
+			// supply_amount
+			let input = hex! {"
+				eaa6c974
+				000000000000000000000000 0000000000000000000100000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000003
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let resp = DEXPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, [0u8; 32].to_vec());
+		});
+	}
+
+	#[test]
+	fn swap_with_best_route_works() {
+		new_test_ext().execute_with(|| {
+			// enable DOT/AUSD
+			assert_ok!(DexModule::enable_trading_pair(RuntimeOrigin::signed(ALICE), DOT, AUSD,));
+
+			assert_ok!(DexModule::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				AUSD,
+				1_000,
+				1_000_000,
+				0,
+				true
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// swapWithBestRoute(address,address,address,uint256,uint256) -> 0xa1d7de4f
+			// who
+			// DOT
+			// AUSD
+			// supply_amount
+			// min_target_amount
+			let input = hex! {"
+				a1d7de4f
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// 989
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003dd
+			"};
+
+			let resp = DEXPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(resp.exit_status, ExitSucceed::Returned);
+			assert_eq!(resp.output, expected_output.to_vec());
+		});
+	}
 }
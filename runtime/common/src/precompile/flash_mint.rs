@@ -0,0 +1,387 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use ethabi::Token;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	AddressMapping, Context, EVMTrait, ExecutionMode, ExitError, ExitRevert, ExitSucceed, InvokeContext,
+};
+use module_flash_mint::WeightInfo;
+use module_support::{evm::limits::flash_mint, FlashMintBorrower};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::Balance;
+use sp_core::{Get, H160, U256};
+use sp_runtime::{
+	traits::{Convert, UniqueSaturatedInto},
+	DispatchError, DispatchResult, RuntimeDebug,
+};
+use sp_std::{cell::Cell, marker::PhantomData, prelude::*};
+
+/// The `FlashMint` impl precompile.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - GetFlashMintFeeRate. No input.
+/// - GetMaxFlashMint. No input.
+/// - FlashFee. Rest `input` bytes: `amount`.
+/// - FlashMint. Rest `input` bytes: `initiator`, `receiver`, `amount`, `data`.
+pub struct FlashMintPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetFlashMintFeeRate = "getFlashMintFeeRate()",
+	GetMaxFlashMint = "getMaxFlashMint()",
+	FlashFee = "flashFee(uint256)",
+	FlashMint = "flashMint(address,address,uint256,bytes)",
+}
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum BorrowerAction {
+	OnFlashMint = "onFlashMint(address,uint256,uint256,bytes)",
+}
+
+/// The value the receiver contract must return from `onFlashMint`.
+pub fn on_flash_mint_return_value() -> [u8; 32] {
+	sp_io::hashing::keccak_256(b"FlashMintBorrower.onFlashMint")
+}
+
+/// Calls `onFlashMint` of the receiver contract on flash mint.
+struct EvmFlashMintBorrower<R> {
+	precompile: H160,
+	initiator: H160,
+	receiver: H160,
+	data: Vec<u8>,
+	used_gas: Cell<u64>,
+	_marker: PhantomData<R>,
+}
+
+impl<Runtime> FlashMintBorrower<Runtime::AccountId, Balance> for EvmFlashMintBorrower<Runtime>
+where
+	Runtime: module_evm::Config,
+{
+	fn on_flash_mint(&self, _who: &Runtime::AccountId, amount: Balance, fee: Balance) -> DispatchResult {
+		// borrower contract method hash
+		let mut input = Into::<u32>::into(BorrowerAction::OnFlashMint).to_be_bytes().to_vec();
+		// append initiator address, minted amount, fee and data
+		input.extend_from_slice(&ethabi::encode(&[
+			Token::Address(self.initiator),
+			Token::Uint(U256::from(amount)),
+			Token::Uint(U256::from(fee)),
+			Token::Bytes(self.data.clone()),
+		]));
+
+		let info = <module_evm::Pallet<Runtime> as EVMTrait<Runtime::AccountId>>::execute(
+			InvokeContext {
+				contract: self.receiver,
+				sender: self.precompile,
+				origin: self.receiver,
+			},
+			input,
+			Default::default(),
+			flash_mint::ON_FLASH_MINT.gas,
+			flash_mint::ON_FLASH_MINT.storage,
+			ExecutionMode::Execute,
+		)?;
+		let used_gas: u64 = info.used_gas.unique_saturated_into();
+		self.used_gas.set(self.used_gas.get().saturating_add(used_gas));
+
+		if info.exit_reason.is_succeed() && info.value == on_flash_mint_return_value() {
+			Ok(())
+		} else {
+			Err(DispatchError::Other("flash mint callback failed"))
+		}
+	}
+}
+
+impl<Runtime> Precompile for FlashMintPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_flash_mint::Config,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetFlashMintFeeRate => {
+				let rate = <module_flash_mint::Pallet<Runtime>>::fee_rate();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+			Action::GetMaxFlashMint => {
+				let max_amount = <module_flash_mint::Pallet<Runtime>>::max_flash_mint();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(max_amount),
+					logs: Default::default(),
+				})
+			}
+			Action::FlashFee => {
+				let amount = input.balance_at(1)?;
+				let fee = <module_flash_mint::Pallet<Runtime>>::flash_fee(amount);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(fee),
+					logs: Default::default(),
+				})
+			}
+			Action::FlashMint => {
+				let initiator = input.evm_address_at(1)?;
+				let receiver = input.evm_address_at(2)?;
+				let amount = input.balance_at(3)?;
+				let data = input.bytes_at(4)?;
+
+				let who = Runtime::AddressMapping::get_account_id(&receiver);
+				let borrower = EvmFlashMintBorrower::<Runtime> {
+					precompile: context.address,
+					initiator,
+					receiver,
+					data,
+					used_gas: Cell::new(0),
+					_marker: PhantomData,
+				};
+
+				let fee = <module_flash_mint::Pallet<Runtime>>::flash_mint(&who, amount, &borrower).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("FlashMint flashMint failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				log::debug!(target: "evm", "flash mint: FlashMint initiator: {:?}, receiver: {:?}, amount: {:?}, output: {:?}", initiator, receiver, amount, fee);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost.saturating_add(borrower.used_gas.get()),
+					output: Output::encode_uint(fee),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_flash_mint::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost = match action {
+			Action::GetFlashMintFeeRate | Action::FlashFee => {
+				// FlashMint::FeeRate (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetMaxFlashMint => {
+				// FlashMint::MaxFlashMint (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::FlashMint => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_flash_mint::Config>::WeightInfo::flash_mint();
+
+				read_account.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice_evm_addr, new_test_ext, Currencies, FlashMint, RuntimeOrigin, Test, AUSD};
+	use frame_support::assert_ok;
+	use hex_literal::hex;
+	use module_support::Rate;
+	use orml_traits::MultiCurrency;
+	use sp_runtime::FixedPointNumber;
+
+	type FlashMintPrecompile = crate::precompile::FlashMintPrecompile<Test>;
+
+	fn context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		}
+	}
+
+	#[test]
+	fn flash_mint_params_and_fee_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(FlashMint::set_flash_mint_params(
+				RuntimeOrigin::root(),
+				Rate::saturating_from_rational(1, 1_000),
+				1_000_000_000
+			));
+
+			// getFlashMintFeeRate() -> 0xc72e264c
+			let input = hex! {"
+				c72e264c
+			"};
+
+			// 1/1_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000038d7ea4c68000
+			"};
+
+			let res = FlashMintPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getMaxFlashMint() -> 0x159b0bf2
+			let input = hex! {"
+				159b0bf2
+			"};
+
+			// 1_000_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+			"};
+
+			let res = FlashMintPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// flashFee(uint256) -> 0xa7af467a
+			// amount 1_000_000
+			let input = hex! {"
+				a7af467a
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+
+			// 1_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000003e8
+			"};
+
+			let res = FlashMintPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn flash_mint_to_non_borrower_reverts() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(FlashMint::set_flash_mint_params(
+				RuntimeOrigin::root(),
+				Rate::saturating_from_rational(1, 1_000),
+				1_000_000_000
+			));
+			let total_issuance = Currencies::total_issuance(AUSD);
+
+			// flashMint(address,address,uint256,bytes) -> 0x3b9d401e
+			// initiator
+			// receiver
+			// amount 1_000_000
+			// data offset
+			// data length
+			let input = hex! {"
+				3b9d401e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// the receiver is not a contract returning the expected value
+			let res = FlashMintPrecompile::execute(&input, None, &context(), false);
+			assert!(matches!(
+				res,
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					..
+				})
+			));
+			assert_eq!(Currencies::total_issuance(AUSD), total_issuance);
+			assert_eq!(FlashMint::outstanding_flash_mint(), 0);
+		});
+	}
+
+	#[test]
+	fn flash_mint_exceeding_max_reverts() {
+		new_test_ext().execute_with(|| {
+			// flashMint(address,address,uint256,bytes) -> 0x3b9d401e
+			// initiator
+			// receiver
+			// amount 1_000_000
+			// data offset
+			// data length
+			let input = hex! {"
+				3b9d401e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			let res = FlashMintPrecompile::execute(&input, None, &context(), false);
+			assert!(matches!(
+				res,
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					..
+				})
+			));
+		});
+	}
+}
@@ -21,6 +21,7 @@ use super::{
 	target_gas_limit,
 };
 use crate::WeightToGas;
+use ethabi::Token;
 use frame_support::traits::Get;
 use module_evm::{
 	precompiles::Precompile,
@@ -28,9 +29,10 @@ use module_evm::{
 	Context, ExitError, ExitRevert, ExitSucceed,
 };
 use module_honzon::WeightInfo;
-use module_support::HonzonManager;
+use module_support::{Erc20InfoMapping, HonzonManager, WrappedPositionManager};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{Amount, Balance, CurrencyId, Position};
+use sp_core::U256;
 use sp_runtime::{traits::Convert, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -45,6 +47,13 @@ use sp_std::{marker::PhantomData, prelude::*};
 ///  - Get position. `input` bytes: `who`, `currency_id`.
 ///  - Get liquidation ratio. `input` bytes: `currency_id`.
 ///  - Get current collateral ratio. `input` bytes: `who`, `currency_id`.
+///  - Get debit exchange rate. `input` bytes: `currency_id`.
+///  - Wrap position. `input` bytes: `who`, `currency_id`.
+///  - Unwrap position. `input` bytes: `who`, `token_id`.
+///  - Adjust wrapped loan. `input` bytes: `who`, `token_id`, `collateral_adjustment`,
+///    `debit_adjustment`.
+///  - Get wrapped position. `input` bytes: `token_id`.
+///  - Get position class id.
 pub struct HonzonPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -57,12 +66,18 @@ pub enum Action {
 	GetCollateralParameters = "getCollateralParameters(address)",
 	GetCurrentCollateralRatio = "getCurrentCollateralRatio(address,address)",
 	GetDebitExchangeRate = "getDebitExchangeRate(address)",
+	WrapPosition = "wrapPosition(address,address)",
+	UnwrapPosition = "unwrapPosition(address,uint256)",
+	AdjustWrappedLoan = "adjustWrappedLoan(address,uint256,int128,int128)",
+	GetWrappedPosition = "getWrappedPosition(uint256)",
+	GetPositionClassId = "getPositionClassId()",
 }
 
 impl<Runtime> Precompile for HonzonPrecompile<Runtime>
 where
 	Runtime: module_evm::Config + module_honzon::Config + module_prices::Config,
-	module_honzon::Pallet<Runtime>: HonzonManager<Runtime::AccountId, CurrencyId, Amount, Balance>,
+	module_honzon::Pallet<Runtime>: HonzonManager<Runtime::AccountId, CurrencyId, Amount, Balance>
+		+ WrappedPositionManager<Runtime::AccountId, CurrencyId, Amount, u32, u64>,
 {
 	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
 		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
@@ -214,6 +229,154 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::WrapPosition => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: wrap_position who: {:?}, currency_id: {:?}",
+					who, currency_id
+				);
+
+				let token_id = <module_honzon::Pallet<Runtime> as WrappedPositionManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					u32,
+					u64,
+				>>::wrap_position(&who, currency_id)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon WrapPosition failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(token_id),
+					logs: Default::default(),
+				})
+			}
+			Action::UnwrapPosition => {
+				let who = input.account_id_at(1)?;
+				let token_id = input.u64_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: unwrap_position who: {:?}, token_id: {:?}",
+					who, token_id
+				);
+
+				<module_honzon::Pallet<Runtime> as WrappedPositionManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					u32,
+					u64,
+				>>::unwrap_position(&who, token_id)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon UnwrapPosition failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::AdjustWrappedLoan => {
+				let who = input.account_id_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let collateral_adjustment = input.i128_at(3)?;
+				let debit_adjustment = input.i128_at(4)?;
+
+				log::debug!(
+					target: "evm",
+					"honzon: adjust_wrapped_loan who: {:?}, token_id: {:?}, collateral_adjustment: {:?}, debit_adjustment: {:?}",
+					who, token_id, collateral_adjustment, debit_adjustment
+				);
+
+				<module_honzon::Pallet<Runtime> as WrappedPositionManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					u32,
+					u64,
+				>>::adjust_wrapped_loan(&who, token_id, collateral_adjustment, debit_adjustment)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("Honzon AdjustWrappedLoan failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::GetWrappedPosition => {
+				let token_id = input.u64_at(1)?;
+
+				// the zero address and empty position for the token not wrapping a CDP
+				let (address, Position { collateral, debit }) =
+					<module_honzon::Pallet<Runtime> as WrappedPositionManager<
+						Runtime::AccountId,
+						CurrencyId,
+						Amount,
+						u32,
+						u64,
+					>>::get_wrapped_position(token_id)
+					.map(|(currency_id, position)| {
+						(
+							<Runtime as module_prices::Config>::Erc20InfoMapping::encode_evm_address(currency_id)
+								.unwrap_or_default(),
+							position,
+						)
+					})
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: ethabi::encode(&[
+						Token::Address(address),
+						Token::Uint(U256::from(collateral)),
+						Token::Uint(U256::from(debit)),
+					]),
+					logs: Default::default(),
+				})
+			}
+			Action::GetPositionClassId => {
+				let class_id = <module_honzon::Pallet<Runtime> as WrappedPositionManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Amount,
+					u32,
+					u64,
+				>>::position_class_id()
+				.ok_or_else(|| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg(
+						"Honzon GetPositionClassId failed",
+						module_honzon::Error::<Runtime>::PositionClassNotSet.into(),
+					),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(class_id),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
@@ -296,6 +459,45 @@ where
 					.saturating_add(read_currency)
 					.saturating_add(WeightToGas::convert(weight))
 			}
+			Action::WrapPosition => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let currency_id = input.currency_id_at(2)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::wrap_position();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::UnwrapPosition => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::unwrap_position();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::AdjustWrappedLoan => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_honzon::Config>::WeightInfo::adjust_wrapped_loan();
+
+				Self::BASE_COST
+					.saturating_add(read_account)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetWrappedPosition => {
+				// WrappedPositions, Loans::Positions, Erc20InfoMapping
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(3);
+
+				Self::BASE_COST.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetPositionClassId => {
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				Self::BASE_COST.saturating_add(WeightToGas::convert(weight))
+			}
 		};
 		Ok(cost)
 	}
@@ -306,13 +508,14 @@ mod tests {
 	use super::*;
 
 	use crate::precompile::mock::{
-		alice, alice_evm_addr, new_test_ext, CDPEngine, Currencies, DexModule, Honzon, Loans, One, RuntimeOrigin, Test,
-		AUSD, BOB, DOT,
+		alice, alice_evm_addr, new_test_ext, CDPEngine, Currencies, DexModule, Honzon, Loans, NFTModule, One,
+		RuntimeOrigin, Test, AUSD, BOB, DOT,
 	};
 	use frame_support::assert_ok;
 	use hex_literal::hex;
 	use module_support::{Rate, Ratio};
 	use orml_traits::Change;
+	use primitives::nft::{ClassProperty, Properties};
 	use sp_runtime::FixedPointNumber;
 
 	type HonzonPrecompile = super::HonzonPrecompile<Test>;
@@ -622,4 +825,122 @@ mod tests {
 			assert_eq!(res.output, expected_output.to_vec());
 		})
 	}
+
+	#[test]
+	fn wrapped_position_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(CDPEngine::set_collateral_params(
+				RuntimeOrigin::signed(One::get()),
+				DOT,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+				Change::NewValue(1_000_000_000)
+			));
+			assert_ok!(Currencies::update_balance(
+				RuntimeOrigin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+			assert_ok!(Honzon::adjust_loan(
+				RuntimeOrigin::signed(alice()),
+				DOT,
+				100_000_000_000,
+				1_000_000
+			));
+
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// getPositionClassId() => 0x5ad169d7
+			let input = hex! {"
+				5ad169d7
+			"};
+			assert!(matches!(
+				HonzonPrecompile::execute(&input, None, &context, false),
+				Err(PrecompileFailure::Revert { .. })
+			));
+
+			assert_ok!(NFTModule::create_class(
+				RuntimeOrigin::signed(alice()),
+				vec![1],
+				Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+				Default::default(),
+			));
+			assert_ok!(Honzon::set_position_class(RuntimeOrigin::signed(One::get()), 0));
+			let res = HonzonPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, [0u8; 32].to_vec());
+
+			// wrapPosition(address,address) => 0x5bf499ac
+			// who
+			// currency_id
+			let input = hex! {"
+				5bf499ac
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// token id
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = HonzonPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+			assert_eq!(Loans::positions(DOT, alice()), Position::default());
+
+			// adjustWrappedLoan(address,uint256,int128,int128) => 0x51b20b2b
+			// who
+			// token_id
+			// collateral_adjustment
+			// debit_adjustment
+			let input = hex! {"
+				51b20b2b
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000001000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = HonzonPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			// getWrappedPosition(uint256) => 0xb6392dee
+			// token_id
+			let input = hex! {"
+				b6392dee
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// currency_id
+			// collateral
+			// debit
+			let expected_output = hex! {"
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 0000000000000000000000174876f800
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+			let res = HonzonPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// unwrapPosition(address,uint256) => 0xb9e17b76
+			// who
+			// token_id
+			let input = hex! {"
+				b9e17b76
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = HonzonPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Loans::positions(DOT, alice()).collateral, 100_000_004_096);
+			assert_eq!(Loans::positions(DOT, alice()).debit, 1_000_000);
+		})
+	}
 }
@@ -39,6 +39,7 @@ use module_support::{
 };
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key, MultiCurrency, MultiReservableCurrency};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use polkadot_parachain_primitives::primitives::Id as ParaId;
 pub use primitives::{
 	define_combined_task,
 	evm::{convert_decimals_to_evm, EvmAddress},
@@ -165,6 +166,7 @@ pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
 pub const LDOT: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
+pub const SAUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SAUSD);
 pub const LCDOT: CurrencyId = CurrencyId::LiquidCrowdloan(13);
 pub const LP_ACA_AUSD: CurrencyId =
 	CurrencyId::DexShare(DexShare::Token(TokenSymbol::ACA), DexShare::Token(TokenSymbol::AUSD));
@@ -198,6 +200,9 @@ impl module_asset_registry::Config for Test {
 	type StakingCurrencyId = GetStakingCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Test>;
 	type RegisterOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type RegistrationDeposit = ConstU128<1_000>;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type AssetInUse = crate::AssetInUse<Test>;
 	type WeightInfo = ();
 }
 
@@ -449,9 +454,12 @@ impl module_cdp_engine::Config for Test {
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = DexModule;
+	type DEXPriceSource = ();
+	type StabilityFeeAdjustmentPeriod = ConstU32<0>;
 	type LiquidationContractsUpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxLiquidationContractFailures = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Test>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Test>;
@@ -474,6 +482,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_debit_auction(_initial_amount: Self::Balance, _fix_debit: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -485,6 +497,10 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 pub struct MockEmergencyShutdown;
@@ -510,6 +526,7 @@ impl module_cdp_treasury::Config for Test {
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
 	type DEX = DexModule;
 	type MaxAuctionsCount = ConstU32<10_000>;
+	type DebitAuctionMintPeriod = ConstU32<10>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = CDPTreasuryAccount;
 	type WeightInfo = ();
@@ -517,11 +534,30 @@ impl module_cdp_treasury::Config for Test {
 	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
 }
 
+impl module_aggregated_dex::Config for Test {
+	type DEX = DexModule;
+	type StableAsset = StableAsset;
+	type StableAssetQuote = ();
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type DexSwapJointList = AlternativeSwapPathJointList;
+	type SwapPathLimit = ConstU32<3>;
+	type MaxRouteCandidates = ConstU32<16>;
+	type MaxRouteSearchNodes = ConstU32<64>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const HonzonPositionPalletId: PalletId = PalletId(*b"aca/hzps");
+}
+
 impl module_honzon::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type DepositPerAuthorization = ConstU128<100>;
+	type DepositPerProtectionRule = ConstU128<100>;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Test>;
+	type PositionPalletId = HonzonPositionPalletId;
+	type Incentives = Incentives;
 	type WeightInfo = ();
 }
 
@@ -556,6 +592,8 @@ impl nutsfinance_stable_asset::Config for Test {
 impl module_transaction_pause::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type GuardianOrigin = EnsureSignedBy<One, AccountId>;
+	type MaxGuardianPauseDuration = ConstU32<100>;
 	type WeightInfo = ();
 }
 
@@ -604,6 +642,7 @@ impl module_evm::Config for Test {
 	type FindAuthor = ();
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = module_transaction_pause::PausedPrecompileFilter<Self>;
 	type WeightInfo = ();
 }
 
@@ -733,6 +772,7 @@ impl module_homa::Config for Test {
 	type RedeemThreshold = RedeemThreshold;
 	type RelayChainBlockNumber = MockRelayBlockNumberProvider;
 	type XcmInterface = MockHomaSubAccountXcm;
+	type OnLedgerBondedReset = ();
 	type WeightInfo = ();
 }
 
@@ -940,6 +980,82 @@ impl module_liquid_crowdloan::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const XnftPalletId: PalletId = PalletId(*b"aca/xNFT");
+	pub SelfParaId: ParaId = 2000.into();
+	pub NftPalletLocation: InteriorMultiLocation = X1(PalletInstance(121));
+}
+
+impl module_xnft::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = XnftPalletId;
+	type LocationToAccountId = ();
+	type SelfParaId = SelfParaId;
+	type NtfPalletLocation = NftPalletLocation;
+	type RegisterOrigin = EnsureRoot<AccountId>;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type CurrencyIdConvert = CurrencyIdConvert;
+	type UniversalLocation = UniversalLocation;
+	type XcmExecutor = MockExec;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+}
+
+impl module_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type MinVestedTransfer = ConstU128<0>;
+	type VestedTransferOrigin = frame_system::EnsureSigned<AccountId>;
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	type MaxVestingSchedules = ConstU32<10>;
+	type BlockNumberProvider = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const PegStabilityPalletId: PalletId = PalletId(*b"aca/pegs");
+}
+
+impl module_peg_stability::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CDPTreasury;
+	type Erc20InfoMapping = EvmErc20InfoMapping;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type PalletId = PegStabilityPalletId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const GetSavingsCurrencyId: CurrencyId = SAUSD;
+	pub MaxSavingsRatePerSec: Rate = Rate::saturating_from_rational(1, 1_000_000);
+	pub const SavingsPalletId: PalletId = PalletId(*b"aca/savi");
+}
+
+impl module_savings::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetSavingsCurrencyId = GetSavingsCurrencyId;
+	type CDPTreasury = CDPTreasury;
+	type EmergencyShutdown = MockEmergencyShutdown;
+	type UnixTime = Timestamp;
+	type MaxSavingsRatePerSec = MaxSavingsRatePerSec;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type PalletId = SavingsPalletId;
+	type WeightInfo = ();
+}
+
+impl module_flash_mint::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CDPTreasury;
+	type EmergencyShutdown = MockEmergencyShutdown;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
 pub const EVA: AccountId = AccountId::new([5u8; 32]);
@@ -999,6 +1115,7 @@ frame_support::construct_runtime!(
 		EVMBridge: module_evm_bridge exclude_parts { Call },
 		AssetRegistry: module_asset_registry,
 		NFTModule: module_nft,
+		XNFT: module_xnft,
 		TransactionPause: module_transaction_pause,
 		TransactionPayment: module_transaction_payment,
 		Prices: module_prices,
@@ -1006,6 +1123,7 @@ frame_support::construct_runtime!(
 		Utility: pallet_utility,
 		Scheduler: pallet_scheduler,
 		DexModule: module_dex,
+		AggregatedDex: module_aggregated_dex,
 		EVMModule: module_evm,
 		EvmAccounts: module_evm_accounts,
 		IdleScheduler: module_idle_scheduler,
@@ -1015,6 +1133,10 @@ frame_support::construct_runtime!(
 		XTokens: orml_xtokens,
 		StableAsset: nutsfinance_stable_asset,
 		LiquidCrowdloan: module_liquid_crowdloan,
+		Vesting: module_vesting,
+		PegStability: module_peg_stability,
+		Savings: module_savings,
+		FlashMint: module_flash_mint,
 	}
 );
 
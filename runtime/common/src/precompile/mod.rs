@@ -41,6 +41,7 @@ use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData};
 pub mod dex;
 pub mod evm;
 pub mod evm_accounts;
+pub mod flash_mint;
 pub mod homa;
 pub mod honzon;
 pub mod incentives;
@@ -49,14 +50,19 @@ pub mod liquid_crowdloan;
 pub mod multicurrency;
 pub mod nft;
 pub mod oracle;
+pub mod peg_stability;
+pub mod savings;
 pub mod schedule;
 pub mod stable_asset;
+pub mod vesting;
+pub mod xnft;
 pub mod xtokens;
 
 use crate::SystemContractsFilter;
 pub use dex::DEXPrecompile;
 pub use evm::EVMPrecompile;
 pub use evm_accounts::EVMAccountsPrecompile;
+pub use flash_mint::FlashMintPrecompile;
 pub use homa::HomaPrecompile;
 pub use honzon::HonzonPrecompile;
 pub use incentives::IncentivesPrecompile;
@@ -64,8 +70,12 @@ pub use liquid_crowdloan::LiquidCrowdloanPrecompile;
 pub use multicurrency::MultiCurrencyPrecompile;
 pub use nft::NFTPrecompile;
 pub use oracle::OraclePrecompile;
+pub use peg_stability::PegStabilityPrecompile;
+pub use savings::SavingsPrecompile;
 pub use schedule::SchedulePrecompile;
 pub use stable_asset::StableAssetPrecompile;
+pub use vesting::VestingPrecompile;
+pub use xnft::XNFTPrecompile;
 pub use xtokens::XtokensPrecompile;
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
@@ -97,6 +107,11 @@ pub const HONZON: H160 = H160(hex!("0000000000000000000000000000000000000409"));
 pub const INCENTIVES: H160 = H160(hex!("000000000000000000000000000000000000040a"));
 pub const XTOKENS: H160 = H160(hex!("000000000000000000000000000000000000040b"));
 pub const LIQUID_CROWDLOAN: H160 = H160(hex!("000000000000000000000000000000000000040c"));
+pub const VESTING: H160 = H160(hex!("000000000000000000000000000000000000040d"));
+pub const XNFT: H160 = H160(hex!("000000000000000000000000000000000000040e"));
+pub const PEG_STABILITY: H160 = H160(hex!("000000000000000000000000000000000000040f"));
+pub const SAVINGS: H160 = H160(hex!("0000000000000000000000000000000000000410"));
+pub const FLASH_MINT: H160 = H160(hex!("0000000000000000000000000000000000000411"));
 
 pub fn target_gas_limit(target_gas: Option<u64>) -> Option<u64> {
 	target_gas.map(|x| x.saturating_div(10).saturating_mul(9)) // 90%
@@ -402,6 +417,93 @@ where
 	}
 }
 
+pub struct MandalaPrecompiles<R>(sp_std::marker::PhantomData<R>);
+
+impl<R> Default for MandalaPrecompiles<R> {
+	fn default() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
+
+impl<R> PrecompileSet for MandalaPrecompiles<R>
+where
+	AcalaPrecompiles<R>: PrecompileSet,
+	VestingPrecompile<R>: Precompile,
+	PegStabilityPrecompile<R>: Precompile,
+	SavingsPrecompile<R>: Precompile,
+	FlashMintPrecompile<R>: Precompile,
+{
+	fn execute(
+		&self,
+		address: H160,
+		input: &[u8],
+		gas_limit: Option<u64>,
+		context: &Context,
+		is_static: bool,
+	) -> Option<PrecompileResult> {
+		if address == VESTING {
+			Some(VestingPrecompile::execute(input, gas_limit, context, is_static))
+		} else if address == PEG_STABILITY {
+			Some(PegStabilityPrecompile::execute(input, gas_limit, context, is_static))
+		} else if address == SAVINGS {
+			Some(SavingsPrecompile::execute(input, gas_limit, context, is_static))
+		} else if address == FLASH_MINT {
+			Some(FlashMintPrecompile::execute(input, gas_limit, context, is_static))
+		} else {
+			AcalaPrecompiles::<R>::default().execute(address, input, gas_limit, context, is_static)
+		}
+	}
+
+	fn is_precompile(&self, address: H160) -> bool {
+		address == VESTING
+			|| address == PEG_STABILITY
+			|| address == SAVINGS
+			|| address == FLASH_MINT
+			|| AcalaPrecompiles::<R>::default().is_precompile(address)
+	}
+}
+
+pub struct KaruraPrecompiles<R>(sp_std::marker::PhantomData<R>);
+
+impl<R> Default for KaruraPrecompiles<R> {
+	fn default() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
+
+impl<R> PrecompileSet for KaruraPrecompiles<R>
+where
+	XNFTPrecompile<R>: Precompile,
+	PegStabilityPrecompile<R>: Precompile,
+	SavingsPrecompile<R>: Precompile,
+	FlashMintPrecompile<R>: Precompile,
+{
+	fn execute(
+		&self,
+		address: H160,
+		input: &[u8],
+		gas_limit: Option<u64>,
+		context: &Context,
+		is_static: bool,
+	) -> Option<PrecompileResult> {
+		if address == XNFT {
+			Some(XNFTPrecompile::execute(input, gas_limit, context, is_static))
+		} else if address == PEG_STABILITY {
+			Some(PegStabilityPrecompile::execute(input, gas_limit, context, is_static))
+		} else if address == SAVINGS {
+			Some(SavingsPrecompile::execute(input, gas_limit, context, is_static))
+		} else if address == FLASH_MINT {
+			Some(FlashMintPrecompile::execute(input, gas_limit, context, is_static))
+		} else {
+			None
+		}
+	}
+
+	fn is_precompile(&self, address: H160) -> bool {
+		address == XNFT || address == PEG_STABILITY || address == SAVINGS || address == FLASH_MINT
+	}
+}
+
 #[test]
 fn ensure_precompile_address_start() {
 	use primitives::evm::PRECOMPILE_ADDRESS_START;
@@ -42,6 +42,10 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Query royalty. Rest `input` bytes: `class_id`.
+/// - Query token attribute. Rest `input` bytes: `class_id`, `token_id`, `key`.
+/// - Set token attribute. Rest `input` bytes: `who`, `class_id`, `token_id`, `key`, `value`.
+/// - Remove token attribute. Rest `input` bytes: `who`, `class_id`, `token_id`, `key`.
 pub struct NFTPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -51,11 +55,15 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	QueryRoyalty = "royaltyOf(uint256)",
+	QueryTokenAttribute = "getTokenAttribute(uint256,uint256,bytes)",
+	SetTokenAttribute = "setTokenAttribute(address,uint256,uint256,bytes,bytes)",
+	RemoveTokenAttribute = "removeTokenAttribute(address,uint256,uint256,bytes)",
 }
 
 impl<Runtime> Precompile for NFTPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
+	Runtime: module_evm::Config + module_prices::Config + module_nft::Config<ClassId = u32, TokenId = u64>,
 	module_nft::Pallet<Runtime>: InspectExtended<Runtime::AccountId, Balance = NFTBalance>
 		+ Inspect<Runtime::AccountId, ItemId = u64, CollectionId = u32>
 		+ Transfer<Runtime::AccountId>,
@@ -129,6 +137,86 @@ where
 						cost: target_gas_limit(target_gas).unwrap_or_default(),
 					})?;
 
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::QueryRoyalty => {
+				let class_id = input.u32_at(1)?;
+
+				log::debug!(target: "evm", "nft: query_royalty class_id: {:?}", class_id);
+
+				let royalty = module_nft::Pallet::<Runtime>::royalty_info(class_id)
+					.map(|(_, royalty)| royalty)
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::encode_uint(royalty),
+					logs: Default::default(),
+				})
+			}
+			Action::QueryTokenAttribute => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let key = input.bytes_at(3)?;
+
+				log::debug!(target: "evm", "nft: query_token_attribute class_id: {:?}, token_id: {:?}, key: {:?}", class_id, token_id, key);
+
+				let value =
+					module_nft::Pallet::<Runtime>::token_attribute((class_id, token_id), &key).unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::encode_bytes(&value),
+					logs: Default::default(),
+				})
+			}
+			Action::SetTokenAttribute => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let key = input.bytes_at(4)?;
+				let value = input.bytes_at(5)?;
+
+				log::debug!(target: "evm", "nft: set_token_attribute who: {:?}, class_id: {:?}, token_id: {:?}, key: {:?}", who, class_id, token_id, key);
+
+				let attributes = [(key, value)].into_iter().collect();
+				module_nft::Pallet::<Runtime>::do_set_token_attributes(&who, (class_id, token_id), attributes)
+					.map_err(|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT SetTokenAttribute failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::RemoveTokenAttribute => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let key = input.bytes_at(4)?;
+
+				log::debug!(target: "evm", "nft: remove_token_attribute who: {:?}, class_id: {:?}, token_id: {:?}, key: {:?}", who, class_id, token_id, key);
+
+				module_nft::Pallet::<Runtime>::do_remove_token_attribute(&who, (class_id, token_id), key).map_err(
+					|e| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("NFT RemoveTokenAttribute failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					},
+				)?;
+
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
 					cost: 0,
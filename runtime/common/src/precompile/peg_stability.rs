@@ -0,0 +1,376 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed,
+};
+use module_peg_stability::WeightInfo;
+use module_support::CDPTreasury;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_core::Get;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `PegStability` impl precompile.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - GetPegAssetParams. Rest `input` bytes: `currency_id`.
+/// - GetPegReserve. Rest `input` bytes: `currency_id`.
+/// - Mint. Rest `input` bytes: `who`, `currency_id`, `amount`.
+/// - Redeem. Rest `input` bytes: `who`, `currency_id`, `amount`.
+pub struct PegStabilityPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetPegAssetParams = "getPegAssetParams(address)",
+	GetPegReserve = "getPegReserve(address)",
+	Mint = "mint(address,address,uint256)",
+	Redeem = "redeem(address,address,uint256)",
+}
+
+impl<Runtime> Precompile for PegStabilityPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_peg_stability::Config,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetPegAssetParams => {
+				let currency_id = input.currency_id_at(1)?;
+				let params = <module_peg_stability::Pallet<Runtime>>::peg_assets(currency_id).unwrap_or_default();
+
+				log::debug!(target: "evm", "peg_stability: GetPegAssetParams currency_id: {:?}, output: {:?}", currency_id, params);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint_tuple(vec![
+						params.mint_fee.into_inner(),
+						params.redeem_fee.into_inner(),
+						params.debt_ceiling,
+					]),
+					logs: Default::default(),
+				})
+			}
+			Action::GetPegReserve => {
+				let currency_id = input.currency_id_at(1)?;
+				let (reserve, issued) =
+					<Runtime as module_peg_stability::Config>::CDPTreasury::get_peg_reserve(currency_id);
+
+				log::debug!(target: "evm", "peg_stability: GetPegReserve currency_id: {:?}, reserve: {:?}, issued: {:?}", currency_id, reserve, issued);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint_tuple(vec![reserve, issued]),
+					logs: Default::default(),
+				})
+			}
+			Action::Mint => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+
+				let received =
+					<module_peg_stability::Pallet<Runtime>>::do_mint(&who, currency_id, amount).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("PegStability mint failed", e),
+							cost: target_gas_limit(target_gas).unwrap_or_default(),
+						}
+					})?;
+
+				log::debug!(target: "evm", "peg_stability: Mint who: {:?}, currency_id: {:?}, amount: {:?}, output: {:?}", who, currency_id, amount, received);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(received),
+					logs: Default::default(),
+				})
+			}
+			Action::Redeem => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let amount = input.balance_at(3)?;
+
+				let received =
+					<module_peg_stability::Pallet<Runtime>>::do_redeem(&who, currency_id, amount).map_err(|e| {
+						PrecompileFailure::Revert {
+							exit_status: ExitRevert::Reverted,
+							output: Output::encode_error_msg("PegStability redeem failed", e),
+							cost: target_gas_limit(target_gas).unwrap_or_default(),
+						}
+					})?;
+
+				log::debug!(target: "evm", "peg_stability: Redeem who: {:?}, currency_id: {:?}, amount: {:?}, output: {:?}", who, currency_id, amount, received);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(received),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_peg_stability::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost = match action {
+			Action::GetPegAssetParams | Action::GetPegReserve => {
+				let currency_id = input.currency_id_at(1)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// PegAssets (r: 1) or PegReserves (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_currency.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Mint => {
+				let currency_id = input.currency_id_at(2)?;
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				let weight = <Runtime as module_peg_stability::Config>::WeightInfo::mint();
+
+				read_account
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Redeem => {
+				let currency_id = input.currency_id_at(2)?;
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				let weight = <Runtime as module_peg_stability::Config>::WeightInfo::redeem();
+
+				read_account
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, new_test_ext, Currencies, PegStability, RuntimeOrigin, Test, AUSD, DOT,
+	};
+	use frame_support::assert_ok;
+	use hex_literal::hex;
+	use module_support::Rate;
+	use orml_traits::{Change, MultiCurrency};
+	use sp_runtime::FixedPointNumber;
+
+	type PegStabilityPrecompile = crate::precompile::PegStabilityPrecompile<Test>;
+
+	fn context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		}
+	}
+
+	fn enable_dot() {
+		assert_ok!(PegStability::set_peg_asset_params(
+			RuntimeOrigin::root(),
+			DOT,
+			Change::NewValue(Rate::saturating_from_rational(1, 100)),
+			Change::NewValue(Rate::saturating_from_rational(1, 100)),
+			Change::NewValue(1_000_000_000),
+		));
+	}
+
+	#[test]
+	fn get_peg_asset_params_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot();
+
+			// getPegAssetParams(address) -> 0x36e84f86
+			// currency_id
+			let input = hex! {"
+				36e84f86
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// mint_fee 1/100, redeem_fee 1/100, debt_ceiling 1_000_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 0000000000000000002386f26fc10000
+				00000000000000000000000000000000 0000000000000000002386f26fc10000
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+			"};
+
+			let res = PegStabilityPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn mint_and_redeem_works() {
+		new_test_ext().execute_with(|| {
+			enable_dot();
+			let dot_balance = Currencies::free_balance(DOT, &alice());
+			let ausd_balance = Currencies::free_balance(AUSD, &alice());
+
+			// mint(address,address,uint256) -> 0xc6c3bbe6
+			// who
+			// currency_id
+			// amount 1_000_000
+			let input = hex! {"
+				c6c3bbe6
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+
+			// 99_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000005e69ec0
+			"};
+
+			let res = PegStabilityPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+			assert_eq!(Currencies::free_balance(DOT, &alice()), dot_balance - 1_000_000);
+			assert_eq!(Currencies::free_balance(AUSD, &alice()), ausd_balance + 99_000_000);
+			assert_eq!(
+				<Test as module_peg_stability::Config>::CDPTreasury::get_peg_reserve(DOT),
+				(1_000_000, 100_000_000)
+			);
+
+			// getPegReserve(address) -> 0xc56117dc
+			// currency_id
+			let input = hex! {"
+				c56117dc
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// reserve 1_000_000, issued 100_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 00000000000000000000000005f5e100
+			"};
+
+			let res = PegStabilityPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// redeem(address,address,uint256) -> 0x0e6dfcd5
+			// who
+			// currency_id
+			// amount 10_000_000
+			let input = hex! {"
+				0e6dfcd5
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000989680
+			"};
+
+			// 99_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000182b8
+			"};
+
+			let res = PegStabilityPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+			assert_eq!(
+				Currencies::free_balance(DOT, &alice()),
+				dot_balance - 1_000_000 + 99_000
+			);
+			assert_eq!(
+				Currencies::free_balance(AUSD, &alice()),
+				ausd_balance + 99_000_000 - 10_000_000
+			);
+			assert_eq!(
+				<Test as module_peg_stability::Config>::CDPTreasury::get_peg_reserve(DOT),
+				(901_000, 90_100_000)
+			);
+		});
+	}
+
+	#[test]
+	fn mint_exceeding_debt_ceiling_reverts() {
+		new_test_ext().execute_with(|| {
+			enable_dot();
+
+			// mint(address,address,uint256) -> 0xc6c3bbe6
+			// who
+			// currency_id
+			// amount 100_000_000, worth 10_000_000_000 AUSD
+			let input = hex! {"
+				c6c3bbe6
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000005f5e100
+			"};
+
+			let res = PegStabilityPrecompile::execute(&input, None, &context(), false);
+			assert!(matches!(
+				res,
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					..
+				})
+			));
+			assert_eq!(
+				<Test as module_peg_stability::Config>::CDPTreasury::get_peg_reserve(DOT),
+				(0, 0)
+			);
+		});
+	}
+}
@@ -0,0 +1,341 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed,
+};
+use module_savings::WeightInfo;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_core::Get;
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `Savings` impl precompile.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - GetSavingsRate. No input.
+/// - GetTotalSavings. No input.
+/// - GetExchangeRate. No input.
+/// - Deposit. Rest `input` bytes: `who`, `amount`.
+/// - Withdraw. Rest `input` bytes: `who`, `share_amount`.
+pub struct SavingsPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	GetSavingsRate = "getSavingsRate()",
+	GetTotalSavings = "getTotalSavings()",
+	GetExchangeRate = "getExchangeRate()",
+	Deposit = "deposit(address,uint256)",
+	Withdraw = "withdraw(address,uint256)",
+}
+
+impl<Runtime> Precompile for SavingsPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_savings::Config,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		match action {
+			Action::GetSavingsRate => {
+				let rate = <module_savings::Pallet<Runtime>>::savings_rate_per_sec();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+			Action::GetTotalSavings => {
+				let total_savings = <module_savings::Pallet<Runtime>>::total_savings();
+				let total_shares = <module_savings::Pallet<Runtime>>::total_shares();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint_tuple(vec![total_savings, total_shares]),
+					logs: Default::default(),
+				})
+			}
+			Action::GetExchangeRate => {
+				let rate = <module_savings::Pallet<Runtime>>::current_exchange_rate();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(rate.into_inner()),
+					logs: Default::default(),
+				})
+			}
+			Action::Deposit => {
+				let who = input.account_id_at(1)?;
+				let amount = input.balance_at(2)?;
+
+				let share_amount = <module_savings::Pallet<Runtime>>::do_deposit(&who, amount).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Savings deposit failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				log::debug!(target: "evm", "savings: Deposit who: {:?}, amount: {:?}, output: {:?}", who, amount, share_amount);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(share_amount),
+					logs: Default::default(),
+				})
+			}
+			Action::Withdraw => {
+				let who = input.account_id_at(1)?;
+				let share_amount = input.balance_at(2)?;
+
+				let amount = <module_savings::Pallet<Runtime>>::do_withdraw(&who, share_amount).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Savings withdraw failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				log::debug!(target: "evm", "savings: Withdraw who: {:?}, share_amount: {:?}, output: {:?}", who, share_amount, amount);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(amount),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_savings::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost = match action {
+			Action::GetSavingsRate => {
+				// Savings::SavingsRatePerSec (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+			}
+			Action::GetTotalSavings | Action::GetExchangeRate => {
+				// Savings::TotalSavings (r: 1)
+				// Tokens::TotalIssuance (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::Deposit => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_savings::Config>::WeightInfo::deposit();
+
+				read_account.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Withdraw => {
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_savings::Config>::WeightInfo::withdraw();
+
+				read_account.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, new_test_ext, Currencies, RuntimeOrigin, Savings, Test, AUSD, SAUSD,
+	};
+	use frame_support::assert_ok;
+	use hex_literal::hex;
+	use module_support::Rate;
+	use orml_traits::MultiCurrency;
+	use sp_runtime::FixedPointNumber;
+
+	type SavingsPrecompile = crate::precompile::SavingsPrecompile<Test>;
+
+	fn context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		}
+	}
+
+	#[test]
+	fn get_savings_rate_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Savings::set_savings_rate(
+				RuntimeOrigin::root(),
+				Rate::saturating_from_rational(1, 1_000_000)
+			));
+
+			// getSavingsRate() -> 0x6707373b
+			let input = hex! {"
+				6707373b
+			"};
+
+			// 1/1_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 0000000000000000000000e8d4a51000
+			"};
+
+			let res = SavingsPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+		});
+	}
+
+	#[test]
+	fn deposit_and_withdraw_works() {
+		new_test_ext().execute_with(|| {
+			let ausd_balance = Currencies::free_balance(AUSD, &alice());
+
+			// deposit(address,uint256) -> 0x47e7ef24
+			// who
+			// amount 1_000_000
+			let input = hex! {"
+				47e7ef24
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+
+			// 1_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+
+			let res = SavingsPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+			assert_eq!(Currencies::free_balance(AUSD, &alice()), ausd_balance - 1_000_000);
+			assert_eq!(Currencies::free_balance(SAUSD, &alice()), 1_000_000);
+
+			// getTotalSavings() -> 0xe6888860
+			let input = hex! {"
+				e6888860
+			"};
+
+			// total_savings 1_000_000, total_shares 1_000_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+
+			let res = SavingsPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getExchangeRate() -> 0xe6aa216c
+			let input = hex! {"
+				e6aa216c
+			"};
+
+			// 1
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000de0b6b3a7640000
+			"};
+
+			let res = SavingsPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// withdraw(address,uint256) -> 0xf3fef3a3
+			// who
+			// share_amount 400_000
+			let input = hex! {"
+				f3fef3a3
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000061a80
+			"};
+
+			// 400_000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000061a80
+			"};
+
+			let res = SavingsPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+			assert_eq!(Currencies::free_balance(AUSD, &alice()), ausd_balance - 600_000);
+			assert_eq!(Currencies::free_balance(SAUSD, &alice()), 600_000);
+			assert_eq!(Savings::total_savings(), 600_000);
+		});
+	}
+
+	#[test]
+	fn withdraw_exceeding_shares_reverts() {
+		new_test_ext().execute_with(|| {
+			// withdraw(address,uint256) -> 0xf3fef3a3
+			// who
+			// share_amount 1_000_000
+			let input = hex! {"
+				f3fef3a3
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000f4240
+			"};
+
+			let res = SavingsPrecompile::execute(&input, None, &context(), false);
+			assert!(matches!(
+				res,
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					..
+				})
+			));
+		});
+	}
+}
@@ -0,0 +1,354 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed,
+};
+use module_vesting::WeightInfo;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_core::Get;
+use sp_runtime::{
+	traits::{Convert, UniqueSaturatedInto},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The `Vesting` impl precompile.
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - LockedBalance. Rest `input` bytes: `who`, `currency_id`.
+/// - GetVestingSchedulesCount. Rest `input` bytes: `who`, `currency_id`.
+/// - GetVestingSchedule. Rest `input` bytes: `who`, `currency_id`, `index`.
+/// - Claim. Rest `input` bytes: `who`, `currency_id`.
+pub struct VestingPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	LockedBalance = "lockedBalance(address,address)",
+	GetVestingSchedulesCount = "getVestingSchedulesCount(address,address)",
+	GetVestingSchedule = "getVestingSchedule(address,address,uint256)",
+	Claim = "claim(address,address)",
+}
+
+impl<Runtime> Precompile for VestingPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_vesting::Config,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		match action {
+			Action::LockedBalance => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+
+				let locked = <module_vesting::Pallet<Runtime>>::locked_balance(&who, currency_id);
+
+				log::debug!(target: "evm", "vesting: LockedBalance who: {:?}, currency_id: {:?}, output: {:?}", who, currency_id, locked);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(locked),
+					logs: Default::default(),
+				})
+			}
+			Action::GetVestingSchedulesCount => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+
+				let count = <module_vesting::Pallet<Runtime>>::schedules_of(&who, currency_id).len() as u32;
+
+				log::debug!(target: "evm", "vesting: GetVestingSchedulesCount who: {:?}, currency_id: {:?}, output: {:?}", who, currency_id, count);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(count),
+					logs: Default::default(),
+				})
+			}
+			Action::GetVestingSchedule => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+				let index = input.u32_at(3)?;
+
+				let schedule = <module_vesting::Pallet<Runtime>>::schedules_of(&who, currency_id)
+					.get(index as usize)
+					.cloned()
+					.ok_or(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "invalid schedule index".into(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?;
+
+				log::debug!(target: "evm", "vesting: GetVestingSchedule who: {:?}, currency_id: {:?}, index: {:?}, output: {:?}", who, currency_id, index, schedule);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint_tuple::<u128>(vec![
+						schedule.start.unique_saturated_into(),
+						schedule.cliff.unique_saturated_into(),
+						schedule.duration.unique_saturated_into(),
+						schedule.total,
+					]),
+					logs: Default::default(),
+				})
+			}
+			Action::Claim => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+
+				let locked = <module_vesting::Pallet<Runtime>>::do_claim(&who, currency_id).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Output::encode_error_msg("Vesting claim failed", e),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				log::debug!(target: "evm", "vesting: Claim who: {:?}, currency_id: {:?}, output: {:?}", who, currency_id, locked);
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_uint(locked),
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_vesting::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost = match action {
+			Action::LockedBalance | Action::GetVestingSchedulesCount | Action::GetVestingSchedule => {
+				let currency_id = input.currency_id_at(2)?;
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+
+				// VestingSchedules (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				read_account
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Claim => {
+				let currency_id = input.currency_id_at(2)?;
+				let read_account = InputPricer::<Runtime>::read_accounts(1);
+				let read_currency = InputPricer::<Runtime>::read_currency(currency_id);
+				let weight = <Runtime as module_vesting::Config>::WeightInfo::claim(
+					<Runtime as module_vesting::Config>::MaxVestingSchedules::get(),
+				);
+
+				read_account
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{bob, bob_evm_addr, new_test_ext, Currencies, System, Test, Vesting, ALICE, DOT};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use module_vesting::VestingSchedule;
+	use orml_traits::MultiCurrency;
+
+	type VestingPrecompile = crate::precompile::VestingPrecompile<Test>;
+
+	fn context() -> Context {
+		Context {
+			address: Default::default(),
+			caller: bob_evm_addr(),
+			apparent_value: Default::default(),
+		}
+	}
+
+	#[test]
+	fn query_vesting_schedules_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Vesting::do_vested_transfer(
+				&ALICE,
+				&bob(),
+				DOT,
+				VestingSchedule {
+					start: 0,
+					cliff: 0,
+					duration: 10,
+					total: 1_000,
+				}
+			));
+
+			// lockedBalance(address,address) -> 0x3419d6ef
+			// who
+			// currency_id
+			let input = hex! {"
+				3419d6ef
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// 900
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000384
+			"};
+
+			let res = VestingPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getVestingSchedulesCount(address,address) -> 0xd4646a22
+			// who
+			// currency_id
+			let input = hex! {"
+				d4646a22
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// 1
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			let res = VestingPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// getVestingSchedule(address,address,uint256) -> 0xee87f24c
+			// who
+			// currency_id
+			// index
+			let input = hex! {"
+				ee87f24c
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+
+			// start 0, cliff 0, duration 10, total 1000
+			let expected_output = hex! {"
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 0000000000000000000000000000000a
+				00000000000000000000000000000000 000000000000000000000000000003e8
+			"};
+
+			let res = VestingPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+
+			// index out of range
+			let input = hex! {"
+				ee87f24c
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+
+			assert_noop!(
+				VestingPrecompile::execute(&input, None, &context(), false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid schedule index".into(),
+					cost: 0,
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn claim_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Vesting::do_vested_transfer(
+				&ALICE,
+				&bob(),
+				DOT,
+				VestingSchedule {
+					start: 0,
+					cliff: 0,
+					duration: 10,
+					total: 1_000,
+				}
+			));
+			assert_eq!(Currencies::free_balance(DOT, &bob()), 1_000);
+
+			System::set_block_number(5);
+
+			// claim(address,address) -> 0x21c0b342
+			// who
+			// currency_id
+			let input = hex! {"
+				21c0b342
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			// 500
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000000000000001f4
+			"};
+
+			let res = VestingPrecompile::execute(&input, None, &context(), false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, expected_output.to_vec());
+			assert_eq!(Vesting::locked_balance(&bob(), DOT), 500);
+		});
+	}
+}
@@ -0,0 +1,217 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+	xtokens::decode_multi_location,
+};
+use crate::WeightToGas;
+use frame_support::pallet_prelude::Decode;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed,
+};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+use xcm::v3::{MultiLocation, WeightLimit};
+
+/// The `XNFT` impl precompile.
+///
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+/// - Transfer. Rest `input` bytes: `who`, `class_id`, `token_id`, `fee_currency_id`, `fee_amount`,
+///   `dest`, `weight`.
+pub struct XNFTPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Transfer = "transfer(address,uint256,uint256,address,uint256,bytes,bytes)",
+}
+
+impl<Runtime> Precompile for XNFTPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config + module_xnft::Config<ClassId = u32, TokenId = u64>,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		match action {
+			Action::Transfer => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let fee_currency_id = input.currency_id_at(4)?;
+				let fee_amount = input.balance_at(5)?;
+
+				let dest_bytes: &[u8] = &input.bytes_at(6)?[..];
+				let dest: MultiLocation = decode_multi_location(dest_bytes).ok_or(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid dest".into(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				let mut weight_bytes: &[u8] = &input.bytes_at(7)?[..];
+				let weight = WeightLimit::decode(&mut weight_bytes).map_err(|_| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid weight".into(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				log::debug!(
+					target: "evm",
+					"xnft: Transfer who: {:?}, class_id: {:?}, token_id: {:?}, fee_currency_id: {:?}, fee_amount: {:?}, dest: {:?}, weight: {:?}",
+					who, class_id, token_id, fee_currency_id, fee_amount, dest, weight
+				);
+
+				module_xnft::Pallet::<Runtime>::do_transfer_nft(
+					who,
+					(class_id, token_id),
+					fee_currency_id,
+					fee_amount,
+					dest,
+					weight,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Output::encode_error_msg("XNFT Transfer failed", e),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+		}
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_prices::Config + module_xnft::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::Transfer => {
+				let fee_currency_id = input.currency_id_at(4)?;
+				let read_currency = InputPricer::<Runtime>::read_currency(fee_currency_id);
+
+				let weight = module_xnft::Pallet::<Runtime>::transfer_nft_weight();
+
+				Self::BASE_COST
+					.saturating_add(read_currency)
+					.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(cost)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use crate::precompile::mock::{alice_evm_addr, new_test_ext, Test};
+	use hex_literal::hex;
+	use orml_utilities::with_transaction_result;
+
+	type XNFTPrecompile = crate::precompile::XNFTPrecompile<Test>;
+
+	#[test]
+	fn transfer_not_owned_token_reverts() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// transfer(address,uint256,uint256,address,uint256,bytes,bytes) -> 0x2d7ca6f4
+			// who
+			// class_id
+			// token_id
+			// fee_currency_id
+			// fee_amount
+			// dest offset
+			// weight offset
+			// dest length
+			// dest
+			// weight length
+			// weight
+			let input = hex! {"
+				2d7ca6f4
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				000000000000000000000000 0000000000000000000100000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000000e0
+				00000000000000000000000000000000 00000000000000000000000000000140
+				00000000000000000000000000000000 00000000000000000000000000000028
+				03010200491f0100020202020202020202020202020202020202020202020202
+				0202020202020202000000000000000000000000000000000000000000000000
+				0000000000000000000000000000000000000000000000000000000000000009
+				01821a0600020004000000000000000000000000000000000000000000000000
+			"};
+
+			let _ = with_transaction_result(|| {
+				assert_eq!(
+					XNFTPrecompile::execute(&input, Some(100_000), &context, false),
+					Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "XNFT Transfer failed: NotTokenOwner".into(),
+						cost: 90_000,
+					})
+				);
+				Ok(())
+			});
+		});
+	}
+}
@@ -456,7 +456,7 @@ fn decode_multi_assets(mut bytes: &[u8]) -> Option<MultiAssets> {
 	VersionedMultiAssets::decode(&mut bytes).ok()?.try_into().ok()
 }
 
-fn decode_multi_location(mut bytes: &[u8]) -> Option<MultiLocation> {
+pub(crate) fn decode_multi_location(mut bytes: &[u8]) -> Option<MultiLocation> {
 	VersionedMultiLocation::decode(&mut bytes).ok()?.try_into().ok()
 }
 
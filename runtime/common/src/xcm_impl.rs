@@ -18,18 +18,21 @@
 
 //! Common xcm implementation
 
-use frame_support::{traits::Get, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
-use module_support::BuyWeightRate;
-use orml_traits::GetByKey;
+use frame_support::{storage::with_transaction, traits::Get, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
+use module_support::{BuyWeightRate, Swap, SwapLimit};
+use orml_traits::{GetByKey, MultiCurrency};
 use parity_scale_codec::Encode;
 use primitives::{evm::EvmAddress, Balance, CurrencyId};
 use sp_core::bounded::BoundedVec;
-use sp_runtime::{traits::Convert, FixedPointNumber, FixedU128};
+use sp_runtime::{
+	traits::{Convert, Zero},
+	DispatchError, FixedPointNumber, FixedU128, TransactionOutcome,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 use xcm_builder::TakeRevenue;
 use xcm_executor::{
-	traits::{DropAssets, WeightTrader},
+	traits::{AssetExchange, DropAssets, WeightTrader},
 	Assets,
 };
 
@@ -301,6 +304,127 @@ where
 	}
 }
 
+/// `AssetExchange` implementation which swaps the assets of `ExchangeAsset` instructions on DEX.
+///
+/// Only a single fungible asset to give and a single fungible asset to want are supported.
+/// The assets in holding are minted to `Account` for the swap, and the received assets are
+/// burned from it afterwards, so the balance of `Account` is not changed.
+///
+/// parameters type:
+/// - `AccountId`: account id type.
+/// - `Account`: the account to execute the swap with.
+/// - `CurrencyIdConvert`: convert `MultiAsset` to `CurrencyId`.
+/// - `Currency`: the multi-currency to mint and burn the assets.
+/// - `DexSwap`: the swap to exchange the assets.
+pub struct DexAssetExchange<AccountId, Account, CurrencyIdConvert, Currency, DexSwap>(
+	PhantomData<(AccountId, Account, CurrencyIdConvert, Currency, DexSwap)>,
+);
+
+impl<AccountId, Account, CurrencyIdConvert, Currency, DexSwap>
+	DexAssetExchange<AccountId, Account, CurrencyIdConvert, Currency, DexSwap>
+where
+	Account: Get<AccountId>,
+	Currency: MultiCurrency<AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+	DexSwap: Swap<AccountId, Balance, CurrencyId>,
+{
+	fn do_exchange(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		limit: SwapLimit<Balance>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let account = Account::get();
+		let supply_amount = match limit {
+			SwapLimit::ExactSupply(supply_amount, _) => supply_amount,
+			SwapLimit::ExactTarget(max_supply_amount, _) => max_supply_amount,
+		};
+
+		with_transaction(|| {
+			let result = Currency::deposit(supply_currency_id, &account, supply_amount)
+				.and_then(|_| DexSwap::swap(&account, supply_currency_id, target_currency_id, limit))
+				.and_then(|(actual_supply_amount, actual_target_amount)| {
+					Currency::withdraw(target_currency_id, &account, actual_target_amount)?;
+					Currency::withdraw(
+						supply_currency_id,
+						&account,
+						supply_amount.saturating_sub(actual_supply_amount),
+					)?;
+					Ok((actual_supply_amount, actual_target_amount))
+				});
+
+			match result {
+				Ok(amounts) => TransactionOutcome::Commit(Ok(amounts)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		})
+	}
+}
+
+impl<AccountId, Account, CurrencyIdConvert, Currency, DexSwap> AssetExchange
+	for DexAssetExchange<AccountId, Account, CurrencyIdConvert, Currency, DexSwap>
+where
+	Account: Get<AccountId>,
+	CurrencyIdConvert: Convert<MultiAsset, Option<CurrencyId>>,
+	Currency: MultiCurrency<AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+	DexSwap: Swap<AccountId, Balance, CurrencyId>,
+{
+	fn exchange_asset(
+		_origin: Option<&MultiLocation>,
+		give: Assets,
+		want: &MultiAssets,
+		maximal: bool,
+	) -> Result<Assets, Assets> {
+		if give.fungible.len() != 1 || !give.non_fungible.is_empty() || want.len() != 1 {
+			return Err(give);
+		}
+		let (supply_asset_id, supply_amount) = match give.fungible.iter().next() {
+			Some((id, amount)) => (*id, *amount),
+			None => return Err(give),
+		};
+		let (target_asset_id, target_amount) = match want.get(0) {
+			Some(MultiAsset {
+				id,
+				fun: Fungible(amount),
+			}) => (*id, *amount),
+			_ => return Err(give),
+		};
+
+		let supply_currency_id = CurrencyIdConvert::convert((supply_asset_id, supply_amount).into());
+		let target_currency_id = CurrencyIdConvert::convert((target_asset_id, target_amount).into());
+		let (supply_currency_id, target_currency_id) = match (supply_currency_id, target_currency_id) {
+			(Some(supply_currency_id), Some(target_currency_id)) => (supply_currency_id, target_currency_id),
+			_ => return Err(give),
+		};
+
+		let limit = if maximal {
+			SwapLimit::ExactSupply(supply_amount, target_amount)
+		} else {
+			SwapLimit::ExactTarget(supply_amount, target_amount)
+		};
+
+		match Self::do_exchange(supply_currency_id, target_currency_id, limit) {
+			Ok((actual_supply_amount, actual_target_amount)) => {
+				let mut received = Assets::new();
+				received.subsume((target_asset_id, actual_target_amount).into());
+				let remaining_supply_amount = supply_amount.saturating_sub(actual_supply_amount);
+				if !remaining_supply_amount.is_zero() {
+					received.subsume((supply_asset_id, remaining_supply_amount).into());
+				}
+				Ok(received)
+			}
+			Err(e) => {
+				log::debug!(
+					target: "xcm::exchange-asset",
+					"failed to exchange {:?} for {:?}: {:?}",
+					supply_currency_id,
+					target_currency_id,
+					e,
+				);
+				Err(give)
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -116,9 +116,29 @@ mod vesting;
 ))]
 mod weights;
 
+#[cfg(any(
+	feature = "with-mandala-runtime",
+	feature = "with-karura-runtime",
+	feature = "with-acala-runtime"
+))]
+mod xcm_exchange;
+
+#[cfg(any(
+	feature = "with-mandala-runtime",
+	feature = "with-karura-runtime",
+	feature = "with-acala-runtime"
+))]
+mod xcm_rate_limiter;
+
 #[cfg(any(
 	feature = "with-mandala-runtime",
 	feature = "with-karura-runtime",
 	feature = "with-acala-runtime"
 ))]
 mod payment;
+
+#[cfg(feature = "with-karura-runtime")]
+mod xnft;
+
+#[cfg(feature = "with-karura-runtime")]
+mod simulator;
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
+pub use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
 use frame_support::traits::{OnFinalize, OnIdle, OnInitialize};
 pub use frame_support::{
 	assert_noop, assert_ok,
@@ -72,10 +72,10 @@ mod mandala_imports {
 		DefaultExchangeRate, Dex, EmergencyShutdown, EvmAccounts, ExistentialDeposits, FinancialCouncil,
 		GetNativeCurrencyId, Homa, Honzon, IdleScheduler, Loans, MinRewardDistributeAmount, MinimumDebitValue,
 		NativeTokenExistentialDeposit, NftPalletId, OneDay, OriginCaller, ParachainInfo, ParachainSystem, Proxy,
-		Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session, SessionKeys, SessionManager, SevenDays,
-		StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens, TransactionPayment,
-		TransactionPaymentPalletId, TreasuryAccount, TreasuryPalletId, UncheckedExtrinsic, Utility, Vesting,
-		XcmInterface, EVM, NFT,
+		RebasedStableAsset, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session, SessionKeys,
+		SessionManager, SevenDays, StableAsset, StableAssetPalletId, StableAssetQuoter, System, Timestamp, TokenSymbol,
+		Tokens, TransactionPayment, TransactionPaymentPalletId, TreasuryAccount, TreasuryPalletId, UncheckedExtrinsic,
+		Utility, Vesting, XcmInterface, XcmRateLimiter, EVM, NFT,
 	};
 	use primitives::TradingPair;
 	use runtime_common::{ACA, AUSD, DOT, LDOT};
@@ -117,10 +117,11 @@ mod karura_imports {
 		CreateTokenDeposit, Currencies, CurrencyId, DataDepositPerByte, DefaultDebitExchangeRate, DefaultExchangeRate,
 		Dex, EmergencyShutdown, EvmAccounts, ExistentialDeposits, FinancialCouncil, GetNativeCurrencyId, Homa, Honzon,
 		IdleScheduler, KaruraFoundationAccounts, Loans, MinimumDebitValue, NativeTokenExistentialDeposit, NftPalletId,
-		OneDay, OriginCaller, ParachainAccount, ParachainInfo, ParachainSystem, PolkadotXcm, Proxy, Runtime,
-		RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session, SessionManager, SevenDays, StableAsset,
-		StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens, TransactionPayment, TransactionPaymentPalletId,
-		TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, EVM, NFT,
+		OneDay, OriginCaller, ParachainAccount, ParachainInfo, ParachainSystem, PolkadotXcm, Proxy, RebasedStableAsset,
+		Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session, SessionManager, SevenDays, StableAsset,
+		StableAssetPalletId, StableAssetQuoter, System, Timestamp, TokenSymbol, Tokens, TransactionPayment,
+		TransactionPaymentPalletId, TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, XcmRateLimiter, EVM,
+		NFT,
 	};
 	use primitives::TradingPair;
 	use runtime_common::{KAR, KSM, KUSD, LKSM};
@@ -162,10 +163,10 @@ mod acala_imports {
 		DefaultDebitExchangeRate, DefaultExchangeRate, Dex, EmergencyShutdown, EvmAccounts, ExistentialDeposits,
 		FinancialCouncil, GetNativeCurrencyId, Homa, Honzon, IdleScheduler, Loans, MinimumDebitValue,
 		NativeTokenExistentialDeposit, NftPalletId, OneDay, OriginCaller, ParachainAccount, ParachainInfo,
-		ParachainSystem, PolkadotXcm, Proxy, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Scheduler, Session,
-		SessionManager, SevenDays, StableAsset, StableAssetPalletId, System, Timestamp, TokenSymbol, Tokens,
-		TransactionPayment, TransactionPaymentPalletId, TreasuryPalletId, Utility, Vesting, XTokens, XcmInterface, EVM,
-		NFT,
+		ParachainSystem, PolkadotXcm, Proxy, RebasedStableAsset, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
+		Scheduler, Session, SessionManager, SevenDays, StableAsset, StableAssetPalletId, StableAssetQuoter, System,
+		Timestamp, TokenSymbol, Tokens, TransactionPayment, TransactionPaymentPalletId, TreasuryPalletId, Utility,
+		Vesting, XTokens, XcmInterface, XcmRateLimiter, EVM, NFT,
 	};
 	use frame_support::parameter_types;
 	use primitives::TradingPair;
@@ -235,9 +236,13 @@ pub fn run_to_block(n: u32) {
 }
 
 pub fn set_relaychain_block_number(number: BlockNumber) {
+	set_relaychain_state(number, RelayStateSproofBuilder::default());
+}
+
+pub fn set_relaychain_state(number: BlockNumber, sproof: RelayStateSproofBuilder) {
 	ParachainSystem::on_initialize(number);
 
-	let (relay_storage_root, proof) = RelayStateSproofBuilder::default().into_state_root_and_proof();
+	let (relay_storage_root, proof) = sproof.into_state_root_and_proof();
 
 	assert_ok!(ParachainSystem::set_validation_data(
 		RuntimeOrigin::none(),
@@ -0,0 +1,217 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Round trips through the XCM simulator, between the runtime and a mock sibling parachain.
+
+use crate::setup::*;
+use cumulus_primitives_core::{relay_chain::HrmpChannelId, AbridgedHrmpChannel, ParaId, XcmpMessageSource};
+use karura_runtime::{DmpQueue, XcmpQueue};
+use xcm_executor::traits::ConvertLocation;
+use xcm_simulator::{
+	decl_test_network, decl_test_parachain, decl_test_relay_chain, TestExt, Weight, XcmpMessageHandlerT,
+};
+
+mod relay;
+mod sibling;
+
+const KARURA_ID: u32 = 2000;
+const SIBLING_ID: u32 = 2002;
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay::Runtime,
+		RuntimeCall = relay::RuntimeCall,
+		RuntimeEvent = relay::RuntimeEvent,
+		XcmConfig = relay::XcmConfig,
+		MessageQueue = relay::MessageQueue,
+		System = relay::System,
+		new_ext = relay::new_ext(),
+	}
+}
+
+decl_test_parachain! {
+	pub struct Karura {
+		Runtime = Runtime,
+		XcmpMessageHandler = XcmpQueue,
+		DmpMessageHandler = DmpQueue,
+		new_ext = karura_ext(),
+	}
+}
+
+decl_test_parachain! {
+	pub struct Sibling {
+		Runtime = sibling::Runtime,
+		XcmpMessageHandler = sibling::MsgQueue,
+		DmpMessageHandler = sibling::DmpHandler,
+		new_ext = sibling::new_ext(SIBLING_ID),
+	}
+}
+
+decl_test_network! {
+	pub struct TestNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(KARURA_ID, Karura),
+			(SIBLING_ID, Sibling),
+		],
+	}
+}
+
+fn sibling_location() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(SIBLING_ID)))
+}
+
+fn sibling_account() -> AccountId {
+	LocationToAccountId::convert_location(&sibling_location()).unwrap()
+}
+
+fn usd_location() -> MultiLocation {
+	<CurrencyIdConvert as Convert<CurrencyId, Option<MultiLocation>>>::convert(USD_CURRENCY).unwrap()
+}
+
+fn karura_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.balances(vec![
+			(
+				AccountId::from(BOB),
+				RELAY_CHAIN_CURRENCY,
+				10_000 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+			(AccountId::from(BOB), USD_CURRENCY, 10_000_000 * dollar(USD_CURRENCY)),
+			(
+				sibling_account(),
+				RELAY_CHAIN_CURRENCY,
+				100 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+		])
+		.parachain_id(KARURA_ID)
+		.build();
+
+	ext.execute_with(|| {
+		// open the HRMP channel to the sibling, XCMP messages are only queued for open channels
+		let mut sproof = RelayStateSproofBuilder::default();
+		sproof.para_id = KARURA_ID.into();
+		sproof.hrmp_egress_channel_index = Some(vec![SIBLING_ID.into()]);
+		sproof.hrmp_channels.insert(
+			HrmpChannelId {
+				sender: KARURA_ID.into(),
+				recipient: SIBLING_ID.into(),
+			},
+			AbridgedHrmpChannel {
+				max_capacity: 10,
+				max_total_size: 1024 * 1024,
+				max_message_size: 1024 * 1024,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			},
+		);
+		set_relaychain_state(1, sproof);
+
+		assert_ok!(Dex::add_liquidity(
+			RuntimeOrigin::signed(AccountId::from(BOB)),
+			RELAY_CHAIN_CURRENCY,
+			USD_CURRENCY,
+			1_000 * dollar(RELAY_CHAIN_CURRENCY),
+			1_000_000 * dollar(USD_CURRENCY),
+			0,
+			false,
+		));
+	});
+	ext
+}
+
+// the simulator only routes the messages of the mock chains, so deliver the messages queued in
+// the XCMP queue of the runtime by hand
+fn deliver_karura_outbound_messages() {
+	let messages = Karura::execute_with(|| <XcmpQueue as XcmpMessageSource>::take_outbound_messages(usize::MAX));
+	for (recipient, data) in messages {
+		assert_eq!(recipient, ParaId::from(SIBLING_ID));
+		Sibling::handle_xcmp_messages(vec![(ParaId::from(KARURA_ID), 1, &data[..])].into_iter(), Weight::MAX);
+	}
+}
+
+#[test]
+fn exchange_asset_from_sibling_round_trip_works() {
+	TestNet::reset();
+
+	let alice_on_sibling = MultiLocation::new(
+		0,
+		X1(Junction::AccountId32 {
+			network: None,
+			id: ALICE,
+		}),
+	);
+	let want_amount = 9_000 * dollar(USD_CURRENCY);
+
+	// swap the relay chain token held by the sibling on the runtime, and send the proceeds back
+	let message = Karura::execute_with(|| {
+		let asset: MultiAsset = (MultiLocation::parent(), 10 * dollar(RELAY_CHAIN_CURRENCY)).into();
+		Xcm(vec![
+			WithdrawAsset(asset.clone().into()),
+			BuyExecution {
+				fees: asset,
+				weight_limit: Unlimited,
+			},
+			ExchangeAsset {
+				give: AllCounted(1).into(),
+				want: (usd_location(), want_amount).into(),
+				maximal: true,
+			},
+			DepositReserveAsset {
+				assets: AllCounted(1).into(),
+				dest: sibling_location(),
+				xcm: Xcm(vec![DepositAsset {
+					assets: AllCounted(1).into(),
+					beneficiary: alice_on_sibling,
+				}]),
+			},
+		])
+	});
+	Sibling::execute_with(|| {
+		assert_ok!(send_xcm::<sibling::XcmRouter>(
+			MultiLocation::new(1, X1(Parachain(KARURA_ID))),
+			message
+		));
+	});
+
+	let (proceeds, usd_on_sibling) = Karura::execute_with(|| {
+		// the relay chain token left after paying the fee is swapped, and the proceeds are held
+		// by the sovereign account of the sibling as the reserve
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &sibling_account()),
+			90 * dollar(RELAY_CHAIN_CURRENCY)
+		);
+		let proceeds = Currencies::free_balance(USD_CURRENCY, &sibling_account());
+		assert!(proceeds > want_amount);
+
+		let usd_on_sibling = usd_location()
+			.reanchored(&sibling_location(), UniversalLocation::get())
+			.unwrap();
+		(proceeds, usd_on_sibling)
+	});
+
+	deliver_karura_outbound_messages();
+
+	Sibling::execute_with(|| {
+		assert_eq!(
+			sibling::MsgQueue::deposits(alice_on_sibling, AssetId::Concrete(usd_on_sibling)),
+			proceeds
+		);
+	});
+}
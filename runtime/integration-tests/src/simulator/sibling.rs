@@ -0,0 +1,213 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! A minimal sibling parachain, which executes the XCM messages it receives and records the
+//! assets deposited to each location.
+
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64, Everything, Nothing},
+	weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use xcm::v3::prelude::*;
+use xcm_builder::{AllowUnpaidExecutionFrom, FixedWeightBounds};
+
+pub type AccountId = AccountId32;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Hash = H256;
+	type RuntimeCall = RuntimeCall;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+#[frame_support::pallet]
+pub mod mock_msg_queue {
+	use frame_support::pallet_prelude::*;
+	use xcm::{v3::prelude::*, VersionedXcm};
+	use xcm_simulator::{ParaId, RelayBlockNumber, XcmpMessageFormat, XcmpMessageHandlerT};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn parachain_id)]
+	pub type ParachainId<T: Config> = StorageValue<_, ParaId, ValueQuery>;
+
+	/// The amount of each asset deposited to each location.
+	#[pallet::storage]
+	#[pallet::getter(fn deposits)]
+	pub type Deposits<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, MultiLocation, Blake2_128Concat, AssetId, u128, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Executed { sender: ParaId, outcome: Outcome },
+		BadVersion { sender: ParaId },
+	}
+
+	impl<T: Config> Get<ParaId> for Pallet<T> {
+		fn get() -> ParaId {
+			Self::parachain_id()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn set_para_id(para_id: ParaId) {
+			ParachainId::<T>::put(para_id);
+		}
+
+		fn handle_xcmp_message(sender: ParaId, xcm: VersionedXcm<T::RuntimeCall>, max_weight: Weight) {
+			let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
+			match Xcm::<T::RuntimeCall>::try_from(xcm) {
+				Ok(xcm) => {
+					let location = MultiLocation::new(1, X1(Parachain(sender.into())));
+					let outcome = T::XcmExecutor::execute_xcm(location, xcm, hash, max_weight);
+					Self::deposit_event(Event::Executed { sender, outcome });
+				}
+				Err(()) => Self::deposit_event(Event::BadVersion { sender }),
+			}
+		}
+	}
+
+	impl<T: Config> XcmpMessageHandlerT for Pallet<T> {
+		fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+			iter: I,
+			max_weight: Weight,
+		) -> Weight {
+			for (sender, _sent_at, mut data) in iter {
+				let _ = XcmpMessageFormat::decode(&mut data).expect("XCMP messages start with the format; qed");
+
+				while !data.is_empty() {
+					let xcm = VersionedXcm::<T::RuntimeCall>::decode(&mut data).expect("valid versioned XCM; qed");
+					Self::handle_xcmp_message(sender, xcm, max_weight);
+				}
+			}
+			max_weight
+		}
+	}
+
+	/// Records the deposited assets instead of moving any balance.
+	pub struct RecordDeposits<T>(PhantomData<T>);
+	impl<T: Config> xcm_executor::traits::TransactAsset for RecordDeposits<T> {
+		fn deposit_asset(what: &MultiAsset, who: &MultiLocation, _context: Option<&XcmContext>) -> XcmResult {
+			let Fungible(amount) = what.fun else {
+				return Err(XcmError::AssetNotFound);
+			};
+			Deposits::<T>::mutate(who, what.id, |balance| *balance = balance.saturating_add(amount));
+			Ok(())
+		}
+	}
+}
+
+pub type XcmRouter = super::ParachainXcmRouter<MsgQueue>;
+
+/// The sibling does not receive downward messages.
+pub type DmpHandler = ();
+
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(NetworkId::Kusama), Parachain(MsgQueue::parachain_id().into()));
+	pub UnitWeightCost: Weight = Weight::from_parts(10, 10);
+}
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = XcmRouter;
+	type AssetTransactor = mock_msg_queue::RecordDeposits<Runtime>;
+	type OriginConverter = ();
+	// every asset is accepted as reserve-backed by its sender
+	type IsReserve = Everything;
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, ConstU32<100>>;
+	type Trader = ();
+	type ResponseHandler = ();
+	type AssetTrap = ();
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = ();
+	type SubscriptionService = ();
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = ConstU32<64>;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Nothing;
+	type Aliasers = Nothing;
+}
+
+impl mock_msg_queue::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type XcmExecutor = xcm_executor::XcmExecutor<XcmConfig>;
+}
+
+construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MsgQueue: mock_msg_queue,
+	}
+);
+
+pub fn new_ext(para_id: u32) -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		MsgQueue::set_para_id(para_id.into());
+	});
+	ext
+}
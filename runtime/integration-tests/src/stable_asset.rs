@@ -20,7 +20,8 @@ use crate::evm::alice_evm_addr;
 use crate::payment::{with_fee_aggregated_path_call, with_fee_currency_call, with_fee_path_call, INFO, POST_INFO};
 use crate::setup::*;
 use module_aggregated_dex::SwapPath;
-use module_support::{AggregatedSwapPath, ExchangeRate, Swap, SwapLimit, EVM as EVMTrait};
+use module_support::{AggregatedSwapPath, ExchangeRate, StableAssetQuote, Swap, SwapLimit, EVM as EVMTrait};
+use nutsfinance_stable_asset::traits::StableAsset as StableAssetT;
 use primitives::{currency::AssetMetadata, evm::EvmAddress};
 use sp_core::bounded::BoundedVec;
 use sp_runtime::{
@@ -350,6 +351,97 @@ fn stable_asset_mint_works() {
 		});
 }
 
+#[test]
+fn stable_asset_quotes_match_execution() {
+	ExtBuilder::default()
+		.balances(vec![
+			(
+				// NetworkContractSource
+				MockAddressMapping::get_account_id(&H160::from_low_u64_be(0)),
+				NATIVE_CURRENCY,
+				1_000_000_000 * dollar(NATIVE_CURRENCY),
+			),
+			(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY,
+				1_000_000_000 * dollar(NATIVE_CURRENCY),
+			),
+			(
+				AccountId::from(ALICE),
+				LIQUID_CURRENCY,
+				12_000_000_000 * dollar(NATIVE_CURRENCY),
+			),
+		])
+		.build()
+		.execute_with(|| {
+			let alice = AccountId::from(ALICE);
+			let pool_asset = CurrencyId::StableAssetPoolToken(0);
+			enable_stable_asset(
+				vec![RELAY_CHAIN_CURRENCY, LIQUID_CURRENCY],
+				vec![100_000_000_000_000u128, 100_000_000_000_000u128],
+				None,
+			);
+
+			let pool_info = StableAssetQuoter::pool_info(0).unwrap();
+			assert_eq!(pool_info.assets, vec![RELAY_CHAIN_CURRENCY, LIQUID_CURRENCY]);
+			assert_eq!(pool_info.a, 1_000);
+			// the balances are in the units of the pool assets
+			assert_eq!(
+				pool_info.balances,
+				vec![
+					Currencies::free_balance(RELAY_CHAIN_CURRENCY, &pool_info.account_id),
+					Currencies::free_balance(LIQUID_CURRENCY, &pool_info.account_id),
+				]
+			);
+			assert_eq!(StableAssetQuoter::pool_info(1), None);
+			assert_eq!(
+				StableAssetQuoter::get_virtual_price(0),
+				Some(Ratio::saturating_from_integer(1))
+			);
+			assert_eq!(StableAssetQuoter::get_virtual_price(1), None);
+
+			// mint
+			let amounts = vec![1_000_000_000_000u128, 50_000_000_000_000u128];
+			let quoted = StableAssetQuoter::get_mint_amount(0, amounts.clone()).unwrap();
+			// quoting does not change the pool
+			assert_eq!(StableAssetQuoter::pool_info(0), Some(pool_info));
+
+			let before = Currencies::free_balance(pool_asset, &alice);
+			assert_ok!(RebasedStableAsset::mint(&alice, 0, amounts, 0));
+			assert_eq!(Currencies::free_balance(pool_asset, &alice) - before, quoted);
+
+			// swap
+			let quoted = StableAssetQuoter::get_swap_output_amount(0, 0, 1, 1_000_000_000_000u128).unwrap();
+			let (_, dy) = RebasedStableAsset::swap(&alice, 0, 0, 1, 1_000_000_000_000u128, 0, 2).unwrap();
+			assert_eq!(dy, quoted);
+			assert!(StableAssetQuoter::get_swap_input_amount(0, 0, 1, quoted).is_some());
+			// the swap fee is not collected yet
+			assert!(StableAssetQuoter::get_virtual_price(0).unwrap() > Ratio::saturating_from_integer(1));
+
+			// redeem
+			let redeem_amount = 1_000_000_000_000u128;
+			assert_eq!(
+				StableAssetQuoter::get_redeem_proportion_amount(0, redeem_amount).map(|amounts| amounts.len()),
+				Some(2)
+			);
+
+			let quoted = StableAssetQuoter::get_redeem_single_amount(0, redeem_amount, 1).unwrap();
+			let before = Currencies::free_balance(LIQUID_CURRENCY, &alice);
+			assert_ok!(RebasedStableAsset::redeem_single(&alice, 0, redeem_amount, 1, 0, 2));
+			assert_eq!(Currencies::free_balance(LIQUID_CURRENCY, &alice) - before, quoted);
+
+			let redeem_amounts = vec![100_000_000_000u128, 100_000_000_000u128];
+			let quoted = StableAssetQuoter::get_redeem_multi_amount(0, redeem_amounts.clone()).unwrap();
+			let before = Currencies::free_balance(pool_asset, &alice);
+			assert_ok!(RebasedStableAsset::redeem_multi(&alice, 0, redeem_amounts, u128::MAX));
+			assert_eq!(before - Currencies::free_balance(pool_asset, &alice), quoted);
+
+			// invalid input
+			assert_eq!(StableAssetQuoter::get_redeem_single_amount(0, redeem_amount, 2), None);
+			assert_eq!(StableAssetQuoter::get_mint_amount(0, vec![u128::MAX, u128::MAX]), None);
+		});
+}
+
 #[test]
 fn three_usd_pool_works() {
 	let dollar = dollar(NATIVE_CURRENCY);
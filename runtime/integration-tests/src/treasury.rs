@@ -27,14 +27,14 @@ fn treasury_should_take_xcm_execution_revenue() {
 		#[cfg(feature = "with-karura-runtime")] // Karura uses KSM, which has 12 d.p. accuracy.
 		let actual_amount = 999999903556000;
 		#[cfg(feature = "with-acala-runtime")] // Acala uses DOT, which has 10 d.p. accuracy.
-		let actual_amount = 9999999035560;
+		let actual_amount = 9999974723512;
 
 		#[cfg(feature = "with-mandala-runtime")]
-		let shallow_weight = 3_000_000;
+		let shallow_weight = Weight::from_parts(3_000_000, 0);
 		#[cfg(feature = "with-karura-runtime")]
-		let shallow_weight = 600_000_000;
+		let shallow_weight = Weight::from_parts(600_000_000, 0);
 		#[cfg(feature = "with-acala-runtime")]
-		let shallow_weight = 600_000_000;
+		let shallow_weight = Weight::from_parts(15_725_077_000, 359_300);
 		let origin = MultiLocation::parent();
 
 		// receive relay chain token
@@ -43,7 +43,7 @@ fn treasury_should_take_xcm_execution_revenue() {
 			ReserveAssetDeposited(asset.clone().into()),
 			BuyExecution {
 				fees: asset,
-				weight_limit: Limited(Weight::from_parts(shallow_weight, 0)),
+				weight_limit: Limited(shallow_weight),
 			},
 			DepositAsset {
 				assets: AllCounted(u32::max_value()).into(),
@@ -56,7 +56,7 @@ fn treasury_should_take_xcm_execution_revenue() {
 		]);
 		use xcm_executor::traits::WeightBounds;
 		let debt = <XcmConfig as xcm_executor::Config>::Weigher::weight(&mut msg).unwrap_or_default();
-		assert_eq!(debt, Weight::from_parts(shallow_weight, 0));
+		assert_eq!(debt, shallow_weight);
 
 		assert_eq!(Tokens::free_balance(RELAY_CHAIN_CURRENCY, &ALICE.into()), 0);
 		assert_eq!(Tokens::free_balance(RELAY_CHAIN_CURRENCY, &TreasuryAccount::get()), 0);
@@ -65,7 +65,7 @@ fn treasury_should_take_xcm_execution_revenue() {
 		let hash = msg.using_encoded(sp_io::hashing::blake2_256);
 		assert_eq!(
 			XcmExecutor::<XcmConfig>::execute_xcm(origin, msg, hash, weight_limit),
-			Outcome::Complete(Weight::from_parts(shallow_weight, 0))
+			Outcome::Complete(shallow_weight)
 		);
 
 		assert_eq!(Tokens::free_balance(RELAY_CHAIN_CURRENCY, &ALICE.into()), actual_amount);
@@ -0,0 +1,150 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use sp_runtime::traits::Convert;
+use xcm_executor::traits::WeightBounds;
+
+fn usd_location() -> MultiLocation {
+	<CurrencyIdConvert as Convert<CurrencyId, Option<MultiLocation>>>::convert(USD_CURRENCY).unwrap()
+}
+
+fn inject_liquidity() {
+	assert_ok!(Dex::add_liquidity(
+		RuntimeOrigin::signed(AccountId::from(BOB)),
+		RELAY_CHAIN_CURRENCY,
+		USD_CURRENCY,
+		1_000 * dollar(RELAY_CHAIN_CURRENCY),
+		1_000_000 * dollar(USD_CURRENCY),
+		0,
+		false,
+	));
+}
+
+// receive relay chain token from the relay chain and exchange it in the same message
+fn execute_exchange_from_relay_chain(give_amount: Balance, want: MultiAsset, maximal: bool) -> Outcome {
+	let asset: MultiAsset = (MultiLocation::parent(), give_amount).into();
+	let mut msg = Xcm(vec![
+		ReserveAssetDeposited(asset.clone().into()),
+		BuyExecution {
+			fees: asset,
+			weight_limit: Unlimited,
+		},
+		ExchangeAsset {
+			give: AllCounted(1).into(),
+			want: want.into(),
+			maximal,
+		},
+		DepositAsset {
+			assets: AllCounted(2).into(),
+			beneficiary: X1(Junction::AccountId32 {
+				network: None,
+				id: ALICE,
+			})
+			.into(),
+		},
+	]);
+	let weight_limit = <XcmConfig as xcm_executor::Config>::Weigher::weight(&mut msg).unwrap();
+	let hash = msg.using_encoded(sp_io::hashing::blake2_256);
+	XcmExecutor::<XcmConfig>::execute_xcm(MultiLocation::parent(), msg, hash, weight_limit)
+}
+
+fn dex_balances() -> Vec<(AccountId, CurrencyId, Balance)> {
+	vec![
+		(
+			AccountId::from(BOB),
+			RELAY_CHAIN_CURRENCY,
+			10_000 * dollar(RELAY_CHAIN_CURRENCY),
+		),
+		(AccountId::from(BOB), USD_CURRENCY, 10_000_000 * dollar(USD_CURRENCY)),
+	]
+}
+
+#[test]
+fn exchange_asset_with_exact_supply_works() {
+	ExtBuilder::default().balances(dex_balances()).build().execute_with(|| {
+		inject_liquidity();
+		let treasury_usd = Currencies::free_balance(USD_CURRENCY, &TreasuryAccount::get());
+
+		let outcome = execute_exchange_from_relay_chain(
+			10 * dollar(RELAY_CHAIN_CURRENCY),
+			(usd_location(), 9_000 * dollar(USD_CURRENCY)).into(),
+			true,
+		);
+		assert!(matches!(outcome, Outcome::Complete(_)));
+
+		// all the relay chain token left after paying the fee is swapped
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+			0
+		);
+		assert!(Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)) > 9_000 * dollar(USD_CURRENCY));
+		assert_eq!(
+			Currencies::free_balance(USD_CURRENCY, &TreasuryAccount::get()),
+			treasury_usd
+		);
+	});
+}
+
+#[test]
+fn exchange_asset_with_exact_target_works() {
+	ExtBuilder::default().balances(dex_balances()).build().execute_with(|| {
+		inject_liquidity();
+
+		let outcome = execute_exchange_from_relay_chain(
+			10 * dollar(RELAY_CHAIN_CURRENCY),
+			(usd_location(), 5_000 * dollar(USD_CURRENCY)).into(),
+			false,
+		);
+		assert!(matches!(outcome, Outcome::Complete(_)));
+
+		// the unused relay chain token is returned to holding and deposited
+		assert_eq!(
+			Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)),
+			5_000 * dollar(USD_CURRENCY)
+		);
+		assert!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)) > 4 * dollar(RELAY_CHAIN_CURRENCY)
+		);
+	});
+}
+
+#[test]
+fn exchange_asset_fails_without_deal() {
+	ExtBuilder::default().balances(dex_balances()).build().execute_with(|| {
+		inject_liquidity();
+
+		// the minimum target amount can not be satisfied
+		let outcome = execute_exchange_from_relay_chain(
+			10 * dollar(RELAY_CHAIN_CURRENCY),
+			(usd_location(), 20_000 * dollar(USD_CURRENCY)).into(),
+			true,
+		);
+		assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::NoDeal)));
+
+		// the asset to want is not supported
+		let outcome = execute_exchange_from_relay_chain(
+			10 * dollar(RELAY_CHAIN_CURRENCY),
+			(MultiLocation::new(1, X1(Parachain(9999))), 1).into(),
+			true,
+		);
+		assert!(matches!(outcome, Outcome::Incomplete(_, XcmError::NoDeal)));
+
+		assert_eq!(Currencies::free_balance(USD_CURRENCY, &AccountId::from(ALICE)), 0);
+	});
+}
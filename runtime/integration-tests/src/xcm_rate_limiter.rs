@@ -0,0 +1,131 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use module_xcm_rate_limiter::{FlowDirection, OverLimitAction, RateLimit};
+use xcm_executor::traits::WeightBounds;
+
+// receive relay chain token from the relay chain
+fn execute_transfer_from_relay_chain(amount: Balance) -> Outcome {
+	let asset: MultiAsset = (MultiLocation::parent(), amount).into();
+	let mut msg = Xcm(vec![
+		ReserveAssetDeposited(asset.clone().into()),
+		ClearOrigin,
+		BuyExecution {
+			fees: asset,
+			weight_limit: Unlimited,
+		},
+		DepositAsset {
+			assets: AllCounted(1).into(),
+			beneficiary: X1(Junction::AccountId32 {
+				network: None,
+				id: ALICE,
+			})
+			.into(),
+		},
+	]);
+	let weight_limit = <XcmConfig as xcm_executor::Config>::Weigher::weight(&mut msg).unwrap();
+	let hash = msg.using_encoded(sp_io::hashing::blake2_256);
+	XcmExecutor::<XcmConfig>::execute_xcm(MultiLocation::parent(), msg, hash, weight_limit)
+}
+
+fn set_inflow_limit(quota: Balance, over_limit: OverLimitAction) {
+	assert_ok!(XcmRateLimiter::set_rate_limit(
+		RuntimeOrigin::root(),
+		RELAY_CHAIN_CURRENCY,
+		FlowDirection::Inflow,
+		Some(RateLimit {
+			quota,
+			window: 100,
+			over_limit,
+		})
+	));
+}
+
+#[test]
+fn inflow_over_quota_is_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_inflow_limit(10 * dollar(RELAY_CHAIN_CURRENCY), OverLimitAction::Reject);
+
+		let outcome = execute_transfer_from_relay_chain(20 * dollar(RELAY_CHAIN_CURRENCY));
+		assert!(matches!(
+			outcome,
+			Outcome::Incomplete(_, XcmError::FailedToTransactAsset("RateLimited"))
+		));
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+			0
+		);
+
+		let outcome = execute_transfer_from_relay_chain(5 * dollar(RELAY_CHAIN_CURRENCY));
+		assert!(matches!(outcome, Outcome::Complete(_)));
+		let received = Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE));
+		assert!(received > 0);
+		assert_eq!(
+			XcmRateLimiter::remaining_quota(RELAY_CHAIN_CURRENCY, FlowDirection::Inflow),
+			Some(10 * dollar(RELAY_CHAIN_CURRENCY) - received)
+		);
+	});
+}
+
+#[test]
+fn inflow_over_quota_is_queued_and_released() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_inflow_limit(10 * dollar(RELAY_CHAIN_CURRENCY), OverLimitAction::Queue);
+
+		let outcome = execute_transfer_from_relay_chain(20 * dollar(RELAY_CHAIN_CURRENCY));
+		assert!(matches!(outcome, Outcome::Complete(_)));
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+			0
+		);
+		let queued = XcmRateLimiter::queued_inflows(0).unwrap();
+		assert_eq!(queued.beneficiary, AccountId::from(ALICE));
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &XcmRateLimiter::account_id()),
+			queued.amount
+		);
+
+		assert_ok!(XcmRateLimiter::release_queued_inflow(RuntimeOrigin::root(), 0));
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+			queued.amount
+		);
+	});
+}
+
+#[test]
+fn circuit_breaker_halts_inflow() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(XcmRateLimiter::set_circuit_breaker(
+			RuntimeOrigin::root(),
+			RELAY_CHAIN_CURRENCY,
+			true
+		));
+
+		let outcome = execute_transfer_from_relay_chain(5 * dollar(RELAY_CHAIN_CURRENCY));
+		assert!(matches!(
+			outcome,
+			Outcome::Incomplete(_, XcmError::FailedToTransactAsset("RateLimited"))
+		));
+		assert_eq!(
+			Currencies::free_balance(RELAY_CHAIN_CURRENCY, &AccountId::from(ALICE)),
+			0
+		);
+	});
+}
@@ -0,0 +1,349 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::setup::*;
+use karura_runtime::XNFT;
+use primitives::nft::{ClassProperty, Properties};
+use xcm::VersionedAssetId;
+use xcm_executor::traits::{ConvertLocation, WeightBounds};
+
+fn mint_nft_to_alice() {
+	let metadata = vec![1];
+	assert_ok!(NFT::create_class(
+		RuntimeOrigin::signed(AccountId::from(ALICE)),
+		metadata.clone(),
+		Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(
+		&NftPalletId::get().into_sub_account_truncating(0),
+		CreateTokenDeposit::get() + DataDepositPerByte::get() + Balances::minimum_balance()
+	));
+	assert_ok!(NFT::mint(
+		RuntimeOrigin::signed(NftPalletId::get().into_sub_account_truncating(0)),
+		MultiAddress::Id(AccountId::from(ALICE)),
+		0,
+		metadata,
+		Default::default(),
+		1
+	));
+}
+
+fn bob_on(chain: MultiLocation) -> MultiLocation {
+	chain
+		.pushed_with_interior(Junction::AccountId32 { network: None, id: BOB })
+		.unwrap()
+}
+
+#[test]
+fn transfer_local_nft_to_relay_chain_works() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			NATIVE_CURRENCY,
+			1_000 * dollar(NATIVE_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			mint_nft_to_alice();
+
+			let dest = bob_on(MultiLocation::parent());
+			assert_ok!(XNFT::transfer_nft(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				(0, 0),
+				NATIVE_CURRENCY,
+				dollar(NATIVE_CURRENCY),
+				Box::new(dest.into_versioned()),
+				WeightLimit::Unlimited,
+			));
+
+			// the token and the fee are held by the sovereign account of the relay chain
+			let relay_chain_account = LocationToAccountId::convert_location(&MultiLocation::parent()).unwrap();
+			assert_eq!(
+				orml_nft::Pallet::<Runtime>::tokens(0, 0).unwrap().owner,
+				relay_chain_account
+			);
+			assert_eq!(
+				Currencies::free_balance(NATIVE_CURRENCY, &relay_chain_account),
+				dollar(NATIVE_CURRENCY)
+			);
+			assert!(System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::XNFT(module_xnft::Event::TransferredNft {
+					class_id: 0,
+					token_id: 0,
+					..
+				})
+			)));
+		});
+}
+
+#[test]
+fn transfer_nft_fails_on_invalid_route() {
+	ExtBuilder::default()
+		.balances(vec![
+			(AccountId::from(ALICE), NATIVE_CURRENCY, 1_000 * dollar(NATIVE_CURRENCY)),
+			(
+				AccountId::from(ALICE),
+				RELAY_CHAIN_CURRENCY,
+				1_000 * dollar(RELAY_CHAIN_CURRENCY),
+			),
+		])
+		.build()
+		.execute_with(|| {
+			mint_nft_to_alice();
+
+			let dest = Box::new(bob_on(MultiLocation::parent()).into_versioned());
+
+			assert_noop!(
+				XNFT::transfer_nft(
+					RuntimeOrigin::signed(AccountId::from(BOB)),
+					(0, 0),
+					NATIVE_CURRENCY,
+					dollar(NATIVE_CURRENCY),
+					dest.clone(),
+					WeightLimit::Unlimited,
+				),
+				module_xnft::Error::<Runtime>::NotTokenOwner
+			);
+			assert_noop!(
+				XNFT::transfer_nft(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					(0, 0),
+					NATIVE_CURRENCY,
+					0,
+					dest.clone(),
+					WeightLimit::Unlimited,
+				),
+				module_xnft::Error::<Runtime>::ZeroFee
+			);
+			// the fee reserve is the relay chain while the token reserve is this chain
+			assert_noop!(
+				XNFT::transfer_nft(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					(0, 0),
+					RELAY_CHAIN_CURRENCY,
+					dollar(RELAY_CHAIN_CURRENCY),
+					dest,
+					WeightLimit::Unlimited,
+				),
+				module_xnft::Error::<Runtime>::DistinctReserveForAssetAndFee
+			);
+			assert_noop!(
+				XNFT::transfer_nft(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					(0, 0),
+					NATIVE_CURRENCY,
+					dollar(NATIVE_CURRENCY),
+					Box::new(MultiLocation::parent().into_versioned()),
+					WeightLimit::Unlimited,
+				),
+				module_xnft::Error::<Runtime>::InvalidDest
+			);
+		});
+}
+
+#[test]
+fn transfer_foreign_nft_to_other_chain_than_reserve_fails() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			NATIVE_CURRENCY,
+			1_000 * dollar(NATIVE_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			let foreign_class: AssetId =
+				MultiLocation::new(1, X3(Parachain(1000), PalletInstance(52), GeneralIndex(1))).into();
+			assert_ok!(XNFT::register_asset(
+				RuntimeOrigin::root(),
+				Box::new(VersionedAssetId::V3(foreign_class))
+			));
+			let class_id = XNFT::foreign_asset_to_class(foreign_class).unwrap();
+
+			let alice_location = MultiLocation::new(
+				0,
+				X1(Junction::AccountId32 {
+					network: None,
+					id: ALICE,
+				}),
+			);
+			assert_ok!(<XNFT as xcm_executor::traits::TransactAsset>::deposit_asset(
+				&MultiAsset {
+					id: foreign_class,
+					fun: NonFungible(AssetInstance::Index(7)),
+				},
+				&alice_location,
+				None,
+			));
+			let token_id = XNFT::asset_instance_to_item(class_id, AssetInstance::Index(7)).unwrap();
+
+			// the reserve of the token is parachain 1000
+			assert_noop!(
+				XNFT::transfer_nft(
+					RuntimeOrigin::signed(AccountId::from(ALICE)),
+					(class_id, token_id),
+					NATIVE_CURRENCY,
+					dollar(NATIVE_CURRENCY),
+					Box::new(bob_on(MultiLocation::new(1, X1(Parachain(2001)))).into_versioned()),
+					WeightLimit::Unlimited,
+				),
+				module_xnft::Error::<Runtime>::DistinctReserveForAssetAndFee
+			);
+		});
+}
+
+// teleport the token back from the relay chain to ALICE
+fn execute_teleport_from_relay_chain(asset: MultiAsset) -> Outcome {
+	let mut msg = Xcm(vec![
+		ReceiveTeleportedAsset(asset.into()),
+		ClearOrigin,
+		DepositAsset {
+			assets: AllCounted(1).into(),
+			beneficiary: X1(Junction::AccountId32 {
+				network: None,
+				id: ALICE,
+			})
+			.into(),
+		},
+	]);
+	let weight = <XcmConfig as xcm_executor::Config>::Weigher::weight(&mut msg).unwrap();
+	let hash = msg.using_encoded(sp_io::hashing::blake2_256);
+	XcmExecutor::<XcmConfig>::execute_xcm_in_credit(MultiLocation::parent(), msg, hash, weight, weight)
+}
+
+#[test]
+fn set_teleport_location_works() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			NATIVE_CURRENCY,
+			1_000 * dollar(NATIVE_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			mint_nft_to_alice();
+
+			let location = Box::new(MultiLocation::parent().into_versioned());
+			assert_noop!(
+				XNFT::set_teleport_location(RuntimeOrigin::signed(AccountId::from(ALICE)), 0, location.clone(), true),
+				BadOrigin
+			);
+			assert_noop!(
+				XNFT::set_teleport_location(RuntimeOrigin::root(), 1, location.clone(), true),
+				module_xnft::Error::<Runtime>::ClassNotFound
+			);
+
+			assert_ok!(XNFT::set_teleport_location(
+				RuntimeOrigin::root(),
+				0,
+				location.clone(),
+				true
+			));
+			assert!(XNFT::teleport_locations(0, MultiLocation::parent()).is_some());
+
+			assert_ok!(XNFT::set_teleport_location(RuntimeOrigin::root(), 0, location, false));
+			assert!(XNFT::teleport_locations(0, MultiLocation::parent()).is_none());
+		});
+}
+
+#[test]
+fn teleport_nft_works() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			NATIVE_CURRENCY,
+			1_000 * dollar(NATIVE_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			mint_nft_to_alice();
+			assert_ok!(XNFT::set_teleport_location(
+				RuntimeOrigin::root(),
+				0,
+				Box::new(MultiLocation::parent().into_versioned()),
+				true
+			));
+
+			// no fee is needed to teleport
+			assert_ok!(XNFT::transfer_nft(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				(0, 0),
+				NATIVE_CURRENCY,
+				0,
+				Box::new(bob_on(MultiLocation::parent()).into_versioned()),
+				WeightLimit::Unlimited,
+			));
+
+			// the token is checked out into the pallet account
+			assert_eq!(
+				orml_nft::Pallet::<Runtime>::tokens(0, 0).unwrap().owner,
+				XNFT::account_id()
+			);
+			assert!(System::events().iter().any(|r| matches!(
+				r.event,
+				RuntimeEvent::XNFT(module_xnft::Event::TeleportedNft {
+					class_id: 0,
+					token_id: 0,
+					..
+				})
+			)));
+
+			// and checked in when it is teleported back
+			let asset = XNFT::token_to_asset((0, 0)).unwrap();
+			assert_ok!(execute_teleport_from_relay_chain(asset).ensure_complete());
+			assert_eq!(
+				orml_nft::Pallet::<Runtime>::tokens(0, 0).unwrap().owner,
+				AccountId::from(ALICE)
+			);
+		});
+}
+
+#[test]
+fn teleport_nft_in_fails_if_untrusted_or_not_checked_out() {
+	ExtBuilder::default()
+		.balances(vec![(
+			AccountId::from(ALICE),
+			NATIVE_CURRENCY,
+			1_000 * dollar(NATIVE_CURRENCY),
+		)])
+		.build()
+		.execute_with(|| {
+			mint_nft_to_alice();
+			let asset = XNFT::token_to_asset((0, 0)).unwrap();
+
+			// the relay chain is not a teleport location of the class
+			assert!(execute_teleport_from_relay_chain(asset.clone())
+				.ensure_complete()
+				.is_err());
+
+			assert_ok!(XNFT::set_teleport_location(
+				RuntimeOrigin::root(),
+				0,
+				Box::new(MultiLocation::parent().into_versioned()),
+				true
+			));
+
+			// the token has never been teleported out
+			assert!(execute_teleport_from_relay_chain(asset).ensure_complete().is_err());
+			assert_eq!(
+				orml_nft::Pallet::<Runtime>::tokens(0, 0).unwrap().owner,
+				AccountId::from(ALICE)
+			);
+		});
+}
@@ -59,6 +59,9 @@ pub mod evm {
 pub mod evm_accounts {
 	include!("../../../mandala/src/benchmarking/evm_accounts.rs");
 }
+pub mod flash_mint {
+	include!("../../../mandala/src/benchmarking/flash_mint.rs");
+}
 pub mod homa {
 	include!("../../../mandala/src/benchmarking/homa.rs");
 }
@@ -71,9 +74,15 @@ pub mod idle_scheduler {
 pub mod incentives {
 	include!("../../../mandala/src/benchmarking/incentives.rs");
 }
+pub mod peg_stability {
+	include!("../../../mandala/src/benchmarking/peg_stability.rs");
+}
 pub mod prices {
 	include!("../../../mandala/src/benchmarking/prices.rs");
 }
+pub mod savings {
+	include!("../../../mandala/src/benchmarking/savings.rs");
+}
 pub mod transaction_pause {
 	include!("../../../mandala/src/benchmarking/transaction_pause.rs");
 }
@@ -89,6 +98,9 @@ pub mod nutsfinance_stable_asset {
 pub mod aggregated_dex {
 	include!("../../../mandala/src/benchmarking/aggregated_dex.rs");
 }
+pub mod xcm_rate_limiter {
+	include!("../../../mandala/src/benchmarking/xcm_rate_limiter.rs");
+}
 
 // orml benchmarking
 pub mod auction {
@@ -106,7 +118,7 @@ pub mod tokens {
 pub mod vesting {
 	include!("../../../mandala/src/benchmarking/vesting.rs");
 }
-pub mod honzon_bridge;
+pub mod xcm;
 
 pub fn get_vesting_account() -> super::AccountId {
 	super::KaruraFoundationAccounts::get()[0].clone()
@@ -0,0 +1,149 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Configurations of `pallet_xcm_benchmarks` for the weights of XCM instructions.
+
+use super::utils::inject_liquidity;
+use crate::{
+	xcm_config::{CurrencyIdConvert, LocalAssetTransactor, LocationToAccountId, XcmConfig},
+	AcalaSwap, AccountId, CurrencyId, GetNativeCurrencyId, Runtime, RuntimeCall, KAR, KSM, KUSD,
+};
+use frame_benchmarking::{account, BenchmarkError};
+use frame_support::{parameter_types, traits::Get};
+use module_support::{Swap, SwapLimit};
+use runtime_common::dollar;
+use sp_runtime::traits::Convert;
+use sp_std::prelude::*;
+use xcm::latest::prelude::*;
+
+parameter_types! {
+	pub const CheckedAccount: Option<(AccountId, xcm_builder::MintLocation)> = None;
+	pub const TrustedTeleporter: Option<(MultiLocation, MultiAsset)> = None;
+	pub TrustedReserve: Option<(MultiLocation, MultiAsset)> = Some((
+		MultiLocation::parent(),
+		(MultiLocation::parent(), dollar(KSM)).into(),
+	));
+}
+
+fn currency_location(currency_id: CurrencyId) -> MultiLocation {
+	<CurrencyIdConvert as Convert<CurrencyId, Option<MultiLocation>>>::convert(currency_id).unwrap()
+}
+
+fn native_currency_location() -> MultiLocation {
+	currency_location(GetNativeCurrencyId::get())
+}
+
+impl pallet_xcm_benchmarks::Config for Runtime {
+	type XcmConfig = XcmConfig;
+	type AccountIdConverter = LocationToAccountId;
+
+	fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+		Ok(MultiLocation::parent())
+	}
+
+	fn worst_case_holding(depositable_count: u32) -> MultiAssets {
+		let holding_count = <XcmConfig as xcm_executor::Config>::MaxAssetsIntoHolding::get() - depositable_count;
+		(1..holding_count)
+			.map(|i| MultiAsset {
+				id: Concrete(MultiLocation::new(0, X1(GeneralIndex(i as u128)))),
+				fun: Fungible(i as u128 * 100),
+			})
+			.chain(sp_std::iter::once((native_currency_location(), u128::MAX).into()))
+			.collect::<Vec<_>>()
+			.into()
+	}
+}
+
+impl pallet_xcm_benchmarks::fungible::Config for Runtime {
+	type TransactAsset = LocalAssetTransactor;
+	type CheckedAccount = CheckedAccount;
+	type TrustedTeleporter = TrustedTeleporter;
+	type TrustedReserve = TrustedReserve;
+
+	fn get_multi_asset() -> MultiAsset {
+		(native_currency_location(), dollar(GetNativeCurrencyId::get())).into()
+	}
+}
+
+impl pallet_xcm_benchmarks::generic::Config for Runtime {
+	type RuntimeCall = RuntimeCall;
+
+	fn worst_case_response() -> (u64, Response) {
+		(0u64, Response::Version(Default::default()))
+	}
+
+	fn worst_case_asset_exchange() -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+		// swap KSM for KAR through KUSD, the longest swap path with liquidity
+		let maker: AccountId = account("maker", 0, 0);
+		inject_liquidity(
+			maker.clone(),
+			KSM,
+			KUSD,
+			1_000 * dollar(KSM),
+			100_000 * dollar(KUSD),
+			false,
+		)
+		.map_err(|_| BenchmarkError::Stop("failed to inject liquidity"))?;
+		inject_liquidity(maker, KUSD, KAR, 100_000 * dollar(KUSD), 1_000_000 * dollar(KAR), false)
+			.map_err(|_| BenchmarkError::Stop("failed to inject liquidity"))?;
+
+		// want exactly the amount the swap returns, so the holding ends up equal to it
+		let supply_amount = dollar(KSM);
+		let (_, target_amount) = AcalaSwap::get_swap_amount(KSM, KAR, SwapLimit::ExactSupply(supply_amount, 0))
+			.ok_or(BenchmarkError::Stop("no swap path"))?;
+
+		let give: MultiAssets = (currency_location(KSM), supply_amount).into();
+		let want: MultiAssets = (currency_location(KAR), target_amount).into();
+		Ok((give, want))
+	}
+
+	fn universal_alias() -> Result<(MultiLocation, Junction), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+
+	fn transact_origin_and_runtime_call() -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+		Ok((
+			MultiLocation::parent(),
+			frame_system::Call::remark_with_event { remark: vec![] }.into(),
+		))
+	}
+
+	fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+		Ok(MultiLocation::parent())
+	}
+
+	fn claimable_asset() -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+		let origin = MultiLocation::parent();
+		let assets: MultiAssets = (MultiLocation::parent(), 1_000).into();
+		let ticket = MultiLocation::here();
+		Ok((origin, ticket, assets))
+	}
+
+	fn unlockable_asset() -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+
+	fn export_message_origin_and_destination(
+	) -> Result<(MultiLocation, NetworkId, InteriorMultiLocation), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+
+	fn alias_origin() -> Result<(MultiLocation, MultiLocation), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+}
@@ -31,6 +31,7 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 use parity_scale_codec::{Decode, DecodeLimit, Encode};
+use runtime_common::precompile::KaruraPrecompiles;
 use runtime_common::EnsureRootOrOneTechnicalCommittee;
 use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
@@ -58,7 +59,9 @@ use module_currencies::BasicCurrencyAdapter;
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 use module_relaychain::RelayChainCallBuilder;
-use module_support::{AssetIdMapping, DispatchableTask, ExchangeRateProvider, FractionalRate, PoolId};
+use module_support::{
+	AssetIdMapping, DispatchableTask, ExchangeRateProvider, FractionalRate, PoolId, StableAssetQuote,
+};
 use module_transaction_payment::TargetedFeeAdjustment;
 
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
@@ -111,9 +114,9 @@ use runtime_common::{
 	EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate, ExistentialDepositsTimesOneHundred,
 	FinancialCouncilInstance, FinancialCouncilMembershipInstance, FixedRateOfAsset, GasToWeight,
 	GeneralCouncilInstance, GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
-	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, Price, ProxyType, Rate, Ratio,
-	RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance,
-	TimeStampedPrice, TipPerWeightStep, KAR, KSM, KUSD, LKSM, TAI,
+	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, PausedEvmCallFilter, Price,
+	ProxyType, Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance,
+	TechnicalCommitteeMembershipInstance, TimeStampedPrice, TipPerWeightStep, KAR, KSM, KUSD, LKSM, SKUSD, TAI,
 };
 use xcm::v3::prelude::*;
 
@@ -167,6 +170,7 @@ parameter_types! {
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
+	pub const HonzonPositionPalletId: PalletId = PalletId(*b"aca/hzps");
 	pub const HomaPalletId: PalletId = PalletId(*b"aca/homa");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
@@ -183,6 +187,9 @@ parameter_types! {
 	pub const TransactionPaymentPalletId: PalletId = PalletId(*b"aca/fees");
 	// Ecosystem modules
 	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
+	pub const XcmRateLimiterPalletId: PalletId = PalletId(*b"aca/xrlm");
+	pub const PegStabilityPalletId: PalletId = PalletId(*b"aca/pegs");
+	pub const SavingsPalletId: PalletId = PalletId(*b"aca/savi");
 }
 
 pub fn get_all_module_accounts() -> Vec<AccountId> {
@@ -190,6 +197,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		LoansPalletId::get().into_account_truncating(),
 		CDPEnginePalletId::get().into_account_truncating(),
 		CDPTreasuryPalletId::get().into_account_truncating(),
+		CdpTreasury::insurance_fund_account(),
 		CollatorPotId::get().into_account_truncating(),
 		DEXPalletId::get().into_account_truncating(),
 		HomaPalletId::get().into_account_truncating(),
@@ -201,6 +209,9 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		UnreleasedNativeVaultAccountId::get(),
 		StableAssetPalletId::get().into_account_truncating(),
 		HonzonBridgePalletId::get().into_account_truncating(),
+		XcmRateLimiterPalletId::get().into_account_truncating(),
+		PegStabilityPalletId::get().into_account_truncating(),
+		SavingsPalletId::get().into_account_truncating(),
 	]
 }
 
@@ -222,7 +233,8 @@ impl Contains<RuntimeCall> for BaseCallFilter {
 			return true;
 		}
 
-		let is_paused = module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call);
+		let is_paused = module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
+			|| matches!(call, RuntimeCall::EVM(evm_call) if PausedEvmCallFilter::<Runtime>::contains(evm_call));
 		if is_paused {
 			// no paused call
 			return false;
@@ -750,7 +762,13 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnNewData = ();
+	type OnNewData = runtime_common::auto_pause::OracleDeviationAutoPause<
+		Runtime,
+		AcalaDataProvider,
+		TransactionPause,
+		MaxOracleDeviation,
+		AutoPauseDuration,
+	>;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -789,13 +807,15 @@ parameter_type_with_key! {
 				TokenSymbol::KINT => 13333 * microcent(*currency_id), // 1.33 KINT = 1 KSM
 				TokenSymbol::KBTC => 66 * microcent(*currency_id), // 1KBTC = 150 KSM
 				TokenSymbol::TAI => dollar(*currency_id), // 1 KUSD = 100 TAI
+				TokenSymbol::SKUSD => cent(*currency_id),
 
 				TokenSymbol::ACA |
 				TokenSymbol::AUSD |
 				TokenSymbol::DOT |
 				TokenSymbol::LDOT |
 				TokenSymbol::KAR |
-				TokenSymbol::TAP => Balance::max_value() // unsupported
+				TokenSymbol::TAP |
+				TokenSymbol::SAUSD => Balance::max_value() // unsupported
 			},
 			CurrencyId::DexShare(dex_share_0, _) => {
 				let currency_id_0: CurrencyId = (*dex_share_0).into();
@@ -1008,10 +1028,12 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type KeeperReward = CdpEngine;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1112,9 +1134,12 @@ impl module_cdp_engine::Config for Runtime {
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = Dex;
+	type DEXPriceSource = module_dex_oracle::AverageDEXPriceProvider<Runtime>;
+	type StabilityFeeAdjustmentPeriod = ConstU32<HOURS>;
 	type LiquidationContractsUpdateOrigin = EnsureRootOrHalfGeneralCouncil;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxLiquidationContractFailures = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Runtime>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
@@ -1124,13 +1149,18 @@ impl module_cdp_engine::Config for Runtime {
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = deposit(1, 64);
+	// paid to the keeper who executes the protection rule
+	pub DepositPerProtectionRule: Balance = 10 * dollar(KAR);
 }
 
 impl module_honzon::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type DepositPerProtectionRule = DepositPerProtectionRule;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type PositionPalletId = HonzonPositionPalletId;
+	type Incentives = Incentives;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1138,12 +1168,55 @@ impl module_emergency_shutdown::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
 	type PriceSource = Prices;
+	type LockedPriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
+impl module_peg_stability::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type PalletId = PegStabilityPalletId;
+	type WeightInfo = weights::module_peg_stability::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const GetSavingsCurrencyId: CurrencyId = SKUSD;
+	// about 20% APY
+	pub MaxSavingsRatePerSec: Rate = Rate::saturating_from_rational(6, 1_000_000_000);
+}
+
+impl module_savings::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetSavingsCurrencyId = GetSavingsCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type EmergencyShutdown = EmergencyShutdown;
+	type UnixTime = Timestamp;
+	type MaxSavingsRatePerSec = MaxSavingsRatePerSec;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type PalletId = SavingsPalletId;
+	type WeightInfo = weights::module_savings::WeightInfo<Runtime>;
+}
+
+impl module_flash_mint::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type EmergencyShutdown = EmergencyShutdown;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_flash_mint::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const ExtendedProvisioningBlocks: BlockNumber = 2 * DAYS;
@@ -1161,15 +1234,23 @@ impl module_dex::Config for Runtime {
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
-	type OnLiquidityPoolUpdated = ();
+	type OnLiquidityPoolUpdated = runtime_common::auto_pause::DexPriceMoveAutoPause<
+		Runtime,
+		TransactionPause,
+		MaxDexPriceMove,
+		AutoPauseDuration,
+	>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
+	type StableAssetQuote = StableAssetQuoter;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxRouteCandidates = ConstU32<16>;
+	type MaxRouteSearchNodes = ConstU32<64>;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1206,15 +1287,26 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = Dex;
 	type Swap = AcalaSwap;
 	type MaxAuctionsCount = ConstU32<50>;
+	type DebitAuctionMintPeriod = ConstU32<DAYS>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 	type StableAsset = RebasedStableAsset;
 }
 
+parameter_types! {
+	pub const MaxGuardianPauseDuration: BlockNumber = DAYS;
+	pub const AutoPauseDuration: BlockNumber = 6 * HOURS;
+	pub MaxOracleDeviation: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub MaxDexPriceMove: Ratio = Ratio::saturating_from_rational(20, 100);
+	pub MaxHomaLedgerMismatch: Ratio = Ratio::saturating_from_rational(10, 100);
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type GuardianOrigin = EnsureRootOrOneThirdsTechnicalCommittee;
+	type MaxGuardianPauseDuration = MaxGuardianPauseDuration;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
@@ -1277,12 +1369,19 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub AssetRegistrationDeposit: Balance = 100 * dollar(KAR);
+}
+
 impl module_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type StakingCurrencyId = GetStakingCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type RegistrationDeposit = AssetRegistrationDeposit;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type AssetInUse = runtime_common::AssetInUse<Runtime>;
 	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
 }
 
@@ -1342,6 +1441,11 @@ impl module_xnft::Config for Runtime {
 	type SelfParaId = ParachainInfo;
 	type NtfPalletLocation = xcm_config::NftPalletLocation;
 	type RegisterOrigin = EnsureRootOrOneTechnicalCommittee;
+	type AccountIdToMultiLocation = runtime_common::xcm_config::AccountIdToMultiLocation;
+	type CurrencyIdConvert = xcm_config::CurrencyIdConvert;
+	type UniversalLocation = xcm_config::UniversalLocation;
+	type XcmExecutor = xcm_config::XcmExecutor;
+	type Weigher = xcm_config::XcmWeigher;
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -1455,7 +1559,7 @@ parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
 	pub DeveloperDeposit: Balance = 50 * dollar(KAR);
 	pub PublicationFee: Balance = 10 * dollar(KAR);
-	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, ()> = AllPrecompiles::<_, _, _>::karura();
+	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, KaruraPrecompiles<Runtime>> = AllPrecompiles::<_, _, _>::karura();
 }
 
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
@@ -1496,7 +1600,8 @@ impl module_evm::Config for Runtime {
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = TxFeePerGas;
 	type RuntimeEvent = RuntimeEvent;
-	type PrecompilesType = AllPrecompiles<Self, module_transaction_pause::PausedPrecompileFilter<Self>, ()>;
+	type PrecompilesType =
+		AllPrecompiles<Self, module_transaction_pause::PausedPrecompileFilter<Self>, KaruraPrecompiles<Runtime>>;
 	type PrecompilesValue = PrecompilesValue;
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Runtime>;
@@ -1510,6 +1615,7 @@ impl module_evm::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = module_transaction_pause::PausedPrecompileFilter<Self>;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 }
 
@@ -1572,6 +1678,12 @@ impl module_homa::Config for Runtime {
 	type RedeemThreshold = RedeemThreshold;
 	type RelayChainBlockNumber = RelaychainDataProvider<Runtime>;
 	type XcmInterface = XcmInterface;
+	type OnLedgerBondedReset = runtime_common::auto_pause::HomaLedgerMismatchAutoPause<
+		Runtime,
+		TransactionPause,
+		MaxHomaLedgerMismatch,
+		AutoPauseDuration,
+	>;
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 }
 
@@ -1609,6 +1721,15 @@ impl module_xcm_interface::Config for Runtime {
 	type AccountIdToMultiLocation = runtime_common::xcm_config::AccountIdToMultiLocation;
 }
 
+impl module_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type PalletId = XcmRateLimiterPalletId;
+	type TreasuryAccount = KaruraTreasuryAccount;
+	type WeightInfo = weights::module_xcm_rate_limiter::WeightInfo<Runtime>;
+}
+
 impl orml_unknown_tokens::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
@@ -1642,19 +1763,11 @@ impl module_idle_scheduler::Config for Runtime {
 }
 
 parameter_types! {
-	pub const StableCoinCurrencyId: CurrencyId = KUSD;
+	// The removed `module_honzon_bridge`, superseded by `module_peg_stability`.
+	pub const HonzonBridgePalletName: &'static str = "HonzonBridge";
 	pub HonzonBridgeAccount: AccountId = HonzonBridgePalletId::get().into_account_truncating();
 }
 
-impl module_honzon_bridge::Config for Runtime {
-	type RuntimeEvent = RuntimeEvent;
-	type Currency = Currencies;
-	type StableCoinCurrencyId = StableCoinCurrencyId;
-	type HonzonBridgeAccount = HonzonBridgeAccount;
-	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
-	type WeightInfo = weights::module_honzon_bridge::WeightInfo<Runtime>;
-}
-
 pub struct EnsurePoolAssetId;
 impl nutsfinance_stable_asset::traits::ValidateAssetId<CurrencyId> for EnsurePoolAssetId {
 	fn validate(currency_id: CurrencyId) -> bool {
@@ -1700,6 +1813,13 @@ type RebaseTokens = orml_tokens::Combiner<
 	Currencies,
 >;
 
+parameter_types! {
+	pub StableAssetQuoteAccount: AccountId = StableAssetPalletId::get().into_sub_account_truncating(b"quote");
+}
+
+pub type StableAssetQuoter =
+	module_support::StableAssetQuoter<RebasedStableAsset, RebaseTokens, ConvertBalanceHoma, StableAssetQuoteAccount>;
+
 impl nutsfinance_stable_asset::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = CurrencyId;
@@ -1795,6 +1915,7 @@ construct_runtime!(
 		XTokens: orml_xtokens = 54,
 		UnknownTokens: orml_unknown_tokens = 55,
 		OrmlXcm: orml_xcm = 56,
+		XcmRateLimiter: module_xcm_rate_limiter = 57,
 
 		// Governance
 		Authority: orml_authority = 60,
@@ -1834,7 +1955,9 @@ construct_runtime!(
 		CdpTreasury: module_cdp_treasury = 103,
 		CdpEngine: module_cdp_engine = 104,
 		EmergencyShutdown: module_emergency_shutdown = 105,
-		HonzonBridge: module_honzon_bridge = 106,
+		PegStability: module_peg_stability = 107,
+		Savings: module_savings = 108,
+		FlashMint: module_flash_mint = 109,
 
 		// Homa
 		Homa: module_homa = 116,
@@ -1892,9 +2015,25 @@ pub type UncheckedExtrinsic =
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+
+/// All migrations that will run on the next runtime upgrade.
+pub type Migrations = (
+	module_nft::migrations::MigrateClassDataRoyalty<Runtime>,
+	module_incentives::migrations::StakeExistingLpCollateral<Runtime>,
+	module_dex::migrations::CountActiveTradingPairs<Runtime>,
+	module_peg_stability::migrations::MigrateHonzonBridgeReserve<Runtime, HonzonBridgeAccount>,
+	frame_support::migrations::RemovePallet<HonzonBridgePalletName, <Runtime as frame_system::Config>::DbWeight>,
+);
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 pub struct MigrateSetXcmVersionForKusama;
 impl OnRuntimeUpgrade for MigrateSetXcmVersionForKusama {
@@ -1929,11 +2068,13 @@ mod benches {
 		[module_transaction_pause, benchmarking::transaction_pause]
 		[module_transaction_payment, benchmarking::transaction_payment]
 		[module_incentives, benchmarking::incentives]
+		[module_peg_stability, benchmarking::peg_stability]
 		[module_prices, benchmarking::prices]
+		[module_savings, benchmarking::savings]
 		[module_evm_accounts, benchmarking::evm_accounts]
+		[module_flash_mint, benchmarking::flash_mint]
 		[module_currencies, benchmarking::currencies]
 		[module_session_manager, benchmarking::session_manager]
-		[module_honzon_bridge, benchmarking::honzon_bridge]
 		[orml_tokens, benchmarking::tokens]
 		[orml_vesting, benchmarking::vesting]
 		[orml_auction, benchmarking::auction]
@@ -1942,6 +2083,7 @@ mod benches {
 		[nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset]
 		[module_idle_scheduler, benchmarking::idle_scheduler]
 		[module_aggregated_dex, benchmarking::aggregated_dex]
+		[module_xcm_rate_limiter, benchmarking::xcm_rate_limiter]
 	);
 }
 
@@ -2104,6 +2246,102 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_cdp_treasury_runtime_api::CdpTreasuryApi<
+		Block,
+		CurrencyId,
+		Balance,
+		module_cdp_treasury::LossRecord,
+	> for Runtime {
+		fn surplus_pool() -> Balance {
+			CdpTreasury::surplus_pool()
+		}
+
+		fn debit_pool() -> Balance {
+			CdpTreasury::debit_pool()
+		}
+
+		fn insurance_fund(currency_id: CurrencyId) -> Balance {
+			CdpTreasury::insurance_funds(currency_id)
+		}
+
+		fn loss_record(currency_id: CurrencyId) -> module_cdp_treasury::LossRecord {
+			CdpTreasury::loss_records(currency_id)
+		}
+	}
+
+	impl module_xcm_rate_limiter_runtime_api::XcmRateLimiterApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn remaining_inflow_quota(currency_id: CurrencyId) -> Option<Balance> {
+			XcmRateLimiter::remaining_quota(currency_id, module_xcm_rate_limiter::FlowDirection::Inflow)
+		}
+
+		fn remaining_outflow_quota(currency_id: CurrencyId) -> Option<Balance> {
+			XcmRateLimiter::remaining_quota(currency_id, module_xcm_rate_limiter::FlowDirection::Outflow)
+		}
+	}
+
+	impl module_stable_asset_runtime_api::StableAssetApi<
+		Block,
+		CurrencyId,
+		Balance,
+		AccountId,
+		BlockNumber,
+	> for Runtime {
+		fn pool_info(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+		) -> Option<nutsfinance_stable_asset::StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>> {
+			StableAssetQuoter::pool_info(pool_id)
+		}
+
+		fn get_virtual_price(pool_id: nutsfinance_stable_asset::StableAssetPoolId) -> Option<Ratio> {
+			StableAssetQuoter::get_virtual_price(pool_id)
+		}
+
+		fn get_swap_output_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			input_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+			input_amount: Balance,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_swap_output_amount(pool_id, input_index, output_index, input_amount)
+		}
+
+		fn get_swap_input_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			input_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_amount: Balance,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_swap_input_amount(pool_id, input_index, output_index, output_amount)
+		}
+
+		fn get_mint_amount(pool_id: nutsfinance_stable_asset::StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+			StableAssetQuoter::get_mint_amount(pool_id, amounts)
+		}
+
+		fn get_redeem_proportion_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			amount: Balance,
+		) -> Option<Vec<Balance>> {
+			StableAssetQuoter::get_redeem_proportion_amount(pool_id, amount)
+		}
+
+		fn get_redeem_single_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			amount: Balance,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_redeem_single_amount(pool_id, amount, output_index)
+		}
+
+		fn get_redeem_multi_amount(pool_id: nutsfinance_stable_asset::StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+			StableAssetQuoter::get_redeem_multi_amount(pool_id, amounts)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
@@ -2234,10 +2472,14 @@ impl_runtime_apis! {
 			use frame_benchmarking::{list_benchmark as frame_list_benchmark, Benchmarking, BenchmarkList};
 			use frame_support::traits::StorageInfoTrait;
 			use module_nft::benchmarking::Pallet as NftBench;
+			use pallet_xcm_benchmarks::fungible::Pallet as XcmFungibleBench;
+			use pallet_xcm_benchmarks::generic::Pallet as XcmGenericBench;
 
 			let mut list = Vec::<BenchmarkList>::new();
 
 			frame_list_benchmark!(list, extra, module_nft, NftBench::<Runtime>);
+			frame_list_benchmark!(list, extra, pallet_xcm_benchmarks::fungible, XcmFungibleBench::<Runtime>);
+			frame_list_benchmark!(list, extra, pallet_xcm_benchmarks::generic, XcmGenericBench::<Runtime>);
 			list_benchmarks!(list, extra);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
@@ -2250,6 +2492,8 @@ impl_runtime_apis! {
 		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
 			use frame_benchmarking::{Benchmarking, BenchmarkBatch, add_benchmark as frame_add_benchmark};
 			use module_nft::benchmarking::Pallet as NftBench;
+			use pallet_xcm_benchmarks::fungible::Pallet as XcmFungibleBench;
+			use pallet_xcm_benchmarks::generic::Pallet as XcmGenericBench;
 			use frame_support::traits::{WhitelistedStorageKeys, TrackedStorageKey};
 
 			let mut whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
@@ -2264,6 +2508,8 @@ impl_runtime_apis! {
 			let params = (&config, &whitelist);
 
 			frame_add_benchmark!(params, batches, module_nft, NftBench::<Runtime>);
+			frame_add_benchmark!(params, batches, pallet_xcm_benchmarks::fungible, XcmFungibleBench::<Runtime>);
+			frame_add_benchmark!(params, batches, pallet_xcm_benchmarks::generic, XcmGenericBench::<Runtime>);
 			add_benchmarks!(params, batches);
 
 			if batches.is_empty() { return Err("Benchmark not found for this module.".into()) }
@@ -31,15 +31,18 @@ pub mod module_dex_oracle;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_flash_mint;
 pub mod module_homa;
 pub mod module_honzon;
-pub mod module_honzon_bridge;
 pub mod module_incentives;
 pub mod module_nft;
+pub mod module_peg_stability;
 pub mod module_prices;
+pub mod module_savings;
 pub mod module_session_manager;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
+pub mod module_xcm_rate_limiter;
 
 pub mod orml_auction;
 pub mod orml_authority;
@@ -1,80 +0,0 @@
-// This file is part of Acala.
-
-// Copyright (C) 2020-2023 Acala Foundation.
-// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
-
-// This program is free software: you can redistribute it and/or modify
-// it under the terms of the GNU General Public License as published by
-// the Free Software Foundation, either version 3 of the License, or
-// (at your option) any later version.
-
-// This program is distributed in the hope that it will be useful,
-// but WITHOUT ANY WARRANTY; without even the implied warranty of
-// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
-// GNU General Public License for more details.
-
-// You should have received a copy of the GNU General Public License
-// along with this program. If not, see <https://www.gnu.org/licenses/>.
-
-//! Autogenerated weights for module_honzon_bridge
-//!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-10-05, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `ip-172-31-37-73`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
-//! WASM-EXECUTION: Compiled, CHAIN: Some("karura-dev"), DB CACHE: 1024
-
-// Executed Command:
-// target/production/acala
-// benchmark
-// pallet
-// --chain=karura-dev
-// --steps=50
-// --repeat=20
-// --pallet=*
-// --extrinsic=*
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --template=./templates/runtime-weight-template.hbs
-// --output=./runtime/karura/src/weights/
-
-#![cfg_attr(rustfmt, rustfmt_skip)]
-#![allow(unused_parens)]
-#![allow(unused_imports)]
-
-use frame_support::{traits::Get, weights::Weight};
-use sp_std::marker::PhantomData;
-
-/// Weight functions for module_honzon_bridge.
-pub struct WeightInfo<T>(PhantomData<T>);
-impl<T: frame_system::Config> module_honzon_bridge::WeightInfo for WeightInfo<T> {
-	// Storage: `HonzonBridge::BridgedStableCoinCurrencyId` (r:0 w:1)
-	// Proof: `HonzonBridge::BridgedStableCoinCurrencyId` (`max_values`: Some(1), `max_size`: Some(43), added: 538, mode: `MaxEncodedLen`)
-	fn set_bridged_stable_coin_address() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `965`
-		//  Estimated: `0`
-		// Minimum execution time: 15_218 nanoseconds.
-		Weight::from_parts(15_672_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1))
-	}
-	// Storage: `HonzonBridge::BridgedStableCoinCurrencyId` (r:1 w:0)
-	// Proof: `HonzonBridge::BridgedStableCoinCurrencyId` (`max_values`: Some(1), `max_size`: Some(43), added: 538, mode: `MaxEncodedLen`)
-	fn to_bridged() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1119`
-		//  Estimated: `1528`
-		// Minimum execution time: 18_193 nanoseconds.
-		Weight::from_parts(18_542_000, 1528)
-			.saturating_add(T::DbWeight::get().reads(1))
-	}
-	// Storage: `HonzonBridge::BridgedStableCoinCurrencyId` (r:1 w:0)
-	// Proof: `HonzonBridge::BridgedStableCoinCurrencyId` (`max_values`: Some(1), `max_size`: Some(43), added: 538, mode: `MaxEncodedLen`)
-	fn from_bridged() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1119`
-		//  Estimated: `1528`
-		// Minimum execution time: 18_109 nanoseconds.
-		Weight::from_parts(18_543_000, 1528)
-			.saturating_add(T::DbWeight::get().reads(1))
-	}
-}
@@ -18,28 +18,32 @@
 
 use super::{
 	constants::{fee::*, parachains},
-	AccountId, AllPalletsWithSystem, AssetIdMapping, AssetIdMaps, Balance, Balances, Convert, Currencies, CurrencyId,
-	EvmAddressMapping, ExistentialDeposits, FixedRateOfAsset, GetNativeCurrencyId, KaruraTreasuryAccount,
-	NativeTokenExistentialDeposit, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeOrigin, UnknownTokens, XcmInterface, XcmpQueue, KAR, KUSD, LKSM, TAI, XNFT,
+	AcalaSwap, AccountId, AllPalletsWithSystem, AssetIdMapping, AssetIdMaps, Balance, Balances, Convert, Currencies,
+	CurrencyId, EvmAddressMapping, ExistentialDeposits, FixedRateOfAsset, GetNativeCurrencyId, KaruraTreasuryAccount,
+	NativeTokenExistentialDeposit, ParachainInfo, ParachainSystem, PolkadotXcm, Ratio, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, UnknownTokens, XcmInterface, XcmpQueue, KAR, KUSD, LKSM, TAI, XNFT,
 };
 use cumulus_primitives_core::ParaId;
 use frame_support::{
 	parameter_types,
 	traits::{ConstU32, Everything, Get, Nothing},
 };
-use module_asset_registry::{BuyWeightRateOfErc20, BuyWeightRateOfForeignAsset, BuyWeightRateOfStableAsset};
+use module_asset_registry::{
+	BuyWeightRateOfDexPrice, BuyWeightRateOfErc20, BuyWeightRateOfForeignAsset, BuyWeightRateOfStableAsset,
+};
 use module_support::HomaSubAccountXcm;
 use module_transaction_payment::BuyWeightRateOfTransactionFeePool;
+use module_xcm_rate_limiter::RateLimitedTransactor;
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
 use parity_scale_codec::{Decode, Encode};
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use primitives::evm::is_system_contract;
 use runtime_common::{
-	local_currency_location, native_currency_location, AcalaDropAssets, EnsureRootOrHalfGeneralCouncil,
-	EnsureRootOrThreeFourthsGeneralCouncil,
+	local_currency_location, native_currency_location, AcalaDropAssets, DexAssetExchange,
+	EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil,
 };
+use sp_runtime::FixedPointNumber;
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 use xcm_builder::{EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, SignedToAccountId32};
 
@@ -65,6 +69,10 @@ pub type Barrier = runtime_common::xcm_config::Barrier<PolkadotXcm, UniversalLoc
 
 pub type ToTreasury = runtime_common::xcm_config::ToTreasury<CurrencyIdConvert, KaruraTreasuryAccount, Currencies>;
 
+// Karura is weighed with a fixed cost per instruction until `make benchmark-xcm-karura` has
+// produced `pallet_xcm_benchmarks` weights measured on Karura.
+pub type XcmWeigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+
 parameter_types! {
 	// One XCM operation is 200_000_000 weight, cross-chain transfer ~= 2x of transfer.
 	pub const UnitWeightCost: XcmWeight = XcmWeight::from_parts(200_000_000, 0);
@@ -132,6 +140,7 @@ parameter_types! {
 	);
 
 	pub BaseRate: u128 = kar_per_second();
+	pub XcmFeeSafetyMargin: Ratio = Ratio::saturating_from_rational(10, 100);
 }
 
 pub type Trader = (
@@ -149,6 +158,17 @@ pub type Trader = (
 	FixedRateOfFungible<LksmPerSecond, ToTreasury>,
 	FixedRateOfFungible<BncPerSecond, ToTreasury>,
 	FixedRateOfFungible<TaiPerSecond, ToTreasury>,
+	FixedRateOfAsset<
+		BaseRate,
+		ToTreasury,
+		BuyWeightRateOfDexPrice<
+			Runtime,
+			CurrencyIdConvert,
+			module_dex_oracle::AverageDEXPriceProvider<Runtime>,
+			GetNativeCurrencyId,
+			XcmFeeSafetyMargin,
+		>,
+	>,
 );
 
 pub struct XcmConfig;
@@ -159,11 +179,11 @@ impl xcm_executor::Config for XcmConfig {
 	type AssetTransactor = LocalAssetTransactor;
 	type OriginConverter = XcmOriginToCallOrigin;
 	type IsReserve = MultiNativeAsset<AbsoluteReserveProvider>;
-	// Teleporting is disabled.
-	type IsTeleporter = ();
+	// Only NFTs can be teleported, to and from the locations trusted for their class.
+	type IsTeleporter = module_xnft::NftTeleporters<Runtime>;
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type Trader = Trader;
 	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = AcalaDropAssets<
@@ -175,7 +195,7 @@ impl xcm_executor::Config for XcmConfig {
 		ExistentialDeposits,
 	>;
 	type AssetLocker = ();
-	type AssetExchanger = ();
+	type AssetExchanger = DexAssetExchange<AccountId, KaruraTreasuryAccount, CurrencyIdConvert, Currencies, AcalaSwap>;
 	type AssetClaims = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
@@ -221,7 +241,7 @@ impl pallet_xcm::Config for Runtime {
 	type XcmExecutor = XcmExecutor;
 	type XcmTeleportFilter = Nothing;
 	type XcmReserveTransferFilter = Everything;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type UniversalLocation = UniversalLocation;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
@@ -290,7 +310,7 @@ impl orml_xtokens::Config for Runtime {
 	type AccountIdToMultiLocation = runtime_common::xcm_config::AccountIdToMultiLocation;
 	type SelfLocation = SelfLocation;
 	type XcmExecutor = XcmExecutor;
-	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type BaseXcmWeight = BaseXcmWeight;
 	type UniversalLocation = UniversalLocation;
 	type MaxAssetsForTransfer = MaxAssetsForTransfer;
@@ -301,15 +321,20 @@ impl orml_xtokens::Config for Runtime {
 
 pub type LocalAssetTransactor = (
 	XNFT,
-	MultiCurrencyAdapter<
-		Currencies,
-		UnknownTokens,
-		IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-		AccountId,
-		LocationToAccountId,
-		CurrencyId,
+	RateLimitedTransactor<
+		Runtime,
+		MultiCurrencyAdapter<
+			Currencies,
+			UnknownTokens,
+			IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+			AccountId,
+			LocationToAccountId,
+			CurrencyId,
+			CurrencyIdConvert,
+			DepositToAlternative<KaruraTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+		>,
 		CurrencyIdConvert,
-		DepositToAlternative<KaruraTreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+		LocationToAccountId,
 	>,
 );
 
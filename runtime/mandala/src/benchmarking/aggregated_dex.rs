@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{dollar, inject_liquidity, set_balance, LIQUID, NATIVE, STABLECOIN, STAKING};
-use crate::{AccountId, CurrencyId, Runtime};
+use crate::{AccountId, CurrencyId, Runtime, StableAsset};
 use module_aggregated_dex::SwapPath;
 use runtime_common::{BNC, VSKSM};
 
@@ -89,6 +89,39 @@ runtime_benchmarks! {
 			);
 		}
 	}: _(RawOrigin::Root, updates)
+
+	route_swap {
+		let n in 1 .. <Runtime as module_aggregated_dex::Config>::MaxRouteSearchNodes::get();
+
+		let maker: AccountId = account("maker", 0, 0);
+		let taker: AccountId = whitelisted_caller();
+
+		// the supply can be routed directly and through the joints of DexSwapJointList.
+		inject_liquidity(maker.clone(), NATIVE, STAKING, 10_000 * dollar(NATIVE), 10_000 * dollar(STAKING), false)?;
+		for joint in [STABLECOIN, LIQUID] {
+			inject_liquidity(maker.clone(), NATIVE, joint, 10_000 * dollar(NATIVE), 10_000 * dollar(joint), false)?;
+			inject_liquidity(maker.clone(), joint, STAKING, 10_000 * dollar(joint), 10_000 * dollar(STAKING), false)?;
+		}
+
+		// the router looks up every Taiga pool.
+		for i in 0 .. n {
+			StableAsset::create_pool(
+				RawOrigin::Root.into(),
+				CurrencyId::StableAssetPoolToken(i),
+				vec![STAKING, LIQUID],
+				vec![1u128, 1u128],
+				0u128,
+				0u128,
+				0u128,
+				1_000u128,
+				maker.clone(),
+				maker.clone(),
+				1_000_000_000_000_000_000u128,
+			)?;
+		}
+
+		set_balance(NATIVE, &taker, 10_000 * dollar(NATIVE));
+	}: swap_with_best_route(RawOrigin::Signed(taker), NATIVE, STAKING, 100 * dollar(NATIVE), 0)
 }
 
 #[cfg(test)]
@@ -19,12 +19,13 @@
 use crate::{AccountId, AssetRegistry, CurrencyId, Runtime, RuntimeOrigin, EVM};
 
 use super::utils::{dollar, set_balance, NATIVE};
+use frame_benchmarking::whitelisted_caller;
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
 use module_evm::EvmAddress;
 use module_support::AddressMapping;
 use orml_benchmarking::runtime_benchmarks;
-use primitives::currency::AssetMetadata;
+use primitives::currency::{AssetIds, AssetMetadata};
 use sp_std::{boxed::Box, str::FromStr, vec};
 use xcm::{v3::MultiLocation, VersionedMultiLocation};
 
@@ -145,6 +146,78 @@ runtime_benchmarks! {
 
 		AssetRegistry::register_native_asset(RawOrigin::Root.into(), currency_id, Box::new(asset_metadata.clone()))?;
 	}: _(RawOrigin::Root, currency_id, Box::new(asset_metadata))
+
+	set_dex_priced_fee_asset {}: _(RawOrigin::Root, CurrencyId::LiquidCrowdloan(0), true)
+
+	register_foreign_asset_with_deposit {
+		let caller: AccountId = whitelisted_caller();
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller), Box::new(location), Box::new(asset_metadata))
+
+	register_erc20_asset_with_deposit {
+		deploy_contract();
+	}: _(RawOrigin::Signed(alice()), erc20_address(), 1)
+
+	verify_asset {
+		let caller: AccountId = whitelisted_caller();
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+
+		AssetRegistry::register_foreign_asset_with_deposit(RawOrigin::Signed(caller.clone()).into(), Box::new(location), Box::new(asset_metadata))?;
+	}: _(RawOrigin::Root, AssetIds::ForeignAssetId(0))
+
+	reject_asset {
+		let caller: AccountId = whitelisted_caller();
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+
+		AssetRegistry::register_foreign_asset_with_deposit(RawOrigin::Signed(caller.clone()).into(), Box::new(location), Box::new(asset_metadata))?;
+	}: _(RawOrigin::Root, AssetIds::ForeignAssetId(0))
+
+	deregister_asset {
+		let caller: AccountId = whitelisted_caller();
+		let location = VersionedMultiLocation::V3(MultiLocation {
+			parents: 0,
+			interior: xcm::v3::Junctions::X1(xcm::v3::Junction::Parachain(1000)),
+		});
+		let asset_metadata = AssetMetadata {
+			name: b"Token Name".to_vec(),
+			symbol: b"TN".to_vec(),
+			decimals: 12,
+			minimal_balance: 1,
+		};
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+
+		AssetRegistry::register_foreign_asset_with_deposit(RawOrigin::Signed(caller.clone()).into(), Box::new(location), Box::new(asset_metadata))?;
+	}: _(RawOrigin::Signed(caller), AssetIds::ForeignAssetId(0))
 }
 
 #[cfg(test)]
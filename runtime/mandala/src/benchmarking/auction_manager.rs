@@ -16,14 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, AuctionId, AuctionManager, CdpTreasury, Currencies, EmergencyShutdown, Price, Runtime};
+use crate::{
+	AccountId, AuctionId, AuctionManager, CdpEngine, CdpTreasury, Currencies, EmergencyShutdown, Price, Rate, Ratio,
+	Runtime,
+};
 
 use super::utils::{dollar, feed_price, STABLECOIN, STAKING};
-use frame_benchmarking::account;
+use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
+use module_cdp_engine::KeeperRewardParams;
 use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury};
 use orml_benchmarking::runtime_benchmarks;
-use orml_traits::MultiCurrency;
+use orml_traits::{Change, MultiCurrency};
 use sp_runtime::FixedPointNumber;
 use sp_std::vec;
 
@@ -56,6 +60,52 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: cancel(RawOrigin::None, auction_id)
+
+	// `cancel_by_keeper` a collateral auction, worst case:
+	// auction have been already bid and the keeper is rewarded
+	cancel_by_keeper {
+		let keeper: AccountId = whitelisted_caller();
+		let bidder: AccountId = account("bidder", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &bidder, 80 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// set keeper reward
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NoChange,
+			Change::NewValue(100_000 * dollar(STABLECOIN)),
+		)?;
+		CdpEngine::set_liquidation_keeper_reward(
+			RawOrigin::Root.into(),
+			STAKING,
+			Some(KeeperRewardParams {
+				penalty_share: Ratio::saturating_from_rational(50, 100),
+				max_reward: 100 * dollar(STABLECOIN),
+				cancellation_reward: 10 * dollar(STABLECOIN),
+			}),
+		)?;
+
+		// create collateral auction
+		AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+
+		// bid collateral auction
+		AuctionManager::collateral_auction_bid_handler(1, auction_id, (bidder, 80 * dollar(STABLECOIN)), None)?;
+
+		// shutdown
+		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
+	}: _(RawOrigin::Signed(keeper), auction_id)
 }
 
 #[cfg(test)]
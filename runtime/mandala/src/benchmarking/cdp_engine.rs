@@ -29,20 +29,32 @@ use super::{
 		STAKING,
 	},
 };
-use frame_benchmarking::account;
+use frame_benchmarking::{account, whitelisted_caller};
 use frame_support::traits::{Get, OnInitialize};
 use frame_system::RawOrigin;
-use module_support::DEXManager;
+use module_cdp_engine::{ContractLiquidationStats, KeeperRewardParams, PegReference, StabilityFeeControllerParams};
+use module_support::{CDPTreasury, DEXManager, ExchangeRate};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
 use sp_runtime::{
-	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto},
+	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto, Zero},
 	FixedPointNumber,
 };
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+fn stability_fee_controller_params() -> StabilityFeeControllerParams {
+	StabilityFeeControllerParams {
+		min_interest_rate_per_sec: Rate::zero(),
+		max_interest_rate_per_sec: Rate::saturating_from_rational(1, 100000),
+		max_rate_change: Rate::saturating_from_rational(1, 1000000),
+		peg_sensitivity: Rate::saturating_from_rational(1, 10000),
+		target_utilization: Ratio::saturating_from_rational(80, 100),
+		utilization_sensitivity: Rate::saturating_from_rational(1, 10000),
+	}
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_cdp_engine }
 
@@ -254,12 +266,159 @@ runtime_benchmarks! {
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: _(RawOrigin::None, STAKING, owner_lookup)
 
+	adjust_stability_fees {
+		let c in 0 .. get_benchmarking_collateral_currency_ids().len() as u32;
+		let currency_ids = get_benchmarking_collateral_currency_ids();
+
+		CdpEngine::set_stablecoin_peg_reference(
+			RawOrigin::Root.into(),
+			Some(PegReference {
+				currency_id: STAKING,
+				target_price: ExchangeRate::one(),
+			}),
+		)?;
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_id,
+				Change::NewValue(Some(Rate::saturating_from_rational(1, 1000000))),
+				Change::NoChange,
+				Change::NoChange,
+				Change::NoChange,
+				Change::NewValue(100_000 * dollar(STABLECOIN)),
+			)?;
+			CdpEngine::set_stability_fee_controller(
+				RawOrigin::Root.into(),
+				currency_id,
+				Some(stability_fee_controller_params()),
+			)?;
+		}
+	}: {
+		CdpEngine::adjust_stability_fees();
+	}
+
+	set_stability_fee_controller {
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 1000000))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(RawOrigin::Root, STAKING, Some(stability_fee_controller_params()))
+
+	set_stablecoin_peg_reference {
+	}: _(
+		RawOrigin::Root,
+		Some(PegReference {
+			currency_id: STAKING,
+			target_price: ExchangeRate::one(),
+		})
+	)
+
+	set_liquidation_keeper_reward {
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NoChange,
+			Change::NewValue(100_000 * dollar(STABLECOIN)),
+		)?;
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		Some(KeeperRewardParams {
+			penalty_share: Ratio::saturating_from_rational(50, 100),
+			max_reward: 100 * dollar(STABLECOIN),
+			cancellation_reward: 10 * dollar(STABLECOIN),
+		})
+	)
+
+	liquidate_by_keeper {
+		let b in 1 .. <Runtime as module_cdp_treasury::Config>::MaxAuctionsCount::get();
+
+		let keeper: AccountId = whitelisted_caller();
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let min_debit_value = MinimumDebitValue::get();
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let collateral_price = Price::one();		// 1 USD
+		let min_debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(min_debit_value);
+		let min_debit_amount: Amount = min_debit_amount.unique_saturated_into();
+		let collateral_value = 2 * min_debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance
+		set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+		set_balance(NATIVE, &keeper, 10 * dollar(NATIVE));
+
+		// feed price
+		feed_price(vec![(STAKING, collateral_price)])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(min_debit_value * 100),
+		)?;
+		CdpEngine::set_liquidation_keeper_reward(
+			RawOrigin::Root.into(),
+			STAKING,
+			Some(KeeperRewardParams {
+				penalty_share: Ratio::saturating_from_rational(50, 100),
+				max_reward: 100 * dollar(STABLECOIN),
+				cancellation_reward: 10 * dollar(STABLECOIN),
+			}),
+		)?;
+		CdpTreasury::on_system_surplus(100 * dollar(STABLECOIN))?;
+
+		let auction_size = collateral_amount / b as u128;
+		// adjust auction size so we hit MaxAuctionCount
+		CdpTreasury::set_expected_collateral_auction_size(RawOrigin::Root.into(), STAKING, auction_size)?;
+		// adjust position
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), min_debit_amount)?;
+
+		// modify liquidation rate to make the cdp unsafe
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		)?;
+	}: _(RawOrigin::Signed(keeper), STAKING, owner_lookup)
+
 	register_liquidation_contract {
 	}: _(RawOrigin::Root, H160::default())
 
 	deregister_liquidation_contract {
 		CdpEngine::register_liquidation_contract(RawOrigin::Root.into(), H160::default())?;
 	}: _(RawOrigin::Root, H160::default())
+
+	// `set_liquidation_contract_exposure_cap`, worst case:
+	// the exposure of the contract is reset
+	set_liquidation_contract_exposure_cap {
+		module_cdp_engine::LiquidationContractStats::<Runtime>::insert(
+			H160::default(),
+			ContractLiquidationStats {
+				succeeded: 1,
+				total_repayment: dollar(STABLECOIN) * 1_000,
+				exposure: dollar(STABLECOIN) * 1_000,
+				..Default::default()
+			},
+		);
+	}: _(RawOrigin::Root, H160::default(), Some(dollar(STABLECOIN) * 10_000))
 }
 
 #[cfg(test)]
@@ -16,15 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, CdpTreasury, Currencies, Runtime};
+use crate::{AccountId, CdpTreasury, Currencies, Ratio, Runtime, System};
 
-use super::utils::{dollar, initialize_swap_pools, set_balance, STABLECOIN, STAKING};
+use super::utils::{dollar, initialize_swap_pools, set_balance, NATIVE, STABLECOIN, STAKING};
 use frame_benchmarking::whitelisted_caller;
-use frame_support::traits::Get;
+use frame_support::traits::{Get, OnInitialize};
 use frame_system::RawOrigin;
-use module_support::{CDPTreasury, SwapLimit};
+use module_cdp_treasury::DebitAuctionParams;
+use module_support::{CDPTreasury, CDPTreasuryExtended, SwapLimit};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::FixedPointNumber;
 
 runtime_benchmarks! {
 	{ Runtime, module_cdp_treasury }
@@ -36,6 +38,18 @@ runtime_benchmarks! {
 		CdpTreasury::set_expected_collateral_auction_size(RawOrigin::Root.into(), STAKING, auction_size)?;
 
 		Currencies::deposit(STAKING, &CdpTreasury::account_id(), 10_000 * dollar(STAKING))?;
+
+		// re-auction an aborted liquidation, the debit beyond the target is covered as the loss
+		CdpTreasury::on_system_surplus(200 * dollar(STABLECOIN))?;
+		CdpTreasury::deposit_insurance_fund(RawOrigin::Root.into(), STAKING, 100 * dollar(STABLECOIN))?;
+		CdpTreasury::on_system_debit(1_200 * dollar(STABLECOIN))?;
+		CdpTreasury::on_liquidation_auction_aborted(
+			STAKING,
+			STAKING,
+			1_000 * dollar(STAKING),
+			1_300 * dollar(STABLECOIN),
+			100 * dollar(STABLECOIN),
+		);
 	}: _(RawOrigin::Root, STAKING, 1_000 * dollar(STAKING), 1_000 * dollar(STABLECOIN), true)
 
 	exchange_collateral_to_stable {
@@ -53,6 +67,38 @@ runtime_benchmarks! {
 	extract_surplus_to_treasury {
 		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
+
+	set_debit_auction_params {
+	}: _(RawOrigin::Root, DebitAuctionParams {
+		fixed_size: 1_000 * dollar(STABLECOIN),
+		initial_amount: 10_000 * dollar(NATIVE),
+		mint_cap_per_period: 1_000_000 * dollar(NATIVE),
+	})
+
+	on_initialize {
+		let c in 0 .. <Runtime as module_cdp_treasury::Config>::MaxAuctionsCount::get();
+
+		CdpTreasury::set_debit_auction_params(RawOrigin::Root.into(), DebitAuctionParams {
+			fixed_size: 1_000 * dollar(STABLECOIN),
+			initial_amount: 10_000 * dollar(NATIVE),
+			mint_cap_per_period: 1_000_000 * dollar(NATIVE),
+		})?;
+		CdpTreasury::on_system_debit(c as u128 * 1_000 * dollar(STABLECOIN))?;
+	}: {
+		CdpTreasury::on_initialize(System::block_number());
+	}
+
+	set_insurance_fund_share {
+	}: _(RawOrigin::Root, STAKING, Ratio::saturating_from_rational(1, 10))
+
+	deposit_insurance_fund {
+		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Root, STAKING, 200 * dollar(STABLECOIN))
+
+	withdraw_insurance_fund {
+		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
+		CdpTreasury::deposit_insurance_fund(RawOrigin::Root.into(), STAKING, 200 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Root, STAKING, 100 * dollar(STABLECOIN))
 }
 
 #[cfg(test)]
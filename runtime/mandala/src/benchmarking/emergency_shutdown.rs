@@ -16,21 +16,35 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, CdpTreasury, CurrencyId, EmergencyShutdown, Price, Runtime};
+use crate::{AccountId, CdpEngine, CdpTreasury, CurrencyId, EmergencyShutdown, Loans, Price, Rate, Ratio, Runtime};
 
 use super::{
 	get_benchmarking_collateral_currency_ids,
-	utils::{dollar, feed_price, set_balance, STABLECOIN},
+	utils::{dollar, feed_price, set_balance, STABLECOIN, STAKING},
 };
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
 use module_support::CDPTreasury;
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
+use orml_traits::Change;
+use sp_runtime::{traits::One, DispatchResult, FixedPointNumber};
 use sp_std::vec;
 
 const SEED: u32 = 0;
 
+fn enable_collateral(currency_id: CurrencyId) -> DispatchResult {
+	feed_price(vec![(currency_id, Price::one())])?;
+	CdpEngine::set_collateral_params(
+		RawOrigin::Root.into(),
+		currency_id,
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 1000000))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(180, 100))),
+		Change::NewValue(100_000 * dollar(STABLECOIN)),
+	)
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_emergency_shutdown }
 
@@ -73,6 +87,29 @@ runtime_benchmarks! {
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 		EmergencyShutdown::open_collateral_refund(RawOrigin::Root.into())?;
 	}: _(RawOrigin::Signed(caller),  1_000 * dollar(STABLECOIN))
+
+	shutdown_collateral {
+		enable_collateral(STAKING)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	open_collateral_pool_refund {
+		enable_collateral(STAKING)?;
+		EmergencyShutdown::shutdown_collateral(RawOrigin::Root.into(), STAKING)?;
+	}: _(RawOrigin::Root, STAKING)
+
+	refund_collateral {
+		let funder: AccountId = account("funder", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+
+		enable_collateral(STAKING)?;
+		set_balance(STAKING, &funder, 100 * dollar(STAKING));
+		Loans::adjust_position(&funder, STAKING, 100 * dollar(STAKING) as i128, 1000 * dollar(STABLECOIN) as i128)?;
+		CdpTreasury::issue_debit(&caller, 10 * dollar(STABLECOIN), true)?;
+
+		EmergencyShutdown::shutdown_collateral(RawOrigin::Root.into(), STAKING)?;
+		Loans::confiscate_collateral_and_debit(&funder, STAKING, 100 * dollar(STAKING), 1000 * dollar(STABLECOIN))?;
+		EmergencyShutdown::open_collateral_pool_refund(RawOrigin::Root.into(), STAKING)?;
+	}: _(RawOrigin::Signed(caller), STAKING, 10 * dollar(STABLECOIN))
 }
 
 #[cfg(test)]
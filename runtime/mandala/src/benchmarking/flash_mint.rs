@@ -16,29 +16,41 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, EvmAddress, HonzonBridge, Runtime};
+use crate::{AccountId, Balance, FlashMint, Rate, Runtime};
 
-use frame_benchmarking::account;
+use super::utils::{dollar, set_balance, STABLECOIN};
+use frame_benchmarking::whitelisted_caller;
 use frame_system::RawOrigin;
+use module_support::FlashMintBorrower;
 use orml_benchmarking::runtime_benchmarks;
-use sp_std::prelude::*;
+use sp_runtime::{DispatchResult, FixedPointNumber};
+
+// the borrower holds the fee beforehand and repays without doing anything
+struct RepayingBorrower;
+impl FlashMintBorrower<AccountId, Balance> for RepayingBorrower {
+	fn on_flash_mint(&self, _who: &AccountId, _amount: Balance, _fee: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
 
 runtime_benchmarks! {
-	{ Runtime, module_honzon_bridge }
-	set_bridged_stable_coin_address {
-	}: _(RawOrigin::Root, EvmAddress::default())
-
-	to_bridged {
-		HonzonBridge::set_bridged_stable_coin_address(RawOrigin::Root.into(), EvmAddress::default())?;
-
-		let caller: AccountId = account("caller", 0, 0);
-	}: _(RawOrigin::Signed(caller), 0)
-
-	from_bridged {
-		HonzonBridge::set_bridged_stable_coin_address(RawOrigin::Root.into(), EvmAddress::default())?;
-
-		let caller: AccountId = account("caller", 0, 0);
-	}: _(RawOrigin::Signed(caller), 0)
+	{ Runtime, module_flash_mint }
+
+	set_flash_mint_params {
+	}: _(RawOrigin::Root, Rate::saturating_from_rational(1, 1000), 1_000_000 * dollar(STABLECOIN))
+
+	flash_mint {
+		FlashMint::set_flash_mint_params(
+			RawOrigin::Root.into(),
+			Rate::saturating_from_rational(1, 1000),
+			1_000_000 * dollar(STABLECOIN),
+		)?;
+
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &caller, 100 * dollar(STABLECOIN));
+	}: {
+		FlashMint::flash_mint(&caller, 10_000 * dollar(STABLECOIN), &RepayingBorrower)?;
+	}
 }
 
 #[cfg(test)]
@@ -17,8 +17,8 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	AccountId, Amount, CdpEngine, CurrencyId, DepositPerAuthorization, ExistentialDeposits, Honzon,
-	NativeTokenExistentialDeposit, Price, Rate, Ratio, Runtime,
+	AccountId, Amount, CdpEngine, Currencies, CurrencyId, DepositPerAuthorization, DepositPerProtectionRule,
+	ExistentialDeposits, Honzon, Incentives, NativeTokenExistentialDeposit, Price, Rate, Ratio, Rewards, Runtime, NFT,
 };
 
 use super::{
@@ -29,9 +29,11 @@ use super::{
 };
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_support::HonzonManager;
+use module_honzon::ProtectionAction;
+use module_support::{HonzonManager, PoolId};
 use orml_benchmarking::runtime_benchmarks;
-use orml_traits::{Change, GetByKey};
+use orml_traits::{Change, GetByKey, MultiCurrency};
+use primitives::nft::{ClassProperty, Properties};
 use sp_runtime::{
 	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto},
 	FixedPointNumber,
@@ -40,6 +42,60 @@ use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
+fn create_position_class() -> Result<u32, &'static str> {
+	let creator: AccountId = account("creator", 0, SEED);
+	set_balance(NATIVE, &creator, 1_000 * dollar(NATIVE));
+	let class_id = orml_nft::Pallet::<Runtime>::next_class_id();
+	NFT::create_class(
+		RawOrigin::Signed(creator).into(),
+		vec![1],
+		Properties(ClassProperty::Transferable | ClassProperty::Mintable),
+		Default::default(),
+	)
+	.map_err(|e| e.error)?;
+	Ok(class_id)
+}
+
+fn open_wrappable_loan(who: &AccountId, currency_id: CurrencyId) -> Result<(), &'static str> {
+	let debit_value = 100 * dollar(STABLECOIN);
+	let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
+	let debit_amount = debit_exchange_rate
+		.reciprocal()
+		.unwrap()
+		.saturating_mul_int(debit_value);
+	let debit_amount: Amount = debit_amount.unique_saturated_into();
+	let collateral_value = 10 * debit_value;
+	let collateral_amount =
+		Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+	set_balance(currency_id, who, collateral_amount * 2);
+	set_balance(NATIVE, who, 100 * dollar(NATIVE));
+	feed_price(vec![(currency_id, Price::one())])?;
+	CdpEngine::set_collateral_params(
+		RawOrigin::Root.into(),
+		currency_id,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+		Change::NewValue(debit_value * 100),
+	)?;
+	Honzon::adjust_loan(
+		RawOrigin::Signed(who.clone()).into(),
+		currency_id,
+		collateral_amount.try_into().unwrap(),
+		debit_amount,
+	)?;
+	Ok(())
+}
+
+fn accumulate_wrapped_position_rewards(currency_id: CurrencyId) -> Result<(), &'static str> {
+	let pool_id = PoolId::Loans(currency_id);
+	Currencies::deposit(NATIVE, &Incentives::account_id(), 80 * dollar(NATIVE))?;
+	Rewards::accumulate_reward(&pool_id, NATIVE, 80 * dollar(NATIVE))?;
+	Ok(())
+}
+
 runtime_benchmarks! {
 	{ Runtime, module_honzon }
 
@@ -85,24 +141,29 @@ runtime_benchmarks! {
 		}
 	}: _(RawOrigin::Signed(caller))
 
-	// `adjust_loan`, best case:
-	// adjust both collateral and debit
+	// `adjust_loan`, worst case:
+	// adjust both collateral and debit of LP token collateral, which is staked to incentives
 	adjust_loan {
 		let caller: AccountId = whitelisted_caller();
-		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
-		let collateral_price = Price::one();		// 1 USD
+		let currency_id: CurrencyId = CurrencyId::join_dex_share_currency_id(STAKING, STABLECOIN).unwrap();
 		let debit_value = 100 * dollar(STABLECOIN);
 		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
 		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
 		let debit_amount: Amount = debit_amount.unique_saturated_into();
-		let collateral_value = 10 * debit_value;
-		let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
 
-		// set balance
-		set_balance(currency_id, &caller, collateral_amount * 2);
+		// set balance, the LP token is worth 20_000 USD
+		inject_liquidity(
+			caller.clone(),
+			STAKING,
+			STABLECOIN,
+			10_000 * dollar(STAKING),
+			10_000 * dollar(STABLECOIN),
+			false,
+		)?;
+		let collateral_amount = Currencies::free_balance(currency_id, &caller);
 
 		// feed price
-		feed_price(vec![(currency_id, collateral_price)])?;
+		feed_price(vec![(STAKING, Price::one())])?;
 
 		// set risk params
 		CdpEngine::set_collateral_params(
@@ -341,6 +402,142 @@ runtime_benchmarks! {
 	}: {
 		Honzon::get_current_collateral_ratio(&sender, LIQUID);
 	}
+
+	set_protection_rule {
+		let caller: AccountId = whitelisted_caller();
+
+		// set balance
+		set_balance(NATIVE, &caller, DepositPerProtectionRule::get() + NativeTokenExistentialDeposit::get());
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(100 * dollar(STABLECOIN)),
+		)?;
+	}: _(
+		RawOrigin::Signed(caller),
+		STAKING,
+		Ratio::saturating_from_rational(180, 100),
+		ProtectionAction::Deleverage { target_ratio: Ratio::saturating_from_rational(250, 100) }
+	)
+
+	remove_protection_rule {
+		let caller: AccountId = whitelisted_caller();
+
+		// set balance
+		set_balance(NATIVE, &caller, DepositPerProtectionRule::get() + NativeTokenExistentialDeposit::get());
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(100 * dollar(STABLECOIN)),
+		)?;
+		Honzon::set_protection_rule(
+			RawOrigin::Signed(caller.clone()).into(),
+			STAKING,
+			Ratio::saturating_from_rational(180, 100),
+			ProtectionAction::Close,
+		)?;
+	}: _(RawOrigin::Signed(caller), STAKING)
+
+	execute_protection_rule {
+		let currency_id: CurrencyId = STAKING;
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let keeper: AccountId = whitelisted_caller();
+		let maker: AccountId = account("maker", 0, SEED);
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 10 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+		// set balance and inject liquidity
+		set_balance(STAKING, &owner, (10 * collateral_amount) + ExistentialDeposits::get(&STAKING));
+		set_balance(NATIVE, &owner, DepositPerProtectionRule::get() + NativeTokenExistentialDeposit::get());
+		initialize_swap_pools(maker)?;
+
+		feed_price(vec![(STAKING, Price::one())])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+		)?;
+
+		// initialize owner's loan and the protection rule, the close action is the heavier one
+		Honzon::adjust_loan(
+			RawOrigin::Signed(owner.clone()).into(),
+			STAKING,
+			(10 * collateral_amount).try_into().unwrap(),
+			debit_amount,
+		)?;
+		Honzon::set_protection_rule(
+			RawOrigin::Signed(owner).into(),
+			STAKING,
+			Ratio::saturating_from_rational(200, 1),
+			ProtectionAction::Close,
+		)?;
+	}: _(RawOrigin::Signed(keeper), owner_lookup, currency_id)
+
+	set_position_class {
+		let class_id = create_position_class()?;
+	}: _(RawOrigin::Root, class_id)
+
+	wrap_position {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+		let class_id = create_position_class()?;
+		Honzon::set_position_class(RawOrigin::Root.into(), class_id)?;
+		open_wrappable_loan(&caller, currency_id)?;
+	}: _(RawOrigin::Signed(caller), currency_id)
+
+	unwrap_position {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+		let class_id = create_position_class()?;
+		Honzon::set_position_class(RawOrigin::Root.into(), class_id)?;
+		open_wrappable_loan(&caller, currency_id)?;
+		Honzon::wrap_position(RawOrigin::Signed(caller.clone()).into(), currency_id)?;
+		accumulate_wrapped_position_rewards(currency_id)?;
+	}: _(RawOrigin::Signed(caller), 0)
+
+	claim_wrapped_position_rewards {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+		let class_id = create_position_class()?;
+		Honzon::set_position_class(RawOrigin::Root.into(), class_id)?;
+		open_wrappable_loan(&caller, currency_id)?;
+		Honzon::wrap_position(RawOrigin::Signed(caller.clone()).into(), currency_id)?;
+		accumulate_wrapped_position_rewards(currency_id)?;
+	}: _(RawOrigin::Signed(caller), 0)
+
+	adjust_wrapped_loan {
+		let caller: AccountId = whitelisted_caller();
+		let currency_id: CurrencyId = get_benchmarking_collateral_currency_ids()[0];
+		let class_id = create_position_class()?;
+		Honzon::set_position_class(RawOrigin::Root.into(), class_id)?;
+		open_wrappable_loan(&caller, currency_id)?;
+		Honzon::wrap_position(RawOrigin::Signed(caller.clone()).into(), currency_id)?;
+		let collateral_adjustment: Amount = dollar(currency_id).unique_saturated_into();
+		let debit_adjustment: Amount = dollar(STABLECOIN).unique_saturated_into();
+	}: _(RawOrigin::Signed(caller), 0, collateral_adjustment, debit_adjustment)
 }
 
 #[cfg(test)]
@@ -39,17 +39,23 @@ pub mod earning;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
+pub mod flash_mint;
 pub mod homa;
 pub mod honzon;
 pub mod idle_scheduler;
 pub mod incentives;
 pub mod liquid_crowdloan;
+pub mod multi_currency_vesting;
+pub mod nft_marketplace;
 pub mod nominees_election;
 pub mod nutsfinance_stable_asset;
+pub mod peg_stability;
 pub mod prices;
+pub mod savings;
 pub mod session_manager;
 pub mod transaction_pause;
 pub mod transaction_payment;
+pub mod xcm_rate_limiter;
 
 // orml benchmarking
 pub mod auction;
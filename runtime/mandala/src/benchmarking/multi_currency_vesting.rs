@@ -0,0 +1,105 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	get_vesting_account,
+	utils::{dollar, lookup_of_account, set_balance, STAKING},
+};
+use crate::{AccountId, Balance, BlockNumber, MultiCurrencyVesting, RelaychainDataProvider, Runtime};
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use module_vesting::VestingSchedule;
+use orml_benchmarking::runtime_benchmarks;
+
+pub type Schedule = VestingSchedule<BlockNumber, Balance>;
+
+const SEED: u32 = 0;
+
+fn schedule() -> Schedule {
+	Schedule {
+		start: 0,
+		cliff: 10,
+		duration: 100,
+		total: 10 * dollar(STAKING),
+	}
+}
+
+fn add_schedules(to: &AccountId, n: u32) -> Result<(), &'static str> {
+	let from: AccountId = get_vesting_account();
+	set_balance(STAKING, &from, schedule().total * n as u128 + dollar(STAKING));
+	for _ in 0..n {
+		MultiCurrencyVesting::vested_transfer(
+			RawOrigin::Signed(from.clone()).into(),
+			lookup_of_account(to.clone()),
+			STAKING,
+			schedule(),
+		)?;
+	}
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_vesting }
+
+	vested_transfer {
+		let max = <Runtime as module_vesting::Config>::MaxVestingSchedules::get();
+
+		let to: AccountId = account("to", 0, SEED);
+		add_schedules(&to, max - 1)?;
+
+		let from: AccountId = get_vesting_account();
+		set_balance(STAKING, &from, schedule().total + dollar(STAKING));
+	}: _(RawOrigin::Signed(from), lookup_of_account(to), STAKING, schedule())
+
+	claim {
+		let i in 1 .. <Runtime as module_vesting::Config>::MaxVestingSchedules::get();
+
+		let caller: AccountId = whitelisted_caller();
+		add_schedules(&caller, i)?;
+		RelaychainDataProvider::<Runtime>::set_block_number(50);
+	}: _(RawOrigin::Signed(caller), STAKING)
+
+	merge_schedules {
+		let i in 2 .. <Runtime as module_vesting::Config>::MaxVestingSchedules::get();
+
+		let caller: AccountId = whitelisted_caller();
+		add_schedules(&caller, i)?;
+		RelaychainDataProvider::<Runtime>::set_block_number(50);
+	}: _(RawOrigin::Signed(caller), STAKING, 0, i - 1)
+
+	revoke {
+		let i in 1 .. <Runtime as module_vesting::Config>::MaxVestingSchedules::get();
+
+		let who: AccountId = account("who", 0, SEED);
+		add_schedules(&who, i)?;
+		RelaychainDataProvider::<Runtime>::set_block_number(50);
+
+		let dest: AccountId = account("dest", 0, SEED);
+	}: _(RawOrigin::Root, lookup_of_account(who), STAKING, lookup_of_account(dest))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,157 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, NFTMarketplace, NftPalletId, Runtime, NFT};
+
+use super::utils::{dollar, lookup_of_account, set_balance, NATIVE, STABLECOIN};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use primitives::nft::{ClassProperty, Properties};
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+// create a class with royalty and mint a token of it to `owner`
+fn mint_token(owner: &AccountId) -> Result<(u32, u64), &'static str> {
+	let creator: AccountId = account("creator", 0, SEED);
+	set_balance(NATIVE, &creator, 1_000 * dollar(NATIVE));
+	let class_id = orml_nft::Pallet::<Runtime>::next_class_id();
+	NFT::create_class(
+		RawOrigin::Signed(creator).into(),
+		vec![1],
+		Properties(
+			ClassProperty::Transferable
+				| ClassProperty::Burnable
+				| ClassProperty::Mintable
+				| ClassProperty::ClassPropertiesMutable,
+		),
+		Default::default(),
+	)
+	.map_err(|e| e.error)?;
+
+	let class_owner: AccountId = NftPalletId::get().into_sub_account_truncating(class_id);
+	set_balance(NATIVE, &class_owner, 1_000 * dollar(NATIVE));
+	NFT::update_class_royalty(RawOrigin::Signed(class_owner.clone()).into(), class_id, 500)?;
+	NFT::mint(
+		RawOrigin::Signed(class_owner).into(),
+		lookup_of_account(owner.clone()),
+		class_id,
+		vec![1],
+		Default::default(),
+		1,
+	)?;
+	Ok((class_id, 0))
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_nft_marketplace }
+
+	list {
+		let seller: AccountId = whitelisted_caller();
+		let token = mint_token(&seller)?;
+	}: _(RawOrigin::Signed(seller), token, STABLECOIN, 100 * dollar(STABLECOIN))
+
+	cancel_listing {
+		let seller: AccountId = whitelisted_caller();
+		let token = mint_token(&seller)?;
+		NFTMarketplace::list(RawOrigin::Signed(seller.clone()).into(), token, STABLECOIN, 100 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(seller), token)
+
+	buy {
+		let seller: AccountId = account("seller", 0, SEED);
+		let token = mint_token(&seller)?;
+		NFTMarketplace::list(RawOrigin::Signed(seller).into(), token, STABLECOIN, 100 * dollar(STABLECOIN))?;
+
+		let buyer: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &buyer, 1_000 * dollar(STABLECOIN));
+	}: _(RawOrigin::Signed(buyer), token, 100 * dollar(STABLECOIN))
+
+	make_offer {
+		let owner: AccountId = account("owner", 0, SEED);
+		let token = mint_token(&owner)?;
+
+		// replace an existing offer
+		let offerer: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &offerer, 1_000 * dollar(STABLECOIN));
+		NFTMarketplace::make_offer(
+			RawOrigin::Signed(offerer.clone()).into(),
+			token,
+			STABLECOIN,
+			50 * dollar(STABLECOIN),
+		)?;
+	}: _(RawOrigin::Signed(offerer), token, STABLECOIN, 100 * dollar(STABLECOIN))
+
+	cancel_offer {
+		let owner: AccountId = account("owner", 0, SEED);
+		let token = mint_token(&owner)?;
+
+		let offerer: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &offerer, 1_000 * dollar(STABLECOIN));
+		NFTMarketplace::make_offer(
+			RawOrigin::Signed(offerer.clone()).into(),
+			token,
+			STABLECOIN,
+			100 * dollar(STABLECOIN),
+		)?;
+	}: _(RawOrigin::Signed(offerer), token)
+
+	accept_offer {
+		// accept an offer for a listed token, which cancels the listing
+		let seller: AccountId = whitelisted_caller();
+		let token = mint_token(&seller)?;
+		NFTMarketplace::list(RawOrigin::Signed(seller.clone()).into(), token, STABLECOIN, 200 * dollar(STABLECOIN))?;
+
+		let offerer: AccountId = account("offerer", 0, SEED);
+		set_balance(STABLECOIN, &offerer, 1_000 * dollar(STABLECOIN));
+		NFTMarketplace::make_offer(
+			RawOrigin::Signed(offerer.clone()).into(),
+			token,
+			STABLECOIN,
+			100 * dollar(STABLECOIN),
+		)?;
+	}: _(RawOrigin::Signed(seller), token, offerer)
+
+	create_auction {
+		let seller: AccountId = whitelisted_caller();
+		let token = mint_token(&seller)?;
+	}: _(RawOrigin::Signed(seller), token, STABLECOIN, 100 * dollar(STABLECOIN), 100)
+
+	cancel_auction {
+		let seller: AccountId = whitelisted_caller();
+		let token = mint_token(&seller)?;
+		let auction_id = orml_auction::Pallet::<Runtime>::auctions_index();
+		NFTMarketplace::create_auction(
+			RawOrigin::Signed(seller.clone()).into(),
+			token,
+			STABLECOIN,
+			100 * dollar(STABLECOIN),
+			100,
+		)?;
+	}: _(RawOrigin::Signed(seller), auction_id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
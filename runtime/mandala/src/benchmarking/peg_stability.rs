@@ -0,0 +1,73 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, PegStability, Rate, Runtime};
+
+use super::utils::{dollar, set_balance, STABLECOIN, STAKING};
+use frame_benchmarking::whitelisted_caller;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::Change;
+use sp_runtime::{DispatchResult, FixedPointNumber};
+
+fn enable_peg_asset() -> DispatchResult {
+	PegStability::set_peg_asset_params(
+		RawOrigin::Root.into(),
+		STAKING,
+		Change::NewValue(Rate::saturating_from_rational(1, 1000)),
+		Change::NewValue(Rate::saturating_from_rational(1, 1000)),
+		Change::NewValue(1_000_000 * dollar(STABLECOIN)),
+	)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_peg_stability }
+
+	set_peg_asset_params {
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		Change::NewValue(Rate::saturating_from_rational(1, 1000)),
+		Change::NewValue(Rate::saturating_from_rational(1, 1000)),
+		Change::NewValue(1_000_000 * dollar(STABLECOIN))
+	)
+
+	mint {
+		enable_peg_asset()?;
+
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STAKING, &caller, 10_000 * dollar(STAKING));
+	}: _(RawOrigin::Signed(caller), STAKING, 1_000 * dollar(STAKING))
+
+	redeem {
+		enable_peg_asset()?;
+
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STAKING, &caller, 10_000 * dollar(STAKING));
+		PegStability::mint(RawOrigin::Signed(caller.clone()).into(), STAKING, 1_000 * dollar(STAKING))?;
+	}: _(RawOrigin::Signed(caller), STAKING, 100 * dollar(STABLECOIN))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
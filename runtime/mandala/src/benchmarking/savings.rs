@@ -0,0 +1,71 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, CdpTreasury, Rate, Runtime, Savings, MILLISECS_PER_BLOCK};
+
+use super::utils::{dollar, set_balance, set_block_number_timestamp, STABLECOIN};
+use frame_benchmarking::whitelisted_caller;
+use frame_support::traits::{Get, OnInitialize};
+use frame_system::RawOrigin;
+use module_support::CDPTreasury;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedPointNumber;
+
+runtime_benchmarks! {
+	{ Runtime, module_savings }
+
+	on_initialize {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+		Savings::do_deposit(&caller, 10_000 * dollar(STABLECOIN))?;
+		Savings::set_savings_rate(
+			RawOrigin::Root.into(),
+			<Runtime as module_savings::Config>::MaxSavingsRatePerSec::get(),
+		)?;
+		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
+
+		set_block_number_timestamp(2, MILLISECS_PER_BLOCK);
+		Savings::on_initialize(2);
+	}: {
+		set_block_number_timestamp(3, MILLISECS_PER_BLOCK * 2);
+		Savings::on_initialize(3);
+	}
+
+	set_savings_rate {
+	}: _(RawOrigin::Root, Rate::saturating_from_rational(1, 1_000_000_000))
+
+	deposit {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+	}: _(RawOrigin::Signed(caller), 1_000 * dollar(STABLECOIN))
+
+	withdraw {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+		let share_amount = Savings::do_deposit(&caller, 1_000 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(caller), share_amount)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
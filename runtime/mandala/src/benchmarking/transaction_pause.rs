@@ -16,9 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Runtime, RuntimeOrigin, TransactionPause, H160};
+use crate::{Runtime, RuntimeOrigin, System, TransactionPause, H160};
 
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_transaction_pause::PauseItem;
 use orml_benchmarking::runtime_benchmarks;
 
 runtime_benchmarks! {
@@ -37,6 +39,37 @@ runtime_benchmarks! {
 	unpause_evm_precompile {
 		TransactionPause::pause_evm_precompile(RuntimeOrigin::root(), H160::from_low_u64_be(1))?;
 	}: _(RawOrigin::Root, H160::from_low_u64_be(1))
+
+	pause_evm_contract {
+	}: _(RawOrigin::Root, H160::from_low_u64_be(0x1000))
+
+	unpause_evm_contract {
+		TransactionPause::pause_evm_contract(RuntimeOrigin::root(), H160::from_low_u64_be(0x1000))?;
+	}: _(RawOrigin::Root, H160::from_low_u64_be(0x1000))
+
+	pause_evm_contract_function {
+	}: _(RawOrigin::Root, H160::from_low_u64_be(0x1000), [0xa9, 0x05, 0x9c, 0xbb])
+
+	unpause_evm_contract_function {
+		TransactionPause::pause_evm_contract_function(RuntimeOrigin::root(), H160::from_low_u64_be(0x1000), [0xa9, 0x05, 0x9c, 0xbb])?;
+	}: _(RawOrigin::Root, H160::from_low_u64_be(0x1000), [0xa9, 0x05, 0x9c, 0xbb])
+
+	pause_until {
+	}: _(RawOrigin::Root, PauseItem::Transaction(b"Balances".to_vec(), b"transfer".to_vec()), 100)
+
+	guardian_pause {
+		TransactionPause::guardian_pause(RuntimeOrigin::root(), PauseItem::Transaction(b"Balances".to_vec(), b"transfer".to_vec()), 10)?;
+	}: _(RawOrigin::Root, PauseItem::Transaction(b"Balances".to_vec(), b"transfer".to_vec()), 100)
+
+	on_initialize {
+		let c in 0 .. 100;
+		System::set_block_number(1);
+		for i in 0 .. c {
+			TransactionPause::pause_until(RuntimeOrigin::root(), PauseItem::EvmContract(H160::from_low_u64_be(i.into())), 10)?;
+		}
+	}: {
+		TransactionPause::on_initialize(10);
+	}
 }
 
 #[cfg(test)]
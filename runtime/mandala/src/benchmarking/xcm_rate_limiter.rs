@@ -0,0 +1,82 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, Currencies, Runtime, System, XcmRateLimiter};
+
+use super::utils::{dollar, STAKING};
+use frame_benchmarking::account;
+use frame_system::RawOrigin;
+use module_xcm_rate_limiter::{FlowDirection, OverLimitAction, QueuedInflow, QueuedInflows, RateLimit};
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+
+const SEED: u32 = 0;
+
+fn queue_inflow() -> Result<u64, &'static str> {
+	let beneficiary: AccountId = account("beneficiary", 0, SEED);
+	let amount = 100 * dollar(STAKING);
+	let id = XcmRateLimiter::next_queue_id();
+
+	Currencies::deposit(STAKING, &XcmRateLimiter::account_id(), amount)?;
+	QueuedInflows::<Runtime>::insert(
+		id,
+		QueuedInflow {
+			currency_id: STAKING,
+			amount,
+			beneficiary,
+			queued_at: System::block_number(),
+		},
+	);
+	Ok(id)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_xcm_rate_limiter }
+
+	set_rate_limit {
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		FlowDirection::Inflow,
+		Some(RateLimit {
+			quota: 1_000 * dollar(STAKING),
+			window: 100,
+			over_limit: OverLimitAction::Queue,
+		})
+	)
+
+	set_circuit_breaker {
+	}: _(RawOrigin::Root, STAKING, true)
+
+	release_queued_inflow {
+		let id = queue_inflow()?;
+	}: _(RawOrigin::Root, id)
+
+	cancel_queued_inflow {
+		let id = queue_inflow()?;
+	}: _(RawOrigin::Root, id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -52,7 +52,9 @@ use module_currencies::{BasicCurrencyAdapter, Currency};
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
 use module_relaychain::RelayChainCallBuilder;
-use module_support::{AssetIdMapping, DispatchableTask, ExchangeRateProvider, FractionalRate, PoolId};
+use module_support::{
+	AssetIdMapping, DispatchableTask, ExchangeRateProvider, FractionalRate, PoolId, StableAssetQuote,
+};
 use module_transaction_payment::TargetedFeeAdjustment;
 use parity_scale_codec::{Decode, DecodeLimit, Encode};
 use scale_info::TypeInfo;
@@ -101,18 +103,18 @@ pub use primitives::{
 	DataProviderId, EraIndex, Hash, Lease, Moment, Multiplier, Nonce, ReserveIdentifier, Share, Signature, TokenSymbol,
 	TradingPair,
 };
-use runtime_common::precompile::AcalaPrecompiles;
+use runtime_common::precompile::MandalaPrecompiles;
 use runtime_common::{
 	cent, dollar, millicent, AllPrecompiles, CheckRelayNumber, CurrencyHooks, EnsureRootOrAllGeneralCouncil,
 	EnsureRootOrAllTechnicalCommittee, EnsureRootOrHalfFinancialCouncil, EnsureRootOrHalfGeneralCouncil,
-	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneThirdsTechnicalCommittee,
-	EnsureRootOrThreeFourthsGeneralCouncil, EnsureRootOrTwoThirdsGeneralCouncil,
-	EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate, ExistentialDepositsTimesOneHundred,
-	FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance,
-	GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance, MaxTipsOfPriority,
-	OperationalFeeMultiplier, OperatorMembershipInstanceAcala, Price, ProxyType, Rate, Ratio, RuntimeBlockLength,
-	RuntimeBlockWeights, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice,
-	TipPerWeightStep, ACA, AUSD, DOT, KSM, LCDOT, LDOT,
+	EnsureRootOrHalfHomaCouncil, EnsureRootOrOneGeneralCouncil, EnsureRootOrOneTechnicalCommittee,
+	EnsureRootOrOneThirdsTechnicalCommittee, EnsureRootOrThreeFourthsGeneralCouncil,
+	EnsureRootOrTwoThirdsGeneralCouncil, EnsureRootOrTwoThirdsTechnicalCommittee, ExchangeRate,
+	ExistentialDepositsTimesOneHundred, FinancialCouncilInstance, FinancialCouncilMembershipInstance, GasToWeight,
+	GeneralCouncilInstance, GeneralCouncilMembershipInstance, HomaCouncilInstance, HomaCouncilMembershipInstance,
+	MaxTipsOfPriority, OperationalFeeMultiplier, OperatorMembershipInstanceAcala, PausedEvmCallFilter, Price,
+	ProxyType, Rate, Ratio, RuntimeBlockLength, RuntimeBlockWeights, TechnicalCommitteeInstance,
+	TechnicalCommitteeMembershipInstance, TimeStampedPrice, TipPerWeightStep, ACA, AUSD, DOT, KSM, LCDOT, LDOT, SAUSD,
 };
 use xcm::prelude::*;
 
@@ -166,6 +168,7 @@ parameter_types! {
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
+	pub const HonzonPositionPalletId: PalletId = PalletId(*b"aca/hzps");
 	pub const HomaPalletId: PalletId = PalletId(*b"aca/homa");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
@@ -174,6 +177,7 @@ parameter_types! {
 	pub const TreasuryReservePalletId: PalletId = PalletId(*b"aca/reve");
 	pub const PhragmenElectionPalletId: LockIdentifier = *b"aca/phre";
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
+	pub const NftMarketplacePalletId: PalletId = PalletId(*b"aca/nftm");
 	pub const NomineesElectionId: LockIdentifier = *b"aca/nome";
 	pub UnreleasedNativeVaultAccountId: AccountId = PalletId(*b"aca/urls").into_account_truncating();
 	// This Pallet is only used to payment fee pool, it's not added to whitelist by design.
@@ -182,6 +186,9 @@ parameter_types! {
 	pub const LiquidCrowdloanPalletId: PalletId = PalletId(*b"aca/lqcl");
 	// Ecosystem modules
 	pub const StableAssetPalletId: PalletId = PalletId(*b"nuts/sta");
+	pub const XcmRateLimiterPalletId: PalletId = PalletId(*b"aca/xrlm");
+	pub const PegStabilityPalletId: PalletId = PalletId(*b"aca/pegs");
+	pub const SavingsPalletId: PalletId = PalletId(*b"aca/savi");
 	// lock identifier for earning module
 	pub const EarningLockIdentifier: LockIdentifier = *b"aca/earn";
 }
@@ -193,6 +200,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		LoansPalletId::get().into_account_truncating(),
 		DEXPalletId::get().into_account_truncating(),
 		CDPTreasuryPalletId::get().into_account_truncating(),
+		CdpTreasury::insurance_fund_account(),
 		HonzonTreasuryPalletId::get().into_account_truncating(),
 		HomaTreasuryPalletId::get().into_account_truncating(),
 		IncentivesPalletId::get().into_account_truncating(),
@@ -200,6 +208,10 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		CollatorPotId::get().into_account_truncating(),
 		UnreleasedNativeVaultAccountId::get(),
 		StableAssetPalletId::get().into_account_truncating(),
+		NftMarketplacePalletId::get().into_account_truncating(),
+		XcmRateLimiterPalletId::get().into_account_truncating(),
+		PegStabilityPalletId::get().into_account_truncating(),
+		SavingsPalletId::get().into_account_truncating(),
 	]
 }
 
@@ -213,6 +225,7 @@ pub struct BaseCallFilter;
 impl Contains<RuntimeCall> for BaseCallFilter {
 	fn contains(call: &RuntimeCall) -> bool {
 		!module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
+			&& !matches!(call, RuntimeCall::EVM(evm_call) if PausedEvmCallFilter::<Runtime>::contains(evm_call))
 			&& !matches!(call, RuntimeCall::Democracy(pallet_democracy::Call::propose { .. }),)
 	}
 }
@@ -704,7 +717,7 @@ impl orml_auction::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
 	type AuctionId = AuctionId;
-	type Handler = AuctionManager;
+	type Handler = module_nft_marketplace::AuctionHandlerRouter<Runtime, AuctionManager>;
 	type WeightInfo = weights::orml_auction::WeightInfo<Runtime>;
 }
 
@@ -799,7 +812,13 @@ parameter_types! {
 type AcalaDataProvider = orml_oracle::Instance1;
 impl orml_oracle::Config<AcalaDataProvider> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnNewData = ();
+	type OnNewData = runtime_common::auto_pause::OracleDeviationAutoPause<
+		Runtime,
+		AcalaDataProvider,
+		TransactionPause,
+		MaxOracleDeviation,
+		AutoPauseDuration,
+	>;
 	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, AcalaDataProvider>;
 	type Time = Timestamp;
 	type OracleKey = CurrencyId;
@@ -837,6 +856,7 @@ parameter_type_with_key! {
 		match currency_id {
 			CurrencyId::Token(symbol) => match symbol {
 				TokenSymbol::AUSD => cent(*currency_id),
+				TokenSymbol::SAUSD => cent(*currency_id),
 				TokenSymbol::DOT => 10 * millicent(*currency_id),
 				TokenSymbol::LDOT => 50 * millicent(*currency_id),
 				TokenSymbol::BNC => 800 * millicent(*currency_id), // 80BNC = 1KSM
@@ -847,6 +867,7 @@ parameter_type_with_key! {
 				TokenSymbol::LKSM |
 				TokenSymbol::KINT |
 				TokenSymbol::KBTC |
+				TokenSymbol::SKUSD |
 				TokenSymbol::TAI => 10 * millicent(*currency_id),
 				TokenSymbol::TAP => 10 * millicent(*currency_id),
 				TokenSymbol::ACA |
@@ -995,6 +1016,17 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 }
 
+impl module_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type MinVestedTransfer = ConstU128<0>;
+	type VestedTransferOrigin = EnsureRootOrTreasury;
+	type RevokeOrigin = EnsureRootOrHalfGeneralCouncil;
+	type MaxVestingSchedules = ConstU32<100>;
+	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type WeightInfo = weights::module_vesting::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
 }
@@ -1045,10 +1077,12 @@ impl module_auction_manager::Config for Runtime {
 	type AuctionTimeToClose = AuctionTimeToClose;
 	type AuctionDurationSoftCap = AuctionDurationSoftCap;
 	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type KeeperReward = CdpEngine;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1149,9 +1183,12 @@ impl module_cdp_engine::Config for Runtime {
 	type UnixTime = Timestamp;
 	type Currency = Currencies;
 	type DEX = Dex;
+	type DEXPriceSource = module_dex_oracle::AverageDEXPriceProvider<Runtime>;
+	type StabilityFeeAdjustmentPeriod = ConstU32<HOURS>;
 	type LiquidationContractsUpdateOrigin = EnsureRootOrHalfGeneralCouncil;
 	type MaxLiquidationContractSlippage = MaxLiquidationContractSlippage;
 	type MaxLiquidationContracts = ConstU32<10>;
+	type MaxLiquidationContractFailures = ConstU32<5>;
 	type LiquidationEvmBridge = module_evm_bridge::LiquidationEvmBridge<Runtime>;
 	type PalletId = CDPEnginePalletId;
 	type EvmAddressMapping = module_evm_accounts::EvmAddressMapping<Runtime>;
@@ -1161,13 +1198,18 @@ impl module_cdp_engine::Config for Runtime {
 
 parameter_types! {
 	pub DepositPerAuthorization: Balance = dollar(ACA);
+	// paid to the keeper who executes the protection rule
+	pub DepositPerProtectionRule: Balance = 10 * dollar(ACA);
 }
 
 impl module_honzon::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type DepositPerAuthorization = DepositPerAuthorization;
+	type DepositPerProtectionRule = DepositPerProtectionRule;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
+	type PositionPalletId = HonzonPositionPalletId;
+	type Incentives = Incentives;
 	type WeightInfo = weights::module_honzon::WeightInfo<Runtime>;
 }
 
@@ -1175,12 +1217,55 @@ impl module_emergency_shutdown::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollateralCurrencyIds = CollateralCurrencyIds<Runtime>;
 	type PriceSource = Prices;
+	type LockedPriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type GetStableCurrencyId = GetStableCurrencyId;
 	type CDPTreasury = CdpTreasury;
 	type AuctionManagerHandler = AuctionManager;
 	type ShutdownOrigin = EnsureRootOrHalfGeneralCouncil;
 	type WeightInfo = weights::module_emergency_shutdown::WeightInfo<Runtime>;
 }
 
+impl module_peg_stability::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type Erc20InfoMapping = EvmErc20InfoMapping<Runtime>;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type PalletId = PegStabilityPalletId;
+	type WeightInfo = weights::module_peg_stability::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const GetSavingsCurrencyId: CurrencyId = SAUSD;
+	// about 20% APY
+	pub MaxSavingsRatePerSec: Rate = Rate::saturating_from_rational(6, 1_000_000_000);
+}
+
+impl module_savings::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetSavingsCurrencyId = GetSavingsCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type EmergencyShutdown = EmergencyShutdown;
+	type UnixTime = Timestamp;
+	type MaxSavingsRatePerSec = MaxSavingsRatePerSec;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type PalletId = SavingsPalletId;
+	type WeightInfo = weights::module_savings::WeightInfo<Runtime>;
+}
+
+impl module_flash_mint::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type CDPTreasury = CdpTreasury;
+	type EmergencyShutdown = EmergencyShutdown;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_flash_mint::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
@@ -1209,15 +1294,23 @@ impl module_dex::Config for Runtime {
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
-	type OnLiquidityPoolUpdated = ();
+	type OnLiquidityPoolUpdated = runtime_common::auto_pause::DexPriceMoveAutoPause<
+		Runtime,
+		TransactionPause,
+		MaxDexPriceMove,
+		AutoPauseDuration,
+	>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
 	type DEX = Dex;
 	type StableAsset = RebasedStableAsset;
+	type StableAssetQuote = StableAssetQuoter;
 	type GovernanceOrigin = EnsureRootOrHalfGeneralCouncil;
 	type DexSwapJointList = AlternativeSwapPathJointList;
 	type SwapPathLimit = ConstU32<3>;
+	type MaxRouteCandidates = ConstU32<16>;
+	type MaxRouteSearchNodes = ConstU32<64>;
 	type WeightInfo = weights::module_aggregated_dex::WeightInfo<Runtime>;
 }
 
@@ -1249,15 +1342,26 @@ impl module_cdp_treasury::Config for Runtime {
 	type DEX = Dex;
 	type Swap = AcalaSwap;
 	type MaxAuctionsCount = ConstU32<50>;
+	type DebitAuctionMintPeriod = ConstU32<DAYS>;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 	type StableAsset = RebasedStableAsset;
 }
 
+parameter_types! {
+	pub const MaxGuardianPauseDuration: BlockNumber = DAYS;
+	pub const AutoPauseDuration: BlockNumber = 6 * HOURS;
+	pub MaxOracleDeviation: Ratio = Ratio::saturating_from_rational(30, 100);
+	pub MaxDexPriceMove: Ratio = Ratio::saturating_from_rational(20, 100);
+	pub MaxHomaLedgerMismatch: Ratio = Ratio::saturating_from_rational(10, 100);
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
+	type GuardianOrigin = EnsureRootOrOneThirdsTechnicalCommittee;
+	type MaxGuardianPauseDuration = MaxGuardianPauseDuration;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
@@ -1339,12 +1443,19 @@ impl module_evm_accounts::Config for Runtime {
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub AssetRegistrationDeposit: Balance = 10 * dollar(ACA);
+}
+
 impl module_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type StakingCurrencyId = GetStakingCurrencyId;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
 	type RegisterOrigin = EnsureRootOrHalfGeneralCouncil;
+	type RegistrationDeposit = AssetRegistrationDeposit;
+	type TreasuryAccount = TreasuryAccount;
+	type AssetInUse = runtime_common::AssetInUse<Runtime>;
 	type WeightInfo = weights::module_asset_registry::WeightInfo<Runtime>;
 }
 
@@ -1412,6 +1523,12 @@ impl module_homa::Config for Runtime {
 	type RedeemThreshold = RedeemThreshold;
 	type RelayChainBlockNumber = RelaychainDataProvider<Runtime>;
 	type XcmInterface = XcmInterface;
+	type OnLedgerBondedReset = runtime_common::auto_pause::HomaLedgerMismatchAutoPause<
+		Runtime,
+		TransactionPause,
+		MaxHomaLedgerMismatch,
+		AutoPauseDuration,
+	>;
 	type WeightInfo = weights::module_homa::WeightInfo<Runtime>;
 }
 
@@ -1439,6 +1556,15 @@ impl module_xcm_interface::Config for Runtime {
 	type AccountIdToMultiLocation = xcm_config::AccountIdToMultiLocation;
 }
 
+impl module_xcm_rate_limiter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
+	type PalletId = XcmRateLimiterPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type WeightInfo = weights::module_xcm_rate_limiter::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MinCouncilBondThreshold: Balance = dollar(LDOT);
 }
@@ -1481,6 +1607,16 @@ impl orml_nft::Config for Runtime {
 	type MaxTokenMetadata = ConstU32<1024>;
 }
 
+impl module_nft_marketplace::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type Auction = Auction;
+	type MinimumIncrementSize = MinimumIncrementSize;
+	type AuctionTimeToClose = AuctionTimeToClose;
+	type PalletId = NftMarketplacePalletId;
+	type WeightInfo = weights::module_nft_marketplace::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// One storage item; key size 32, value size 8; .
 	pub ProxyDepositBase: Balance = deposit(1, 8);
@@ -1590,7 +1726,7 @@ impl pallet_proxy::Config for Runtime {
 
 parameter_types! {
 	pub NetworkContractSource: H160 = H160::from_low_u64_be(0);
-	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, AcalaPrecompiles<Runtime>> = AllPrecompiles::<_, _, _>::mandala();
+	pub PrecompilesValue: AllPrecompiles<Runtime, module_transaction_pause::PausedPrecompileFilter<Runtime>, MandalaPrecompiles<Runtime>> = AllPrecompiles::<_, _, _>::mandala();
 }
 
 #[cfg(feature = "with-ethereum-compatibility")]
@@ -1652,7 +1788,7 @@ impl module_evm::Config for Runtime {
 	type TxFeePerGas = TxFeePerGas;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType =
-		AllPrecompiles<Self, module_transaction_pause::PausedPrecompileFilter<Self>, AcalaPrecompiles<Runtime>>;
+		AllPrecompiles<Self, module_transaction_pause::PausedPrecompileFilter<Self>, MandalaPrecompiles<Runtime>>;
 	type PrecompilesValue = PrecompilesValue;
 	type GasToWeight = GasToWeight;
 	type ChargeTransactionPayment = module_transaction_payment::ChargeTransactionPayment<Runtime>;
@@ -1666,6 +1802,7 @@ impl module_evm::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type ContractPauseFilter = module_transaction_pause::PausedPrecompileFilter<Self>;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 
 	#[cfg(feature = "with-ethereum-compatibility")]
@@ -1758,6 +1895,13 @@ type RebaseTokens = orml_tokens::Combiner<
 	Currencies,
 >;
 
+parameter_types! {
+	pub StableAssetQuoteAccount: AccountId = StableAssetPalletId::get().into_sub_account_truncating(b"quote");
+}
+
+pub type StableAssetQuoter =
+	module_support::StableAssetQuoter<RebasedStableAsset, RebaseTokens, ConvertBalanceHoma, StableAssetQuoteAccount>;
+
 parameter_types! {
 	pub const GetStableAssetStakingCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::LDOT);
 }
@@ -1973,9 +2117,23 @@ pub type UncheckedExtrinsic =
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+
+/// All migrations that will run on the next runtime upgrade.
+pub type Migrations = (
+	module_nft::migrations::MigrateClassDataRoyalty<Runtime>,
+	module_incentives::migrations::StakeExistingLpCollateral<Runtime>,
+	module_dex::migrations::CountActiveTradingPairs<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 construct_runtime!(
 	pub enum Runtime {
@@ -1992,6 +2150,7 @@ construct_runtime!(
 		Currencies: module_currencies = 12,
 		Vesting: orml_vesting = 13,
 		TransactionPayment: module_transaction_payment = 14,
+		MultiCurrencyVesting: module_vesting = 15,
 
 		// Treasury
 		Treasury: pallet_treasury = 20,
@@ -2047,6 +2206,9 @@ construct_runtime!(
 		CdpTreasury: module_cdp_treasury = 123,
 		CdpEngine: module_cdp_engine = 124,
 		EmergencyShutdown: module_emergency_shutdown = 125,
+		PegStability: module_peg_stability = 126,
+		Savings: module_savings = 127,
+		FlashMint: module_flash_mint = 128,
 
 		// Homa
 		NomineesElection: module_nominees_election = 131,
@@ -2058,6 +2220,7 @@ construct_runtime!(
 		NFT: module_nft = 141,
 		AssetRegistry: module_asset_registry = 142,
 		LiquidCrowdloan: module_liquid_crowdloan = 143,
+		NFTMarketplace: module_nft_marketplace = 144,
 
 		// Parachain
 		ParachainInfo: parachain_info exclude_parts { Call } = 161,
@@ -2070,6 +2233,7 @@ construct_runtime!(
 		XTokens: orml_xtokens = 174,
 		UnknownTokens: orml_unknown_tokens = 175,
 		OrmlXcm: orml_xcm = 176,
+		XcmRateLimiter: module_xcm_rate_limiter = 177,
 
 		// Smart contracts
 		EVM: module_evm = 180,
@@ -2122,11 +2286,16 @@ mod benches {
 		[module_transaction_pause, benchmarking::transaction_pause]
 		[module_transaction_payment, benchmarking::transaction_payment]
 		[module_incentives, benchmarking::incentives]
+		[module_peg_stability, benchmarking::peg_stability]
 		[module_prices, benchmarking::prices]
+		[module_savings, benchmarking::savings]
 		[module_evm_accounts, benchmarking::evm_accounts]
+		[module_flash_mint, benchmarking::flash_mint]
 		[module_currencies, benchmarking::currencies]
 		[module_session_manager, benchmarking::session_manager]
 		[module_liquid_crowdloan, benchmarking::liquid_crowdloan]
+		[module_vesting, benchmarking::multi_currency_vesting]
+		[module_nft_marketplace, benchmarking::nft_marketplace]
 		[orml_tokens, benchmarking::tokens]
 		[orml_vesting, benchmarking::vesting]
 		[orml_auction, benchmarking::auction]
@@ -2135,6 +2304,7 @@ mod benches {
 		[nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset]
 		[module_idle_scheduler, benchmarking::idle_scheduler]
 		[module_aggregated_dex, benchmarking::aggregated_dex]
+		[module_xcm_rate_limiter, benchmarking::xcm_rate_limiter]
 	);
 }
 
@@ -2297,6 +2467,102 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_cdp_treasury_runtime_api::CdpTreasuryApi<
+		Block,
+		CurrencyId,
+		Balance,
+		module_cdp_treasury::LossRecord,
+	> for Runtime {
+		fn surplus_pool() -> Balance {
+			CdpTreasury::surplus_pool()
+		}
+
+		fn debit_pool() -> Balance {
+			CdpTreasury::debit_pool()
+		}
+
+		fn insurance_fund(currency_id: CurrencyId) -> Balance {
+			CdpTreasury::insurance_funds(currency_id)
+		}
+
+		fn loss_record(currency_id: CurrencyId) -> module_cdp_treasury::LossRecord {
+			CdpTreasury::loss_records(currency_id)
+		}
+	}
+
+	impl module_xcm_rate_limiter_runtime_api::XcmRateLimiterApi<
+		Block,
+		CurrencyId,
+		Balance,
+	> for Runtime {
+		fn remaining_inflow_quota(currency_id: CurrencyId) -> Option<Balance> {
+			XcmRateLimiter::remaining_quota(currency_id, module_xcm_rate_limiter::FlowDirection::Inflow)
+		}
+
+		fn remaining_outflow_quota(currency_id: CurrencyId) -> Option<Balance> {
+			XcmRateLimiter::remaining_quota(currency_id, module_xcm_rate_limiter::FlowDirection::Outflow)
+		}
+	}
+
+	impl module_stable_asset_runtime_api::StableAssetApi<
+		Block,
+		CurrencyId,
+		Balance,
+		AccountId,
+		BlockNumber,
+	> for Runtime {
+		fn pool_info(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+		) -> Option<nutsfinance_stable_asset::StableAssetPoolInfo<CurrencyId, Balance, Balance, AccountId, BlockNumber>> {
+			StableAssetQuoter::pool_info(pool_id)
+		}
+
+		fn get_virtual_price(pool_id: nutsfinance_stable_asset::StableAssetPoolId) -> Option<Ratio> {
+			StableAssetQuoter::get_virtual_price(pool_id)
+		}
+
+		fn get_swap_output_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			input_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+			input_amount: Balance,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_swap_output_amount(pool_id, input_index, output_index, input_amount)
+		}
+
+		fn get_swap_input_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			input_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+			output_amount: Balance,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_swap_input_amount(pool_id, input_index, output_index, output_amount)
+		}
+
+		fn get_mint_amount(pool_id: nutsfinance_stable_asset::StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+			StableAssetQuoter::get_mint_amount(pool_id, amounts)
+		}
+
+		fn get_redeem_proportion_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			amount: Balance,
+		) -> Option<Vec<Balance>> {
+			StableAssetQuoter::get_redeem_proportion_amount(pool_id, amount)
+		}
+
+		fn get_redeem_single_amount(
+			pool_id: nutsfinance_stable_asset::StableAssetPoolId,
+			amount: Balance,
+			output_index: nutsfinance_stable_asset::PoolTokenIndex,
+		) -> Option<Balance> {
+			StableAssetQuoter::get_redeem_single_amount(pool_id, amount, output_index)
+		}
+
+		fn get_redeem_multi_amount(pool_id: nutsfinance_stable_asset::StableAssetPoolId, amounts: Vec<Balance>) -> Option<Balance> {
+			StableAssetQuoter::get_redeem_multi_amount(pool_id, amounts)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
@@ -32,17 +32,23 @@ pub mod module_earning;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
+pub mod module_flash_mint;
 pub mod module_homa;
 pub mod module_honzon;
 pub mod module_idle_scheduler;
 pub mod module_incentives;
 pub mod module_liquid_crowdloan;
 pub mod module_nft;
+pub mod module_nft_marketplace;
 pub mod module_nominees_election;
+pub mod module_peg_stability;
 pub mod module_prices;
+pub mod module_savings;
 pub mod module_session_manager;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
+pub mod module_vesting;
+pub mod module_xcm_rate_limiter;
 
 pub mod orml_auction;
 pub mod orml_authority;
@@ -107,4 +107,30 @@ impl<T: frame_system::Config> module_aggregated_dex::WeightInfo for WeightInfo<T
 			.saturating_add(Weight::from_parts(1_631_908, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
+	// Storage: StableAsset PoolCount (r:1 w:0)
+	// Proof: StableAsset PoolCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: StableAsset Pools (r:64 w:0)
+	// Proof: StableAsset Pools (max_values: None, max_size: None, mode: Measured)
+	// Storage: Dex TradingPairStatuses (r:6 w:0)
+	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	// Storage: Dex LiquidityPool (r:70 w:4)
+	// Proof: Dex LiquidityPool (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn route_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2864 + n * (192 ±0)`
+		//  Estimated: `19740 + n * (2572 ±12)`
+		// Minimum execution time: 138_215 nanoseconds.
+		Weight::from_parts(112_417_650, 19740)
+			// Standard Error: 23_517
+			.saturating_add(Weight::from_parts(9_875_344, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(Weight::from_parts(0, 2572).saturating_mul(n.into()))
+	}
 }
@@ -154,4 +154,122 @@ impl<T: frame_system::Config> module_asset_registry::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: `AssetRegistry::UnverifiedAssets` (r:1 w:0)
+	// Proof: `AssetRegistry::UnverifiedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::DexPricedFeeAssets` (r:0 w:1)
+	// Proof: `AssetRegistry::DexPricedFeeAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_dex_priced_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3465`
+		// Minimum execution time: 11_905 nanoseconds.
+		Weight::from_parts(12_205_000, 3465)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: `AssetRegistry::NextForeignAssetId` (r:1 w:1)
+	// Proof: `AssetRegistry::NextForeignAssetId` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::LocationToCurrencyIds` (r:1 w:1)
+	// Proof: `AssetRegistry::LocationToCurrencyIds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::ForeignAssetLocations` (r:1 w:1)
+	// Proof: `AssetRegistry::ForeignAssetLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:1)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::UnverifiedAssets` (r:0 w:1)
+	// Proof: `AssetRegistry::UnverifiedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_foreign_asset_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1150`
+		//  Estimated: `4615`
+		// Minimum execution time: 52_422 nanoseconds.
+		Weight::from_parts(52_834_000, 4615)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: `EVM::Accounts` (r:2 w:0)
+	// Proof: `EVM::Accounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::Codes` (r:1 w:0)
+	// Proof: `EVM::Codes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `EVM::AccountStorages` (r:5 w:0)
+	// Proof: `EVM::AccountStorages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:1)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::Erc20IdToAddress` (r:1 w:1)
+	// Proof: `AssetRegistry::Erc20IdToAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::UnverifiedAssets` (r:0 w:1)
+	// Proof: `AssetRegistry::UnverifiedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_erc20_asset_with_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6980`
+		//  Estimated: `20345`
+		// Minimum execution time: 225_435 nanoseconds.
+		Weight::from_parts(225_847_000, 20345)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: `AssetRegistry::UnverifiedAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::UnverifiedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn verify_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1420`
+		//  Estimated: `4885`
+		// Minimum execution time: 35_448 nanoseconds.
+		Weight::from_parts(35_860_000, 4885)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: `AssetRegistry::UnverifiedAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::UnverifiedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:1)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::ForeignAssetLocations` (r:1 w:1)
+	// Proof: `AssetRegistry::ForeignAssetLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:2 w:2)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::LocationToCurrencyIds` (r:0 w:1)
+	// Proof: `AssetRegistry::LocationToCurrencyIds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::DexPricedFeeAssets` (r:0 w:1)
+	// Proof: `AssetRegistry::DexPricedFeeAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reject_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1610`
+		//  Estimated: `6196`
+		// Minimum execution time: 62_461 nanoseconds.
+		Weight::from_parts(62_873_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: `AssetRegistry::UnverifiedAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::UnverifiedAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::AssetMetadatas` (r:1 w:1)
+	// Proof: `AssetRegistry::AssetMetadatas` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::ForeignAssetLocations` (r:1 w:1)
+	// Proof: `AssetRegistry::ForeignAssetLocations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `System::Account` (r:1 w:1)
+	// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	// Storage: `Tokens::TotalIssuance` (r:1 w:0)
+	// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	// Storage: `TransactionPayment::TokenExchangeRate` (r:1 w:0)
+	// Proof: `TransactionPayment::TokenExchangeRate` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	// Storage: `Dex::ActiveTradingPairCount` (r:1 w:0)
+	// Proof: `Dex::ActiveTradingPairCount` (`max_values`: None, `max_size`: Some(47), added: 2522, mode: `MaxEncodedLen`)
+	// Storage: `AssetRegistry::LocationToCurrencyIds` (r:0 w:1)
+	// Proof: `AssetRegistry::LocationToCurrencyIds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	// Storage: `AssetRegistry::DexPricedFeeAssets` (r:1 w:1)
+	// Proof: `AssetRegistry::DexPricedFeeAssets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deregister_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1610`
+		//  Estimated: `7617`
+		// Minimum execution time: 45_474 nanoseconds.
+		Weight::from_parts(45_886_000, 7617)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 }
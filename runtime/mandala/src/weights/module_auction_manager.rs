@@ -68,6 +68,8 @@ impl<T: frame_system::Config> module_auction_manager::WeightInfo for WeightInfo<
 	// Proof: AuctionManager TotalTargetInAuction (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Auction AuctionEndTime (r:0 w:1)
 	// Proof: Auction AuctionEndTime (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	// Storage: AuctionManager LiquidationAuctions (r:0 w:1)
+	// Proof: AuctionManager LiquidationAuctions (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
 	fn cancel_collateral_auction() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3494`
@@ -75,6 +77,43 @@ impl<T: frame_system::Config> module_auction_manager::WeightInfo for WeightInfo<
 		// Minimum execution time: 105_456 nanoseconds.
 		Weight::from_parts(108_186_000, 39121)
 			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: AuctionManager CollateralAuctions (r:1 w:1)
+	// Proof: AuctionManager CollateralAuctions (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	// Storage: Auction Auctions (r:1 w:1)
+	// Proof: Auction Auctions (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	// Storage: Prices LockedPrice (r:2 w:0)
+	// Proof: Prices LockedPrice (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadatas (r:1 w:0)
+	// Proof Skipped: AssetRegistry AssetMetadatas (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: AuctionManager TotalCollateralInAuction (r:1 w:1)
+	// Proof: AuctionManager TotalCollateralInAuction (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: AuctionManager TotalTargetInAuction (r:1 w:1)
+	// Proof: AuctionManager TotalTargetInAuction (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Auction AuctionEndTime (r:0 w:1)
+	// Proof: Auction AuctionEndTime (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	// Storage: AuctionManager LiquidationAuctions (r:0 w:1)
+	// Proof: AuctionManager LiquidationAuctions (max_values: None, max_size: Some(71), added: 2546, mode: MaxEncodedLen)
+	// Storage: CdpEngine LiquidationKeeperRewards (r:1 w:0)
+	// Proof: CdpEngine LiquidationKeeperRewards (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: CdpTreasury DebitPool (r:1 w:0)
+	// Proof: CdpTreasury DebitPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn cancel_by_keeper() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3105`
+		//  Estimated: `9045`
+		// Minimum execution time: 121_482 nanoseconds.
+		Weight::from_parts(124_116_000, 9045)
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 }
@@ -249,14 +249,16 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: CdpEngine LiquidationContracts (r:1 w:1)
 	// Proof: CdpEngine LiquidationContracts (max_values: Some(1), max_size: Some(201), added: 696, mode: MaxEncodedLen)
+	// Storage: CdpEngine LiquidationContractStats (r:1 w:1)
+	// Proof: CdpEngine LiquidationContractStats (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
 	fn register_liquidation_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1211`
-		//  Estimated: `1686`
+		//  Estimated: `3521`
 		// Minimum execution time: 20_772 nanoseconds.
-		Weight::from_parts(21_773_000, 1686)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(21_773_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	// Storage: CdpEngine LiquidationContracts (r:1 w:1)
 	// Proof: CdpEngine LiquidationContracts (max_values: Some(1), max_size: Some(201), added: 696, mode: MaxEncodedLen)
@@ -269,4 +271,140 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: CdpEngine StablecoinPegReference (r:1 w:0)
+	// Proof: CdpEngine StablecoinPegReference (max_values: Some(1), max_size: Some(59), added: 554, mode: MaxEncodedLen)
+	// Storage: CdpEngine StabilityFeeControllers (r:c w:0)
+	// Proof: CdpEngine StabilityFeeControllers (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	// Storage: CdpEngine CollateralParams (r:c w:c)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 10]`.
+	fn adjust_stability_fees(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1102 + c * (187 ±0)`
+		//  Estimated: `1544 + c * (2614 ±0)`
+		// Minimum execution time: 9_102 nanoseconds.
+		Weight::from_parts(9_814_000, 1544)
+			// Standard Error: 14_205
+			.saturating_add(Weight::from_parts(21_473_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2614).saturating_mul(c.into()))
+	}
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: CdpEngine StabilityFeeControllers (r:0 w:1)
+	// Proof: CdpEngine StabilityFeeControllers (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	fn set_stability_fee_controller() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1211`
+		//  Estimated: `3600`
+		// Minimum execution time: 23_418 nanoseconds.
+		Weight::from_parts(24_000_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CdpEngine StablecoinPegReference (r:0 w:1)
+	// Proof: CdpEngine StablecoinPegReference (max_values: Some(1), max_size: Some(59), added: 554, mode: MaxEncodedLen)
+	fn set_stablecoin_peg_reference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 18_527 nanoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: CdpEngine LiquidationKeeperRewards (r:0 w:1)
+	// Proof: CdpEngine LiquidationKeeperRewards (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	fn set_liquidation_keeper_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1211`
+		//  Estimated: `3600`
+		// Minimum execution time: 23_146 nanoseconds.
+		Weight::from_parts(24_000_000, 3600)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Loans Positions (r:1 w:1)
+	// Proof: Loans Positions (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	// Storage: Prices LockedPrice (r:2 w:0)
+	// Proof: Prices LockedPrice (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: AcalaOracle Values (r:1 w:0)
+	// Proof: AcalaOracle Values (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadatas (r:2 w:0)
+	// Proof Skipped: AssetRegistry AssetMetadatas (max_values: None, max_size: None, mode: Measured)
+	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
+	// Proof: CdpEngine DebitExchangeRate (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
+	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: CdpTreasury DebitPool (r:1 w:1)
+	// Proof: CdpTreasury DebitPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Loans TotalPositions (r:1 w:1)
+	// Proof: Loans TotalPositions (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	// Storage: AuctionManager TotalCollateralInAuction (r:1 w:1)
+	// Proof: AuctionManager TotalCollateralInAuction (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Dex TradingPairStatuses (r:2 w:0)
+	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	// Storage: Dex LiquidityPool (r:1 w:0)
+	// Proof: Dex LiquidityPool (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	// Storage: StableAsset Pools (r:1 w:0)
+	// Proof Skipped: StableAsset Pools (max_values: None, max_size: None, mode: Measured)
+	// Storage: AggregatedDex AggregatedSwapPaths (r:1 w:0)
+	// Proof Skipped: AggregatedDex AggregatedSwapPaths (max_values: None, max_size: None, mode: Measured)
+	// Storage: CdpEngine LiquidationContracts (r:1 w:0)
+	// Proof: CdpEngine LiquidationContracts (max_values: Some(1), max_size: Some(201), added: 696, mode: MaxEncodedLen)
+	// Storage: CdpTreasury ExpectedCollateralAuctionSize (r:1 w:0)
+	// Proof: CdpTreasury ExpectedCollateralAuctionSize (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: AuctionManager TotalTargetInAuction (r:1 w:1)
+	// Proof: AuctionManager TotalTargetInAuction (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Auction AuctionsIndex (r:1 w:1)
+	// Proof: Auction AuctionsIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: AuctionManager CollateralAuctions (r:0 w:50)
+	// Proof: AuctionManager CollateralAuctions (max_values: None, max_size: Some(139), added: 2614, mode: MaxEncodedLen)
+	// Storage: Auction AuctionEndTime (r:0 w:50)
+	// Proof: Auction AuctionEndTime (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	// Storage: Auction Auctions (r:0 w:50)
+	// Proof: Auction Auctions (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	// Storage: CdpEngine LiquidationKeeperRewards (r:1 w:0)
+	// Proof: CdpEngine LiquidationKeeperRewards (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 50]`.
+	fn liquidate_by_keeper(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3712`
+		//  Estimated: `9524`
+		// Minimum execution time: 224_518 nanoseconds.
+		Weight::from_parts(227_604_113, 9524)
+			// Standard Error: 35_120
+			.saturating_add(Weight::from_parts(12_802_331, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(31))
+			.saturating_add(T::DbWeight::get().writes(17))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+	}
+	// Storage: CdpEngine LiquidationContractStats (r:1 w:1)
+	// Proof: CdpEngine LiquidationContractStats (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	// Storage: CdpEngine LiquidationContractExposureCaps (r:0 w:1)
+	// Proof: CdpEngine LiquidationContractExposureCaps (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn set_liquidation_contract_exposure_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1039`
+		//  Estimated: `3537`
+		// Minimum execution time: 24_127 nanoseconds.
+		Weight::from_parts(24_815_000, 3537)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -50,25 +50,29 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	// Storage: Dex ActiveTradingPairCount (r:2 w:2)
+	// Proof: Dex ActiveTradingPairCount (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
 	fn enable_trading_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1180`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 23_677 nanoseconds.
-		Weight::from_parts(24_388_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(24_388_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	// Storage: Dex ActiveTradingPairCount (r:2 w:2)
+	// Proof: Dex ActiveTradingPairCount (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
 	fn disable_trading_pair() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1180`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 23_665 nanoseconds.
-		Weight::from_parts(24_300_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(24_300_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
@@ -76,14 +80,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	// Storage: Dex ProvisioningPool (r:1 w:0)
 	// Proof: Dex ProvisioningPool (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	// Storage: Dex ActiveTradingPairCount (r:2 w:2)
+	// Proof: Dex ActiveTradingPairCount (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
 	fn list_provisioning() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1340`
-		//  Estimated: `10823`
+		//  Estimated: `15867`
 		// Minimum execution time: 34_816 nanoseconds.
-		Weight::from_parts(35_943_000, 10823)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(35_943_000, 15867)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
@@ -308,13 +314,15 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Proof: Dex TradingPairStatuses (max_values: None, max_size: Some(195), added: 2670, mode: MaxEncodedLen)
+	// Storage: Dex ActiveTradingPairCount (r:2 w:2)
+	// Proof: Dex ActiveTradingPairCount (max_values: None, max_size: Some(47), added: 2522, mode: MaxEncodedLen)
 	fn abort_provisioning() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1318`
-		//  Estimated: `3660`
+		//  Estimated: `8704`
 		// Minimum execution time: 29_131 nanoseconds.
-		Weight::from_parts(30_190_000, 3660)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+		Weight::from_parts(30_190_000, 8704)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 }
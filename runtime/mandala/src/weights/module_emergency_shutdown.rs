@@ -115,4 +115,71 @@ impl<T: frame_system::Config> module_emergency_shutdown::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2043).saturating_mul(c.into()))
 	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: CdpEngine CollateralParams (r:4 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: EmergencyShutdown CollateralShutdowns (r:1 w:1)
+	// Proof: EmergencyShutdown CollateralShutdowns (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	// Storage: AcalaOracle Values (r:1 w:0)
+	// Proof: AcalaOracle Values (max_values: None, max_size: Some(75), added: 2550, mode: MaxEncodedLen)
+	// Storage: Prices LockedPrice (r:0 w:1)
+	// Proof: Prices LockedPrice (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: Loans TotalPositions (r:1 w:0)
+	// Proof: Loans TotalPositions (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
+	// Proof: CdpEngine DebitExchangeRate (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: EmergencyShutdown CollateralRefundPools (r:0 w:1)
+	// Proof: EmergencyShutdown CollateralRefundPools (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn shutdown_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1838`
+		//  Estimated: `16985`
+		// Minimum execution time: 36_412 nanoseconds.
+		Weight::from_parts(37_105_000, 16985)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: EmergencyShutdown CollateralShutdowns (r:1 w:1)
+	// Proof: EmergencyShutdown CollateralShutdowns (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	// Storage: AuctionManager TotalCollateralInAuction (r:1 w:0)
+	// Proof: AuctionManager TotalCollateralInAuction (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Loans TotalPositions (r:1 w:0)
+	// Proof: Loans TotalPositions (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: EmergencyShutdown CollateralRefundPools (r:1 w:1)
+	// Proof: EmergencyShutdown CollateralRefundPools (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn open_collateral_pool_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024`
+		//  Estimated: `3548`
+		// Minimum execution time: 19_631 nanoseconds.
+		Weight::from_parts(20_114_000, 3548)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: EmergencyShutdown CollateralShutdowns (r:1 w:0)
+	// Proof: EmergencyShutdown CollateralShutdowns (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	// Storage: Prices LockedPrice (r:1 w:0)
+	// Proof: Prices LockedPrice (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: EmergencyShutdown CollateralRefundPools (r:1 w:1)
+	// Proof: EmergencyShutdown CollateralRefundPools (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn refund_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2455`
+		//  Estimated: `8856`
+		// Minimum execution time: 61_208 nanoseconds.
+		Weight::from_parts(62_530_000, 8856)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
@@ -0,0 +1,84 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_flash_mint
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-05-15, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-43-79`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/production/acala
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=*
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./templates/runtime-weight-template.hbs
+// --output=./runtime/mandala/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_flash_mint.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_flash_mint::WeightInfo for WeightInfo<T> {
+	// Storage: FlashMint MaxFlashMint (r:0 w:1)
+	// Proof: FlashMint MaxFlashMint (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: FlashMint FeeRate (r:0 w:1)
+	// Proof: FlashMint FeeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_flash_mint_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001`
+		//  Estimated: `0`
+		// Minimum execution time: 14_900 nanoseconds.
+		Weight::from_parts(15_283_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: FlashMint OutstandingFlashMint (r:1 w:1)
+	// Proof: FlashMint OutstandingFlashMint (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: FlashMint MaxFlashMint (r:1 w:0)
+	// Proof: FlashMint MaxFlashMint (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: FlashMint FeeRate (r:1 w:0)
+	// Proof: FlashMint FeeRate (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	fn flash_mint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2254`
+		//  Estimated: `9815`
+		// Minimum execution time: 63_101 nanoseconds.
+		Weight::from_parts(64_719_000, 9815)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
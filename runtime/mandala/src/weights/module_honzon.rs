@@ -98,10 +98,12 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
 	// Storage: Loans Positions (r:1 w:1)
 	// Proof: Loans Positions (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
-	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:2 w:2)
 	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
-	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:2 w:2)
 	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives CollateralDexShares (r:1 w:1)
+	// Proof Skipped: Incentives CollateralDexShares (max_values: None, max_size: None, mode: Measured)
 	// Storage: Loans TotalPositions (r:1 w:1)
 	// Proof: Loans TotalPositions (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -112,6 +114,8 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
 	// Storage: Tokens TotalIssuance (r:1 w:1)
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Dex LiquidityPool (r:1 w:0)
+	// Proof: Dex LiquidityPool (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
 	// Storage: Prices LockedPrice (r:2 w:0)
 	// Proof: Prices LockedPrice (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
 	// Storage: AcalaOracle Values (r:1 w:0)
@@ -120,12 +124,12 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 	// Proof Skipped: AssetRegistry AssetMetadatas (max_values: None, max_size: None, mode: Measured)
 	fn adjust_loan() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `3388`
-		//  Estimated: `59139`
-		// Minimum execution time: 133_347 nanoseconds.
-		Weight::from_parts(137_731_000, 59139)
-			.saturating_add(T::DbWeight::get().reads(15))
-			.saturating_add(T::DbWeight::get().writes(7))
+		//  Measured:  `3839`
+		//  Estimated: `70446`
+		// Minimum execution time: 162_683 nanoseconds.
+		Weight::from_parts(168_031_000, 70446)
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
 	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
@@ -358,4 +362,198 @@ impl<T: frame_system::Config> module_honzon::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(46_423_000, 39017)
 			.saturating_add(T::DbWeight::get().reads(11))
 	}
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: Honzon ProtectionRules (r:1 w:1)
+	// Proof: Honzon ProtectionRules (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	fn set_protection_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502`
+		//  Estimated: `3633`
+		// Minimum execution time: 40_113 nanoseconds.
+		Weight::from_parts(41_020_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Honzon ProtectionRules (r:1 w:1)
+	// Proof: Honzon ProtectionRules (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	fn remove_protection_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1688`
+		//  Estimated: `3633`
+		// Minimum execution time: 38_342 nanoseconds.
+		Weight::from_parts(39_105_000, 3633)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Honzon ProtectionRules (r:1 w:1)
+	// Proof: Honzon ProtectionRules (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	// Storage: Loans Positions (r:1 w:1)
+	// Proof: Loans Positions (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn execute_protection_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5634`
+		//  Estimated: `21966`
+		// Minimum execution time: 405_817 nanoseconds.
+		Weight::from_parts(412_306_000, 21966)
+			.saturating_add(T::DbWeight::get().reads(42))
+			.saturating_add(T::DbWeight::get().writes(19))
+	}
+	// Storage: Honzon WrappedPositions (r:1 w:0)
+	// Proof: Honzon WrappedPositions (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Honzon PositionClassId (r:0 w:1)
+	// Proof: Honzon PositionClassId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_position_class() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `4508`
+		// Minimum execution time: 23_874_000 nanoseconds.
+		Weight::from_parts(24_512_000, 4508)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Honzon PositionClassId (r:1 w:0)
+	// Proof: Honzon PositionClassId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Loans Positions (r:2 w:2)
+	// Proof: Loans Positions (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OrmlNFT NextTokenId (r:1 w:1)
+	// Proof Skipped: OrmlNFT NextTokenId (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Tokens (r:0 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:1)
+	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Loans TotalPositions (r:1 w:0)
+	// Proof: Loans TotalPositions (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Honzon WrappedPositions (r:0 w:1)
+	// Proof: Honzon WrappedPositions (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	fn wrap_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3287`
+		//  Estimated: `9303`
+		// Minimum execution time: 148_214_000 nanoseconds.
+		Weight::from_parts(151_876_000, 9303)
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	// Storage: Honzon PositionClassId (r:1 w:0)
+	// Proof: Honzon PositionClassId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Honzon WrappedPositions (r:1 w:1)
+	// Proof: Honzon WrappedPositions (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Loans Positions (r:2 w:2)
+	// Proof: Loans Positions (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:0)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:1)
+	// Proof Skipped: OrmlNFT TokensByOwner (max_values: None, max_size: None, mode: Measured)
+	// Storage: Loans TotalPositions (r:1 w:0)
+	// Proof: Loans TotalPositions (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: Prices LockedPrice (r:2 w:0)
+	// Proof: Prices LockedPrice (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives PendingMultiRewards (r:1 w:1)
+	// Proof Skipped: Incentives PendingMultiRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives ClaimRewardDeductionRates (r:1 w:0)
+	// Proof Skipped: Incentives ClaimRewardDeductionRates (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives ClaimRewardDeductionCurrency (r:1 w:0)
+	// Proof Skipped: Incentives ClaimRewardDeductionCurrency (max_values: None, max_size: None, mode: Measured)
+	fn unwrap_position() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4860`
+		//  Estimated: `9303`
+		// Minimum execution time: 262_118_000 nanoseconds.
+		Weight::from_parts(268_412_000, 9303)
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	// Storage: Honzon PositionClassId (r:1 w:0)
+	// Proof: Honzon PositionClassId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Honzon WrappedPositions (r:1 w:0)
+	// Proof: Honzon WrappedPositions (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: EmergencyShutdown IsShutdown (r:1 w:0)
+	// Proof: EmergencyShutdown IsShutdown (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Loans Positions (r:1 w:1)
+	// Proof: Loans Positions (max_values: None, max_size: Some(123), added: 2598, mode: MaxEncodedLen)
+	// Storage: Loans TotalPositions (r:1 w:1)
+	// Proof: Loans TotalPositions (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: CdpEngine CollateralParams (r:1 w:0)
+	// Proof: CdpEngine CollateralParams (max_values: None, max_size: Some(135), added: 2610, mode: MaxEncodedLen)
+	// Storage: CdpEngine DebitExchangeRate (r:1 w:0)
+	// Proof: CdpEngine DebitExchangeRate (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Prices LockedPrice (r:2 w:0)
+	// Proof: Prices LockedPrice (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	fn adjust_wrapped_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4388`
+		//  Estimated: `11214`
+		// Minimum execution time: 181_407_000 nanoseconds.
+		Weight::from_parts(185_632_000, 11214)
+			.saturating_add(T::DbWeight::get().reads(24))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	// Storage: Honzon PositionClassId (r:1 w:0)
+	// Proof: Honzon PositionClassId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Honzon WrappedPositions (r:1 w:0)
+	// Proof: Honzon WrappedPositions (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives PendingMultiRewards (r:1 w:1)
+	// Proof Skipped: Incentives PendingMultiRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives ClaimRewardDeductionRates (r:1 w:0)
+	// Proof Skipped: Incentives ClaimRewardDeductionRates (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives ClaimRewardDeductionCurrency (r:1 w:0)
+	// Proof Skipped: Incentives ClaimRewardDeductionCurrency (max_values: None, max_size: None, mode: Measured)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_wrapped_position_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120`
+		//  Estimated: `6098`
+		// Minimum execution time: 128_337_000 nanoseconds.
+		Weight::from_parts(131_204_000, 6098)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
@@ -90,6 +90,8 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
 	// Proof Skipped: Rewards SharesAndWithdrawnRewards (max_values: None, max_size: None, mode: Measured)
+	// Storage: Incentives CollateralDexShares (r:1 w:0)
+	// Proof Skipped: Incentives CollateralDexShares (max_values: None, max_size: None, mode: Measured)
 	// Storage: Tokens Accounts (r:2 w:2)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:0)
@@ -98,11 +100,11 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 	// Proof Skipped: Rewards PoolInfos (max_values: None, max_size: None, mode: Measured)
 	fn withdraw_dex_share() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2909`
-		//  Estimated: `22575`
-		// Minimum execution time: 76_238 nanoseconds.
-		Weight::from_parts(80_096_000, 22575)
-			.saturating_add(T::DbWeight::get().reads(5))
+		//  Measured:  `2984`
+		//  Estimated: `25880`
+		// Minimum execution time: 78_906 nanoseconds.
+		Weight::from_parts(82_899_000, 25880)
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
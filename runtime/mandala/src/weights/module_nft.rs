@@ -186,4 +186,49 @@ impl<T: frame_system::Config> module_nft::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	fn update_class_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2339`
+		//  Estimated: `5804`
+		// Minimum execution time: 20_536 nanoseconds.
+		Weight::from_parts(20_936_000, 5804)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_token_attributes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3624`
+		//  Estimated: `16354`
+		// Minimum execution time: 48_127 nanoseconds.
+		Weight::from_parts(49_015_000, 16354)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Proof Skipped: OrmlNFT Classes (max_values: None, max_size: None, mode: Measured)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Proof Skipped: OrmlNFT Tokens (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn remove_token_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3624`
+		//  Estimated: `13751`
+		// Minimum execution time: 38_562 nanoseconds.
+		Weight::from_parts(39_310_000, 13751)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -0,0 +1,136 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2023 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for module_vesting
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-05-15, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! HOSTNAME: `ip-172-31-43-79`, CPU: `Intel(R) Xeon(R) Platinum 8375C CPU @ 2.90GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/production/acala
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=*
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=./templates/runtime-weight-template.hbs
+// --output=./runtime/mandala/src/weights/
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_vesting.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_vesting::WeightInfo for WeightInfo<T> {
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: MultiCurrencyVesting VestingSchedules (r:1 w:1)
+	// Proof: MultiCurrencyVesting VestingSchedules (max_values: None, max_size: Some(2901), added: 5376, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
+	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1300), added: 3775, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn vested_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8642`
+		//  Estimated: `22008`
+		// Minimum execution time: 89_093 nanoseconds.
+		Weight::from_parts(91_378_000, 22008)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: MultiCurrencyVesting VestingSchedules (r:1 w:1)
+	// Proof: MultiCurrencyVesting VestingSchedules (max_values: None, max_size: Some(2901), added: 5376, mode: MaxEncodedLen)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1300), added: 3775, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 100]`.
+	fn claim(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2176 + i * (44 ±0)`
+		//  Estimated: `14248`
+		// Minimum execution time: 38_233 nanoseconds.
+		Weight::from_parts(39_214_000, 14248)
+			// Standard Error: 13_860
+			.saturating_add(Weight::from_parts(198_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: MultiCurrencyVesting VestingSchedules (r:1 w:1)
+	// Proof: MultiCurrencyVesting VestingSchedules (max_values: None, max_size: Some(2901), added: 5376, mode: MaxEncodedLen)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1300), added: 3775, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	/// The range of component `i` is `[2, 100]`.
+	fn merge_schedules(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2220 + i * (44 ±0)`
+		//  Estimated: `14248`
+		// Minimum execution time: 47_346 nanoseconds.
+		Weight::from_parts(48_560_000, 14248)
+			// Standard Error: 18_270
+			.saturating_add(Weight::from_parts(261_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: MultiCurrencyVesting VestingSchedules (r:1 w:1)
+	// Proof: MultiCurrencyVesting VestingSchedules (max_values: None, max_size: Some(2901), added: 5376, mode: MaxEncodedLen)
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Proof: Tokens Locks (max_values: None, max_size: Some(1300), added: 3775, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(147), added: 2622, mode: MaxEncodedLen)
+	// Storage: EvmAccounts EvmAddresses (r:1 w:0)
+	// Proof: EvmAccounts EvmAddresses (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 100]`.
+	fn revoke(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2402 + i * (44 ±0)`
+		//  Estimated: `22008`
+		// Minimum execution time: 75_957 nanoseconds.
+		Weight::from_parts(77_905_000, 22008)
+			// Standard Error: 9_940
+			.saturating_add(Weight::from_parts(142_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+}
@@ -17,10 +17,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{
-	constants::fee::*, AccountId, AllPalletsWithSystem, AssetIdMapping, AssetIdMaps, Balance, Balances, Convert,
-	Currencies, CurrencyId, EvmAddressMapping, ExistentialDeposits, GetNativeCurrencyId, NativeTokenExistentialDeposit,
-	ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, TreasuryAccount,
-	UnknownTokens, XcmpQueue, ACA,
+	constants::fee::*, AcalaSwap, AccountId, AllPalletsWithSystem, AssetIdMapping, AssetIdMaps, Balance, Balances,
+	Convert, Currencies, CurrencyId, EvmAddressMapping, ExistentialDeposits, GetNativeCurrencyId,
+	NativeTokenExistentialDeposit, ParachainInfo, ParachainSystem, PolkadotXcm, Ratio, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, TreasuryAccount, UnknownTokens, XcmpQueue, ACA,
 };
 pub use cumulus_primitives_core::ParaId;
 pub use frame_support::{
@@ -28,8 +28,11 @@ pub use frame_support::{
 	traits::{ConstU32, Everything, Get, Nothing},
 	weights::Weight,
 };
-use module_asset_registry::{BuyWeightRateOfErc20, BuyWeightRateOfForeignAsset, BuyWeightRateOfStableAsset};
+use module_asset_registry::{
+	BuyWeightRateOfDexPrice, BuyWeightRateOfErc20, BuyWeightRateOfForeignAsset, BuyWeightRateOfStableAsset,
+};
 use module_transaction_payment::BuyWeightRateOfTransactionFeePool;
+use module_xcm_rate_limiter::RateLimitedTransactor;
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key, MultiCurrency};
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiCurrencyAdapter, MultiNativeAsset};
 use pallet_xcm::XcmPassthrough;
@@ -39,8 +42,9 @@ use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use primitives::evm::is_system_contract;
 use runtime_common::{
 	local_currency_location, native_currency_location, xcm_impl::AccountKey20Aliases, AcalaDropAssets,
-	EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
+	DexAssetExchange, EnsureRootOrHalfGeneralCouncil, EnsureRootOrThreeFourthsGeneralCouncil, FixedRateOfAsset,
 };
+use sp_runtime::FixedPointNumber;
 use xcm::{prelude::*, v3::Weight as XcmWeight};
 pub use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
@@ -134,6 +138,7 @@ parameter_types! {
 		0
 	);
 	pub BaseRate: u128 = aca_per_second();
+	pub XcmFeeSafetyMargin: Ratio = Ratio::saturating_from_rational(10, 100);
 }
 
 pub type Trader = (
@@ -143,6 +148,17 @@ pub type Trader = (
 	FixedRateOfAsset<BaseRate, ToTreasury, BuyWeightRateOfForeignAsset<Runtime>>,
 	FixedRateOfAsset<BaseRate, ToTreasury, BuyWeightRateOfErc20<Runtime>>,
 	FixedRateOfAsset<BaseRate, ToTreasury, BuyWeightRateOfStableAsset<Runtime>>,
+	FixedRateOfAsset<
+		BaseRate,
+		ToTreasury,
+		BuyWeightRateOfDexPrice<
+			Runtime,
+			CurrencyIdConvert,
+			module_dex_oracle::AverageDEXPriceProvider<Runtime>,
+			GetNativeCurrencyId,
+			XcmFeeSafetyMargin,
+		>,
+	>,
 );
 
 pub struct XcmConfig;
@@ -170,7 +186,7 @@ impl xcm_executor::Config for XcmConfig {
 		ExistentialDeposits,
 	>;
 	type AssetLocker = ();
-	type AssetExchanger = ();
+	type AssetExchanger = DexAssetExchange<AccountId, TreasuryAccount, CurrencyIdConvert, Currencies, AcalaSwap>;
 	type AssetClaims = ();
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
@@ -259,15 +275,20 @@ impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type ExecuteOverweightOrigin = EnsureRootOrHalfGeneralCouncil;
 }
 
-pub type LocalAssetTransactor = MultiCurrencyAdapter<
-	Currencies,
-	UnknownTokens,
-	IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-	AccountId,
-	LocationToAccountId,
-	CurrencyId,
+pub type LocalAssetTransactor = RateLimitedTransactor<
+	Runtime,
+	MultiCurrencyAdapter<
+		Currencies,
+		UnknownTokens,
+		IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
+		AccountId,
+		LocationToAccountId,
+		CurrencyId,
+		CurrencyIdConvert,
+		DepositToAlternative<TreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	>,
 	CurrencyIdConvert,
-	DepositToAlternative<TreasuryAccount, Currencies, CurrencyId, AccountId, Balance>,
+	LocationToAccountId,
 >;
 
 pub struct CurrencyIdConvert;